@@ -0,0 +1,46 @@
+//! Compares BFS performance of the default per-word adjacency lists against
+//! the compact CSR layout produced by `WordGraph::compact()`.
+//!
+//! Run with `cargo bench --features bench --bench adjacency`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use wordladder_engine::graph::WordGraph;
+
+/// Word pairs spanning short, medium, and long ladders in the bundled dictionary.
+const PAIRS: &[(&str, &str)] = &[
+    ("cat", "dog"),
+    ("cold", "warm"),
+    ("earth", "goals"),
+    ("shirt", "board"),
+];
+
+fn bench_adjacency_layouts(c: &mut Criterion) {
+    let mut lists = WordGraph::new();
+    lists
+        .load_dictionary("data/dictionary.txt")
+        .expect("bundled dictionary should load");
+    lists.build_all();
+    let mut csr = lists.clone();
+    csr.compact();
+
+    let mut group = c.benchmark_group("bfs_layout");
+    group.bench_function("lists", |b| {
+        b.iter(|| {
+            for (start, end) in PAIRS {
+                black_box(lists.find_shortest_path(start, end));
+            }
+        })
+    });
+    group.bench_function("csr", |b| {
+        b.iter(|| {
+            for (start, end) in PAIRS {
+                black_box(csr.find_shortest_path(start, end));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_adjacency_layouts);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+//! End-to-end benchmark suite for the engine against the bundled dictionary.
+//!
+//! Covers graph construction, single-pair BFS, bidirectional BFS, batch
+//! generation throughput, and SQL export throughput.
+//!
+//! Run with `cargo bench --features bench --bench engine`.
+
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use wordladder_engine::exporters::sql::SqlExporter;
+use wordladder_engine::graph::WordGraph;
+use wordladder_engine::puzzle::{Difficulty, PuzzleGenerator};
+
+const DICTIONARY: &str = "data/dictionary.txt";
+const BASE_WORDS: &str = "data/base_words.txt";
+
+/// Word pairs spanning short, medium, and long ladders in the bundled dictionary.
+const PAIRS: &[(&str, &str)] = &[
+    ("cat", "dog"),
+    ("cold", "warm"),
+    ("earth", "goals"),
+    ("shirt", "board"),
+];
+
+fn load_graph() -> WordGraph {
+    let mut graph = WordGraph::new();
+    graph
+        .load_dictionary(DICTIONARY)
+        .expect("bundled dictionary should load");
+    graph
+        .load_base_words(BASE_WORDS)
+        .expect("bundled base words should load");
+    graph
+}
+
+fn bench_graph_build(c: &mut Criterion) {
+    let content = std::fs::read_to_string(DICTIONARY).expect("bundled dictionary should load");
+
+    let mut group = c.benchmark_group("graph_build");
+    group.sample_size(10);
+    group.bench_function("load_dictionary", |b| {
+        b.iter(|| {
+            let mut graph = WordGraph::new();
+            graph.load_dictionary_from_str(black_box(&content));
+            graph
+        })
+    });
+    group.bench_function("build_all", |b| {
+        b.iter(|| {
+            let mut graph = WordGraph::new();
+            graph.load_dictionary_from_str(black_box(&content));
+            graph.build_all();
+            graph
+        })
+    });
+    group.finish();
+}
+
+fn bench_solvers(c: &mut Criterion) {
+    let graph = load_graph();
+
+    let mut group = c.benchmark_group("solve");
+    group.throughput(Throughput::Elements(PAIRS.len() as u64));
+    group.bench_function("bfs", |b| {
+        b.iter(|| {
+            for (start, end) in PAIRS {
+                black_box(graph.find_shortest_path(start, end));
+            }
+        })
+    });
+    group.bench_function("bidirectional_bfs", |b| {
+        b.iter(|| {
+            for (start, end) in PAIRS {
+                black_box(graph.find_shortest_path_bidirectional(start, end));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_batch_generation(c: &mut Criterion) {
+    let generator = PuzzleGenerator::new(load_graph());
+    let count = 50;
+
+    let mut group = c.benchmark_group("generate_batch");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(count as u64));
+    for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+        group.bench_function(format!("{:?}", difficulty).to_lowercase(), |b| {
+            b.iter(|| generator.generate_batch(count, difficulty))
+        });
+    }
+    group.finish();
+}
+
+fn bench_sql_export(c: &mut Criterion) {
+    let generator = PuzzleGenerator::new(load_graph());
+    let puzzles = generator.generate_batch(1000, Difficulty::Easy).puzzles;
+
+    let mut group = c.benchmark_group("sql_export");
+    group.throughput(Throughput::Elements(puzzles.len() as u64));
+    group.bench_function("export_puzzles", |b| {
+        b.iter_batched(
+            SqlExporter::new,
+            |mut exporter| exporter.export_puzzles(black_box(&puzzles)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_graph_build,
+    bench_solvers,
+    bench_batch_generation,
+    bench_sql_export
+);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+//! Compiles the gRPC service definition when the `grpc` feature is enabled.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto");
+    #[cfg(feature = "grpc")]
+    tonic_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/wordladder_service.proto"], &["proto"])?;
+    Ok(())
+}
@@ -0,0 +1,4923 @@
+//! # Word Graph Implementation
+//!
+//! This module implements the core word graph data structure and BFS algorithm
+//! for finding shortest paths between words in word ladder puzzles.
+//!
+//! ## Architecture
+//!
+//! The `WordGraph` interns every dictionary word into a `Vec<String>` and refers
+//! to words by their `u32` index internally. The adjacency list is a
+//! `Vec<Vec<u32>>` indexed by word ID, so each word is stored once no matter how
+//! many neighbors it has, and BFS works on dense integer IDs instead of hashing
+//! strings. The public API remains string-based.
+//!
+//! Words are interned ordered by length, and the adjacency is split into one
+//! subgraph per word length. Each subgraph is built lazily the first time a
+//! word of that length is queried, so loading a dictionary is cheap and a
+//! caller that only asks for 4-letter ladders never pays for the other lengths.
+//! Edge rules that connect different lengths (`InsertOrDeleteOne`) use a single
+//! subgraph covering the whole dictionary. `WordGraph::build_all()` builds every
+//! subgraph up front for long-running services. `WordGraph::add_word()` and
+//! `WordGraph::remove_word()` patch a single subgraph in place instead of
+//! rebuilding the graph.
+//!
+//! Callers that repeat the same queries (daemons, batch generation) can wrap
+//! searches in `cache::CachedSolver`, a bounded LRU cache keyed by word pair.
+//!
+//! For very large dictionaries, `landmarks::Landmarks` precomputes distances
+//! from a few landmark words per length class. With landmarks set,
+//! `find_shortest_path` runs A* guided by their triangle-inequality bounds
+//! instead of BFS.
+//!
+//! After loading, `WordGraph::compact()` can convert the adjacency list into a
+//! compressed sparse row (CSR) layout: one contiguous neighbor array plus an
+//! offset per word. This removes the per-word allocations and keeps neighbor
+//! lists adjacent in memory, which helps BFS on very large dictionaries.
+//!
+//! ## Key Components
+//!
+//! - **Dictionary Words**: Full set of valid words for path finding
+//! - **Base Words**: Curated words used as puzzle start/end points
+//! - **Accepted Words**: Extra words players may use in their own ladders
+//! - **Interned Words**: Word ID -> word table plus a word -> ID lookup
+//! - **Adjacency Graph**: Maps each word ID to the IDs of its valid neighbors
+//! - **BFS Algorithm**: Finds shortest paths between any two words
+//!
+//! ## Edge Rules
+//!
+//! Which words are connected is controlled by a set of `EdgeRule`s, so the same
+//! engine can serve several game modes. The default is classic word ladder
+//! (`SubstituteOne`); word golf variants can add `InsertOrDeleteOne` (which
+//! connects words of different lengths), `SwapAdjacent`, or `Anagram` (which
+//! connects words made of the same letters, as in legacy word golf). A pair of words is
+//! connected if any of the graph's rules connects them.
+//!
+//! ## Word Normalization
+//!
+//! Every word entering the graph is trimmed and normalized by the graph's
+//! `WordNormalization`: letter case is mapped by a `CaseMapping` (Unicode
+//! lowercasing by default, Turkic lowercasing, or none), and apostrophes and
+//! hyphens are rejected, stripped, or kept by a `PunctuationPolicy`. Words
+//! with any other non-letter character are skipped. Input typed by players
+//! should go through `WordNormalization::normalize_query` so lookups agree
+//! with the loaded dictionary.
+//!
+//! ## Comparison Units
+//!
+//! A ladder step changes one letter, where a letter is a `char` by default.
+//! Scripts that write one letter with several code points (Devanagari vowel
+//! signs, letters with combining accents) can switch the graph's
+//! `ComparisonUnit` to grapheme clusters: words are then compared and measured
+//! grapheme by grapheme, and neighbor generation substitutes or inserts any
+//! grapheme the dictionary uses.
+//!
+//! ## Dictionary Sources
+//!
+//! A dictionary can be assembled from several tagged files (for example a
+//! tournament word list, a frequency list, and a custom allowlist) with
+//! `load_dictionary_tagged`. The tagged sources are combined by the graph's
+//! `MergePolicy`, and `word_sources` reports which sources contain a word.
+//!
+//! ## Performance
+//!
+//! - **Space Complexity**: O(V + E) where V is words, E is word relationships
+//! - **Time Complexity**: O(V + E) for BFS, O(1) average for neighbor lookup
+//! - **Optimized for**: Dictionaries with 15k-25k words
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use wordladder_core::graph::WordGraph;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt")?;
+//! graph.load_base_words("data/base_words.txt")?;
+//!
+//! // Find shortest path
+//! if let Some(path) = graph.find_shortest_path("cat", "dog") {
+//!     println!("Path: {:?}", path);
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+pub mod cache;
+pub mod landmarks;
+
+use crate::graph::landmarks::Landmarks;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+#[cfg(feature = "fs")]
+use std::fs;
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The alphabet used when generating candidate neighbors.
+const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// The unit standing for any letter in a `PatternIndex` key; never a letter
+/// of a dictionary word.
+const WILDCARD: &str = "*";
+
+/// A rule deciding whether two words are one step apart in a ladder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeRule {
+    /// Change exactly one letter (`cat` -> `cot`)
+    SubstituteOne,
+    /// Insert or delete exactly one letter (`cat` -> `cart`)
+    InsertOrDeleteOne,
+    /// Swap two adjacent letters (`form` -> `from`)
+    SwapAdjacent,
+    /// Rearrange the letters into any other word (`stop` -> `pots`)
+    Anagram,
+}
+
+impl EdgeRule {
+    /// Returns `true` if this rule connects `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::EdgeRule;
+    ///
+    /// assert!(EdgeRule::SubstituteOne.connects("cat", "cot"));
+    /// assert!(EdgeRule::InsertOrDeleteOne.connects("cart", "cat"));
+    /// assert!(EdgeRule::SwapAdjacent.connects("form", "from"));
+    /// assert!(!EdgeRule::SwapAdjacent.connects("cat", "cot"));
+    /// ```
+    pub fn connects(&self, a: &str, b: &str) -> bool {
+        // ASCII words are compared byte by byte without collecting chars,
+        // which keeps verification of large puzzle sets allocation-free
+        if a.is_ascii() && b.is_ascii() {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            return match self {
+                EdgeRule::SubstituteOne => a.len() == b.len() && count_mismatches(a, b, 2) == 1,
+                _ => self.connects_letters(a, b),
+            };
+        }
+
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        self.connects_letters(&a, &b)
+    }
+
+    /// Returns `true` if this rule connects `a` and `b`, comparing them in
+    /// the given units.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{ComparisonUnit, EdgeRule};
+    ///
+    /// // "é" written as "e" followed by a combining acute accent
+    /// let cafe = "cafe\u{301}";
+    /// assert!(!EdgeRule::SubstituteOne.connects_with(ComparisonUnit::Char, "cafa", cafe));
+    /// assert!(EdgeRule::SubstituteOne.connects_with(ComparisonUnit::Grapheme, "cafa", cafe));
+    /// ```
+    pub fn connects_with(&self, unit: ComparisonUnit, a: &str, b: &str) -> bool {
+        match unit {
+            ComparisonUnit::Char => self.connects(a, b),
+            ComparisonUnit::Grapheme => self.connects_letters(&unit.units(a), &unit.units(b)),
+        }
+    }
+
+    /// Returns `true` if this rule connects two words given as letters
+    /// (bytes of ASCII words, chars, or graphemes).
+    fn connects_letters<T: Copy + Ord>(&self, a: &[T], b: &[T]) -> bool {
+        match self {
+            EdgeRule::SubstituteOne => {
+                a.len() == b.len() && a.iter().zip(b).filter(|(x, y)| x != y).count() == 1
+            }
+            EdgeRule::InsertOrDeleteOne => {
+                let (short, long) = if a.len() < b.len() { (a, b) } else { (b, a) };
+                if long.len() != short.len() + 1 {
+                    return false;
+                }
+                let prefix = short
+                    .iter()
+                    .zip(long.iter())
+                    .take_while(|(x, y)| x == y)
+                    .count();
+                short[prefix..] == long[prefix + 1..]
+            }
+            EdgeRule::SwapAdjacent => {
+                if a.len() != b.len() {
+                    return false;
+                }
+                let diffs: Vec<usize> = (0..a.len()).filter(|&i| a[i] != b[i]).collect();
+                diffs.len() == 2
+                    && diffs[1] == diffs[0] + 1
+                    && a[diffs[0]] == b[diffs[1]]
+                    && a[diffs[1]] == b[diffs[0]]
+            }
+            EdgeRule::Anagram => a != b && anagram_key(a) == anagram_key(b),
+        }
+    }
+
+    /// Returns `true` if this rule connects words of different lengths.
+    pub fn changes_length(&self) -> bool {
+        matches!(self, EdgeRule::InsertOrDeleteOne)
+    }
+
+    /// Returns every string one step from `word` under this rule.
+    ///
+    /// Candidates are not checked against the dictionary and may repeat.
+    /// `Anagram` produces no candidates because enumerating permutations is
+    /// impractical; its neighbors come from the anagram index instead.
+    fn candidates(&self, word: &[char]) -> Vec<String> {
+        let alphabet: Vec<char> = ALPHABET.iter().map(|&c| c as char).collect();
+        self.candidates_from(word, &alphabet)
+    }
+
+    /// Returns every string one step from `word`, given as units, under this
+    /// rule, substituting and inserting the units of `alphabet`.
+    fn candidates_from<T: Copy + PartialEq>(&self, word: &[T], alphabet: &[T]) -> Vec<String>
+    where
+        String: FromIterator<T>,
+    {
+        let mut candidates = Vec::new();
+        match self {
+            EdgeRule::SubstituteOne => {
+                for i in 0..word.len() {
+                    for &c in alphabet {
+                        if c != word[i] {
+                            let mut candidate = word.to_vec();
+                            candidate[i] = c;
+                            candidates.push(candidate.into_iter().collect());
+                        }
+                    }
+                }
+            }
+            EdgeRule::InsertOrDeleteOne => {
+                for i in 0..=word.len() {
+                    for &c in alphabet {
+                        let mut candidate = word.to_vec();
+                        candidate.insert(i, c);
+                        candidates.push(candidate.into_iter().collect());
+                    }
+                }
+                for i in 0..word.len() {
+                    let mut candidate = word.to_vec();
+                    candidate.remove(i);
+                    if !candidate.is_empty() {
+                        candidates.push(candidate.into_iter().collect());
+                    }
+                }
+            }
+            EdgeRule::SwapAdjacent => {
+                for i in 1..word.len() {
+                    if word[i - 1] != word[i] {
+                        let mut candidate = word.to_vec();
+                        candidate.swap(i - 1, i);
+                        candidates.push(candidate.into_iter().collect());
+                    }
+                }
+            }
+            EdgeRule::Anagram => {}
+        }
+        candidates
+    }
+
+    /// Calls `visit` with every ASCII string one step from an ASCII word.
+    ///
+    /// Produces the same candidates as `candidates`, but builds each one in
+    /// `buffer` instead of allocating a string per candidate.
+    fn visit_ascii_candidates(
+        &self,
+        word: &[u8],
+        buffer: &mut Vec<u8>,
+        mut visit: impl FnMut(&[u8]),
+    ) {
+        match self {
+            EdgeRule::SubstituteOne => {
+                buffer.clear();
+                buffer.extend_from_slice(word);
+                for i in 0..word.len() {
+                    for &c in ALPHABET {
+                        if c != word[i] {
+                            buffer[i] = c;
+                            visit(buffer);
+                        }
+                    }
+                    buffer[i] = word[i];
+                }
+            }
+            EdgeRule::InsertOrDeleteOne => {
+                for i in 0..=word.len() {
+                    buffer.clear();
+                    buffer.extend_from_slice(&word[..i]);
+                    buffer.push(0);
+                    buffer.extend_from_slice(&word[i..]);
+                    for &c in ALPHABET {
+                        buffer[i] = c;
+                        visit(buffer);
+                    }
+                }
+                if word.len() > 1 {
+                    for i in 0..word.len() {
+                        buffer.clear();
+                        buffer.extend_from_slice(&word[..i]);
+                        buffer.extend_from_slice(&word[i + 1..]);
+                        visit(buffer);
+                    }
+                }
+            }
+            EdgeRule::SwapAdjacent => {
+                buffer.clear();
+                buffer.extend_from_slice(word);
+                for i in 1..word.len() {
+                    if word[i - 1] != word[i] {
+                        buffer.swap(i - 1, i);
+                        visit(buffer);
+                        buffer.swap(i - 1, i);
+                    }
+                }
+            }
+            EdgeRule::Anagram => {}
+        }
+    }
+}
+
+/// Counts the positions where two equally long byte strings differ, stopping
+/// once `limit` is reached.
+///
+/// Eight bytes are compared at a time: the XOR of two words has a nonzero
+/// byte exactly where they differ, and those bytes are counted with a few
+/// bit operations instead of one comparison per byte.
+fn count_mismatches(a: &[u8], b: &[u8], limit: usize) -> usize {
+    const LOW_BITS: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+
+    let mut mismatches = 0;
+    let mut a_chunks = a.chunks_exact(8);
+    let mut b_chunks = b.chunks_exact(8);
+    for (x, y) in (&mut a_chunks).zip(&mut b_chunks) {
+        let diff =
+            u64::from_ne_bytes(x.try_into().unwrap()) ^ u64::from_ne_bytes(y.try_into().unwrap());
+        // Sets the high bit of every nonzero byte without carrying between bytes
+        let nonzero = (((diff & LOW_BITS) + LOW_BITS) | diff) & !LOW_BITS;
+        mismatches += nonzero.count_ones() as usize;
+        if mismatches >= limit {
+            return mismatches;
+        }
+    }
+    for (x, y) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        if x != y {
+            mismatches += 1;
+            if mismatches >= limit {
+                break;
+            }
+        }
+    }
+    mismatches
+}
+
+/// The unit of a word that one ladder step changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonUnit {
+    /// One `char` (Unicode scalar value); only `a`-`z` are substituted or inserted
+    #[default]
+    Char,
+    /// One extended grapheme cluster, so a letter followed by combining marks
+    /// changes as a whole; any grapheme used by the dictionary can be
+    /// substituted or inserted
+    Grapheme,
+}
+
+impl ComparisonUnit {
+    /// Splits a word into its units.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::ComparisonUnit;
+    ///
+    /// assert_eq!(ComparisonUnit::Char.units("ne\u{301}e"), ["n", "e", "\u{301}", "e"]);
+    /// assert_eq!(ComparisonUnit::Grapheme.units("ne\u{301}e"), ["n", "e\u{301}", "e"]);
+    /// ```
+    pub fn units<'a>(&self, word: &'a str) -> Vec<&'a str> {
+        match self {
+            ComparisonUnit::Char => word
+                .char_indices()
+                .map(|(i, c)| &word[i..i + c.len_utf8()])
+                .collect(),
+            ComparisonUnit::Grapheme => word.graphemes(true).collect(),
+        }
+    }
+
+    /// Returns the length of a word in units.
+    pub fn length(&self, word: &str) -> usize {
+        match self {
+            ComparisonUnit::Char => word.chars().count(),
+            ComparisonUnit::Grapheme => word.graphemes(true).count(),
+        }
+    }
+}
+
+/// How words from several tagged dictionary sources are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergePolicy {
+    /// A word is valid if any source contains it
+    #[default]
+    Union,
+    /// A word is valid only if every source contains it
+    Intersection,
+}
+
+/// How letter case is normalized when words enter the graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseMapping {
+    /// Unicode lowercasing (`Cat` -> `cat`)
+    #[default]
+    Lowercase,
+    /// Turkish and Azerbaijani lowercasing, where `I` maps to dotless `ı`
+    /// and `İ` to `i`
+    Turkic,
+    /// Keep letter case, for dictionaries where it matters
+    Preserve,
+}
+
+impl CaseMapping {
+    /// Applies the case mapping to a word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::CaseMapping;
+    ///
+    /// assert_eq!(CaseMapping::Lowercase.apply("KILIM"), "kilim");
+    /// assert_eq!(CaseMapping::Turkic.apply("KILIM"), "kılım");
+    /// assert_eq!(CaseMapping::Turkic.apply("İZMİR"), "izmir");
+    /// assert_eq!(CaseMapping::Preserve.apply("Paris"), "Paris");
+    /// ```
+    pub fn apply(&self, word: &str) -> String {
+        match self {
+            CaseMapping::Lowercase => word.to_lowercase(),
+            CaseMapping::Turkic => word
+                .chars()
+                .map(|c| match c {
+                    'I' => 'ı'.to_string(),
+                    'İ' => 'i'.to_string(),
+                    _ => c.to_lowercase().collect(),
+                })
+                .collect(),
+            CaseMapping::Preserve => word.to_string(),
+        }
+    }
+}
+
+/// How apostrophes and hyphens inside words (`don't`, `x-ray`) are treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PunctuationPolicy {
+    /// Skip words containing them
+    #[default]
+    Reject,
+    /// Remove them (`don't` -> `dont`)
+    Strip,
+    /// Keep them as part of the word; they never change in a ladder step,
+    /// so such words only connect to words with the same punctuation
+    Keep,
+}
+
+/// Normalization applied to every word entering the graph.
+///
+/// Dictionary and base word files, `from_words`, `set_base_words`, and
+/// `add_word` all normalize words with the graph's policy, and words a player
+/// types should go through `normalize_query` before they are looked up, so
+/// both sides agree. Words are trimmed, case-mapped, and have apostrophes and
+/// hyphens handled by `punctuation`; any other character that is not a letter
+/// (digits, spaces) skips the word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordNormalization {
+    /// How letter case is normalized
+    pub case: CaseMapping,
+    /// How apostrophes and hyphens are treated
+    pub punctuation: PunctuationPolicy,
+}
+
+impl WordNormalization {
+    /// Normalizes a dictionary entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Entry as read from a word list
+    ///
+    /// # Returns
+    ///
+    /// The normalized word, or `None` if the entry is empty or is skipped
+    /// under the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{PunctuationPolicy, WordNormalization};
+    ///
+    /// let reject = WordNormalization::default();
+    /// assert_eq!(reject.normalize(" Cat "), Some("cat".to_string()));
+    /// assert_eq!(reject.normalize("don't"), None);
+    ///
+    /// let strip = WordNormalization { punctuation: PunctuationPolicy::Strip, ..reject };
+    /// assert_eq!(strip.normalize("Don't"), Some("dont".to_string()));
+    ///
+    /// let keep = WordNormalization { punctuation: PunctuationPolicy::Keep, ..reject };
+    /// assert_eq!(keep.normalize("x-ray"), Some("x-ray".to_string()));
+    /// assert_eq!(keep.normalize("b2b"), None);
+    /// ```
+    pub fn normalize(&self, word: &str) -> Option<String> {
+        self.normalize_with_unit(word, ComparisonUnit::Char)
+    }
+
+    /// Normalizes a dictionary entry whose letters are the given units.
+    ///
+    /// With `ComparisonUnit::Grapheme`, a grapheme counts as a letter when its
+    /// first character is one, so letters written with combining marks (which
+    /// are not alphabetic on their own) are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Entry as read from a word list
+    /// * `unit` - Unit of the words' letters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{ComparisonUnit, WordNormalization};
+    ///
+    /// let normalization = WordNormalization::default();
+    /// assert_eq!(normalization.normalize_with_unit("ne\u{301}e", ComparisonUnit::Char), None);
+    /// assert!(normalization.normalize_with_unit("ne\u{301}e", ComparisonUnit::Grapheme).is_some());
+    /// ```
+    pub fn normalize_with_unit(&self, word: &str, unit: ComparisonUnit) -> Option<String> {
+        let word = self.normalize_query(word);
+        let is_letter = |c: char| {
+            c.is_alphabetic()
+                || (self.punctuation == PunctuationPolicy::Keep && is_word_punctuation(c))
+        };
+        let valid = !word.is_empty()
+            && word.chars().any(|c| c.is_alphabetic())
+            && match unit {
+                ComparisonUnit::Char => word.chars().all(is_letter),
+                ComparisonUnit::Grapheme => word
+                    .graphemes(true)
+                    .all(|letter| letter.chars().next().is_some_and(is_letter)),
+            };
+        valid.then_some(word)
+    }
+
+    /// Normalizes a word typed by a player before it is looked up.
+    ///
+    /// Unlike `normalize`, this never rejects a word: a word the dictionary
+    /// cannot contain is simply not found, which gives the caller a better
+    /// error message than an empty input.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word as typed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{PunctuationPolicy, WordNormalization};
+    ///
+    /// let strip = WordNormalization { punctuation: PunctuationPolicy::Strip, ..Default::default() };
+    /// assert_eq!(strip.normalize_query(" Can't "), "cant");
+    /// ```
+    pub fn normalize_query(&self, word: &str) -> String {
+        let word = self.case.apply(word.trim());
+        match self.punctuation {
+            PunctuationPolicy::Strip => word.chars().filter(|&c| !is_word_punctuation(c)).collect(),
+            PunctuationPolicy::Reject | PunctuationPolicy::Keep => word,
+        }
+    }
+}
+
+/// Returns `true` for the apostrophes and hyphens handled by `PunctuationPolicy`.
+fn is_word_punctuation(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '-' | '\u{2010}')
+}
+
+/// Limits used by `WordGraph::curate_base_words`.
+#[derive(Debug, Clone)]
+pub struct BaseWordCuration {
+    /// Most words kept per word length
+    pub per_length: usize,
+    /// Words with fewer neighbors than this are skipped
+    pub min_neighbors: usize,
+}
+
+impl Default for BaseWordCuration {
+    fn default() -> Self {
+        Self {
+            per_length: 500,
+            min_neighbors: 2,
+        }
+    }
+}
+
+/// Maximum number of example words kept in a `LoadWarning`.
+const WARNING_SAMPLE_SIZE: usize = 5;
+
+/// What a word list load found in its lines, returned by the `load_dictionary`
+/// and `load_base_words` methods.
+///
+/// Every line is counted once in `total_lines`; blank lines are counted
+/// nowhere else.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_core::graph::{LoadWarning, WordGraph};
+///
+/// let mut graph = WordGraph::new();
+/// let report = graph.load_dictionary_from_str("cafe\nCafe\ncafé\nnaïve\nb2b\n\n");
+///
+/// assert_eq!(report.total_lines, 6);
+/// assert_eq!(report.accepted, 3);
+/// assert_eq!(report.rejected_non_alpha, 1);
+/// assert_eq!(report.duplicates, 1);
+/// assert_eq!(report.by_length[&4], 2);
+/// assert!(report.alphabet.contains(&'é'));
+/// match &report.warnings[..] {
+///     [LoadWarning::LettersOutsideAlphabet { letters, words, sample }] => {
+///         assert_eq!(letters, &['é', 'ï']);
+///         assert_eq!(*words, 2);
+///         assert_eq!(sample, &["café", "naïve"]);
+///     }
+///     warnings => panic!("unexpected warnings: {:?}", warnings),
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LoadReport {
+    /// Number of lines read
+    pub total_lines: usize,
+    /// Number of distinct words loaded
+    pub accepted: usize,
+    /// Lines skipped by the graph's `WordNormalization`, such as words with
+    /// digits or rejected punctuation
+    pub rejected_non_alpha: usize,
+    /// Lines skipped by a word filter (see `WordGraph::with_word_filter`)
+    pub filtered: usize,
+    /// Lines normalizing to a word already loaded from an earlier line
+    pub duplicates: usize,
+    /// Number of loaded words per length in letters (in the graph's
+    /// `ComparisonUnit`)
+    pub by_length: BTreeMap<usize, usize>,
+    /// Every letter used by the loaded words, sorted
+    pub alphabet: Vec<char>,
+    /// Problems found in the loaded words
+    pub warnings: Vec<LoadWarning>,
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lengths: Vec<String> = self
+            .by_length
+            .iter()
+            .map(|(length, count)| format!("{}: {}", length, count))
+            .collect();
+        let filtered = match self.filtered {
+            0 => String::new(),
+            filtered => format!(", {} filtered", filtered),
+        };
+        write!(
+            f,
+            "{} of {} lines accepted ({} rejected{}, {} duplicates); words by length: {}",
+            self.accepted,
+            self.total_lines,
+            self.rejected_non_alpha,
+            filtered,
+            self.duplicates,
+            lengths.join(", ")
+        )
+    }
+}
+
+/// A problem found while loading a word list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LoadWarning {
+    /// Some words use letters that neighbor generation never produces.
+    ///
+    /// `SubstituteOne` and `InsertOrDeleteOne` only try the letters `a` to `z`,
+    /// so a step that changes a word into one with another letter (`cafe` ->
+    /// `café`) is missing from the graph.
+    LettersOutsideAlphabet {
+        /// The letters outside the alphabet, sorted
+        letters: Vec<char>,
+        /// Number of words using any of them
+        words: usize,
+        /// The first few of those words, sorted
+        sample: Vec<String>,
+    },
+}
+
+impl fmt::Display for LoadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadWarning::LettersOutsideAlphabet {
+                letters,
+                words,
+                sample,
+            } => {
+                let letters: Vec<String> = letters.iter().map(char::to_string).collect();
+                write!(
+                    f,
+                    "{} words use letters outside a-z ({}), so steps to them are missing (e.g. {})",
+                    words,
+                    letters.join(" "),
+                    sample.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// A tagged set of words loaded by `WordGraph::load_dictionary_tagged`.
+#[derive(Debug, Clone)]
+struct DictionarySource {
+    /// Name identifying the source
+    tag: String,
+    /// Words loaded from the source
+    words: HashSet<String>,
+}
+
+/// A caller-supplied check that words must pass to enter the graph.
+#[derive(Clone)]
+struct WordFilter(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl fmt::Debug for WordFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WordFilter")
+    }
+}
+
+/// Joins a word's units with the units in `replaced` swapped for `WILDCARD`
+/// (an empty range inserts it).
+fn wildcard_pattern(units: &[&str], replaced: Range<usize>) -> String {
+    let mut pattern = units[..replaced.start].concat();
+    pattern.push_str(WILDCARD);
+    pattern.push_str(&units[replaced.end..].concat());
+    pattern
+}
+
+/// Returns the sorted letters of a word, shared by all of its anagrams.
+fn anagram_key<T: Copy + Ord>(word: &[T]) -> Vec<T> {
+    let mut key = word.to_vec();
+    key.sort_unstable();
+    key
+}
+
+/// Core data structure representing a graph of words connected by single-letter changes.
+///
+/// The `WordGraph` maintains these key data structures:
+/// - `word_list` / `word_ids`: Interned words and the reverse word -> ID lookup
+/// - `subgraphs`: Per-length adjacency lists mapping word IDs to neighbor IDs
+/// - `words`: Set of all valid dictionary words
+/// - `base_words`: Set of curated words for puzzle endpoints
+/// - `accepted_words`: Extra words accepted in player ladders, never in paths
+/// - `edge_rules`: Rules deciding which words are connected
+/// - `sources` / `merge_policy`: Tagged dictionary sources and how they combine
+/// - `normalization`: How words are normalized when they enter the graph
+/// - `word_filters`: Caller-supplied checks words must pass to enter the graph
+/// - `patterns`: Wildcard patterns of the words, built on first suggestion
+///
+/// This design allows efficient path finding while maintaining separation between
+/// the full dictionary (for paths) and base words (for puzzle selection).
+#[derive(Debug, Clone)]
+pub struct WordGraph {
+    /// Interned words, indexed by word ID
+    word_list: Vec<String>,
+    /// Reverse lookup: word -> word ID
+    word_ids: HashMap<String, u32>,
+    /// Adjacency per word length: word ID -> IDs of words one step away
+    subgraphs: Vec<Subgraph>,
+    /// Whether subgraphs use the compact CSR layout
+    compact: bool,
+    /// Set of all valid dictionary words for path finding
+    words: HashSet<String>,
+    /// Set of curated words used as puzzle start/end points
+    base_words: HashSet<String>,
+    /// Extra words players may use in a ladder, beyond the dictionary
+    accepted_words: HashSet<String>,
+    /// Rules deciding which words are connected
+    edge_rules: Vec<EdgeRule>,
+    /// Tagged dictionary sources, in load order (empty for untagged loads)
+    sources: Vec<DictionarySource>,
+    /// How tagged sources are combined into `words`
+    merge_policy: MergePolicy,
+    /// Landmark distances guiding `find_shortest_path`, if set
+    landmarks: Option<Landmarks>,
+    /// How words are normalized when they enter the graph
+    normalization: WordNormalization,
+    /// The unit of a word that one ladder step changes
+    comparison_unit: ComparisonUnit,
+    /// Checks words must pass to enter the graph, such as excluding proper nouns
+    word_filters: Vec<WordFilter>,
+    /// Words by wildcard pattern, for `suggest_similar`
+    patterns: OnceLock<PatternIndex>,
+}
+
+/// Storage layout for the adjacency structure.
+#[derive(Debug, Clone)]
+enum Adjacency {
+    /// One neighbor vector per word (built by `load_dictionary`)
+    Lists(Vec<Vec<u32>>),
+    /// Compressed sparse row layout (built by `WordGraph::compact`): the
+    /// neighbors of word `i` are `neighbors[offsets[i]..offsets[i + 1]]`
+    Csr {
+        offsets: Vec<u32>,
+        neighbors: Vec<u32>,
+    },
+}
+
+impl Adjacency {
+    /// Returns the neighbor IDs of a word by its index within the subgraph.
+    fn neighbors(&self, index: u32) -> &[u32] {
+        match self {
+            Adjacency::Lists(lists) => &lists[index as usize],
+            Adjacency::Csr { offsets, neighbors } => {
+                let start = offsets[index as usize] as usize;
+                let end = offsets[index as usize + 1] as usize;
+                &neighbors[start..end]
+            }
+        }
+    }
+
+    /// Returns the estimated heap bytes held by the layout.
+    fn heap_size(&self) -> usize {
+        match self {
+            Adjacency::Lists(lists) => {
+                lists.capacity() * size_of::<Vec<u32>>()
+                    + lists
+                        .iter()
+                        .map(|list| list.capacity() * size_of::<u32>())
+                        .sum::<usize>()
+            }
+            Adjacency::Csr { offsets, neighbors } => {
+                (offsets.capacity() + neighbors.capacity()) * size_of::<u32>()
+            }
+        }
+    }
+
+    /// Converts per-word lists to the CSR layout; CSR input is returned as-is.
+    fn into_compact(self) -> Self {
+        let Adjacency::Lists(lists) = self else {
+            return self;
+        };
+
+        let mut offsets = Vec::with_capacity(lists.len() + 1);
+        let mut neighbors = Vec::with_capacity(lists.iter().map(Vec::len).sum());
+        offsets.push(0);
+        for list in &lists {
+            neighbors.extend_from_slice(list);
+            offsets.push(neighbors.len() as u32);
+        }
+
+        Adjacency::Csr { offsets, neighbors }
+    }
+
+    /// Converts the CSR layout back to per-word lists; lists are returned as-is.
+    fn into_lists(self) -> Vec<Vec<u32>> {
+        match self {
+            Adjacency::Lists(lists) => lists,
+            Adjacency::Csr { offsets, neighbors } => offsets
+                .windows(2)
+                .map(|range| neighbors[range[0] as usize..range[1] as usize].to_vec())
+                .collect(),
+        }
+    }
+
+    /// Replaces every neighbor ID with `f(id)`, keeping the layout.
+    fn map_ids(&mut self, f: impl Fn(u32) -> u32) {
+        let ids: Box<dyn Iterator<Item = &mut u32>> = match self {
+            Adjacency::Lists(lists) => Box::new(lists.iter_mut().flatten()),
+            Adjacency::Csr { neighbors, .. } => Box::new(neighbors.iter_mut()),
+        };
+        for id in ids {
+            *id = f(*id);
+        }
+    }
+}
+
+/// Estimated heap memory held by a `WordGraph`, in bytes.
+///
+/// Sizes are computed from collection capacities and the per-entry control
+/// byte of hash tables, so they are close to, but not exactly, what the
+/// allocator hands out. Subgraphs that have not been built yet count as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemoryFootprint {
+    /// Interned word strings, indexed by word ID
+    pub words: usize,
+    /// Built adjacency of every subgraph, in the current layout
+    pub adjacency: usize,
+    /// Word ID lookup, dictionary, base word, accepted word, and source
+    /// sets, and the per-length subgraph ranges
+    pub indices: usize,
+    /// Landmark distances, if set
+    pub landmarks: usize,
+}
+
+impl MemoryFootprint {
+    /// Returns the sum of all parts.
+    pub fn total(&self) -> usize {
+        self.words + self.adjacency + self.indices + self.landmarks
+    }
+}
+
+/// Returns the estimated heap bytes of a string set: its table plus the strings.
+fn string_set_size(set: &HashSet<String>) -> usize {
+    set.capacity() * (size_of::<String>() + 1) + set.iter().map(String::capacity).sum::<usize>()
+}
+
+/// A contiguous range of word IDs whose adjacency is built together on first use.
+#[derive(Debug, Clone)]
+struct Subgraph {
+    /// First word ID in the subgraph
+    start: u32,
+    /// One past the last word ID in the subgraph
+    end: u32,
+    /// Neighbor IDs of the subgraph's words, indexed by `id - start`
+    adjacency: OnceLock<Adjacency>,
+}
+
+/// Dictionary words by wildcard pattern, so words one edit from any string
+/// are found without trying every letter.
+#[derive(Debug, Clone, Default)]
+struct PatternIndex {
+    /// Word IDs by the units of a word with one of them replaced by `WILDCARD`
+    /// (`c*t` holds `cat` and `cot`)
+    patterns: HashMap<String, Vec<u32>>,
+    /// Every unit used by the dictionary's words, sorted
+    alphabet: Vec<String>,
+}
+
+/// Limits on how much work a single path search may do.
+///
+/// Long-running services use these so a pathological query (two words in a
+/// huge component with no short ladder) cannot hog a core. The default has no
+/// limits.
+///
+/// A `timeout` reads the system clock, which is not available on
+/// `wasm32-unknown-unknown`; use `max_visited` there instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SolveOptions {
+    /// Most words the search may visit, or `None` for no limit
+    pub max_visited: Option<usize>,
+    /// Longest the search may run, or `None` for no limit
+    pub timeout: Option<Duration>,
+}
+
+impl SolveOptions {
+    /// Creates options without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the most words the search may visit.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_visited` - Visit budget, including the start word
+    pub fn with_max_visited(mut self, max_visited: usize) -> Self {
+        self.max_visited = Some(max_visited);
+        self
+    }
+
+    /// Sets the longest the search may run.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time budget of the search
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// Result of a path search run with `SolveOptions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveOutcome {
+    /// The shortest path, including the start and end words
+    Found(Vec<String>),
+    /// The words are not connected (or not in the dictionary)
+    NoPath,
+    /// The search hit its visit or time budget before finishing
+    BudgetExceeded,
+}
+
+impl SolveOutcome {
+    /// Returns the path if one was found.
+    pub fn into_path(self) -> Option<Vec<String>> {
+        match self {
+            SolveOutcome::Found(path) => Some(path),
+            SolveOutcome::NoPath | SolveOutcome::BudgetExceeded => None,
+        }
+    }
+}
+
+/// Why no ladder connects two words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LadderError {
+    /// The words have different lengths and no edge rule changes the length
+    DifferentLengths {
+        /// The start word
+        start: String,
+        /// The end word
+        end: String,
+        /// Length of the start word in the graph's comparison unit
+        start_length: usize,
+        /// Length of the end word in the graph's comparison unit
+        end_length: usize,
+    },
+    /// The start word is not in the dictionary
+    StartNotInDictionary(String),
+    /// The end word is not in the dictionary
+    EndNotInDictionary(String),
+    /// Both words are in the dictionary but not connected
+    NoPath {
+        /// The start word
+        start: String,
+        /// The end word
+        end: String,
+    },
+}
+
+impl fmt::Display for LadderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LadderError::DifferentLengths {
+                start,
+                end,
+                start_length,
+                end_length,
+            } => write!(
+                f,
+                "{} and {} have different lengths ({} and {} letters)",
+                start, end, start_length, end_length
+            ),
+            LadderError::StartNotInDictionary(word) => {
+                write!(f, "Start word {} is not in the dictionary", word)
+            }
+            LadderError::EndNotInDictionary(word) => {
+                write!(f, "End word {} is not in the dictionary", word)
+            }
+            LadderError::NoPath { start, end } => {
+                write!(f, "No path found between {} and {}", start, end)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LadderError {}
+
+/// Statistics for one BFS layer of a traced search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerTrace {
+    /// Distance of the expanded frontier from the start word
+    pub depth: usize,
+    /// Number of words in the frontier expanded at this depth
+    pub frontier: usize,
+    /// Number of words first visited while expanding this frontier
+    pub visited: usize,
+    /// Microseconds since the search started when the layer finished
+    pub elapsed_micros: u64,
+}
+
+/// A record of how a shortest-path search progressed, for debugging odd paths.
+///
+/// Filled in by `WordGraph::find_shortest_path_traced` and
+/// `WordGraph::find_shortest_path_avoiding_traced`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolveTrace {
+    /// One entry per expanded BFS layer, in search order
+    pub layers: Vec<LayerTrace>,
+    /// Total number of words visited, including the start word
+    pub visited: usize,
+    /// Whether the end word was reached
+    pub found: bool,
+    /// Microseconds the whole search took
+    pub elapsed_micros: u64,
+}
+
+impl fmt::Display for SolveTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for layer in &self.layers {
+            writeln!(
+                f,
+                "depth {}: frontier {}, visited {} new, {}us",
+                layer.depth, layer.frontier, layer.visited, layer.elapsed_micros
+            )?;
+        }
+        write!(
+            f,
+            "{} words visited in {}us ({})",
+            self.visited,
+            self.elapsed_micros,
+            if self.found { "found" } else { "not found" }
+        )
+    }
+}
+
+/// Sentinel parent value marking a word as not yet visited during BFS.
+const UNVISITED: u32 = u32::MAX;
+
+/// State for one direction of a bidirectional BFS.
+struct SearchSide {
+    /// Parent word ID for each visited word (`UNVISITED` otherwise)
+    parent: Vec<u32>,
+    /// Distance from this side's origin for each visited word
+    depth: Vec<u32>,
+    /// Word IDs discovered in the most recent layer
+    frontier: Vec<u32>,
+}
+
+impl SearchSide {
+    fn new(origin: u32, word_count: usize) -> Self {
+        let mut parent = vec![UNVISITED; word_count];
+        parent[origin as usize] = origin;
+        Self {
+            parent,
+            depth: vec![0; word_count],
+            frontier: vec![origin],
+        }
+    }
+}
+
+impl WordGraph {
+    /// Creates a new empty word graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::new();
+    /// assert!(graph.get_words().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            word_list: Vec::new(),
+            word_ids: HashMap::new(),
+            subgraphs: Vec::new(),
+            compact: false,
+            words: HashSet::new(),
+            base_words: HashSet::new(),
+            accepted_words: HashSet::new(),
+            edge_rules: vec![EdgeRule::SubstituteOne],
+            sources: Vec::new(),
+            merge_policy: MergePolicy::Union,
+            landmarks: None,
+            normalization: WordNormalization::default(),
+            comparison_unit: ComparisonUnit::Char,
+            word_filters: Vec::new(),
+            patterns: OnceLock::new(),
+        }
+    }
+
+    /// Creates a word graph from in-memory dictionary words.
+    ///
+    /// Words are normalized like dictionary file lines under the default
+    /// `WordNormalization`: trimmed, lowercased, and skipped unless alphabetic.
+    /// The graph uses the default edge rules; `with_edge_rules` can change
+    /// them afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - Dictionary words
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "cog", "dog"].map(String::from));
+    /// assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+    /// ```
+    pub fn from_words(words: impl IntoIterator<Item = String>) -> Self {
+        let mut graph = Self::new();
+        graph.words = words
+            .into_iter()
+            .filter_map(|word| graph.normalize(&word))
+            .collect();
+        graph.build_graph();
+        graph
+    }
+
+    /// Sets the rules deciding which words are connected.
+    ///
+    /// If a dictionary is already loaded, the graph is rebuilt with the new rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - Rules to combine; words are connected if any rule connects them
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{EdgeRule, WordGraph};
+    ///
+    /// let mut graph = WordGraph::new()
+    ///     .with_edge_rules(vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne]);
+    /// graph.load_dictionary_from_str("cat\ncart\ncard\n");
+    ///
+    /// assert_eq!(graph.find_shortest_path("cat", "card").unwrap().len(), 3);
+    /// ```
+    pub fn with_edge_rules(mut self, rules: Vec<EdgeRule>) -> Self {
+        self.edge_rules = rules;
+        if !self.words.is_empty() {
+            self.build_graph();
+        }
+        self
+    }
+
+    /// Sets how tagged dictionary sources are combined.
+    ///
+    /// If tagged sources are already loaded, the dictionary and graph are rebuilt
+    /// with the new policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Union or intersection of the sources
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{MergePolicy, WordGraph};
+    ///
+    /// let mut graph = WordGraph::new().with_merge_policy(MergePolicy::Intersection);
+    /// graph.load_dictionary_tagged_from_str("cat\ncot\ncwm\n", "tournament");
+    /// graph.load_dictionary_tagged_from_str("cat\ncot\ndog\n", "common");
+    ///
+    /// assert_eq!(graph.get_words().len(), 2);
+    /// ```
+    pub fn with_merge_policy(mut self, policy: MergePolicy) -> Self {
+        self.merge_policy = policy;
+        if !self.sources.is_empty() {
+            self.merge_sources();
+        }
+        self
+    }
+
+    /// Returns how tagged dictionary sources are combined.
+    pub fn merge_policy(&self) -> MergePolicy {
+        self.merge_policy
+    }
+
+    /// Sets how words are normalized when they enter the graph.
+    ///
+    /// The policy applies to words loaded or added afterwards; set it before
+    /// loading the dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalization` - Case mapping and apostrophe/hyphen policy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{PunctuationPolicy, WordGraph, WordNormalization};
+    ///
+    /// let mut graph = WordGraph::new().with_normalization(WordNormalization {
+    ///     punctuation: PunctuationPolicy::Keep,
+    ///     ..Default::default()
+    /// });
+    /// graph.load_dictionary_from_str("x-ray\nx-ran\nX-Men\n");
+    ///
+    /// assert_eq!(graph.get_words().len(), 3);
+    /// assert_eq!(graph.find_shortest_path("x-ray", "x-ran").unwrap().len(), 2);
+    /// ```
+    pub fn with_normalization(mut self, normalization: WordNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Returns how words are normalized when they enter the graph.
+    ///
+    /// Callers looking up words typed by a player should normalize them with
+    /// `normalization().normalize_query` first.
+    pub fn normalization(&self) -> WordNormalization {
+        self.normalization
+    }
+
+    /// Adds a check that words must pass to enter the graph.
+    ///
+    /// Filters see each dictionary, base, or added word trimmed but before
+    /// normalization, so capitalized proper nouns can still be told apart
+    /// from lowercase words. A word is loaded only if every filter returns
+    /// `true`; words skipped this way are counted in `LoadReport::filtered`.
+    /// Like the normalization, filters apply to words loaded or added
+    /// afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Returns `true` for words to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new()
+    ///     .with_word_filter(|word| !word.starts_with(char::is_uppercase))
+    ///     .with_word_filter(|word| !["ii", "iv", "vi"].contains(&word));
+    /// let report = graph.load_dictionary_from_str("cat\nParis\niv\ncot\n");
+    ///
+    /// assert_eq!(report.filtered, 2);
+    /// assert_eq!(graph.get_words().len(), 2);
+    /// ```
+    pub fn with_word_filter(
+        mut self,
+        filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.word_filters.push(WordFilter(Arc::new(filter)));
+        self
+    }
+
+    /// Returns `true` if a word entry passes every word filter.
+    fn passes_filters(&self, word: &str) -> bool {
+        let word = word.trim();
+        self.word_filters.iter().all(|filter| (filter.0)(word))
+    }
+
+    /// Normalizes a word entering the graph, in the graph's comparison unit,
+    /// or returns `None` if it is skipped by normalization or a word filter.
+    fn normalize(&self, word: &str) -> Option<String> {
+        if !self.passes_filters(word) {
+            return None;
+        }
+        self.normalization
+            .normalize_with_unit(word, self.comparison_unit)
+    }
+
+    /// Sets the unit of a word that one ladder step changes.
+    ///
+    /// With `ComparisonUnit::Grapheme`, words are compared grapheme by
+    /// grapheme, word lengths are counted in graphemes, and neighbor
+    /// generation substitutes or inserts any grapheme used by the dictionary
+    /// instead of only `a`-`z`. Dictionary words are also accepted when they
+    /// contain letters written with combining marks. Set it before loading the
+    /// dictionary; if one is already loaded, the graph is rebuilt.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Chars (the default) or grapheme clusters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{ComparisonUnit, WordGraph};
+    ///
+    /// // Devanagari vowel signs join the consonant before them into one grapheme
+    /// let mut graph = WordGraph::new().with_comparison_unit(ComparisonUnit::Grapheme);
+    /// graph.load_dictionary_from_str("कल\nकुल\nकिल\n");
+    ///
+    /// assert!(graph.is_step("कल", "कुल"));
+    /// assert_eq!(graph.find_shortest_path("कल", "कुल").unwrap().len(), 2);
+    /// ```
+    pub fn with_comparison_unit(mut self, unit: ComparisonUnit) -> Self {
+        self.comparison_unit = unit;
+        if !self.words.is_empty() {
+            self.build_graph();
+        }
+        self
+    }
+
+    /// Returns the unit of a word that one ladder step changes.
+    pub fn comparison_unit(&self) -> ComparisonUnit {
+        self.comparison_unit
+    }
+
+    /// Returns the rules deciding which words are connected.
+    pub fn edge_rules(&self) -> &[EdgeRule] {
+        &self.edge_rules
+    }
+
+    /// Returns `true` if an edge rule connects words of different lengths, so
+    /// ladders (and generated puzzles) may change the word length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{EdgeRule, WordGraph};
+    ///
+    /// assert!(!WordGraph::new().crosses_lengths());
+    /// let golf = WordGraph::new()
+    ///     .with_edge_rules(vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne]);
+    /// assert!(golf.crosses_lengths());
+    /// ```
+    pub fn crosses_lengths(&self) -> bool {
+        self.edge_rules.iter().any(EdgeRule::changes_length)
+    }
+
+    /// Returns `true` if any of the graph's edge rules connects `a` and `b`.
+    ///
+    /// This checks the rules only, not dictionary membership.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::new();
+    /// assert!(graph.is_step("cat", "cot"));
+    /// assert!(!graph.is_step("cat", "cart"));
+    /// ```
+    pub fn is_step(&self, a: &str, b: &str) -> bool {
+        self.edge_rules
+            .iter()
+            .any(|rule| rule.connects_with(self.comparison_unit, a, b))
+    }
+
+    /// Loads dictionary words from a file and builds the word graph.
+    ///
+    /// This method reads a text file containing one word per line, filters for
+    /// valid alphabetic words, and constructs the adjacency graph for efficient
+    /// path finding.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the dictionary file
+    ///
+    /// # Returns
+    ///
+    /// A `LoadReport` describing the loaded words, or an error if the file
+    /// cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// let report = graph.load_dictionary("data/dictionary.txt")?;
+    /// for warning in &report.warnings {
+    ///     eprintln!("Warning: {}", warning);
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn load_dictionary(&mut self, path: &str) -> Result<LoadReport> {
+        let content = fs::read_to_string(path)?;
+        Ok(self.load_dictionary_from_str(&content))
+    }
+
+    /// Loads dictionary words from in-memory text and builds the word graph.
+    ///
+    /// This is the file-system independent core of `load_dictionary`, used by
+    /// targets without file access such as WebAssembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Dictionary text with one word per line
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// assert_eq!(graph.get_words().len(), 4);
+    /// ```
+    pub fn load_dictionary_from_str(&mut self, content: &str) -> LoadReport {
+        self.sources.clear();
+        let (words, report) = self.read_words(content);
+        self.words = words;
+        self.build_graph();
+        report
+    }
+
+    /// Loads a tagged dictionary source from a file and rebuilds the word graph.
+    ///
+    /// Unlike `load_dictionary`, which replaces the dictionary, tagged sources
+    /// accumulate: the dictionary becomes the combination of all tagged sources
+    /// under the graph's `MergePolicy`. Loading a tag again replaces that source.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the dictionary file
+    /// * `tag` - Name identifying the source
+    ///
+    /// # Returns
+    ///
+    /// A `LoadReport` describing the words of this source, or an error if the
+    /// file cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_tagged("data/dictionary.txt", "main")?;
+    /// graph.load_dictionary_tagged("data/base_words.txt", "common")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn load_dictionary_tagged(&mut self, path: &str, tag: &str) -> Result<LoadReport> {
+        let content = fs::read_to_string(path)?;
+        Ok(self.load_dictionary_tagged_from_str(&content, tag))
+    }
+
+    /// Loads a tagged dictionary source from in-memory text and rebuilds the word graph.
+    ///
+    /// This is the file-system independent core of `load_dictionary_tagged`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Dictionary text with one word per line
+    /// * `tag` - Name identifying the source
+    ///
+    /// # Returns
+    ///
+    /// A `LoadReport` describing the words of this source, not the merged dictionary.
+    pub fn load_dictionary_tagged_from_str(&mut self, content: &str, tag: &str) -> LoadReport {
+        let (words, report) = self.read_words(content);
+        match self.sources.iter_mut().find(|source| source.tag == tag) {
+            Some(source) => source.words = words,
+            None => self.sources.push(DictionarySource {
+                tag: tag.to_string(),
+                words,
+            }),
+        }
+        self.merge_sources();
+        report
+    }
+
+    /// Returns the tags of the loaded dictionary sources, in load order.
+    pub fn source_tags(&self) -> Vec<&str> {
+        self.sources
+            .iter()
+            .map(|source| source.tag.as_str())
+            .collect()
+    }
+
+    /// Returns the tags of the sources containing a word, in load order.
+    ///
+    /// Sources are reported even if the merge policy excluded the word from the
+    /// dictionary. Words loaded without a tag have no sources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_tagged_from_str("cat\ncwm\n", "tournament");
+    /// graph.load_dictionary_tagged_from_str("cat\ndog\n", "common");
+    ///
+    /// assert_eq!(graph.word_sources("cat"), vec!["tournament", "common"]);
+    /// assert_eq!(graph.word_sources("cwm"), vec!["tournament"]);
+    /// ```
+    pub fn word_sources(&self, word: &str) -> Vec<&str> {
+        self.sources
+            .iter()
+            .filter(|source| source.words.contains(word))
+            .map(|source| source.tag.as_str())
+            .collect()
+    }
+
+    /// Returns `true` if the source with the given tag contains the word.
+    pub fn source_contains(&self, tag: &str, word: &str) -> bool {
+        self.sources
+            .iter()
+            .any(|source| source.tag == tag && source.words.contains(word))
+    }
+
+    /// Combines the tagged sources into the dictionary and rebuilds the graph.
+    fn merge_sources(&mut self) {
+        let mut sources = self.sources.iter();
+        let mut words = sources
+            .next()
+            .map(|source| source.words.clone())
+            .unwrap_or_default();
+        for source in sources {
+            match self.merge_policy {
+                MergePolicy::Union => words.extend(source.words.iter().cloned()),
+                MergePolicy::Intersection => words.retain(|word| source.words.contains(word)),
+            }
+        }
+        self.words = words;
+        self.build_graph();
+    }
+
+    /// Loads base words from a file for use as puzzle endpoints.
+    ///
+    /// Base words are a curated subset of dictionary words that are suitable
+    /// for use as start and end points in puzzles. They should be common words
+    /// that players are likely to know.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the base words file
+    ///
+    /// # Returns
+    ///
+    /// A `LoadReport` describing the loaded words, or an error if the file
+    /// cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// let report = graph.load_base_words("data/base_words.txt")?;
+    /// println!("Base words: {}", report);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn load_base_words(&mut self, path: &str) -> Result<LoadReport> {
+        let content = fs::read_to_string(path)?;
+        Ok(self.load_base_words_from_str(&content))
+    }
+
+    /// Loads base words from in-memory text.
+    ///
+    /// This is the file-system independent core of `load_base_words`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Base word text with one word per line
+    pub fn load_base_words_from_str(&mut self, content: &str) -> LoadReport {
+        let (words, report) = self.read_words(content);
+        self.base_words = words;
+        report
+    }
+
+    /// Replaces the base words with in-memory words.
+    ///
+    /// Words are normalized like base word file lines. Base words need not be
+    /// in the dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - Curated words for puzzle endpoints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::from_words(["cat", "cot", "dog"].map(String::from));
+    /// graph.set_base_words(["Cat", "dog"].map(String::from));
+    ///
+    /// assert!(graph.get_base_words().contains("cat"));
+    /// assert_eq!(graph.get_base_words().len(), 2);
+    /// ```
+    pub fn set_base_words(&mut self, words: impl IntoIterator<Item = String>) {
+        self.base_words = words
+            .into_iter()
+            .filter_map(|word| self.normalize(&word))
+            .collect();
+    }
+
+    /// Loads the accepted words from a file.
+    ///
+    /// Accepted words are a broader guess list: players may use them in
+    /// their ladders, but generated paths only use dictionary words. See
+    /// `is_accepted`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the accepted words file, one word per line
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if successful, or an error if the file cannot be read.
+    #[cfg(feature = "fs")]
+    pub fn load_accepted_words(&mut self, path: &str) -> Result<()> {
+        let content = fs::read_to_string(path)?;
+        self.load_accepted_words_from_str(&content);
+        Ok(())
+    }
+
+    /// Loads the accepted words from in-memory text.
+    ///
+    /// This is the file-system independent core of `load_accepted_words`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Accepted word text with one word per line
+    pub fn load_accepted_words_from_str(&mut self, content: &str) {
+        self.accepted_words = self.read_words(content).0;
+    }
+
+    /// Returns `true` if players may use a word in a ladder.
+    ///
+    /// Once accepted words are loaded, a word must be in the dictionary or
+    /// the accepted words. Without accepted words, every word is accepted
+    /// and only the steps of a ladder are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::from_words(["cat", "cot", "cog", "dog"].map(String::from));
+    /// assert!(graph.is_accepted("cag"));
+    ///
+    /// graph.load_accepted_words_from_str("cag\n");
+    /// assert!(graph.is_accepted("cag"));
+    /// assert!(graph.is_accepted("cot"));
+    /// assert!(!graph.is_accepted("dag"));
+    /// // Path finding still uses the dictionary only
+    /// assert_eq!(graph.find_shortest_path("cat", "cag"), None);
+    /// ```
+    pub fn is_accepted(&self, word: &str) -> bool {
+        self.accepted_words.is_empty()
+            || self.words.contains(word)
+            || self.accepted_words.contains(word)
+    }
+
+    /// Returns a reference to the set of accepted words (empty unless loaded).
+    pub fn get_accepted_words(&self) -> &HashSet<String> {
+        &self.accepted_words
+    }
+
+    /// Normalizes word list text into a set of words under the graph's
+    /// normalization, reporting what was skipped and found along the way.
+    ///
+    /// The report warns about letters that neighbor generation under the
+    /// graph's edge rules cannot produce.
+    fn read_words(&self, content: &str) -> (HashSet<String>, LoadReport) {
+        let mut words = HashSet::new();
+        let mut report = LoadReport::default();
+        for line in content.lines() {
+            report.total_lines += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if !self.passes_filters(line) {
+                report.filtered += 1;
+                continue;
+            }
+            match self
+                .normalization
+                .normalize_with_unit(line, self.comparison_unit)
+            {
+                Some(word) => {
+                    if !words.insert(word) {
+                        report.duplicates += 1;
+                    }
+                }
+                None => report.rejected_non_alpha += 1,
+            }
+        }
+
+        let mut alphabet = HashSet::new();
+        let mut outside = HashSet::new();
+        let mut affected: Vec<&String> = Vec::new();
+        for word in &words {
+            *report
+                .by_length
+                .entry(self.comparison_unit.length(word))
+                .or_default() += 1;
+            let mut is_affected = false;
+            for c in word.chars().filter(|c| c.is_alphabetic()) {
+                alphabet.insert(c);
+                if !c.is_ascii() || !ALPHABET.contains(&(c as u8)) {
+                    outside.insert(c);
+                    is_affected = true;
+                }
+            }
+            if is_affected {
+                affected.push(word);
+            }
+        }
+
+        // Graphemes are generated from the dictionary's own letters
+        let generates_letters = self.comparison_unit == ComparisonUnit::Char
+            && self
+                .edge_rules
+                .iter()
+                .any(|rule| matches!(rule, EdgeRule::SubstituteOne | EdgeRule::InsertOrDeleteOne));
+        if generates_letters && !affected.is_empty() {
+            let mut letters: Vec<char> = outside.into_iter().collect();
+            letters.sort_unstable();
+            affected.sort_unstable();
+            report.warnings.push(LoadWarning::LettersOutsideAlphabet {
+                letters,
+                words: affected.len(),
+                sample: affected
+                    .iter()
+                    .take(WARNING_SAMPLE_SIZE)
+                    .map(|word| word.to_string())
+                    .collect(),
+            });
+        }
+
+        report.accepted = words.len();
+        report.alphabet = alphabet.into_iter().collect();
+        report.alphabet.sort_unstable();
+        (words, report)
+    }
+
+    /// Prepares the word graph for the loaded dictionary words.
+    ///
+    /// This method interns the dictionary (sorted by length, then
+    /// alphabetically, so IDs are deterministic and each length is a contiguous
+    /// ID range) and sets up one empty subgraph per word length. The adjacency
+    /// of a subgraph, connecting each word to all words reachable in one step
+    /// under the configured edge rules, is built on first access.
+    ///
+    /// # Performance
+    ///
+    /// Time complexity: O(W log W) here; building a subgraph later is
+    /// O(W * L * 26) where W is its word count and L the word length
+    fn build_graph(&mut self) {
+        let mut word_list: Vec<String> = self.words.iter().cloned().collect();
+        let unit = self.comparison_unit;
+        word_list.sort_by_cached_key(|word| (unit.length(word), word.clone()));
+
+        self.word_ids = word_list
+            .iter()
+            .enumerate()
+            .map(|(id, word)| (word.clone(), id as u32))
+            .collect();
+        self.word_list = word_list;
+        self.compact = false;
+        self.landmarks = None;
+        self.patterns = OnceLock::new();
+
+        // Rules that change the word length need every length in one subgraph
+        let crosses_lengths = self.crosses_lengths();
+        let mut subgraphs: Vec<Subgraph> = Vec::new();
+        let mut current_length = None;
+        for (id, word) in self.word_list.iter().enumerate() {
+            let length = unit.length(word);
+            if subgraphs.is_empty() || (!crosses_lengths && current_length != Some(length)) {
+                subgraphs.push(Subgraph {
+                    start: id as u32,
+                    end: id as u32,
+                    adjacency: OnceLock::new(),
+                });
+            }
+            current_length = Some(length);
+            subgraphs.last_mut().unwrap().end = id as u32 + 1;
+        }
+        self.subgraphs = subgraphs;
+    }
+
+    /// Builds the adjacency of the words with IDs in `start..end`.
+    fn build_subgraph(&self, start: u32, end: u32) -> Adjacency {
+        // Anagrams can't be generated letter by letter, so group words by
+        // their sorted letters up front and look neighbors up by key.
+        let mut anagrams: HashMap<Vec<char>, Vec<u32>> = HashMap::new();
+        if self.edge_rules.contains(&EdgeRule::Anagram) {
+            for id in start..end {
+                let key = self.anagram_key_of(&self.word_list[id as usize]);
+                anagrams.entry(key).or_default().push(id);
+            }
+        }
+        let graphemes = self.graphemes(start, end);
+
+        self.with_layout(
+            self.word_list[start as usize..end as usize]
+                .iter()
+                .map(|word| self.generate_neighbors(word, &anagrams, &graphemes))
+                .collect(),
+        )
+    }
+
+    /// Returns the sorted letters of a word in the graph's comparison unit,
+    /// shared by all of its anagrams.
+    fn anagram_key_of(&self, word: &str) -> Vec<char> {
+        match self.comparison_unit {
+            ComparisonUnit::Char => anagram_key(&word.chars().collect::<Vec<char>>()),
+            ComparisonUnit::Grapheme => anagram_key(&self.comparison_unit.units(word))
+                .concat()
+                .chars()
+                .collect(),
+        }
+    }
+
+    /// Returns every grapheme used by the words with IDs in `start..end`,
+    /// which neighbor generation substitutes and inserts under
+    /// `ComparisonUnit::Grapheme` (empty under `ComparisonUnit::Char`).
+    fn graphemes(&self, start: u32, end: u32) -> Vec<&str> {
+        if self.comparison_unit == ComparisonUnit::Char {
+            return Vec::new();
+        }
+        let graphemes: HashSet<&str> = self.word_list[start as usize..end as usize]
+            .iter()
+            .flat_map(|word| word.graphemes(true))
+            .collect();
+        graphemes.into_iter().collect()
+    }
+
+    /// Wraps per-word neighbor lists in the graph's current adjacency layout.
+    fn with_layout(&self, lists: Vec<Vec<u32>>) -> Adjacency {
+        let adjacency = Adjacency::Lists(lists);
+        if self.compact {
+            adjacency.into_compact()
+        } else {
+            adjacency
+        }
+    }
+
+    /// Returns the neighbor IDs of a word ID, building its subgraph if needed.
+    fn neighbors(&self, id: u32) -> &[u32] {
+        let index = self
+            .subgraphs
+            .partition_point(|subgraph| subgraph.end <= id);
+        let subgraph = &self.subgraphs[index];
+        subgraph
+            .adjacency
+            .get_or_init(|| self.build_subgraph(subgraph.start, subgraph.end))
+            .neighbors(id - subgraph.start)
+    }
+
+    /// Builds the subgraphs of every word length now instead of on first use.
+    ///
+    /// Useful for long-running services that should answer their first query
+    /// for each length as fast as later ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncold\ncord\n");
+    /// graph.build_all();
+    ///
+    /// assert!(graph.is_length_built(3));
+    /// assert!(graph.is_length_built(4));
+    /// ```
+    pub fn build_all(&self) {
+        for subgraph in &self.subgraphs {
+            subgraph
+                .adjacency
+                .get_or_init(|| self.build_subgraph(subgraph.start, subgraph.end));
+        }
+    }
+
+    /// Returns `true` if the subgraph holding words of this length has been built.
+    ///
+    /// Subgraphs are built on the first query touching a word of their length,
+    /// or by `build_all`. Returns `false` if the dictionary has no such words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncold\ncord\n");
+    /// assert!(!graph.is_length_built(3));
+    ///
+    /// graph.find_shortest_path("cat", "cot");
+    /// assert!(graph.is_length_built(3));
+    /// assert!(!graph.is_length_built(4));
+    /// ```
+    pub fn is_length_built(&self, length: usize) -> bool {
+        self.subgraphs.iter().any(|subgraph| {
+            subgraph.adjacency.get().is_some()
+                && self.word_list[subgraph.start as usize..subgraph.end as usize]
+                    .iter()
+                    .any(|word| self.comparison_unit.length(word) == length)
+        })
+    }
+
+    /// Converts the adjacency structure to a compact CSR layout.
+    ///
+    /// The graph answers queries identically afterwards; only the memory layout
+    /// changes. Call this once after loading the dictionary; subgraphs built
+    /// later use the compact layout too. Reloading the dictionary rebuilds the
+    /// default per-word layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.compact();
+    ///
+    /// assert!(graph.is_compact());
+    /// assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+    /// ```
+    pub fn compact(&mut self) {
+        self.compact = true;
+        for subgraph in &mut self.subgraphs {
+            if let Some(adjacency) = subgraph.adjacency.take() {
+                subgraph.adjacency = OnceLock::from(adjacency.into_compact());
+            }
+        }
+    }
+
+    /// Returns `true` if the adjacency structure uses the compact CSR layout.
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    /// Estimates the heap memory used by words, adjacency, and indices.
+    ///
+    /// Compare the footprint before and after `compact` (with every subgraph
+    /// built by `build_all`) to decide whether the CSR layout is worth it for
+    /// a dictionary, or how many graphs a cache can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// assert_eq!(graph.memory_footprint().adjacency, 0);
+    ///
+    /// graph.build_all();
+    /// let lists = graph.memory_footprint();
+    /// graph.compact();
+    /// let csr = graph.memory_footprint();
+    ///
+    /// assert!(csr.adjacency < lists.adjacency);
+    /// assert_eq!(csr.words, lists.words);
+    /// ```
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let words = self.word_list.capacity() * size_of::<String>()
+            + self.word_list.iter().map(String::capacity).sum::<usize>();
+        let adjacency = self
+            .subgraphs
+            .iter()
+            .filter_map(|subgraph| subgraph.adjacency.get())
+            .map(Adjacency::heap_size)
+            .sum::<usize>();
+        let sources = self.sources.capacity() * size_of::<DictionarySource>()
+            + self
+                .sources
+                .iter()
+                .map(|source| source.tag.capacity() + string_set_size(&source.words))
+                .sum::<usize>();
+        let indices = self.word_ids.capacity() * (size_of::<(String, u32)>() + 1)
+            + self.word_ids.keys().map(String::capacity).sum::<usize>()
+            + string_set_size(&self.words)
+            + string_set_size(&self.base_words)
+            + string_set_size(&self.accepted_words)
+            + self.subgraphs.capacity() * size_of::<Subgraph>()
+            + sources;
+
+        MemoryFootprint {
+            words,
+            adjacency,
+            indices,
+            landmarks: self.landmarks.as_ref().map_or(0, Landmarks::heap_size),
+        }
+    }
+
+    /// Sets landmark distances, switching `find_shortest_path` to A*.
+    ///
+    /// Landmarks are dropped when the dictionary is reloaded or a word is
+    /// added or removed, since word IDs and distances change.
+    ///
+    /// # Arguments
+    ///
+    /// * `landmarks` - Landmarks built for this graph (see `Landmarks::build`)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error if the landmarks were built for a different
+    /// dictionary or different edge rules.
+    pub fn set_landmarks(&mut self, landmarks: Landmarks) -> Result<()> {
+        if !landmarks.matches(self) {
+            anyhow::bail!("Landmarks were built for a different dictionary or edge rules");
+        }
+        self.landmarks = Some(landmarks);
+        Ok(())
+    }
+
+    /// Returns the landmark distances, if set.
+    pub fn landmarks(&self) -> Option<&Landmarks> {
+        self.landmarks.as_ref()
+    }
+
+    /// Returns a lower bound on the number of steps between two words, using
+    /// landmark distances.
+    ///
+    /// # Returns
+    ///
+    /// `None` if either word is not in the dictionary or the landmarks prove
+    /// the words are not connected, otherwise the bound (0 without landmarks).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::graph::landmarks::Landmarks;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// assert_eq!(graph.estimate_distance("cat", "dog"), Some(0));
+    ///
+    /// graph.set_landmarks(Landmarks::build(&graph, 1)).unwrap();
+    /// assert_eq!(graph.estimate_distance("cat", "dog"), Some(3));
+    /// ```
+    pub fn estimate_distance(&self, start: &str, end: &str) -> Option<usize> {
+        let start_id = self.word_id(start)?;
+        let end_id = self.word_id(end)?;
+        match &self.landmarks {
+            Some(landmarks) => landmarks
+                .lower_bound(start_id, end_id)
+                .map(|bound| bound as usize),
+            None => Some(0),
+        }
+    }
+
+    /// Generates all valid neighbors for a given word.
+    ///
+    /// A neighbor is a word one step from the input under any of the edge
+    /// rules that exists in the dictionary. This method generates each rule's
+    /// candidates and keeps those found in the dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to find neighbors for
+    /// * `anagrams` - Word IDs grouped by anagram key (empty unless the
+    ///   `Anagram` rule is enabled)
+    /// * `graphemes` - Graphemes to substitute and insert (used under
+    ///   `ComparisonUnit::Grapheme` only)
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of neighboring word IDs
+    ///
+    /// # Performance
+    ///
+    /// Time complexity: O(L * 26) per rule where L is word length
+    fn generate_neighbors(
+        &self,
+        word: &str,
+        anagrams: &HashMap<Vec<char>, Vec<u32>>,
+        graphemes: &[&str],
+    ) -> Vec<u32> {
+        let mut neighbors: Vec<u32> = Vec::new();
+        if self.comparison_unit == ComparisonUnit::Grapheme {
+            let units = self.comparison_unit.units(word);
+            neighbors.extend(
+                self.edge_rules
+                    .iter()
+                    .flat_map(|rule| rule.candidates_from(&units, graphemes))
+                    .filter_map(|candidate| self.word_ids.get(&candidate).copied()),
+            );
+        } else if word.is_ascii() {
+            // Candidates of ASCII words are built in one reused buffer
+            let mut buffer = Vec::with_capacity(word.len() + 1);
+            for rule in &self.edge_rules {
+                rule.visit_ascii_candidates(word.as_bytes(), &mut buffer, |candidate| {
+                    if let Some(&id) = std::str::from_utf8(candidate)
+                        .ok()
+                        .and_then(|candidate| self.word_ids.get(candidate))
+                    {
+                        neighbors.push(id);
+                    }
+                });
+            }
+        } else {
+            let chars: Vec<char> = word.chars().collect();
+            neighbors.extend(
+                self.edge_rules
+                    .iter()
+                    .flat_map(|rule| rule.candidates(&chars))
+                    .filter_map(|candidate| self.word_ids.get(&candidate).copied()),
+            );
+        }
+        if let Some(group) = anagrams.get(&self.anagram_key_of(word)) {
+            let id = self.word_ids[word];
+            neighbors.extend(group.iter().copied().filter(|&other| other != id));
+        }
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Suggests dictionary words close to a word, for correcting typos.
+    ///
+    /// Dictionary words one letter substitution, insertion, deletion, or
+    /// adjacent swap from a string are looked up in a wildcard pattern index
+    /// (built on the first call and kept until the dictionary changes), so no
+    /// letters are tried. For larger distances, the strings up to
+    /// `max_distance - 1` edits away are generated with the letters the
+    /// dictionary uses and looked up the same way. Work grows quickly with the
+    /// distance, so 1 or 2 is the practical range.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The unknown word, already normalized
+    /// * `max_distance` - Most edits between the word and a suggestion
+    ///
+    /// # Returns
+    ///
+    /// Dictionary words within `max_distance` edits, closest first and
+    /// alphabetically within a distance. The word itself is never included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cart", "coat", "dog"].map(String::from));
+    ///
+    /// assert_eq!(graph.suggest_similar("catt", 1), ["cart", "cat"]);
+    /// assert_eq!(graph.suggest_similar("catt", 2), ["cart", "cat", "coat"]);
+    /// assert!(graph.suggest_similar("zebra", 2).is_empty());
+    /// ```
+    pub fn suggest_similar(&self, word: &str, max_distance: usize) -> Vec<String> {
+        const EDITS: [EdgeRule; 3] = [
+            EdgeRule::SubstituteOne,
+            EdgeRule::InsertOrDeleteOne,
+            EdgeRule::SwapAdjacent,
+        ];
+
+        let index = self.pattern_index();
+        let alphabet: Vec<&str> = index.alphabet.iter().map(String::as_str).collect();
+        let mut suggested: HashSet<u32> = self.word_ids.get(word).copied().into_iter().collect();
+        let mut seen: HashSet<String> = HashSet::from([word.to_string()]);
+        let mut frontier = vec![word.to_string()];
+        let mut suggestions = Vec::new();
+        for distance in 1..=max_distance {
+            let mut found = Vec::new();
+            for current in &frontier {
+                let units = self.comparison_unit.units(current);
+                for id in self.words_one_edit_from(index, &units) {
+                    if suggested.insert(id) {
+                        found.push(self.word_list[id as usize].clone());
+                    }
+                }
+            }
+            found.sort_unstable();
+            suggestions.extend(found);
+
+            if distance < max_distance {
+                // A letter the dictionary never uses can't be part of a
+                // shortest edit path to one of its words
+                let mut next = Vec::new();
+                for current in &frontier {
+                    let units = self.comparison_unit.units(current);
+                    for candidate in EDITS
+                        .iter()
+                        .flat_map(|rule| rule.candidates_from(&units, &alphabet))
+                    {
+                        if seen.insert(candidate.clone()) {
+                            next.push(candidate);
+                        }
+                    }
+                }
+                frontier = next;
+            }
+        }
+        suggestions
+    }
+
+    /// Returns the wildcard pattern index, building it on first use.
+    fn pattern_index(&self) -> &PatternIndex {
+        self.patterns.get_or_init(|| {
+            let mut index = PatternIndex::default();
+            let mut alphabet = HashSet::new();
+            for (id, word) in self.word_list.iter().enumerate() {
+                let units = self.comparison_unit.units(word);
+                for i in 0..units.len() {
+                    index
+                        .patterns
+                        .entry(wildcard_pattern(&units, i..i + 1))
+                        .or_default()
+                        .push(id as u32);
+                }
+                alphabet.extend(units);
+            }
+            index.alphabet = alphabet.into_iter().map(String::from).collect();
+            index.alphabet.sort_unstable();
+            index
+        })
+    }
+
+    /// Returns the IDs of dictionary words one substitution, insertion,
+    /// deletion, or adjacent swap from a string given as units.
+    ///
+    /// A word with a unit substituted matches the string with that unit
+    /// replaced by the wildcard, and a word with a unit inserted matches the
+    /// string with the wildcard inserted; deletions and swaps are looked up
+    /// directly. IDs may repeat, and the string's own ID is included if it is
+    /// a word.
+    fn words_one_edit_from(&self, index: &PatternIndex, units: &[&str]) -> Vec<u32> {
+        let mut ids = Vec::new();
+        for i in 0..=units.len() {
+            let mut patterns = vec![wildcard_pattern(units, i..i)];
+            if i < units.len() {
+                patterns.push(wildcard_pattern(units, i..i + 1));
+            }
+            for pattern in patterns {
+                ids.extend(index.patterns.get(&pattern).into_iter().flatten());
+            }
+        }
+        // Without an alphabet, these rules only delete and swap units
+        ids.extend(
+            [EdgeRule::InsertOrDeleteOne, EdgeRule::SwapAdjacent]
+                .iter()
+                .flat_map(|rule| rule.candidates_from(units, &[]))
+                .filter_map(|candidate| self.word_ids.get(&candidate).copied()),
+        );
+        ids
+    }
+
+    /// Checks that a ladder between two words is possible before searching.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or the first problem found: different lengths (unless an
+    /// edge rule changes the length), then a start or end word missing from
+    /// the dictionary. Whether a path exists is not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{LadderError, WordGraph};
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "dog"].map(String::from));
+    /// assert!(graph.check_pair("cat", "dog").is_ok());
+    /// assert!(matches!(
+    ///     graph.check_pair("cat", "horse"),
+    ///     Err(LadderError::DifferentLengths { .. })
+    /// ));
+    /// assert_eq!(
+    ///     graph.check_pair("cat", "emu"),
+    ///     Err(LadderError::EndNotInDictionary("emu".to_string()))
+    /// );
+    /// ```
+    pub fn check_pair(&self, start: &str, end: &str) -> Result<(), LadderError> {
+        let start_length = self.comparison_unit.length(start);
+        let end_length = self.comparison_unit.length(end);
+        if !self.crosses_lengths() && start_length != end_length {
+            return Err(LadderError::DifferentLengths {
+                start: start.to_string(),
+                end: end.to_string(),
+                start_length,
+                end_length,
+            });
+        }
+        if !self.words.contains(start) {
+            return Err(LadderError::StartNotInDictionary(start.to_string()));
+        }
+        if !self.words.contains(end) {
+            return Err(LadderError::EndNotInDictionary(end.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Finds the shortest path between two words, explaining a failure.
+    ///
+    /// Like `find_shortest_path`, but reports why no ladder exists instead of
+    /// returning `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// The path including both words, or the `LadderError` from `check_pair`,
+    /// or `LadderError::NoPath` if the words are not connected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{LadderError, WordGraph};
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "cog", "dog", "emu"].map(String::from));
+    /// assert_eq!(graph.solve("cat", "dog").unwrap().len(), 4);
+    /// assert!(matches!(graph.solve("cat", "emu"), Err(LadderError::NoPath { .. })));
+    /// assert_eq!(
+    ///     graph.solve("cat", "horse").unwrap_err().to_string(),
+    ///     "cat and horse have different lengths (3 and 5 letters)"
+    /// );
+    /// ```
+    pub fn solve(&self, start: &str, end: &str) -> Result<Vec<String>, LadderError> {
+        self.check_pair(start, end)?;
+        self.find_shortest_path(start, end)
+            .ok_or_else(|| LadderError::NoPath {
+                start: start.to_string(),
+                end: end.to_string(),
+            })
+    }
+
+    /// Finds the shortest path between two words using BFS.
+    ///
+    /// This method implements breadth-first search to find the shortest path
+    /// between a start and end word. The path consists of words where each
+    /// consecutive pair differs by exactly one letter.
+    ///
+    /// If landmarks are set (see `set_landmarks`), the search runs A* instead.
+    /// The path has the same length, but when several shortest paths exist it
+    /// may pick a different one than BFS.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(path)` if a path exists, `None` if no path is found.
+    /// The path includes both start and end words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// // ... load dictionary ...
+    /// # graph.load_dictionary("data/dictionary.txt").ok();
+    ///
+    /// if let Some(path) = graph.find_shortest_path("cat", "dog") {
+    ///     println!("Path: {:?}", path); // ["cat", "cot", "cog", "dog"]
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// Time complexity: O(V + E) where V is vertices (words), E is edges
+    pub fn find_shortest_path(&self, start: &str, end: &str) -> Option<Vec<String>> {
+        if start == end {
+            return Some(vec![start.to_string()]);
+        }
+
+        let start_id = self.word_id(start)?;
+        let end_id = self.word_id(end)?;
+        if let Some(landmarks) = &self.landmarks {
+            return self.astar_path(landmarks, start_id, end_id);
+        }
+        self.bfs_path(
+            vec![UNVISITED; self.word_list.len()],
+            start_id,
+            end_id,
+            usize::MAX,
+            &SolveOptions::new(),
+            None,
+        )
+        .into_path()
+    }
+
+    /// Finds the shortest path between two words if it has at most `max_steps` steps.
+    ///
+    /// The search stops after exploring `max_steps` BFS layers instead of
+    /// covering the whole connected component, so "is there a ladder within N
+    /// steps?" is cheap even when the answer is no.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `max_steps` - Most steps the path may have
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(path)` with the shortest path if it has at most
+    /// `max_steps` steps, `None` if it is longer or no path exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// assert_eq!(graph.find_path_within("cat", "dog", 3).unwrap().len(), 4);
+    /// assert_eq!(graph.find_path_within("cat", "dog", 2), None);
+    /// ```
+    pub fn find_path_within(
+        &self,
+        start: &str,
+        end: &str,
+        max_steps: usize,
+    ) -> Option<Vec<String>> {
+        if start == end {
+            return Some(vec![start.to_string()]);
+        }
+
+        let start_id = self.word_id(start)?;
+        let end_id = self.word_id(end)?;
+        self.bfs_path(
+            vec![UNVISITED; self.word_list.len()],
+            start_id,
+            end_id,
+            max_steps,
+            &SolveOptions::new(),
+            None,
+        )
+        .into_path()
+    }
+
+    /// Finds the shortest path between two words that passes through none of
+    /// the banned words.
+    ///
+    /// This gives an alternative route when the regular shortest path goes
+    /// through an objectionable or obscure word. Words in `banned` that are
+    /// not in the dictionary are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `banned` - Words the path may not contain
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(path)` with the shortest remaining path, or `None` if no
+    /// path avoids the banned words or `start` or `end` is itself banned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+    ///
+    /// let banned = HashSet::from(["cog".to_string()]);
+    /// let path = graph.find_shortest_path_avoiding("cat", "dog", &banned).unwrap();
+    /// assert_eq!(path, vec!["cat", "cot", "dot", "dog"]);
+    /// ```
+    pub fn find_shortest_path_avoiding(
+        &self,
+        start: &str,
+        end: &str,
+        banned: &HashSet<String>,
+    ) -> Option<Vec<String>> {
+        self.search_avoiding(start, end, banned, &SolveOptions::new(), None)
+            .into_path()
+    }
+
+    /// Finds the shortest path like `find_shortest_path`, giving up once the
+    /// search exceeds the budget in `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `options` - Visit and time budget of the search
+    ///
+    /// # Returns
+    ///
+    /// The path, `SolveOutcome::NoPath` if the words are not connected, or
+    /// `SolveOutcome::BudgetExceeded` if the search was cut short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{SolveOptions, SolveOutcome, WordGraph};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let tight = SolveOptions::new().with_max_visited(2);
+    /// assert_eq!(graph.find_shortest_path_with("cat", "dog", &tight), SolveOutcome::BudgetExceeded);
+    ///
+    /// let path = graph.find_shortest_path_with("cat", "dog", &SolveOptions::new());
+    /// assert_eq!(path.into_path().unwrap().len(), 4);
+    /// ```
+    pub fn find_shortest_path_with(
+        &self,
+        start: &str,
+        end: &str,
+        options: &SolveOptions,
+    ) -> SolveOutcome {
+        self.search_avoiding(start, end, &HashSet::new(), options, None)
+    }
+
+    /// Finds a path like `find_shortest_path_avoiding`, giving up once the
+    /// search exceeds the budget in `options` (see `find_shortest_path_with`).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `banned` - Words the path may not contain
+    /// * `options` - Visit and time budget of the search
+    pub fn find_shortest_path_avoiding_with(
+        &self,
+        start: &str,
+        end: &str,
+        banned: &HashSet<String>,
+        options: &SolveOptions,
+    ) -> SolveOutcome {
+        self.search_avoiding(start, end, banned, options, None)
+    }
+
+    /// Finds the shortest path like `find_shortest_path`, also recording how
+    /// the search progressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// The same path `find_shortest_path` returns, and a trace with the
+    /// frontier size and newly visited words per BFS layer. The trace has no
+    /// layers if no search was needed (equal or unknown words).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let (path, trace) = graph.find_shortest_path_traced("cat", "dog");
+    /// assert_eq!(path.unwrap().len(), 4);
+    /// assert!(trace.found);
+    /// assert_eq!(trace.layers.len(), 3);
+    /// assert_eq!(trace.visited, 4);
+    /// ```
+    pub fn find_shortest_path_traced(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> (Option<Vec<String>>, SolveTrace) {
+        self.find_shortest_path_avoiding_traced(start, end, &HashSet::new())
+    }
+
+    /// Finds a path like `find_shortest_path_avoiding`, also recording how the
+    /// search progressed (see `find_shortest_path_traced`).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `banned` - Words the path may not contain
+    pub fn find_shortest_path_avoiding_traced(
+        &self,
+        start: &str,
+        end: &str,
+        banned: &HashSet<String>,
+    ) -> (Option<Vec<String>>, SolveTrace) {
+        let mut trace = SolveTrace::default();
+        let path = self
+            .search_avoiding(start, end, banned, &SolveOptions::new(), Some(&mut trace))
+            .into_path();
+        trace.found = path.is_some();
+        (path, trace)
+    }
+
+    /// Shared implementation of the `find_shortest_path_avoiding` variants.
+    fn search_avoiding(
+        &self,
+        start: &str,
+        end: &str,
+        banned: &HashSet<String>,
+        options: &SolveOptions,
+        trace: Option<&mut SolveTrace>,
+    ) -> SolveOutcome {
+        if banned.contains(start) || banned.contains(end) {
+            return SolveOutcome::NoPath;
+        }
+        if start == end {
+            return SolveOutcome::Found(vec![start.to_string()]);
+        }
+
+        let (Some(start_id), Some(end_id)) = (self.word_id(start), self.word_id(end)) else {
+            return SolveOutcome::NoPath;
+        };
+
+        // Marking banned words as visited keeps the BFS from entering them
+        let mut parent = vec![UNVISITED; self.word_list.len()];
+        for id in banned.iter().filter_map(|word| self.word_id(word)) {
+            parent[id as usize] = id;
+        }
+        self.bfs_path(parent, start_id, end_id, usize::MAX, options, trace)
+    }
+
+    /// Finds the shortest paths from one word to many targets with a single BFS.
+    ///
+    /// This is much cheaper than calling `find_shortest_path` once per target:
+    /// the search runs once and stops as soon as every reachable target has
+    /// been found. Each path is the same one `find_shortest_path` returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `targets` - Words to find paths to
+    ///
+    /// # Returns
+    ///
+    /// A map from each reachable target to its path (including `start` and the
+    /// target). Targets that are unreachable or not in the dictionary are
+    /// left out, as is everything if `start` is not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\nhen\n");
+    ///
+    /// let paths = graph.shortest_paths_to_many("cat", &["cog", "dog", "hen"]);
+    /// assert_eq!(paths["dog"], vec!["cat", "cot", "cog", "dog"]);
+    /// assert_eq!(paths["cog"].len(), 3);
+    /// assert!(!paths.contains_key("hen"));
+    /// ```
+    pub fn shortest_paths_to_many(
+        &self,
+        start: &str,
+        targets: &[&str],
+    ) -> HashMap<String, Vec<String>> {
+        let mut paths = HashMap::new();
+        let Some(start_id) = self.word_id(start) else {
+            return paths;
+        };
+        let mut remaining: HashSet<u32> = targets
+            .iter()
+            .filter_map(|target| self.word_id(target))
+            .collect();
+        if remaining.remove(&start_id) {
+            paths.insert(start.to_string(), vec![start.to_string()]);
+        }
+
+        let mut parent = vec![UNVISITED; self.word_list.len()];
+        parent[start_id as usize] = start_id;
+        let mut frontier = vec![start_id];
+
+        while !frontier.is_empty() && !remaining.is_empty() {
+            let mut next = Vec::new();
+            for &current in &frontier {
+                for &neighbor in self.neighbors(current) {
+                    if parent[neighbor as usize] != UNVISITED {
+                        continue;
+                    }
+                    parent[neighbor as usize] = current;
+                    if remaining.remove(&neighbor) {
+                        paths.insert(
+                            self.word_list[neighbor as usize].clone(),
+                            self.reconstruct_path(&parent, start_id, neighbor),
+                        );
+                    }
+                    next.push(neighbor);
+                }
+            }
+            frontier = next;
+        }
+        paths
+    }
+
+    /// Runs an A* search from `start` to `end`, using landmark lower bounds as
+    /// the heuristic.
+    ///
+    /// The bounds are consistent, so each word is expanded at most once and
+    /// the first time `end` is popped its path is a shortest one. Words the
+    /// landmarks prove cannot reach `end` are never queued.
+    fn astar_path(&self, landmarks: &Landmarks, start_id: u32, end_id: u32) -> Option<Vec<String>> {
+        let estimator = landmarks.estimator(end_id);
+        let mut parent = vec![UNVISITED; self.word_list.len()];
+        let mut cost = vec![UNVISITED; self.word_list.len()];
+        parent[start_id as usize] = start_id;
+        cost[start_id as usize] = 0;
+
+        // Ordered by estimated total, then by deepest first to break ties
+        let mut open = BinaryHeap::new();
+        open.push(Reverse((
+            estimator.estimate(start_id)?,
+            Reverse(0),
+            start_id,
+        )));
+        while let Some(Reverse((_, Reverse(steps), current))) = open.pop() {
+            if current == end_id {
+                return Some(self.reconstruct_path(&parent, start_id, end_id));
+            }
+            if steps > cost[current as usize] {
+                continue;
+            }
+            for &neighbor in self.neighbors(current) {
+                let next_steps = steps + 1;
+                if next_steps >= cost[neighbor as usize] {
+                    continue;
+                }
+                let Some(bound) = estimator.estimate(neighbor) else {
+                    continue;
+                };
+                cost[neighbor as usize] = next_steps;
+                parent[neighbor as usize] = current;
+                open.push(Reverse((next_steps + bound, Reverse(next_steps), neighbor)));
+            }
+        }
+        None
+    }
+
+    /// Runs a BFS from `start` to `end`, skipping words already marked in `parent`.
+    ///
+    /// The search goes layer by layer and gives up after `max_steps` layers,
+    /// or as soon as it exceeds the visit or time budget in `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Parent word ID per word ID: `UNVISITED` for words the
+    ///   search may enter, anything else for words it must skip
+    /// * `start` - Starting word ID
+    /// * `end` - Ending word ID
+    /// * `max_steps` - Most steps the path may have
+    /// * `options` - Visit and time budget of the search
+    /// * `trace` - Trace to record layer statistics in, if any
+    ///
+    /// # Returns
+    ///
+    /// The path from start to end, `SolveOutcome::NoPath` if `end` is not
+    /// reachable within `max_steps` steps, or `SolveOutcome::BudgetExceeded`.
+    fn bfs_path(
+        &self,
+        mut parent: Vec<u32>,
+        start_id: u32,
+        end_id: u32,
+        max_steps: usize,
+        options: &SolveOptions,
+        mut trace: Option<&mut SolveTrace>,
+    ) -> SolveOutcome {
+        // The clock is only read when needed; it is unavailable on wasm32
+        let started = (trace.is_some() || options.timeout.is_some()).then(Instant::now);
+        let elapsed = || started.map_or(Duration::ZERO, |started| started.elapsed());
+        let max_visited = options.max_visited.unwrap_or(usize::MAX);
+        parent[start_id as usize] = start_id;
+        let mut frontier = vec![start_id];
+        let mut steps = 0;
+        let mut visited = 1;
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.visited = 1;
+        }
+
+        while !frontier.is_empty() && steps < max_steps {
+            let mut next = Vec::new();
+            let mut found = false;
+            let mut exceeded = false;
+            'expand: for &current in &frontier {
+                if options.timeout.is_some_and(|timeout| elapsed() >= timeout) {
+                    exceeded = true;
+                    break;
+                }
+                for &neighbor in self.neighbors(current) {
+                    if parent[neighbor as usize] == UNVISITED {
+                        parent[neighbor as usize] = current;
+                        next.push(neighbor);
+                        visited += 1;
+                        if neighbor == end_id {
+                            found = true;
+                            break 'expand;
+                        }
+                        if visited > max_visited {
+                            exceeded = true;
+                            break 'expand;
+                        }
+                    }
+                }
+            }
+            if let Some(trace) = trace.as_deref_mut() {
+                let elapsed_micros = elapsed().as_micros() as u64;
+                trace.layers.push(LayerTrace {
+                    depth: steps,
+                    frontier: frontier.len(),
+                    visited: next.len(),
+                    elapsed_micros,
+                });
+                trace.visited += next.len();
+                trace.elapsed_micros = elapsed_micros;
+            }
+            if found {
+                return SolveOutcome::Found(self.reconstruct_path(&parent, start_id, end_id));
+            }
+            if exceeded {
+                return SolveOutcome::BudgetExceeded;
+            }
+            frontier = next;
+            steps += 1;
+        }
+        if let Some(trace) = trace {
+            trace.elapsed_micros = elapsed().as_micros() as u64;
+        }
+        SolveOutcome::NoPath
+    }
+
+    /// Returns `true` if exactly one shortest path connects two words.
+    ///
+    /// Competitive puzzles need a unique optimal solution so every solver's
+    /// answer can be checked against the same ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// `true` if the words are connected by exactly one shortest path, `false`
+    /// if there are several or none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+    ///
+    /// assert!(graph.has_unique_shortest_path("cat", "cog"));
+    /// assert!(!graph.has_unique_shortest_path("cat", "dog")); // via cog or dot
+    /// ```
+    pub fn has_unique_shortest_path(&self, start: &str, end: &str) -> bool {
+        self.count_shortest_paths(start, end) == 1
+    }
+
+    /// Counts the distinct shortest paths between two words.
+    ///
+    /// Runs BFS layer by layer, giving each word the sum of the counts of its
+    /// neighbors one layer closer to `start`, and stops once `end`'s layer is
+    /// complete. This ranks puzzles by "solution richness" without enumerating
+    /// every path, which can be exponential.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// The number of shortest paths, `0` if the words are not connected or not
+    /// in the dictionary, and `1` if `start == end`. Counts saturate at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+    ///
+    /// assert_eq!(graph.count_shortest_paths("cat", "dog"), 2);
+    /// assert_eq!(graph.count_shortest_paths("cat", "zzz"), 0);
+    /// ```
+    pub fn count_shortest_paths(&self, start: &str, end: &str) -> u64 {
+        let (Some(start), Some(end)) = (self.word_id(start), self.word_id(end)) else {
+            return 0;
+        };
+
+        let mut depth = vec![UNVISITED; self.word_list.len()];
+        let mut count = vec![0u64; self.word_list.len()];
+        depth[start as usize] = 0;
+        count[start as usize] = 1;
+
+        let mut frontier = vec![start];
+        while !frontier.is_empty() && depth[end as usize] == UNVISITED {
+            let mut next = Vec::new();
+            for &current in &frontier {
+                let next_depth = depth[current as usize] + 1;
+                for &neighbor in self.neighbors(current) {
+                    if depth[neighbor as usize] == UNVISITED {
+                        depth[neighbor as usize] = next_depth;
+                        next.push(neighbor);
+                    }
+                    if depth[neighbor as usize] == next_depth {
+                        count[neighbor as usize] =
+                            count[neighbor as usize].saturating_add(count[current as usize]);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        count[end as usize]
+    }
+
+    /// Finds a ladder back from the end of a path to its start through different words.
+    ///
+    /// A "mirror puzzle" can be solved in both directions along different
+    /// routes: the returned path runs from the last word of `path` to its
+    /// first word, has the same number of steps, and shares none of its
+    /// intermediate words.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A ladder, normally a shortest path, from its first to its last word
+    ///
+    /// # Returns
+    ///
+    /// The reverse path (starting at the end word), or `None` if no path of
+    /// the same length avoids the intermediate words, `path` has fewer than
+    /// two steps, or its endpoints are not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncag\ndag\n");
+    ///
+    /// let ladder = |words: [&str; 4]| words.map(String::from).to_vec();
+    ///
+    /// let path = ladder(["cat", "cot", "cog", "dog"]);
+    /// assert_eq!(graph.find_mirror_path(&path).unwrap(), vec!["dog", "dag", "cag", "cat"]);
+    ///
+    /// // Going via cag and cog leaves no way back
+    /// let path = ladder(["cat", "cag", "cog", "dog"]);
+    /// assert_eq!(graph.find_mirror_path(&path), None);
+    /// ```
+    pub fn find_mirror_path(&self, path: &[String]) -> Option<Vec<String>> {
+        if path.len() < 3 {
+            return None;
+        }
+        let start_id = self.word_id(&path[0])?;
+        let end_id = self.word_id(&path[path.len() - 1])?;
+
+        let mut parent = vec![UNVISITED; self.word_list.len()];
+        for id in path[1..path.len() - 1]
+            .iter()
+            .filter_map(|word| self.word_id(word))
+        {
+            parent[id as usize] = id;
+        }
+        self.bfs_path(
+            parent,
+            end_id,
+            start_id,
+            path.len() - 1,
+            &SolveOptions::new(),
+            None,
+        )
+        .into_path()
+        .filter(|mirror| mirror.len() == path.len())
+    }
+
+    /// Finds two shortest ladders between two words that share no intermediate words.
+    ///
+    /// Unlike `find_mirror_path`, which keeps a given ladder fixed, this
+    /// searches every shortest ladder, so it also finds mirror puzzles whose
+    /// regular shortest path happens to block the way back. It routes two
+    /// units of flow through the shortest-path DAG with every word able to
+    /// carry only one.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// `Some((path, reverse))` with a shortest path from `start` to `end` and
+    /// a ladder from `end` back to `start` with the same number of steps and
+    /// none of its intermediate words, or `None` if there are no two such
+    /// ladders, the words are fewer than two steps apart, or either word is
+    /// not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncag\ndag\n");
+    ///
+    /// let (path, reverse) = graph.find_mirror_ladders("cat", "dog").unwrap();
+    /// assert_eq!(path, vec!["cat", "cag", "dag", "dog"]);
+    /// assert_eq!(reverse, vec!["dog", "cog", "cot", "cat"]);
+    /// assert_eq!(graph.find_mirror_ladders("cat", "dag"), None); // only via cag
+    /// ```
+    pub fn find_mirror_ladders(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> Option<(Vec<String>, Vec<String>)> {
+        let start_id = self.word_id(start)?;
+        let end_id = self.word_id(end)?;
+        let from_start = &self.bfs_distances(start_id);
+        let from_end = &self.bfs_distances(end_id);
+        let steps = from_start[end_id as usize];
+        if steps == UNVISITED || steps < 2 {
+            return None;
+        }
+
+        // Edges of the shortest-path DAG lead one step further from `start`
+        // while staying on some shortest ladder to `end`
+        let on_ladder = move |id: u32| {
+            from_end[id as usize] != UNVISITED
+                && from_start[id as usize] + from_end[id as usize] == steps
+        };
+        let successors = move |id: u32| {
+            self.neighbors(id).iter().copied().filter(move |&next| {
+                from_start[next as usize] == from_start[id as usize] + 1 && on_ladder(next)
+            })
+        };
+        let predecessors = move |id: u32| {
+            self.neighbors(id).iter().copied().filter(move |&prev| {
+                from_start[prev as usize] + 1 == from_start[id as usize] && on_ladder(prev)
+            })
+        };
+
+        // Each word is split into an entry and an exit node (`false`/`true`)
+        // joined by an edge of capacity one; `used_words` holds the words
+        // whose edge carries flow and `used_steps` the DAG edges that do
+        let mut used_words: HashSet<u32> = HashSet::new();
+        let mut used_steps: HashSet<(u32, u32)> = HashSet::new();
+        for _ in 0..2 {
+            let source = (start_id, true);
+            let sink = (end_id, false);
+            let mut parent: HashMap<(u32, bool), (u32, bool)> = HashMap::new();
+            let mut queue = VecDeque::from([source]);
+            while let Some(node) = queue.pop_front() {
+                if node == sink {
+                    break;
+                }
+                let (id, exit) = node;
+                let next: Vec<(u32, bool)> = if exit {
+                    successors(id)
+                        .filter(|&next| !used_steps.contains(&(id, next)))
+                        .map(|next| (next, false))
+                        .chain(used_words.contains(&id).then_some((id, false)))
+                        .collect()
+                } else {
+                    predecessors(id)
+                        .filter(|&prev| used_steps.contains(&(prev, id)))
+                        .map(|prev| (prev, true))
+                        .chain((!used_words.contains(&id)).then_some((id, true)))
+                        .collect()
+                };
+                for next in next {
+                    if next != source && !parent.contains_key(&next) {
+                        parent.insert(next, node);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            let mut node = sink;
+            while node != source {
+                let prev = *parent.get(&node)?;
+                // Every move switches between entry and exit nodes
+                match (prev.1, prev.0 == node.0) {
+                    (false, true) => used_words.insert(node.0),
+                    (true, true) => used_words.remove(&node.0),
+                    (true, false) => used_steps.insert((prev.0, node.0)),
+                    (false, false) => used_steps.remove(&(node.0, prev.0)),
+                };
+                node = prev;
+            }
+        }
+
+        let mut ladders = successors(start_id)
+            .filter(|&next| used_steps.contains(&(start_id, next)))
+            .map(|mut current| {
+                let mut ladder = vec![start_id, current];
+                while current != end_id {
+                    current = successors(current)
+                        .find(|&next| used_steps.contains(&(current, next)))
+                        .expect("flow leaves every word it enters");
+                    ladder.push(current);
+                }
+                ladder
+            });
+        let to_words = |ladder: Vec<u32>| -> Vec<String> {
+            ladder
+                .into_iter()
+                .map(|id| self.word_list[id as usize].clone())
+                .collect()
+        };
+        let path = to_words(ladders.next()?);
+        let mut reverse = to_words(ladders.next()?);
+        reverse.reverse();
+        Some((path, reverse))
+    }
+
+    /// Lists up to `limit` distinct shortest paths between two words.
+    ///
+    /// Useful for showing curators the alternative solutions of a puzzle.
+    /// Paths are returned in lexicographic order of their words.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `limit` - Maximum number of paths to return
+    ///
+    /// # Returns
+    ///
+    /// The shortest paths found, each including both endpoints, or an empty
+    /// vector if the words are not connected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+    ///
+    /// let paths = graph.shortest_paths("cat", "dog", 10);
+    /// assert_eq!(paths, vec![
+    ///     vec!["cat", "cot", "cog", "dog"],
+    ///     vec!["cat", "cot", "dot", "dog"],
+    /// ]);
+    /// ```
+    pub fn shortest_paths(&self, start: &str, end: &str, limit: usize) -> Vec<Vec<String>> {
+        let (Some(start), Some(end)) = (self.word_id(start), self.word_id(end)) else {
+            return Vec::new();
+        };
+
+        // Distances from `end`, so the walk from `start` can follow any
+        // neighbor that is exactly one step closer.
+        let mut depth = vec![UNVISITED; self.word_list.len()];
+        depth[end as usize] = 0;
+        for (distance, layer) in self.bfs_layers(end).iter().enumerate() {
+            for &id in layer {
+                depth[id as usize] = distance as u32;
+            }
+        }
+        if depth[start as usize] == UNVISITED {
+            return Vec::new();
+        }
+
+        let mut paths = Vec::new();
+        let mut stack = vec![start];
+        self.collect_shortest_paths(&depth, &mut stack, &mut paths, limit);
+        paths
+    }
+
+    /// Depth-first walk along decreasing distances, collecting complete paths.
+    fn collect_shortest_paths(
+        &self,
+        depth: &[u32],
+        stack: &mut Vec<u32>,
+        paths: &mut Vec<Vec<String>>,
+        limit: usize,
+    ) {
+        let current = *stack.last().unwrap();
+        if depth[current as usize] == 0 {
+            paths.push(
+                stack
+                    .iter()
+                    .map(|&id| self.word_list[id as usize].clone())
+                    .collect(),
+            );
+            return;
+        }
+
+        for &neighbor in self.neighbors(current) {
+            if paths.len() >= limit {
+                return;
+            }
+            if depth[neighbor as usize] + 1 == depth[current as usize] {
+                stack.push(neighbor);
+                self.collect_shortest_paths(depth, stack, paths, limit);
+                stack.pop();
+            }
+        }
+    }
+
+    /// Finds the shortest path between two words using bidirectional BFS.
+    ///
+    /// Searches simultaneously from both ends, always expanding the smaller
+    /// frontier by one full layer, and stops once the two searches meet. This
+    /// visits far fewer words than a one-sided BFS on long ladders. The result
+    /// always has the same length as `find_shortest_path`, although it may pick
+    /// a different path when several shortest paths exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(path)` if a path exists, `None` if no path is found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let path = graph.find_shortest_path_bidirectional("cat", "dog").unwrap();
+    /// assert_eq!(path, vec!["cat", "cot", "cog", "dog"]);
+    /// ```
+    pub fn find_shortest_path_bidirectional(&self, start: &str, end: &str) -> Option<Vec<String>> {
+        if start == end {
+            return Some(vec![start.to_string()]);
+        }
+
+        let start_id = self.word_id(start)?;
+        let end_id = self.word_id(end)?;
+        let word_count = self.word_list.len();
+
+        let mut forward = SearchSide::new(start_id, word_count);
+        let mut backward = SearchSide::new(end_id, word_count);
+
+        while !forward.frontier.is_empty() && !backward.frontier.is_empty() {
+            let meeting = if forward.frontier.len() <= backward.frontier.len() {
+                self.expand_layer(&mut forward, &backward)
+            } else {
+                self.expand_layer(&mut backward, &forward)
+            };
+
+            if let Some(meeting) = meeting {
+                let mut path = self.reconstruct_path(&forward.parent, start_id, meeting);
+                let mut current = meeting;
+                while current != end_id {
+                    current = backward.parent[current as usize];
+                    path.push(self.word_list[current as usize].clone());
+                }
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    /// Groups every word reachable from `start` by its BFS distance.
+    ///
+    /// Layer `i` of the result contains all words exactly `i` steps from
+    /// `start` (layer 0 is `start` itself). Words are sorted within each layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Word to measure distances from
+    ///
+    /// # Returns
+    ///
+    /// The distance layers, or an empty vector if `start` is not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let layers = graph.distance_layers("cat");
+    /// assert_eq!(layers, vec![vec!["cat"], vec!["cot"], vec!["cog"], vec!["dog"]]);
+    /// ```
+    pub fn distance_layers(&self, start: &str) -> Vec<Vec<String>> {
+        let Some(start_id) = self.word_id(start) else {
+            return Vec::new();
+        };
+
+        self.bfs_layers(start_id)
+            .into_iter()
+            .map(|layer| {
+                let mut words: Vec<String> = layer
+                    .into_iter()
+                    .map(|id| self.word_list[id as usize].clone())
+                    .collect();
+                words.sort();
+                words
+            })
+            .collect()
+    }
+
+    /// Returns all words at least `min_steps` BFS steps away from `start`.
+    ///
+    /// This is the basis for finding hard puzzles efficiently: any word in the
+    /// result is guaranteed to need at least `min_steps` steps from `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Word to measure distances from
+    /// * `min_steps` - Minimum BFS distance of returned words
+    ///
+    /// # Returns
+    ///
+    /// The matching words ordered by increasing distance, or an empty vector if
+    /// `start` is not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// assert_eq!(graph.farthest_words("cat", 2), vec!["cog", "dog"]);
+    /// ```
+    pub fn farthest_words(&self, start: &str, min_steps: usize) -> Vec<String> {
+        self.distance_layers(start)
+            .into_iter()
+            .skip(min_steps)
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the number of neighbors of a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to look up
+    ///
+    /// # Returns
+    ///
+    /// The word's degree, or `None` if it is not in the dictionary.
+    pub fn degree(&self, word: &str) -> Option<usize> {
+        self.word_id(word).map(|id| self.neighbors(id).len())
+    }
+
+    /// Removes words with no neighbors from the dictionary.
+    ///
+    /// Isolated words can never appear in a ladder, so dropping them shrinks
+    /// exports (e.g. the mobile dictionary) without losing any puzzle. Word IDs
+    /// are reassigned; a compact graph stays compact. Base words are unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The number of words removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\nzzz\n");
+    ///
+    /// assert_eq!(graph.prune_isolated(), 1);
+    /// assert!(!graph.get_words().contains("zzz"));
+    /// ```
+    pub fn prune_isolated(&mut self) -> usize {
+        let isolated: Vec<&String> = self
+            .word_list
+            .iter()
+            .enumerate()
+            .filter(|&(id, _)| self.neighbors(id as u32).is_empty())
+            .map(|(_, word)| word)
+            .collect();
+        if isolated.is_empty() {
+            return 0;
+        }
+
+        let removed = isolated.len();
+        for word in isolated {
+            self.words.remove(word);
+        }
+        let compact = self.is_compact();
+        self.build_graph();
+        if compact {
+            self.compact();
+        }
+        removed
+    }
+
+    /// Adds a word to the dictionary without rebuilding the graph.
+    ///
+    /// The word is normalized like dictionary file lines, by the graph's
+    /// `WordNormalization`. Only the subgraph holding words of its length changes: if
+    /// it is already built, the word's neighbors are linked in place, and the
+    /// IDs of longer words shift by one. The word is not added to the base
+    /// words or to any tagged source, so reloading the dictionary or changing
+    /// the merge policy drops it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to add
+    ///
+    /// # Returns
+    ///
+    /// `true` if the word was added, `false` if it was already in the
+    /// dictionary or is skipped by the graph's `WordNormalization`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncog\ndog\n");
+    /// assert!(graph.find_shortest_path("cat", "dog").is_none());
+    ///
+    /// assert!(graph.add_word("Cot"));
+    /// assert!(!graph.add_word("cot"));
+    /// assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+    /// ```
+    pub fn add_word(&mut self, word: &str) -> bool {
+        let Some(word) = self.normalize(word) else {
+            return false;
+        };
+        if self.words.contains(&word) {
+            return false;
+        }
+
+        let unit = self.comparison_unit;
+        let length = unit.length(&word);
+        let crosses_lengths = self.crosses_lengths();
+        let id = self
+            .word_list
+            .partition_point(|other| (unit.length(other), other.as_str()) < (length, &word))
+            as u32;
+        let index = match self.subgraphs.iter().position(|subgraph| {
+            crosses_lengths || unit.length(&self.word_list[subgraph.start as usize]) == length
+        }) {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .subgraphs
+                    .partition_point(|subgraph| subgraph.start < id);
+                self.subgraphs.insert(
+                    index,
+                    Subgraph {
+                        start: id,
+                        end: id,
+                        adjacency: OnceLock::new(),
+                    },
+                );
+                index
+            }
+        };
+
+        self.words.insert(word.clone());
+        self.landmarks = None;
+        self.patterns = OnceLock::new();
+        self.word_list.insert(id as usize, word.clone());
+        self.word_ids.insert(word.clone(), id);
+        for (offset, other) in self.word_list[id as usize + 1..].iter().enumerate() {
+            *self.word_ids.get_mut(other).unwrap() = id + 1 + offset as u32;
+        }
+        for subgraph in &mut self.subgraphs[index + 1..] {
+            subgraph.start += 1;
+            subgraph.end += 1;
+            if let Some(adjacency) = subgraph.adjacency.get_mut() {
+                adjacency.map_ids(|other| other + 1);
+            }
+        }
+
+        let subgraph = &mut self.subgraphs[index];
+        subgraph.end += 1;
+        let (start, end) = (subgraph.start, subgraph.end);
+        let Some(adjacency) = subgraph.adjacency.take() else {
+            return true;
+        };
+        let mut lists = adjacency.into_lists();
+        for list in &mut lists {
+            for other in list.iter_mut().filter(|other| **other >= id) {
+                *other += 1;
+            }
+        }
+
+        let mut anagrams: HashMap<Vec<char>, Vec<u32>> = HashMap::new();
+        if self.edge_rules.contains(&EdgeRule::Anagram) {
+            let key = self.anagram_key_of(&word);
+            let group = (start..end)
+                .filter(|&other| self.anagram_key_of(&self.word_list[other as usize]) == key)
+                .collect();
+            anagrams.insert(key, group);
+        }
+        let graphemes = self.graphemes(start, end);
+        let neighbors = self.generate_neighbors(&word, &anagrams, &graphemes);
+        lists.insert((id - start) as usize, neighbors.clone());
+        for neighbor in neighbors {
+            let list = &mut lists[(neighbor - start) as usize];
+            let position = list.partition_point(|&other| other < id);
+            list.insert(position, id);
+        }
+        self.subgraphs[index].adjacency = OnceLock::from(self.with_layout(lists));
+        true
+    }
+
+    /// Removes a word from the dictionary and the base words without
+    /// rebuilding the graph.
+    ///
+    /// Only the subgraph holding words of its length changes: if it is already
+    /// built, the word is unlinked from its neighbors in place, and the IDs of
+    /// longer words shift by one. Tagged sources keep the word, so reloading
+    /// the dictionary or changing the merge policy restores it.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to remove
+    ///
+    /// # Returns
+    ///
+    /// `true` if the word was removed, `false` if it was not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+    ///
+    /// assert!(graph.remove_word("cot"));
+    /// assert!(!graph.remove_word("cot"));
+    /// assert!(graph.find_shortest_path("cat", "dog").is_none());
+    /// ```
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        let word = self.normalization.normalize_query(word);
+        let Some(id) = self.word_ids.remove(&word) else {
+            return false;
+        };
+
+        self.words.remove(&word);
+        self.base_words.remove(&word);
+        self.landmarks = None;
+        self.patterns = OnceLock::new();
+        self.word_list.remove(id as usize);
+        for (offset, other) in self.word_list[id as usize..].iter().enumerate() {
+            *self.word_ids.get_mut(other).unwrap() = id + offset as u32;
+        }
+        let index = self
+            .subgraphs
+            .partition_point(|subgraph| subgraph.end <= id);
+        for subgraph in &mut self.subgraphs[index + 1..] {
+            subgraph.start -= 1;
+            subgraph.end -= 1;
+            if let Some(adjacency) = subgraph.adjacency.get_mut() {
+                adjacency.map_ids(|other| other - 1);
+            }
+        }
+
+        let subgraph = &mut self.subgraphs[index];
+        subgraph.end -= 1;
+        let start = subgraph.start;
+        if subgraph.start == subgraph.end {
+            self.subgraphs.remove(index);
+            return true;
+        }
+        let Some(adjacency) = subgraph.adjacency.take() else {
+            return true;
+        };
+        let mut lists = adjacency.into_lists();
+        for neighbor in lists.remove((id - start) as usize) {
+            let neighbor = if neighbor > id {
+                neighbor - 1
+            } else {
+                neighbor
+            };
+            lists[(neighbor - start) as usize].retain(|&other| other != id);
+        }
+        for list in &mut lists {
+            for other in list.iter_mut().filter(|other| **other > id) {
+                *other -= 1;
+            }
+        }
+        self.subgraphs[index].adjacency = OnceLock::from(self.with_layout(lists));
+        true
+    }
+
+    /// Partitions the dictionary into connected components.
+    ///
+    /// Two words are in the same component if a ladder exists between them.
+    ///
+    /// # Returns
+    ///
+    /// The components, largest first, with words sorted within each component.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ndog\n");
+    ///
+    /// let components = graph.connected_components();
+    /// assert_eq!(components, vec![vec!["cat", "cot"], vec!["dog"]]);
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<String>> {
+        let labels = self.component_labels();
+        let count = labels.iter().max().map_or(0, |&max| max as usize + 1);
+
+        let mut components = vec![Vec::new(); count];
+        for (id, &label) in labels.iter().enumerate() {
+            components[label as usize].push(self.word_list[id].clone());
+        }
+        for component in &mut components {
+            component.sort();
+        }
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+
+    /// Picks base words from the dictionary: the most frequent words of each
+    /// length that are well connected.
+    ///
+    /// A word qualifies if it is in the frequency list, has at least
+    /// `curation.min_neighbors` neighbors, and is in the component holding the
+    /// most words of its length, so every pair of curated words of one length
+    /// is joined by a ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranks` - Frequency rank of each word, 1 for the most common (see
+    ///   `WordMetadata::parse_frequency_list` in the engine crate)
+    /// * `curation` - How many words to keep and how connected they must be
+    ///
+    /// # Returns
+    ///
+    /// Up to `curation.per_length` words per length, ordered by length and
+    /// then by rank.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use wordladder_core::graph::{BaseWordCuration, WordGraph};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\nzzz\nzza\n");
+    /// let ranks: HashMap<String, usize> = ["dog", "zzz", "cat", "cog", "zza"]
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(i, word)| (word.to_string(), i + 1))
+    ///     .collect();
+    ///
+    /// let curation = BaseWordCuration { per_length: 2, min_neighbors: 1 };
+    /// assert_eq!(graph.curate_base_words(&ranks, &curation), vec!["dog", "cat"]);
+    /// ```
+    pub fn curate_base_words(
+        &self,
+        ranks: &HashMap<String, usize>,
+        curation: &BaseWordCuration,
+    ) -> Vec<String> {
+        let labels = self.component_labels();
+        let mut counts: HashMap<(usize, u32), usize> = HashMap::new();
+        for (id, &label) in labels.iter().enumerate() {
+            *counts
+                .entry((self.comparison_unit.length(&self.word_list[id]), label))
+                .or_default() += 1;
+        }
+        // The component with the most words of each length, lowest label on ties
+        let mut largest: HashMap<usize, (usize, u32)> = HashMap::new();
+        for (&(length, label), &count) in &counts {
+            let best = largest.entry(length).or_insert((count, label));
+            if (count, Reverse(label)) > (best.0, Reverse(best.1)) {
+                *best = (count, label);
+            }
+        }
+
+        let mut candidates: Vec<(usize, usize, &String)> = self
+            .word_list
+            .iter()
+            .enumerate()
+            .filter_map(|(id, word)| {
+                let length = self.comparison_unit.length(word);
+                let rank = *ranks.get(word)?;
+                let well_connected = self.neighbors(id as u32).len() >= curation.min_neighbors
+                    && largest[&length].1 == labels[id];
+                well_connected.then_some((length, rank, word))
+            })
+            .collect();
+        candidates.sort_unstable();
+
+        let mut curated = Vec::new();
+        let mut taken: HashMap<usize, usize> = HashMap::new();
+        for (length, _, word) in candidates {
+            let count = taken.entry(length).or_default();
+            if *count < curation.per_length {
+                *count += 1;
+                curated.push(word.clone());
+            }
+        }
+        curated
+    }
+
+    /// Labels every word ID with the index of its connected component.
+    pub(crate) fn component_labels(&self) -> Vec<u32> {
+        let mut labels = vec![UNVISITED; self.word_list.len()];
+        let mut next_label = 0;
+
+        for id in 0..self.word_list.len() as u32 {
+            if labels[id as usize] != UNVISITED {
+                continue;
+            }
+            labels[id as usize] = next_label;
+            let mut stack = vec![id];
+            while let Some(current) = stack.pop() {
+                for &neighbor in self.neighbors(current) {
+                    if labels[neighbor as usize] == UNVISITED {
+                        labels[neighbor as usize] = next_label;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            next_label += 1;
+        }
+        labels
+    }
+
+    /// Runs a full BFS from `start`, returning each word ID's distance
+    /// (`UNVISITED` for words it cannot reach).
+    fn bfs_distances(&self, start: u32) -> Vec<u32> {
+        let mut distances = vec![UNVISITED; self.word_list.len()];
+        for (distance, layer) in self.bfs_layers(start).into_iter().enumerate() {
+            for id in layer {
+                distances[id as usize] = distance as u32;
+            }
+        }
+        distances
+    }
+
+    /// Runs a full BFS from `start`, returning word IDs grouped by distance.
+    fn bfs_layers(&self, start: u32) -> Vec<Vec<u32>> {
+        let mut visited = vec![false; self.word_list.len()];
+        visited[start as usize] = true;
+
+        let mut layers = vec![vec![start]];
+        loop {
+            let mut next = Vec::new();
+            for &current in layers.last().unwrap() {
+                for &neighbor in self.neighbors(current) {
+                    if !visited[neighbor as usize] {
+                        visited[neighbor as usize] = true;
+                        next.push(neighbor);
+                    }
+                }
+            }
+            if next.is_empty() {
+                return layers;
+            }
+            layers.push(next);
+        }
+    }
+
+    /// Expands one full BFS layer of `side`, returning the best meeting word if
+    /// the layer touched any word already reached by `other`.
+    fn expand_layer(&self, side: &mut SearchSide, other: &SearchSide) -> Option<u32> {
+        let mut next = Vec::new();
+        let mut best: Option<(u32, u32)> = None;
+
+        for &current in &side.frontier {
+            for &neighbor in self.neighbors(current) {
+                if side.parent[neighbor as usize] != UNVISITED {
+                    continue;
+                }
+                side.parent[neighbor as usize] = current;
+                side.depth[neighbor as usize] = side.depth[current as usize] + 1;
+                next.push(neighbor);
+
+                if other.parent[neighbor as usize] != UNVISITED {
+                    let total = side.depth[neighbor as usize] + other.depth[neighbor as usize];
+                    if best.is_none_or(|(best_total, _)| total < best_total) {
+                        best = Some((total, neighbor));
+                    }
+                }
+            }
+        }
+
+        side.frontier = next;
+        best.map(|(_, meeting)| meeting)
+    }
+
+    /// Reconstructs the path from BFS parent pointers.
+    ///
+    /// This helper method traces back from the end word to the start word
+    /// using the parent IDs recorded during BFS to reconstruct the complete path.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Parent word ID for each visited word ID
+    /// * `start` - Starting word ID
+    /// * `end` - Ending word ID
+    ///
+    /// # Returns
+    ///
+    /// The complete path from start to end
+    fn reconstruct_path(&self, parent: &[u32], start: u32, end: u32) -> Vec<String> {
+        let mut path = vec![self.word_list[end as usize].clone()];
+        let mut current = end;
+
+        while current != start {
+            current = parent[current as usize];
+            path.push(self.word_list[current as usize].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the interned ID of a word, or `None` if it is not in the dictionary.
+    pub(crate) fn word_id(&self, word: &str) -> Option<u32> {
+        self.word_ids.get(word).copied()
+    }
+
+    /// Returns a reference to the set of dictionary words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// // ... load dictionary ...
+    /// # graph.load_dictionary("data/dictionary.txt").ok();
+    ///
+    /// let words = graph.get_words();
+    /// println!("Dictionary contains {} words", words.len());
+    /// ```
+    pub fn get_words(&self) -> &HashSet<String> {
+        &self.words
+    }
+
+    /// Returns a reference to the set of base words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// // ... load base words ...
+    /// # graph.load_base_words("data/base_words.txt").ok();
+    ///
+    /// let base_words = graph.get_base_words();
+    /// println!("{} base words available", base_words.len());
+    /// ```
+    pub fn get_base_words(&self) -> &HashSet<String> {
+        &self.base_words
+    }
+
+    /// Returns the neighbors of a word, or `None` if the word is not in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// # graph.load_dictionary("data/dictionary.txt").ok();
+    ///
+    /// if let Some(neighbors) = graph.get_neighbors("cat") {
+    ///     println!("cat has {} neighbors", neighbors.count());
+    /// }
+    /// ```
+    pub fn get_neighbors(&self, word: &str) -> Option<impl Iterator<Item = &str> + '_> {
+        let id = self.word_id(word)?;
+        Some(
+            self.neighbors(id)
+                .iter()
+                .map(|&neighbor| self.word_list[neighbor as usize].as_str()),
+        )
+    }
+}
+
+impl Default for WordGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_load_dictionary() {
+        let mut graph = WordGraph::new();
+        // Create a temporary dictionary
+        let dict_content = "cat\ndog\nbat\nrat\nmat\n";
+        std::fs::write("test_dict.txt", dict_content).unwrap();
+        graph.load_dictionary("test_dict.txt").unwrap();
+        std::fs::remove_file("test_dict.txt").unwrap();
+
+        assert!(graph.words.contains("cat"));
+        assert!(graph.words.contains("dog"));
+        assert_eq!(graph.words.len(), 5);
+    }
+
+    #[test]
+    fn test_load_report_counts() {
+        let mut graph = WordGraph::new();
+        let report = graph.load_dictionary_from_str("cat\n  \nCat\ndon't\ncot\n42\ncold\ncat\n");
+        assert_eq!(report.total_lines, 8);
+        assert_eq!(report.accepted, 3);
+        assert_eq!(report.rejected_non_alpha, 2);
+        assert_eq!(report.duplicates, 2);
+        assert_eq!(report.by_length, BTreeMap::from([(3, 2), (4, 1)]));
+        assert_eq!(
+            report.to_string(),
+            "3 of 8 lines accepted (2 rejected, 2 duplicates); words by length: 3: 2, 4: 1"
+        );
+
+        let report = graph.load_base_words_from_str("cat\ncold\nword\nCOLD\n");
+        assert_eq!(report.accepted, 3);
+        assert_eq!(report.duplicates, 1);
+        assert_eq!(report.by_length, BTreeMap::from([(3, 1), (4, 2)]));
+        assert_eq!(graph.get_base_words().len(), 3);
+    }
+
+    #[test]
+    fn test_word_filters() {
+        let mut graph = WordGraph::new()
+            .with_word_filter(|word| !word.starts_with(char::is_uppercase))
+            .with_word_filter(|word| word != "xiv");
+        let report = graph.load_dictionary_from_str("cat\n Paris \nxiv\ncot\nXIV\ncat\n");
+        assert_eq!(report.filtered, 3);
+        assert_eq!(report.accepted, 2);
+        assert_eq!(
+            report.to_string(),
+            "2 of 6 lines accepted (0 rejected, 3 filtered, 1 duplicates); words by length: 3: 2"
+        );
+
+        graph.load_base_words_from_str("Cat\ncot\n");
+        assert_eq!(graph.get_base_words().len(), 1);
+        assert!(!graph.add_word("Rome"));
+        assert!(graph.add_word("rome"));
+    }
+
+    #[test]
+    fn test_load_report_alphabet_warnings() {
+        let mut graph = WordGraph::new();
+        let report = graph.load_dictionary_from_str("cat\ncot\ndog\n");
+        assert_eq!(report.accepted, 3);
+        assert_eq!(report.alphabet, ['a', 'c', 'd', 'g', 'o', 't']);
+        assert!(report.warnings.is_empty());
+
+        let dictionary = "ana\naña\nbaño\nsueño\nniño\nmoño\nleña\npaño\ncafé\n";
+        let report = graph.load_dictionary_from_str(dictionary);
+        assert_eq!(
+            report.warnings,
+            [LoadWarning::LettersOutsideAlphabet {
+                letters: vec!['é', 'ñ'],
+                words: 8,
+                sample: ["aña", "baño", "café", "leña", "moño"]
+                    .map(String::from)
+                    .to_vec(),
+            }]
+        );
+        assert!(report.warnings[0].to_string().starts_with("8 words"));
+
+        let mut preserve = WordGraph::new().with_normalization(WordNormalization {
+            case: CaseMapping::Preserve,
+            ..Default::default()
+        });
+        let report = preserve.load_dictionary_from_str("cat\nParis\n");
+        assert!(matches!(
+            &report.warnings[..],
+            [LoadWarning::LettersOutsideAlphabet { letters, .. }] if letters == &['P']
+        ));
+
+        let mut anagrams = WordGraph::new().with_edge_rules(vec![EdgeRule::Anagram]);
+        let report = anagrams.load_dictionary_tagged_from_str("café\nfacé\n", "main");
+        assert_eq!(report.accepted, 2);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_word_normalization_policies() {
+        let dictionary = "Don't\ndont\nx-ray\nx-ran\nb2b\n";
+
+        let mut reject = WordGraph::new();
+        reject.load_dictionary_from_str(dictionary);
+        assert_eq!(reject.get_words().len(), 1);
+
+        let mut strip = WordGraph::new().with_normalization(WordNormalization {
+            punctuation: PunctuationPolicy::Strip,
+            ..Default::default()
+        });
+        strip.load_dictionary_from_str(dictionary);
+        assert_eq!(strip.get_words().len(), 3);
+        assert!(strip.get_words().contains("xray"));
+        assert!(strip.remove_word("Don't"));
+
+        let mut keep = WordGraph::new().with_normalization(WordNormalization {
+            case: CaseMapping::Preserve,
+            punctuation: PunctuationPolicy::Keep,
+        });
+        keep.load_dictionary_from_str(dictionary);
+        assert_eq!(keep.get_words().len(), 4);
+        assert!(keep.get_words().contains("Don't"));
+        assert!(keep.add_word("X-Ray"));
+        assert!(!keep.add_word("x2ray"));
+        assert_eq!(keep.find_shortest_path("x-ray", "x-ran").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_suggest_similar() {
+        let graph = WordGraph::from_words(["cold", "cord", "clod", "warm"].map(String::from));
+
+        assert!(graph.suggest_similar("cold", 0).is_empty());
+        assert_eq!(graph.suggest_similar("cold", 1), ["clod", "cord"]);
+        assert_eq!(graph.suggest_similar("colt", 1), ["cold"]);
+        assert_eq!(graph.suggest_similar("clt", 2), ["clod", "cold"]);
+        assert!(
+            graph
+                .suggest_similar("worm", 1)
+                .contains(&"warm".to_string())
+        );
+
+        // Letters outside a-z come from the dictionary's alphabet
+        let mut graph = WordGraph::from_words(["café", "cafés", "bar"].map(String::from));
+        assert_eq!(graph.suggest_similar("cafe", 1), ["café"]);
+        assert_eq!(graph.suggest_similar("cafes", 2), ["cafés", "café"]);
+
+        // The pattern index follows words added and removed later
+        assert!(graph.add_word("cafs"));
+        assert_eq!(graph.suggest_similar("cafe", 1), ["cafs", "café"]);
+        assert!(graph.remove_word("café"));
+        assert_eq!(graph.suggest_similar("cafe", 1), ["cafs"]);
+    }
+
+    #[test]
+    fn test_find_shortest_path() {
+        let graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+
+        let path = graph.find_shortest_path("cat", "dog");
+        assert!(path.is_some());
+        let path = path.unwrap();
+        assert_eq!(path, vec!["cat", "cot", "cog", "dog"]);
+    }
+
+    #[test]
+    fn test_find_shortest_path_avoiding() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+
+        let no_ban = HashSet::new();
+        assert_eq!(
+            graph.find_shortest_path_avoiding("cat", "dog", &no_ban),
+            graph.find_shortest_path("cat", "dog")
+        );
+
+        let banned: HashSet<String> = ["cog", "zzz"].iter().map(|w| w.to_string()).collect();
+        let path = graph
+            .find_shortest_path_avoiding("cat", "dog", &banned)
+            .unwrap();
+        assert!(!path.contains(&"cog".to_string()));
+        assert_eq!(path.len(), 4);
+
+        let banned: HashSet<String> = ["cog", "dot"].iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            graph.find_shortest_path_avoiding("cat", "dog", &banned),
+            None
+        );
+
+        let banned = HashSet::from(["dog".to_string()]);
+        assert_eq!(
+            graph.find_shortest_path_avoiding("cat", "dog", &banned),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_shortest_path_with_budget() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nhen\n");
+
+        let unlimited = SolveOptions::new();
+        assert_eq!(
+            graph
+                .find_shortest_path_with("cat", "dog", &unlimited)
+                .into_path(),
+            graph.find_shortest_path("cat", "dog")
+        );
+        assert_eq!(
+            graph.find_shortest_path_with("cat", "hen", &unlimited),
+            SolveOutcome::NoPath
+        );
+
+        // cat, cot, and one of cog/dot fit; dog would be the fifth word
+        let tight = SolveOptions::new().with_max_visited(3);
+        assert_eq!(
+            graph.find_shortest_path_with("cat", "dog", &tight),
+            SolveOutcome::BudgetExceeded
+        );
+        let enough = SolveOptions::new().with_max_visited(5);
+        assert!(matches!(
+            graph.find_shortest_path_with("cat", "dog", &enough),
+            SolveOutcome::Found(_)
+        ));
+
+        let banned = HashSet::from(["cog".to_string()]);
+        let expired = SolveOptions::new().with_timeout(Duration::ZERO);
+        assert_eq!(
+            graph.find_shortest_path_avoiding_with("cat", "dog", &banned, &expired),
+            SolveOutcome::BudgetExceeded
+        );
+    }
+
+    #[test]
+    fn test_find_shortest_path_traced() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nhen\n");
+
+        let (path, trace) = graph.find_shortest_path_traced("cat", "dog");
+        assert_eq!(path, graph.find_shortest_path("cat", "dog"));
+        assert!(trace.found);
+        let frontiers: Vec<usize> = trace.layers.iter().map(|layer| layer.frontier).collect();
+        assert_eq!(frontiers, vec![1, 1, 2]);
+        assert_eq!(trace.layers[1].visited, 2); // cog and dot
+        assert_eq!(
+            trace.visited,
+            1 + trace
+                .layers
+                .iter()
+                .map(|layer| layer.visited)
+                .sum::<usize>()
+        );
+
+        let banned = HashSet::from(["cog".to_string(), "dot".to_string()]);
+        let (path, trace) = graph.find_shortest_path_avoiding_traced("cat", "dog", &banned);
+        assert_eq!(path, None);
+        assert!(!trace.found);
+        assert_eq!(trace.visited, 2);
+
+        let (path, trace) = graph.find_shortest_path_traced("cat", "hen");
+        assert_eq!(path, None);
+        assert_eq!(trace.layers.len(), 4);
+        assert_eq!(trace.layers[3].visited, 0);
+        assert!(trace.to_string().ends_with("(not found)"));
+    }
+
+    #[test]
+    fn test_shortest_paths_to_many() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cold\ncord\ncard\nward\nwarm\nworm\nword\nhen\n");
+
+        let targets = ["warm", "word", "cold", "hen", "nope"];
+        let paths = graph.shortest_paths_to_many("cold", &targets);
+        assert_eq!(paths.len(), 3);
+        for target in ["warm", "word", "cold"] {
+            assert_eq!(
+                paths.get(target),
+                graph.find_shortest_path("cold", target).as_ref()
+            );
+        }
+        assert!(graph.shortest_paths_to_many("nope", &targets).is_empty());
+    }
+
+    #[test]
+    fn test_find_path_within() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cold\ncord\ncard\nward\nwarm\nworm\nword\n");
+
+        let shortest = graph.find_shortest_path("cold", "warm").unwrap();
+        assert_eq!(shortest.len(), 5);
+        assert_eq!(
+            graph.find_path_within("cold", "warm", 4),
+            Some(shortest.clone())
+        );
+        assert_eq!(graph.find_path_within("cold", "warm", 10), Some(shortest));
+        assert_eq!(graph.find_path_within("cold", "warm", 3), None);
+        assert_eq!(
+            graph.find_path_within("cold", "cold", 0),
+            Some(vec!["cold".to_string()])
+        );
+        assert_eq!(graph.find_path_within("cold", "nope", 10), None);
+    }
+
+    #[test]
+    fn test_interned_graph_queries() {
+        let graph = WordGraph::from_words(["cat", "dog", "cog", "cot", "zzz"].map(String::from));
+
+        let mut neighbors: Vec<&str> = graph.get_neighbors("cot").unwrap().collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!["cat", "cog"]);
+        assert_eq!(graph.get_neighbors("zzz").unwrap().count(), 0);
+        assert!(graph.get_neighbors("missing").is_none());
+
+        assert!(graph.find_shortest_path("cat", "zzz").is_none());
+        assert!(graph.find_shortest_path("missing", "dog").is_none());
+        assert_eq!(graph.find_shortest_path("dog", "dog").unwrap(), vec!["dog"]);
+    }
+
+    #[test]
+    fn test_compact_matches_list_layout() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ndog\ncog\ncot\nzzz\ncart\ncard\n");
+        let list_path = graph.find_shortest_path("cat", "dog");
+        let mut list_neighbors: Vec<&str> = graph.get_neighbors("cot").unwrap().collect();
+        list_neighbors.sort();
+        let list_neighbors: Vec<String> = list_neighbors.iter().map(|s| s.to_string()).collect();
+
+        graph.compact();
+        assert!(graph.is_compact());
+        assert_eq!(graph.find_shortest_path("cat", "dog"), list_path);
+        let mut csr_neighbors: Vec<&str> = graph.get_neighbors("cot").unwrap().collect();
+        csr_neighbors.sort();
+        assert_eq!(csr_neighbors, list_neighbors);
+        assert_eq!(graph.get_neighbors("zzz").unwrap().count(), 0);
+        assert_eq!(
+            graph.get_neighbors("card").unwrap().collect::<Vec<_>>(),
+            vec!["cart"]
+        );
+
+        graph.load_dictionary_from_str("cat\ncot\n");
+        assert!(!graph.is_compact());
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ndog\ncog\ncot\nzzz\ncart\ncard\n");
+        let unbuilt = graph.memory_footprint();
+        assert!(unbuilt.words >= "catdogcogcotzzzcartcard".len());
+        assert!(unbuilt.indices > unbuilt.words);
+        assert_eq!(unbuilt.landmarks, 0);
+
+        graph.build_all();
+        let lists = graph.memory_footprint();
+        assert!(lists.adjacency > unbuilt.adjacency);
+        assert_eq!(
+            lists.total(),
+            lists.words + lists.adjacency + lists.indices + lists.landmarks
+        );
+
+        graph.compact();
+        let csr = graph.memory_footprint();
+        assert!(csr.adjacency < lists.adjacency);
+        assert_eq!((csr.words, csr.indices), (lists.words, lists.indices));
+
+        graph.set_landmarks(Landmarks::build(&graph, 1)).unwrap();
+        assert!(graph.memory_footprint().landmarks > 0);
+    }
+
+    #[test]
+    fn test_bidirectional_matches_bfs_length() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str(
+            "cold\ncord\ncard\nward\nwarm\nword\nworm\ncore\nwore\nwire\nzzzz\n",
+        );
+
+        for (start, end) in [
+            ("cold", "warm"),
+            ("warm", "cold"),
+            ("core", "wire"),
+            ("cold", "word"),
+        ] {
+            let bfs = graph.find_shortest_path(start, end).unwrap();
+            let bidirectional = graph.find_shortest_path_bidirectional(start, end).unwrap();
+            assert_eq!(bidirectional.len(), bfs.len(), "{} -> {}", start, end);
+            assert_eq!(bidirectional.first().unwrap(), start);
+            assert_eq!(bidirectional.last().unwrap(), end);
+        }
+
+        assert!(
+            graph
+                .find_shortest_path_bidirectional("cold", "zzzz")
+                .is_none()
+        );
+        assert!(
+            graph
+                .find_shortest_path_bidirectional("cold", "nope")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_farthest_words() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncat\nbat\nzzz\n");
+
+        let layers = graph.distance_layers("cat");
+        assert_eq!(layers.len(), 4);
+        assert_eq!(layers[1], vec!["bat", "cot"]);
+
+        assert_eq!(graph.farthest_words("cat", 3), vec!["dog"]);
+        assert!(graph.farthest_words("cat", 4).is_empty());
+        assert!(graph.farthest_words("zzz", 1).is_empty());
+        assert!(graph.farthest_words("nope", 0).is_empty());
+    }
+
+    #[test]
+    fn test_edge_rules() {
+        let dictionary = "cat\ncart\ncard\nact\ncot\nform\nfrom\n";
+
+        let mut classic = WordGraph::new();
+        classic.load_dictionary_from_str(dictionary);
+        assert!(classic.find_shortest_path("cat", "card").is_none());
+        assert_eq!(classic.degree("form"), Some(0));
+
+        let mut golf = WordGraph::new();
+        golf.load_dictionary_from_str(dictionary);
+        let golf = golf.with_edge_rules(vec![
+            EdgeRule::SubstituteOne,
+            EdgeRule::InsertOrDeleteOne,
+            EdgeRule::SwapAdjacent,
+        ]);
+        assert_eq!(
+            golf.find_shortest_path("cot", "card").unwrap(),
+            vec!["cot", "cat", "cart", "card"]
+        );
+        assert_eq!(golf.find_shortest_path("cat", "act").unwrap().len(), 2);
+        assert_eq!(golf.find_shortest_path("form", "from").unwrap().len(), 2);
+
+        assert!(EdgeRule::InsertOrDeleteOne.connects("cart", "art"));
+        assert!(EdgeRule::InsertOrDeleteOne.connects("cat", "cats"));
+        assert!(!EdgeRule::InsertOrDeleteOne.connects("cat", "dogs"));
+        assert!(!EdgeRule::InsertOrDeleteOne.connects("cat", "cat"));
+        assert!(!EdgeRule::SwapAdjacent.connects("abc", "cba"));
+    }
+
+    #[test]
+    fn test_ascii_fast_paths() {
+        let pairs = [
+            ("cat", "cot"),
+            ("cat", "cat"),
+            ("cat", "dog"),
+            ("cart", "cat"),
+            ("form", "from"),
+            ("stop", "pots"),
+            ("abcdefghijklmnop", "abcdefghijklmnoq"),
+            ("abcdefghijklmnop", "abcdefgxijklmnop"),
+            ("abcdefghijklmnop", "xbcdefghijklmnoq"),
+            ("abcdefghijklmnop", "abcdefghjiklmnop"),
+            ("abcdefghijklmnop", "abcdefghijklmnopq"),
+            ("Cat", "cat"),
+        ];
+        let rules = [
+            EdgeRule::SubstituteOne,
+            EdgeRule::InsertOrDeleteOne,
+            EdgeRule::SwapAdjacent,
+            EdgeRule::Anagram,
+        ];
+        for rule in rules {
+            for (a, b) in pairs {
+                let chars = |word: &str| word.chars().collect::<Vec<char>>();
+                assert_eq!(
+                    rule.connects(a, b),
+                    rule.connects_letters(&chars(a), &chars(b)),
+                    "{:?}: {} -> {}",
+                    rule,
+                    a,
+                    b
+                );
+            }
+
+            let word = b"cart";
+            let mut buffer = Vec::new();
+            let mut visited = Vec::new();
+            rule.visit_ascii_candidates(word, &mut buffer, |candidate| {
+                visited.push(String::from_utf8(candidate.to_vec()).unwrap())
+            });
+            let chars: Vec<char> = "cart".chars().collect();
+            assert_eq!(visited, rule.candidates(&chars), "{:?}", rule);
+        }
+
+        assert_eq!(count_mismatches(b"abcdefghij", b"abcdefghij", 2), 0);
+        assert_eq!(count_mismatches(b"abcdefghij", b"abcdefgxij", 2), 1);
+        assert_eq!(count_mismatches(b"xbcdefghiy", b"abcdefghij", 5), 2);
+        assert!(EdgeRule::SubstituteOne.connects("café", "cafè"));
+        assert!(!EdgeRule::SubstituteOne.connects("café", "cafés"));
+    }
+
+    #[test]
+    fn test_grapheme_comparison_unit() {
+        // "é" written as "e" and a combining acute accent
+        let words = ["cafe\u{301}", "cafa", "cafes", "care\u{301}"].map(String::from);
+
+        let mut chars = WordGraph::new();
+        chars.load_dictionary_from_str(&words.join("\n"));
+        assert_eq!(chars.get_words().len(), 2);
+        assert_eq!(chars.find_shortest_path("cafa", "cafes"), None);
+
+        let mut graphemes = WordGraph::new()
+            .with_edge_rules(vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne])
+            .with_comparison_unit(ComparisonUnit::Grapheme);
+        let report = graphemes.load_dictionary_from_str(&words.join("\n"));
+        assert_eq!(report.by_length[&4], 3);
+        assert!(report.warnings.is_empty());
+        assert_eq!(
+            graphemes.find_shortest_path("cafa", "care\u{301}").unwrap(),
+            ["cafa", "cafe\u{301}", "care\u{301}"]
+        );
+        // As chars, the accent is a letter that can be substituted
+        assert!(chars.is_step("cafe\u{301}", "cafes"));
+        assert!(!graphemes.is_step("cafe\u{301}", "cafes"));
+
+        // Words added later connect through the graphemes already in use
+        assert!(graphemes.add_word("cafi"));
+        assert_eq!(
+            graphemes
+                .find_shortest_path("cafi", "care\u{301}")
+                .unwrap()
+                .len(),
+            3
+        );
+
+        // Errors report the lengths that were compared
+        let mut substitutions = WordGraph::new().with_comparison_unit(ComparisonUnit::Grapheme);
+        substitutions.load_dictionary_from_str(&words.join("\n"));
+        let error = substitutions
+            .check_pair("cafe\u{301}", "cafes")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cafe\u{301} and cafes have different lengths (4 and 5 letters)"
+        );
+    }
+
+    #[test]
+    fn test_anagram_rule() {
+        let dictionary = "stop\npots\ntops\nspot\nshop\nchop\n";
+
+        let mut graph = WordGraph::new().with_edge_rules(vec![EdgeRule::Anagram]);
+        graph.load_dictionary_from_str(dictionary);
+        assert_eq!(graph.degree("stop"), Some(3));
+        assert!(graph.find_shortest_path("stop", "shop").is_none());
+
+        let mut combined =
+            WordGraph::new().with_edge_rules(vec![EdgeRule::SubstituteOne, EdgeRule::Anagram]);
+        combined.load_dictionary_from_str(dictionary);
+        assert_eq!(
+            combined.find_shortest_path("pots", "chop").unwrap(),
+            vec!["pots", "stop", "shop", "chop"]
+        );
+
+        assert!(EdgeRule::Anagram.connects("stop", "pots"));
+        assert!(!EdgeRule::Anagram.connects("stop", "stop"));
+        assert!(!EdgeRule::Anagram.connects("stop", "stops"));
+    }
+
+    #[test]
+    fn test_count_shortest_paths() {
+        // A 2x2x2 "cube" of words: every shortest aaa -> bbb path flips the
+        // three letters in some order, so there are 3! = 6 of them.
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("aaa\naab\naba\nabb\nbaa\nbab\nbba\nbbb\n");
+
+        assert_eq!(graph.count_shortest_paths("aaa", "bbb"), 6);
+        assert_eq!(graph.count_shortest_paths("aaa", "abb"), 2);
+        assert_eq!(graph.count_shortest_paths("aaa", "aab"), 1);
+        assert_eq!(graph.count_shortest_paths("aaa", "aaa"), 1);
+        assert_eq!(graph.count_shortest_paths("aaa", "nope"), 0);
+    }
+
+    #[test]
+    fn test_find_mirror_path() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("aaa\naab\naba\nabb\nbaa\nbab\nbba\nbbb\n");
+        let path =
+            |words: &[&str]| -> Vec<String> { words.iter().map(|w| w.to_string()).collect() };
+
+        let forward = path(&["aaa", "aab", "abb", "bbb"]);
+        let mirror = graph.find_mirror_path(&forward).unwrap();
+        assert_eq!(mirror.len(), 4);
+        assert_eq!((mirror[0].as_str(), mirror[3].as_str()), ("bbb", "aaa"));
+        assert!(mirror[1..3].iter().all(|word| !forward.contains(word)));
+        assert!(
+            mirror
+                .windows(2)
+                .all(|pair| graph.is_step(&pair[0], &pair[1]))
+        );
+
+        assert_eq!(
+            graph.find_mirror_path(&path(&["aaa", "aab", "abb"])),
+            Some(path(&["abb", "aba", "aaa"]))
+        );
+        assert_eq!(graph.find_mirror_path(&path(&["aaa", "aab"])), None);
+        assert_eq!(graph.find_mirror_path(&path(&["aaa"])), None);
+        assert_eq!(graph.find_mirror_path(&[]), None);
+
+        // A chain has no second route
+        let mut chain = WordGraph::new();
+        chain.load_dictionary_from_str("aaa\nbaa\nbba\n");
+        assert_eq!(chain.find_mirror_path(&path(&["aaa", "baa", "bba"])), None);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("aaa\naab\naba\nabb\nbaa\nbab\nbba\nbbb\nzzz\n");
+
+        let paths = graph.shortest_paths("aaa", "bbb", 100);
+        assert_eq!(paths.len(), 6);
+        assert!(paths.iter().all(|path| path.len() == 4));
+        assert_eq!(paths[0], vec!["aaa", "aab", "abb", "bbb"]);
+
+        assert_eq!(graph.shortest_paths("aaa", "bbb", 2).len(), 2);
+        assert_eq!(graph.shortest_paths("aaa", "aaa", 5), vec![vec!["aaa"]]);
+        assert!(graph.shortest_paths("aaa", "zzz", 5).is_empty());
+    }
+
+    #[test]
+    fn test_has_unique_shortest_path() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nzzz\n");
+
+        assert!(graph.has_unique_shortest_path("cat", "cot"));
+        assert!(!graph.has_unique_shortest_path("cot", "dog"));
+        assert!(graph.has_unique_shortest_path("cat", "cat"));
+        assert!(!graph.has_unique_shortest_path("cat", "zzz"));
+        assert!(!graph.has_unique_shortest_path("cat", "nope"));
+    }
+
+    #[test]
+    fn test_subgraphs_built_lazily() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ndot\ndog\ncold\ncord\nword\n");
+        assert!(!graph.is_length_built(3));
+        assert!(!graph.is_length_built(4));
+
+        let path = graph.find_shortest_path("cat", "dog").unwrap();
+        assert_eq!(path, vec!["cat", "cot", "dot", "dog"]);
+        assert!(graph.is_length_built(3));
+        assert!(!graph.is_length_built(4));
+
+        graph.compact();
+        assert_eq!(graph.find_shortest_path("cold", "word").unwrap().len(), 3);
+        assert!(graph.is_length_built(4));
+        assert!(!graph.is_length_built(5));
+
+        let mut cross = WordGraph::new().with_edge_rules(vec![EdgeRule::InsertOrDeleteOne]);
+        cross.load_dictionary_from_str("cat\ncart\n");
+        assert!(cross.find_shortest_path("cat", "cart").is_some());
+        assert!(cross.is_length_built(3) && cross.is_length_built(4));
+    }
+
+    #[test]
+    fn test_prune_isolated() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\nzzz\nqua\n");
+        graph.compact();
+
+        assert_eq!(graph.prune_isolated(), 2);
+        assert_eq!(graph.get_words().len(), 4);
+        assert!(graph.is_compact());
+        assert_eq!(graph.degree("zzz"), None);
+        assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+        assert_eq!(graph.prune_isolated(), 0);
+    }
+
+    #[test]
+    fn test_add_and_remove_words() {
+        let dictionary = "cat\ncot\ncog\ndog\ncold\ncord\ncard\nact\n";
+        let rule_sets = [
+            vec![EdgeRule::SubstituteOne],
+            vec![EdgeRule::SubstituteOne, EdgeRule::Anagram],
+            vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne],
+        ];
+        for rules in rule_sets {
+            for compact in [false, true] {
+                let mut graph = WordGraph::new().with_edge_rules(rules.clone());
+                graph.load_dictionary_from_str(dictionary);
+                graph.load_base_words_from_str("cat\ndog\n");
+                if compact {
+                    graph.compact();
+                }
+                // Build only the 3-letter subgraph, so both paths are exercised
+                graph.find_shortest_path("cat", "dog");
+
+                assert!(graph.add_word("bat"));
+                assert!(graph.add_word("tac"));
+                assert!(graph.add_word("to"));
+                assert!(graph.add_word("ward"));
+                assert!(!graph.add_word("cat"));
+                assert!(!graph.add_word("c4t"));
+                assert!(graph.remove_word("cot"));
+                assert!(graph.remove_word("dog"));
+                assert!(graph.remove_word("card"));
+                assert!(!graph.remove_word("cot"));
+                assert!(!graph.get_base_words().contains("dog"));
+
+                let mut rebuilt = WordGraph::new().with_edge_rules(rules.clone());
+                let words: Vec<&str> = graph.get_words().iter().map(String::as_str).collect();
+                rebuilt.load_dictionary_from_str(&words.join("\n"));
+                assert_eq!(graph.word_list, rebuilt.word_list);
+                assert_eq!(graph.is_compact(), compact);
+                for word in &rebuilt.word_list {
+                    let expected: Vec<&str> = rebuilt.get_neighbors(word).unwrap().collect();
+                    let actual: Vec<&str> = graph.get_neighbors(word).unwrap().collect();
+                    assert_eq!(actual, expected, "{:?} neighbors of {}", rules, word);
+                }
+            }
+        }
+
+        // Removing the last word of a length drops its subgraph
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\nzebra\n");
+        graph.build_all();
+        assert!(graph.remove_word("zebra"));
+        assert!(!graph.is_length_built(5));
+        assert!(graph.add_word("zebra"));
+        assert_eq!(graph.degree("zebra"), Some(0));
+        assert_eq!(graph.find_shortest_path("cat", "cot").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_tagged_sources() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_tagged_from_str("cat\ncot\ncog\ncwm\n", "tournament");
+        graph.load_dictionary_tagged_from_str("cat\ncot\ndot\ndog\n", "common");
+
+        assert_eq!(graph.source_tags(), vec!["tournament", "common"]);
+        assert_eq!(graph.get_words().len(), 6);
+        assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+        assert_eq!(graph.word_sources("cot"), vec!["tournament", "common"]);
+        assert!(graph.word_sources("nope").is_empty());
+        assert!(graph.source_contains("tournament", "cwm"));
+        assert!(!graph.source_contains("common", "cwm"));
+
+        // Intersection keeps only words present in every source
+        let mut graph = graph.with_merge_policy(MergePolicy::Intersection);
+        assert_eq!(graph.get_words().len(), 2);
+        assert!(graph.find_shortest_path("cat", "dog").is_none());
+
+        // Reloading a tag replaces that source
+        graph.load_dictionary_tagged_from_str("cat\ncot\ncog\ndot\ndog\n", "tournament");
+        assert_eq!(graph.get_words().len(), 4);
+        assert_eq!(graph.find_shortest_path("cat", "dog").unwrap().len(), 4);
+
+        // An untagged load replaces all sources
+        graph.load_dictionary_from_str("cat\nbat\n");
+        assert!(graph.source_tags().is_empty());
+        assert_eq!(graph.get_words().len(), 2);
+    }
+
+    #[test]
+    fn test_components_and_degree() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\nbat\nzzz\nfish\nfist\n");
+
+        let components = graph.connected_components();
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0], vec!["bat", "cat", "cog", "cot"]);
+        assert_eq!(components[1], vec!["fish", "fist"]);
+        assert_eq!(components[2], vec!["zzz"]);
+
+        assert_eq!(graph.degree("cat"), Some(2));
+        assert_eq!(graph.degree("zzz"), Some(0));
+        assert_eq!(graph.degree("nope"), None);
+    }
+
+    #[test]
+    fn test_curate_base_words() {
+        let mut graph = WordGraph::new();
+        // "zzz"/"zza" form a second, smaller 3-letter component
+        graph.load_dictionary_from_str(
+            "cat\ncot\ncog\ndog\ndot\nzzz\nzza\ncold\ncord\ncard\nword\nfish\nfist\n",
+        );
+        let ranks: HashMap<String, usize> = [
+            "zzz", "fish", "dog", "cold", "cat", "fist", "cord", "cot", "card", "word", "zza",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, word)| (word.to_string(), i + 1))
+        .collect();
+
+        let curation = BaseWordCuration {
+            per_length: 3,
+            min_neighbors: 1,
+        };
+        assert_eq!(
+            graph.curate_base_words(&ranks, &curation),
+            vec!["dog", "cat", "cot", "cold", "cord", "card"]
+        );
+
+        // "cat", "cold", "card" and "word" have a single neighbor
+        let curation = BaseWordCuration {
+            per_length: 3,
+            min_neighbors: 2,
+        };
+        assert_eq!(
+            graph.curate_base_words(&ranks, &curation),
+            vec!["dog", "cot", "cord"]
+        );
+    }
+
+    /// Generates a dense random dictionary of 2- to 4-letter words over a
+    /// small alphabet, so most pairs are connected and paths are long enough
+    /// to be interesting.
+    fn random_graph() -> impl Strategy<Value = WordGraph> {
+        let rules = prop_oneof![
+            Just(vec![EdgeRule::SubstituteOne]),
+            Just(vec![EdgeRule::SubstituteOne, EdgeRule::SwapAdjacent]),
+            Just(vec![EdgeRule::SubstituteOne, EdgeRule::Anagram]),
+            Just(vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne]),
+        ];
+        let words = prop::collection::vec("[a-e]{2,4}", 5..60);
+        (rules, words, any::<bool>()).prop_map(|(rules, words, compact)| {
+            let mut graph = WordGraph::new().with_edge_rules(rules);
+            graph.load_dictionary_from_str(&words.join("\n"));
+            if compact {
+                graph.compact();
+            }
+            graph
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(200))]
+
+        #[test]
+        fn test_solver_properties(
+            graph in random_graph(),
+            start in any::<Index>(),
+            ends in prop::collection::vec(any::<Index>(), 5),
+        ) {
+            let start = start.get(&graph.word_list);
+            let layers = graph.distance_layers(start);
+            let distance = |word: &str| {
+                layers
+                    .iter()
+                    .position(|layer| layer.iter().any(|w| w == word))
+            };
+
+            for end in ends.iter().map(|end| end.get(&graph.word_list)) {
+                let context = format!("{} -> {}", start, end);
+                let path = graph.find_shortest_path(start, end);
+                let bidirectional = graph.find_shortest_path_bidirectional(start, end);
+                let avoiding = graph.find_shortest_path_avoiding(start, end, &HashSet::new());
+                let Some(steps) = distance(end) else {
+                    prop_assert!(path.is_none(), "{}", context);
+                    prop_assert!(bidirectional.is_none(), "{}", context);
+                    prop_assert!(avoiding.is_none(), "{}", context);
+                    prop_assert_eq!(graph.count_shortest_paths(start, end), 0, "{}", context);
+                    continue;
+                };
+
+                let path = path.expect(&context);
+                prop_assert_eq!(path.first(), Some(start), "{}", context);
+                prop_assert_eq!(path.last(), Some(end), "{}", context);
+                prop_assert_eq!(path.len() - 1, steps, "{}", context);
+                prop_assert!(path.iter().all(|word| graph.get_words().contains(word)));
+                prop_assert!(
+                    path.windows(2)
+                        .all(|pair| graph.is_step(&pair[0], &pair[1])),
+                    "{}: {:?}",
+                    context,
+                    path
+                );
+
+                for other in [bidirectional.expect(&context), avoiding.expect(&context)] {
+                    prop_assert_eq!(other.len(), path.len(), "{}", context);
+                    prop_assert!(
+                        other
+                            .windows(2)
+                            .all(|pair| graph.is_step(&pair[0], &pair[1]))
+                    );
+                }
+                prop_assert!(graph.find_path_within(start, end, steps).is_some());
+                if steps > 0 {
+                    prop_assert!(graph.find_path_within(start, end, steps - 1).is_none());
+                }
+                let many = graph.shortest_paths_to_many(start, &[end.as_str()]);
+                prop_assert_eq!(many[end.as_str()].len(), path.len(), "{}", context);
+                prop_assert!(graph.count_shortest_paths(start, end) >= 1, "{}", context);
+                for other in graph.shortest_paths(start, end, 3) {
+                    prop_assert_eq!(other.len(), path.len(), "{}", context);
+                }
+            }
+        }
+
+        #[test]
+        fn test_mirror_ladder_properties(
+            graph in random_graph(),
+            pairs in prop::collection::vec((any::<Index>(), any::<Index>()), 5),
+        ) {
+            for (start, end) in &pairs {
+                let start = start.get(&graph.word_list);
+                let end = end.get(&graph.word_list);
+                let context = format!("{} -> {}", start, end);
+                let all = graph.shortest_paths(start, end, 10_000);
+                let disjoint = |a: &[String], b: &[String]| {
+                    a[1..a.len() - 1].iter().all(|word| !b.contains(word))
+                };
+                let expected = all.first().is_some_and(|path| path.len() > 2)
+                    && all
+                        .iter()
+                        .enumerate()
+                        .any(|(i, a)| all[i + 1..].iter().any(|b| disjoint(a, b)));
+
+                let ladders = graph.find_mirror_ladders(start, end);
+                prop_assert_eq!(ladders.is_some(), expected, "{}", context);
+                if let Some((path, mut reverse)) = ladders {
+                    prop_assert_eq!(reverse.first(), Some(end), "{}", context);
+                    reverse.reverse();
+                    for ladder in [&path, &reverse] {
+                        prop_assert_eq!(ladder.len(), all[0].len(), "{}", context);
+                        prop_assert_eq!(ladder.first(), Some(start), "{}", context);
+                        prop_assert_eq!(ladder.last(), Some(end), "{}", context);
+                        prop_assert!(
+                            ladder
+                                .windows(2)
+                                .all(|pair| graph.is_step(&pair[0], &pair[1])),
+                            "{}",
+                            context
+                        );
+                    }
+                    prop_assert!(disjoint(&path, &reverse), "{}", context);
+                }
+                for path in &all {
+                    if graph.find_mirror_path(path).is_some() {
+                        prop_assert!(expected, "{}", context);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! # Solve Cache
+//!
+//! Server mode and batch generation ask for the same `(start, end)` pairs over
+//! and over. `CachedSolver` remembers the outcome of recent searches in a
+//! bounded least-recently-used cache, so a repeated query costs a hash lookup
+//! instead of a BFS. Both found paths and "no path" answers are cached; a
+//! search that ran out of budget is not, since a later call with a larger
+//! budget may still succeed.
+//!
+//! The cache does not hold the graph. Pass the same graph to every call, and
+//! call `clear()` after adding or removing words, since cached answers may no
+//! longer be shortest paths.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_core::graph::WordGraph;
+//! use wordladder_core::graph::cache::CachedSolver;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+//! let mut solver = CachedSolver::new(1024);
+//!
+//! assert_eq!(solver.distance(&graph, "cat", "dog"), Some(3));
+//! assert_eq!(solver.distance(&graph, "cat", "dog"), Some(3));
+//! assert_eq!(solver.stats().hits, 1);
+//! assert_eq!(solver.stats().misses, 1);
+//! ```
+
+use super::{SolveOptions, SolveOutcome, WordGraph};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Hit and miss counters of a `CachedSolver`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that ran a search
+    pub misses: u64,
+    /// Entries dropped to stay within capacity
+    pub evictions: u64,
+    /// Pairs currently cached
+    pub entries: usize,
+    /// Most pairs the cache holds
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Returns the fraction of lookups answered from the cache (0.0 if none).
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// A cached search answer and when it was last used.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// Shortest path, or `None` if the words are not connected
+    path: Option<Vec<String>>,
+    /// Tick of the most recent lookup
+    last_used: u64,
+}
+
+/// Path solver that caches results of recent `(start, end)` queries.
+///
+/// Entries are evicted least recently used first once `capacity` pairs are
+/// cached. A capacity of 0 disables caching; every call then runs a search
+/// and counts as a miss.
+#[derive(Debug, Clone)]
+pub struct CachedSolver {
+    /// Most pairs to keep
+    capacity: usize,
+    /// Cached answers keyed by `(start, end)`
+    entries: HashMap<(String, String), Entry>,
+    /// Keys ordered by the tick of their last use, oldest first
+    recency: BTreeMap<u64, (String, String)>,
+    /// Counter advanced on every lookup
+    tick: u64,
+    /// Hit, miss, and eviction counters
+    stats: CacheStats,
+}
+
+impl CachedSolver {
+    /// Creates an empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Most `(start, end)` pairs to keep (0 disables caching)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats {
+                capacity,
+                ..CacheStats::default()
+            },
+        }
+    }
+
+    /// Finds the shortest path between two words, using the cache when possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to search on a miss
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// The path including both words, or `None` if they are not connected.
+    pub fn find_shortest_path(
+        &mut self,
+        graph: &WordGraph,
+        start: &str,
+        end: &str,
+    ) -> Option<Vec<String>> {
+        self.find_shortest_path_with(graph, start, end, &SolveOptions::new())
+            .into_path()
+    }
+
+    /// Finds the shortest path like `WordGraph::find_shortest_path_with`,
+    /// using the cache when possible.
+    ///
+    /// A cached answer is returned regardless of the budget in `options`; only
+    /// a miss runs a (budgeted) search.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to search on a miss
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `options` - Visit and time budget of the search
+    pub fn find_shortest_path_with(
+        &mut self,
+        graph: &WordGraph,
+        start: &str,
+        end: &str,
+        options: &SolveOptions,
+    ) -> SolveOutcome {
+        let key = (start.to_string(), end.to_string());
+        if let Some(path) = self.lookup(&key) {
+            self.stats.hits += 1;
+            return match path {
+                Some(path) => SolveOutcome::Found(path),
+                None => SolveOutcome::NoPath,
+            };
+        }
+
+        self.stats.misses += 1;
+        let outcome = graph.find_shortest_path_with(start, end, options);
+        match &outcome {
+            SolveOutcome::Found(path) => self.insert(key, Some(path.clone())),
+            SolveOutcome::NoPath => self.insert(key, None),
+            SolveOutcome::BudgetExceeded => {}
+        }
+        outcome
+    }
+
+    /// Returns the number of steps between two words, using the cache when possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to search on a miss
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    pub fn distance(&mut self, graph: &WordGraph, start: &str, end: &str) -> Option<usize> {
+        self.find_shortest_path(graph, start, end)
+            .map(|path| path.len() - 1)
+    }
+
+    /// Returns the hit and miss counters and the current size.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.len(),
+            ..self.stats
+        }
+    }
+
+    /// Drops every cached answer, keeping the counters.
+    ///
+    /// Call this after the graph changes.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Returns a cached answer and marks it as most recently used.
+    fn lookup(&mut self, key: &(String, String)) -> Option<Option<Vec<String>>> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        self.recency.remove(&entry.last_used);
+        entry.last_used = self.tick;
+        self.recency.insert(self.tick, key.clone());
+        Some(entry.path.clone())
+    }
+
+    /// Caches an answer, evicting the least recently used pair if full.
+    fn insert(&mut self, key: (String, String), path: Option<Vec<String>>) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+        self.recency.insert(self.tick, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                path,
+                last_used: self.tick,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_graph() -> WordGraph {
+        WordGraph::from_words(["cat", "cot", "cog", "dog", "bat", "zzz"].map(String::from))
+    }
+
+    #[test]
+    fn test_hits_and_misses() {
+        let graph = create_test_graph();
+        let mut solver = CachedSolver::new(8);
+
+        assert_eq!(solver.distance(&graph, "cat", "dog"), Some(3));
+        assert_eq!(solver.distance(&graph, "cat", "dog"), Some(3));
+        assert_eq!(solver.find_shortest_path(&graph, "cat", "zzz"), None);
+        assert_eq!(solver.find_shortest_path(&graph, "cat", "zzz"), None);
+
+        let stats = solver.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (2, 2, 2));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let graph = create_test_graph();
+        let mut solver = CachedSolver::new(2);
+
+        solver.distance(&graph, "cat", "dog");
+        solver.distance(&graph, "bat", "cot");
+        // Touch cat->dog so bat->cot becomes the oldest entry
+        solver.distance(&graph, "cat", "dog");
+        solver.distance(&graph, "cot", "dog");
+        assert_eq!(solver.stats().evictions, 1);
+
+        solver.distance(&graph, "cat", "dog");
+        assert_eq!(solver.stats().hits, 2);
+        solver.distance(&graph, "bat", "cot");
+        assert_eq!(solver.stats().misses, 4);
+        assert_eq!(solver.stats().entries, 2);
+    }
+
+    #[test]
+    fn test_budget_exceeded_is_not_cached() {
+        let graph = create_test_graph();
+        let mut solver = CachedSolver::new(8);
+        let tight = SolveOptions::new().with_max_visited(2);
+
+        assert_eq!(
+            solver.find_shortest_path_with(&graph, "cat", "dog", &tight),
+            SolveOutcome::BudgetExceeded
+        );
+        assert_eq!(solver.stats().entries, 0);
+        assert_eq!(solver.distance(&graph, "cat", "dog"), Some(3));
+        // Once cached, the answer is returned even under a tight budget
+        assert!(matches!(
+            solver.find_shortest_path_with(&graph, "cat", "dog", &tight),
+            SolveOutcome::Found(_)
+        ));
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_caching() {
+        let graph = create_test_graph();
+        let mut solver = CachedSolver::new(0);
+
+        solver.distance(&graph, "cat", "dog");
+        solver.distance(&graph, "cat", "dog");
+        assert_eq!(solver.stats().hits, 0);
+        assert_eq!(solver.stats().misses, 2);
+        assert_eq!(solver.stats().entries, 0);
+    }
+}
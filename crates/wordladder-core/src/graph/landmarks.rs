@@ -0,0 +1,402 @@
+//! # Landmark Distance Estimation
+//!
+//! On very large dictionaries (hundreds of thousands of words across several
+//! languages) even a bidirectional BFS explores a large part of a component
+//! before it meets in the middle. Landmarks trade a one-time preprocessing
+//! pass for much smaller searches: a few words per length class are picked as
+//! landmarks, and the distance from each landmark to every word of its class
+//! is stored. By the triangle inequality, `|d(L, a) - d(L, b)|` is a lower
+//! bound on `d(a, b)` for every landmark `L`, which lets
+//! `WordGraph::find_shortest_path` run A* instead of BFS (the "ALT"
+//! algorithm). If a landmark reaches one word but not the other, the words are
+//! known to be disconnected without searching at all.
+//!
+//! Landmarks are picked from the largest component of each length class by
+//! farthest-point selection, so they sit on the edges of the component where
+//! their bounds are tightest. Words outside that component get no bound and
+//! are searched as by BFS.
+//!
+//! Preprocessing runs one full BFS per landmark, so landmarks can be saved to
+//! a `.landmarks.cbor` file next to the dictionary and loaded on the next
+//! start. The file records a fingerprint of the dictionary and edge rules and
+//! is rejected if either changed.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_core::graph::WordGraph;
+//! use wordladder_core::graph::landmarks::Landmarks;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndig\n");
+//!
+//! let landmarks = Landmarks::build(&graph, 2);
+//! graph.set_landmarks(landmarks).unwrap();
+//!
+//! assert_eq!(graph.estimate_distance("cat", "dig"), Some(4));
+//! assert_eq!(graph.find_shortest_path("cat", "dig").unwrap().len(), 5);
+//! ```
+
+use super::{UNVISITED, WordGraph};
+#[cfg(feature = "fs")]
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File extension of saved landmarks, replacing the dictionary's own extension.
+pub const LANDMARKS_EXTENSION: &str = "landmarks.cbor";
+
+/// Landmarks of one length class (one subgraph of the word graph).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LandmarkClass {
+    /// First word ID of the class
+    start: u32,
+    /// One past the last word ID of the class
+    end: u32,
+    /// Word IDs of the landmarks
+    landmarks: Vec<u32>,
+    /// Per landmark, the distance to each word of the class, indexed by
+    /// `id - start` (`UNVISITED` for words the landmark cannot reach)
+    distances: Vec<Vec<u32>>,
+}
+
+impl LandmarkClass {
+    /// Returns `true` if the word ID belongs to this class.
+    fn contains(&self, id: u32) -> bool {
+        (self.start..self.end).contains(&id)
+    }
+
+    /// Returns each landmark's distance to a word of this class.
+    fn distances_to(&self, id: u32) -> impl Iterator<Item = u32> + '_ {
+        let index = (id - self.start) as usize;
+        self.distances.iter().map(move |distances| distances[index])
+    }
+}
+
+/// Precomputed landmark distances for a word graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Landmarks {
+    /// Fingerprint of the dictionary and edge rules the landmarks were built for
+    fingerprint: String,
+    /// Landmarks per length class, in word ID order
+    classes: Vec<LandmarkClass>,
+}
+
+impl Landmarks {
+    /// Picks landmarks and computes their distances.
+    ///
+    /// Builds every subgraph of `graph` and runs one full BFS per landmark.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to preprocess
+    /// * `per_class` - Landmarks to pick per length class; fewer are picked
+    ///   when a class's largest component is too small to need them
+    pub fn build(graph: &WordGraph, per_class: usize) -> Self {
+        let labels = graph.component_labels();
+        let classes = graph
+            .subgraphs
+            .iter()
+            .filter_map(|subgraph| {
+                Self::build_class(graph, &labels, subgraph.start, subgraph.end, per_class)
+            })
+            .collect();
+        Self {
+            fingerprint: fingerprint(graph),
+            classes,
+        }
+    }
+
+    /// Picks the landmarks of the words with IDs in `start..end`.
+    fn build_class(
+        graph: &WordGraph,
+        labels: &[u32],
+        start: u32,
+        end: u32,
+        per_class: usize,
+    ) -> Option<LandmarkClass> {
+        let range = start as usize..end as usize;
+        let mut sizes: HashMap<u32, usize> = HashMap::new();
+        for &label in &labels[range.clone()] {
+            *sizes.entry(label).or_default() += 1;
+        }
+        let (largest, size) = sizes
+            .into_iter()
+            .max_by_key(|&(label, size)| (size, std::cmp::Reverse(label)))?;
+        if size < 2 || per_class == 0 {
+            return None;
+        }
+
+        // Farthest-point selection: start from the word farthest from an
+        // arbitrary seed, then repeatedly add the word farthest from every
+        // landmark picked so far.
+        let seed = range.clone().find(|&id| labels[id] == largest)? as u32;
+        let mut nearest = graph.bfs_distances(seed)[range.clone()].to_vec();
+        let mut landmarks = Vec::new();
+        let mut distances = Vec::new();
+        while landmarks.len() < per_class {
+            let (farthest, &distance) = nearest
+                .iter()
+                .enumerate()
+                .filter(|&(_, &distance)| distance != UNVISITED)
+                .max_by_key(|&(index, &distance)| (distance, std::cmp::Reverse(index)))?;
+            if distance == 0 && !landmarks.is_empty() {
+                break;
+            }
+            let landmark = start + farthest as u32;
+            let from_landmark = graph.bfs_distances(landmark)[range.clone()].to_vec();
+            if landmarks.is_empty() {
+                nearest.clone_from(&from_landmark);
+            } else {
+                for (nearest, &distance) in nearest.iter_mut().zip(&from_landmark) {
+                    *nearest = (*nearest).min(distance);
+                }
+            }
+            landmarks.push(landmark);
+            distances.push(from_landmark);
+        }
+
+        Some(LandmarkClass {
+            start,
+            end,
+            landmarks,
+            distances,
+        })
+    }
+
+    /// Returns the number of landmarks across all length classes.
+    pub fn len(&self) -> usize {
+        self.classes.iter().map(|class| class.landmarks.len()).sum()
+    }
+
+    /// Returns `true` if no landmarks were picked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the estimated heap bytes held by the landmark distances.
+    pub(super) fn heap_size(&self) -> usize {
+        let class_size = |class: &LandmarkClass| {
+            class.landmarks.capacity() * size_of::<u32>()
+                + class.distances.capacity() * size_of::<Vec<u32>>()
+                + class
+                    .distances
+                    .iter()
+                    .map(|distances| distances.capacity() * size_of::<u32>())
+                    .sum::<usize>()
+        };
+        self.fingerprint.capacity()
+            + self.classes.capacity() * size_of::<LandmarkClass>()
+            + self.classes.iter().map(class_size).sum::<usize>()
+    }
+
+    /// Returns the landmark words, in the order they were picked per length class.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph the landmarks were built for
+    pub fn words(&self, graph: &WordGraph) -> Vec<String> {
+        self.classes
+            .iter()
+            .flat_map(|class| &class.landmarks)
+            .map(|&id| graph.word_list[id as usize].clone())
+            .collect()
+    }
+
+    /// Returns `true` if the landmarks were built for this graph's dictionary
+    /// and edge rules.
+    pub fn matches(&self, graph: &WordGraph) -> bool {
+        self.fingerprint == fingerprint(graph)
+    }
+
+    /// Writes the landmarks to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination file; missing parent directories are created
+    #[cfg(feature = "fs")]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes)?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Reads landmarks written by `save`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File to read
+    ///
+    /// # Returns
+    ///
+    /// The landmarks, or an error if the file cannot be read or decoded.
+    /// Check `matches` before using them with a graph.
+    #[cfg(feature = "fs")]
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        ciborium::from_reader(bytes.as_slice())
+            .with_context(|| format!("Invalid landmark file {}", path.display()))
+    }
+
+    /// Returns a lower bound on the distance between two word IDs.
+    ///
+    /// `None` means the words are provably not connected: some landmark
+    /// reaches one of them but not the other.
+    pub(super) fn lower_bound(&self, a: u32, b: u32) -> Option<u32> {
+        self.estimator(b).estimate(a)
+    }
+
+    /// Returns an estimator of distances to a fixed target word ID.
+    pub(super) fn estimator(&self, target: u32) -> Estimator<'_> {
+        let class = self.classes.iter().find(|class| class.contains(target));
+        Estimator {
+            class,
+            target: class.map_or_else(Vec::new, |class| class.distances_to(target).collect()),
+        }
+    }
+}
+
+/// Lower bounds on the distance to one target word, used as the A* heuristic.
+pub(super) struct Estimator<'a> {
+    /// Landmark class of the target, if it has landmarks
+    class: Option<&'a LandmarkClass>,
+    /// Each landmark's distance to the target
+    target: Vec<u32>,
+}
+
+impl Estimator<'_> {
+    /// Returns a lower bound on the distance from a word ID to the target,
+    /// or `None` if the word provably cannot reach it.
+    pub(super) fn estimate(&self, id: u32) -> Option<u32> {
+        let Some(class) = self.class.filter(|class| class.contains(id)) else {
+            return Some(0);
+        };
+        let mut bound = 0;
+        for (from, &to) in class.distances_to(id).zip(&self.target) {
+            match (from == UNVISITED, to == UNVISITED) {
+                (true, true) => {}
+                (false, false) => bound = bound.max(from.abs_diff(to)),
+                _ => return None,
+            }
+        }
+        Some(bound)
+    }
+}
+
+/// Returns the sidecar path of saved landmarks for a dictionary file.
+///
+/// # Arguments
+///
+/// * `dictionary_path` - Path of the dictionary file
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::Path;
+/// use wordladder_core::graph::landmarks::landmarks_path;
+///
+/// let sidecar = landmarks_path(Path::new("data/dictionary.txt"));
+/// assert_eq!(sidecar, Path::new("data/dictionary.landmarks.cbor"));
+/// ```
+pub fn landmarks_path(dictionary_path: &Path) -> PathBuf {
+    dictionary_path.with_extension(LANDMARKS_EXTENSION)
+}
+
+/// Hashes the interned word list and edge rules, which together decide every
+/// word ID and distance the landmarks store.
+fn fingerprint(graph: &WordGraph) -> String {
+    let mut hasher = blake3::Hasher::new();
+    for word in &graph.word_list {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.update(format!("{:?}", graph.edge_rules).as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_graph() -> WordGraph {
+        WordGraph::from_words(
+            [
+                "cat", "cot", "cog", "dog", "dig", "dug", "bat", "bag", "big", "zzz", "cold",
+                "cord", "card",
+            ]
+            .map(String::from),
+        )
+    }
+
+    #[test]
+    fn test_lower_bounds_never_exceed_distances() {
+        let graph = create_test_graph();
+        let landmarks = Landmarks::build(&graph, 3);
+        assert!(!landmarks.is_empty());
+
+        let words: Vec<String> = graph.word_list.clone();
+        for a in &words {
+            for b in &words {
+                let (a_id, b_id) = (graph.word_id(a).unwrap(), graph.word_id(b).unwrap());
+                let bound = landmarks.lower_bound(a_id, b_id);
+                match graph.find_shortest_path(a, b) {
+                    Some(path) => assert!((bound.unwrap() as usize) < path.len()),
+                    None => assert!(bound.is_none_or(|bound| bound == 0)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_astar_matches_bfs_lengths() {
+        let graph = create_test_graph();
+        let mut with_landmarks = graph.clone();
+        with_landmarks
+            .set_landmarks(Landmarks::build(&graph, 2))
+            .unwrap();
+
+        for (start, end) in [
+            ("cat", "dig"),
+            ("bag", "dug"),
+            ("cold", "card"),
+            ("cat", "zzz"),
+        ] {
+            assert_eq!(
+                with_landmarks
+                    .find_shortest_path(start, end)
+                    .map(|path| path.len()),
+                graph.find_shortest_path(start, end).map(|path| path.len())
+            );
+        }
+        assert_eq!(with_landmarks.estimate_distance("cat", "zzz"), None);
+        assert_eq!(with_landmarks.estimate_distance("cat", "cold"), Some(0));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_save_load_and_fingerprint() {
+        let path = std::env::temp_dir().join(format!("wl_landmarks_{}.cbor", std::process::id()));
+        let mut graph = create_test_graph();
+        let landmarks = Landmarks::build(&graph, 2);
+        landmarks.save(&path).unwrap();
+
+        let loaded = Landmarks::load(&path).unwrap();
+        assert_eq!(loaded, landmarks);
+        assert!(loaded.matches(&graph));
+
+        graph.add_word("cut");
+        assert!(graph.landmarks().is_none());
+        assert!(!loaded.matches(&graph));
+        assert!(graph.set_landmarks(loaded).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,40 @@
+//! # Word Ladder Core
+//!
+//! The word graph and puzzle generation algorithms of the word ladder engine,
+//! without the command-line interface, exporters, or servers. Apps that only
+//! need to solve and generate puzzles can depend on this crate alone;
+//! `wordladder-engine` re-exports both modules unchanged.
+//!
+//! ## Modules
+//!
+//! - `graph`: Word graph construction and BFS path finding
+//! - `puzzle`: Puzzle generation, validation, and difficulty assessment
+//!
+//! ## Features
+//!
+//! - `fs` (default): Loading dictionaries and landmarks from files. Without
+//!   it, the crate never touches the file system; dictionaries are loaded
+//!   from in-memory text with `WordGraph::load_dictionary_from_str`.
+//! - `stream`: Async `Stream` of puzzles (`PuzzleGenerator::puzzle_stream`)
+//! - `test-support`: Test fixtures shared with the engine's tests; not a
+//!   stable API
+//!
+//! ## Example
+//!
+//! ```rust
+//! use wordladder_core::{graph::WordGraph, puzzle::PuzzleGenerator};
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+//! graph.load_base_words_from_str("cat\ndog\n");
+//!
+//! let generator = PuzzleGenerator::new(graph);
+//! let puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+//! assert_eq!(puzzle.path, ["cat", "cot", "cog", "dog"]);
+//! ```
+
+pub mod graph;
+pub mod puzzle;
+#[cfg(any(test, feature = "test-support"))]
+#[doc(hidden)]
+pub mod test_support;
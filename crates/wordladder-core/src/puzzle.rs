@@ -0,0 +1,4874 @@
+//! # Puzzle Generation and Validation
+//!
+//! This module handles puzzle generation, difficulty assessment, and validation
+//! for word ladder puzzles. It provides the core logic for creating puzzles of
+//! varying difficulty levels and verifying user solutions.
+//!
+//! ## Key Components
+//!
+//! - **Puzzle Structure**: Represents a complete word ladder with start, end, path, and difficulty
+//! - **Difficulty Levels**: Easy (2-3 steps), Medium (4-5 steps), Hard (6-10 steps);
+//!   with a length-changing edge rule, each insertion or deletion is one step
+//!   and ladders may join words of different lengths
+//! - **Puzzle Generator**: Creates puzzles using random word selection and path finding
+//! - **Validation**: Verifies that puzzle solutions are valid word ladders
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use wordladder_core::puzzle::{PuzzleGenerator, Difficulty};
+//!
+//! // Create generator with loaded graph
+//! let mut graph = wordladder_core::graph::WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt").unwrap();
+//! graph.load_base_words("data/base_words.txt").unwrap();
+//! let generator = PuzzleGenerator::new(graph);
+//!
+//! // Generate a single puzzle
+//! if let Ok(puzzle) = generator.generate_puzzle("cat", "dog") {
+//!     println!("Difficulty: {:?}", puzzle.difficulty);
+//! }
+//!
+//! // Generate batch of puzzles
+//! let result = generator.generate_batch(10, Difficulty::Medium);
+//! println!("{}", result); // e.g. "10 puzzles from 14 attempts in 0.02s (rejected: ...)"
+//!
+//! // Verify a solution
+//! let is_valid = generator.verify_puzzle("cat,cot,cog,dog").unwrap();
+//! ```
+
+pub mod calibration;
+pub mod curation;
+pub mod selection;
+pub mod usage;
+
+use crate::graph::{LadderError, WordGraph, WordNormalization};
+use crate::puzzle::calibration::CalibrationModel;
+use crate::puzzle::selection::{
+    DistanceTargeted, EndpointQuota, FrequencyWeighted, PairContext, PairSelector, RandomUniform,
+};
+use anyhow::{Result, anyhow};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Most start words `PuzzleGenerator::estimate_capacity` searches from before
+/// it samples them instead.
+pub const CAPACITY_SAMPLE_STARTS: usize = 500;
+
+/// Puzzles `PuzzleGenerator::puzzle_stream` generates ahead of its consumer.
+#[cfg(all(feature = "stream", not(target_arch = "wasm32")))]
+const PUZZLE_STREAM_BUFFER: usize = 16;
+
+/// Represents a complete word ladder puzzle with its solution path and difficulty.
+///
+/// A puzzle consists of a starting word, ending word, the complete path between them,
+/// and an automatically calculated difficulty level based on the number of steps.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Puzzle {
+    /// The starting word of the puzzle
+    pub start: String,
+    /// The ending word of the puzzle
+    pub end: String,
+    /// The complete path from start to end, including all intermediate words
+    pub path: Vec<String>,
+    /// The difficulty level of this puzzle based on path length
+    pub difficulty: Difficulty,
+    /// Whether `path` is the only shortest solution (`None` if not checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique_solution: Option<bool>,
+    /// A ladder from `end` back to `start` with as many steps as `path` but
+    /// different intermediate words (`None` if not checked or there is none,
+    /// see `PuzzleGenerator::annotate_reverse_path`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse_path: Option<Vec<String>>,
+    /// Par and star thresholds for scoring players (`None` unless enabled,
+    /// see `PuzzleGenerator::with_star_ratings`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stars: Option<StarRating>,
+    /// When and how the puzzle was generated (`None` unless enabled, see
+    /// `PuzzleGenerator::with_puzzle_meta`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<PuzzleMeta>,
+}
+
+/// Which parts of a puzzle `Puzzle::canonical_hash` covers.
+///
+/// The default hashes the start word, end word, and path in that order, so
+/// two puzzles share a hash exactly when they are the same ladder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CanonicalHashOptions {
+    /// Whether the start and end words are put in sorted order first (and
+    /// the path reversed with them), so a puzzle and its reverse share a hash
+    pub unordered: bool,
+    /// Whether the path is left out, so every ladder between the same two
+    /// words shares a hash
+    pub path_independent: bool,
+}
+
+/// Audit information recording when and how a puzzle was generated.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PuzzleMeta {
+    /// Generation time as seconds since the Unix epoch
+    pub created_at: u64,
+    /// Version of the engine that generated the puzzle
+    pub engine_version: String,
+    /// Hex SHA-256 of the dictionary the puzzle was built from
+    pub dictionary_sha256: String,
+    /// RNG seed of the generator, or `None` if generation was not seeded
+    pub seed: Option<u64>,
+}
+
+impl PuzzleMeta {
+    /// Creates metadata stamped with the current time and engine version.
+    ///
+    /// The system clock is not available on `wasm32-unknown-unknown`, so this
+    /// is meant for native builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary_sha256` - Hex SHA-256 of the dictionary (see
+    ///   `wordladder_engine::exporters::manifest::sha256_hex`)
+    /// * `seed` - RNG seed used for generation, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::PuzzleMeta;
+    ///
+    /// let meta = PuzzleMeta::new("ab12", Some(42));
+    /// assert_eq!(meta.engine_version, env!("CARGO_PKG_VERSION"));
+    /// assert!(meta.created_at > 0);
+    /// ```
+    pub fn new(dictionary_sha256: impl Into<String>, seed: Option<u64>) -> Self {
+        Self {
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            dictionary_sha256: dictionary_sha256.into(),
+            seed,
+        }
+    }
+}
+
+/// Represents the difficulty level of a word ladder puzzle.
+///
+/// The difficulty is determined by the number of steps required to solve the puzzle:
+/// - **Easy**: 2-3 steps (short paths)
+/// - **Medium**: 4-5 steps (moderate complexity)
+/// - **Hard**: 6-10 steps (complex puzzles requiring multiple transformations)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// Easy puzzles (2-3 steps)
+    Easy,
+    /// Medium puzzles (4-5 steps)
+    Medium,
+    /// Hard puzzles (6-10 steps)
+    Hard,
+}
+
+impl Difficulty {
+    /// Every difficulty level, from easiest to hardest.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    /// Returns the lowercase name of the difficulty, as accepted by `from_str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// Returns the difficulty of a puzzle according to observed player results.
+    ///
+    /// Puzzles with player data are labeled by their hardness; others by the
+    /// hardness of observed puzzles with the same number of steps, falling
+    /// back to their current label. See `puzzle::calibration` for how the
+    /// model is fitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Model fitted to player statistics
+    /// * `puzzle` - The puzzle to label
+    pub fn from_calibration(model: &CalibrationModel, puzzle: &Puzzle) -> Self {
+        model.classify(puzzle)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = ParseDifficultyError;
+
+    /// Parses a difficulty name (`easy`, `medium`, or `hard`), ignoring case
+    /// and surrounding whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::Difficulty;
+    ///
+    /// assert_eq!("Hard".parse::<Difficulty>().unwrap(), Difficulty::Hard);
+    /// let error = "expert".parse::<Difficulty>().unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Unknown difficulty 'expert' (valid values: easy, medium, hard)"
+    /// );
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim();
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseDifficultyError {
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Error returned when parsing an unknown difficulty name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDifficultyError {
+    /// The name that matched no difficulty level
+    pub name: String,
+}
+
+impl fmt::Display for ParseDifficultyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let valid: Vec<&str> = Difficulty::ALL.iter().map(Difficulty::name).collect();
+        write!(
+            f,
+            "Unknown difficulty '{}' (valid values: {})",
+            self.name,
+            valid.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseDifficultyError {}
+
+impl Puzzle {
+    /// Creates a new puzzle with the specified path and automatically determines difficulty.
+    ///
+    /// The difficulty is calculated based on the number of steps in the path:
+    /// - 2-3 steps: Easy
+    /// - 4-5 steps: Medium
+    /// - 6-10 steps: Hard
+    ///
+    /// Returns `None` if the path has 1 step or fewer, or more than 10 steps (invalid puzzle).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `path` - Complete path including start and end words
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{Puzzle, Difficulty};
+    ///
+    /// let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+    /// let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+    /// assert!(matches!(puzzle.difficulty, Difficulty::Easy)); // 3 steps = Easy
+    /// ```
+    pub fn new(start: String, end: String, path: Vec<String>) -> Option<Self> {
+        Self::with_thresholds(start, end, path, &DifficultyThresholds::default())
+    }
+
+    /// Creates a new puzzle, determining difficulty from custom step thresholds.
+    ///
+    /// Returns `None` if the path has 1 step or fewer, or more steps than
+    /// `thresholds.hard_max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    /// * `path` - Complete path including start and end words
+    /// * `thresholds` - Step counts separating the difficulty levels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{Difficulty, DifficultyThresholds, Puzzle};
+    ///
+    /// let thresholds = DifficultyThresholds { easy_max: 2, medium_max: 4, hard_max: 8 };
+    /// let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+    /// let puzzle = Puzzle::with_thresholds("cat".to_string(), "dog".to_string(), path, &thresholds).unwrap();
+    /// assert_eq!(puzzle.difficulty, Difficulty::Medium); // 3 steps = Medium here
+    /// ```
+    pub fn with_thresholds(
+        start: String,
+        end: String,
+        path: Vec<String>,
+        thresholds: &DifficultyThresholds,
+    ) -> Option<Self> {
+        let len = path.len() - 1; // number of steps
+        let difficulty = thresholds.classify(len)?;
+        Some(Self {
+            start,
+            end,
+            path,
+            difficulty,
+            unique_solution: None,
+            reverse_path: None,
+            stars: None,
+            meta: None,
+        })
+    }
+
+    /// Serializes the puzzle to a JSON string.
+    ///
+    /// # Returns
+    ///
+    /// A pretty-printed JSON string representation of the puzzle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::Puzzle;
+    ///
+    /// let puzzle = Puzzle::new(
+    ///     "cat".to_string(),
+    ///     "dog".to_string(),
+    ///     vec!["cat".to_string(), "cot".to_string(), "dog".to_string()]
+    /// ).unwrap();
+    ///
+    /// let json = puzzle.to_json().unwrap();
+    /// println!("{}", json);
+    /// ```
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Returns the ladder with intermediate words masked for print puzzles.
+    ///
+    /// The start and end words are given in full. In each intermediate word,
+    /// the letters that differ from the previous word are replaced with `_`,
+    /// so the solver sees the word length and where the change happens.
+    ///
+    /// # Returns
+    ///
+    /// One entry per word in the path, starting with `start` and ending with `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::Puzzle;
+    ///
+    /// let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+    /// let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+    /// assert_eq!(puzzle.to_clue_format(), vec!["cat", "c_t", "co_", "dog"]);
+    /// ```
+    pub fn to_clue_format(&self) -> Vec<String> {
+        let last = self.path.len() - 1;
+        self.path
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 || i == last {
+                    return word.clone();
+                }
+                let previous: Vec<char> = self.path[i - 1].chars().collect();
+                word.chars()
+                    .enumerate()
+                    .map(|(j, c)| if previous.get(j) == Some(&c) { c } else { '_' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns a hash identifying the puzzle independently of how and when it
+    /// was generated, e.g. to dedupe leaderboard submissions server-side.
+    ///
+    /// The hash is the lowercase hex BLAKE3 hash of `start|end|path` (words
+    /// of the path joined with commas), or of `start|end` when
+    /// `path_independent` is set. Difficulty, annotations, and metadata are
+    /// not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Whether the endpoints are sorted and whether the path is included
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{CanonicalHashOptions, Puzzle};
+    ///
+    /// let words = |w: &[&str]| w.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    /// let forward = Puzzle::new("cat".into(), "dog".into(), words(&["cat", "cot", "cog", "dog"])).unwrap();
+    /// let backward = Puzzle::new("dog".into(), "cat".into(), words(&["dog", "cog", "cot", "cat"])).unwrap();
+    ///
+    /// let unordered = CanonicalHashOptions { unordered: true, ..Default::default() };
+    /// assert_ne!(forward.canonical_hash(Default::default()), backward.canonical_hash(Default::default()));
+    /// assert_eq!(forward.canonical_hash(unordered), backward.canonical_hash(unordered));
+    /// ```
+    pub fn canonical_hash(&self, options: CanonicalHashOptions) -> String {
+        let reversed = options.unordered && self.end < self.start;
+        let (first, second) = if reversed {
+            (&self.end, &self.start)
+        } else {
+            (&self.start, &self.end)
+        };
+        // Separators keep e.g. ("ab", "c") and ("a", "bc") distinct
+        let mut content = format!("{}|{}", first, second);
+        if !options.path_independent {
+            let path: Vec<&str> = if reversed {
+                self.path.iter().rev().map(String::as_str).collect()
+            } else {
+                self.path.iter().map(String::as_str).collect()
+            };
+            content.push('|');
+            content.push_str(&path.join(","));
+        }
+        blake3::hash(content.as_bytes()).to_hex().to_string()
+    }
+
+    /// Serializes the puzzle to a JSON string including its clue format.
+    ///
+    /// The output matches `to_json` with an extra `clues` array holding the
+    /// result of `to_clue_format`.
+    ///
+    /// # Returns
+    ///
+    /// A pretty-printed JSON string with both the solution and the clues.
+    pub fn to_json_with_clues(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        value["clues"] = serde_json::to_value(self.to_clue_format())?;
+        serde_json::to_string_pretty(&value)
+    }
+}
+
+/// Step counts separating the difficulty levels.
+///
+/// A puzzle with `steps` steps is easy for `2..=easy_max`, medium for
+/// `easy_max + 1..=medium_max`, and hard for `medium_max + 1..=hard_max`.
+/// Longer or shorter puzzles are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DifficultyThresholds {
+    /// Most steps in an easy puzzle
+    pub easy_max: usize,
+    /// Most steps in a medium puzzle
+    pub medium_max: usize,
+    /// Most steps in a hard puzzle
+    pub hard_max: usize,
+}
+
+impl Default for DifficultyThresholds {
+    fn default() -> Self {
+        Self {
+            easy_max: 3,
+            medium_max: 5,
+            hard_max: 10,
+        }
+    }
+}
+
+impl DifficultyThresholds {
+    /// Returns the difficulty of a puzzle with the given number of steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - Number of steps in the ladder
+    ///
+    /// # Returns
+    ///
+    /// The difficulty level, or `None` if the step count is outside every level.
+    pub fn classify(&self, steps: usize) -> Option<Difficulty> {
+        if steps <= 1 {
+            None
+        } else if steps <= self.easy_max {
+            Some(Difficulty::Easy)
+        } else if steps <= self.medium_max {
+            Some(Difficulty::Medium)
+        } else if steps <= self.hard_max {
+            Some(Difficulty::Hard)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the minimum number of steps in a hard puzzle.
+    pub fn hard_min(&self) -> usize {
+        self.medium_max + 1
+    }
+
+    /// Returns the minimum number of steps in a puzzle of the given difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty level
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{Difficulty, DifficultyThresholds};
+    ///
+    /// let thresholds = DifficultyThresholds::default();
+    /// assert_eq!(thresholds.min_steps(Difficulty::Easy), 2);
+    /// assert_eq!(thresholds.min_steps(Difficulty::Medium), 4);
+    /// ```
+    pub fn min_steps(&self, difficulty: Difficulty) -> usize {
+        match difficulty {
+            Difficulty::Easy => 2,
+            Difficulty::Medium => self.easy_max + 1,
+            Difficulty::Hard => self.hard_min(),
+        }
+    }
+
+    /// Returns the maximum number of steps in a puzzle of the given difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty level
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{Difficulty, DifficultyThresholds};
+    ///
+    /// let thresholds = DifficultyThresholds::default();
+    /// assert_eq!(thresholds.max_steps(Difficulty::Medium), 5);
+    /// ```
+    pub fn max_steps(&self, difficulty: Difficulty) -> usize {
+        match difficulty {
+            Difficulty::Easy => self.easy_max,
+            Difficulty::Medium => self.medium_max,
+            Difficulty::Hard => self.hard_max,
+        }
+    }
+}
+
+/// Rules rejecting near-trivial puzzles, such as `cat -> bat` or `cat -> cats`.
+///
+/// They apply to both `generate_puzzle` and batch generation. The defaults
+/// reject one-step ladders and endpoints a single edit apart, and leave the
+/// shared-prefix check off.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrivialityRules {
+    /// Fewest steps a puzzle may have
+    pub min_steps: usize,
+    /// Whether to reject endpoints one insertion, deletion, or substitution apart
+    pub reject_single_edit: bool,
+    /// Highest allowed ratio of the endpoints' common prefix to the longer
+    /// word's length, or `None` to allow any shared prefix
+    pub max_shared_prefix_ratio: Option<f64>,
+}
+
+impl Default for TrivialityRules {
+    fn default() -> Self {
+        Self {
+            min_steps: 2,
+            reject_single_edit: true,
+            max_shared_prefix_ratio: None,
+        }
+    }
+}
+
+impl TrivialityRules {
+    /// Returns `true` if a pair of endpoints is too similar to make a puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::TrivialityRules;
+    ///
+    /// let rules = TrivialityRules {
+    ///     max_shared_prefix_ratio: Some(0.5),
+    ///     ..TrivialityRules::default()
+    /// };
+    /// assert!(rules.is_trivial_pair("cat", "cats"));
+    /// assert!(rules.is_trivial_pair("faced", "facts"));
+    /// assert!(!rules.is_trivial_pair("cold", "warm"));
+    /// ```
+    pub fn is_trivial_pair(&self, start: &str, end: &str) -> bool {
+        if self.reject_single_edit && within_one_edit(start, end) {
+            return true;
+        }
+        self.max_shared_prefix_ratio.is_some_and(|max| {
+            let shared = start
+                .chars()
+                .zip(end.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let longer = start.chars().count().max(end.chars().count());
+            longer > 0 && shared as f64 / longer as f64 > max
+        })
+    }
+
+    /// Returns `true` if a puzzle is too trivial to keep.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to check
+    pub fn is_trivial(&self, puzzle: &Puzzle) -> bool {
+        puzzle.path.len().saturating_sub(1) < self.min_steps
+            || self.is_trivial_pair(&puzzle.start, &puzzle.end)
+    }
+}
+
+/// Returns `true` if two words are at most one insertion, deletion, or substitution apart.
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (shorter, longer) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+    let prefix = shorter
+        .iter()
+        .zip(longer.iter())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let skip = if shorter.len() == longer.len() { 1 } else { 0 };
+    shorter.get(prefix + skip..).unwrap_or(&[]) == &longer[(prefix + 1).min(longer.len())..]
+}
+
+/// Minimum branching constraint for each difficulty level.
+///
+/// Every intermediate word of a generated puzzle must have at least this many
+/// in-dictionary neighbors. Because an intermediate word always neighbors the
+/// words before and after it, values of 2 or less accept every puzzle; 3 means
+/// at least one alternative move exists at every step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinBranching {
+    /// Minimum neighbors per intermediate word for easy puzzles
+    pub easy: usize,
+    /// Minimum neighbors per intermediate word for medium puzzles
+    pub medium: usize,
+    /// Minimum neighbors per intermediate word for hard puzzles
+    pub hard: usize,
+}
+
+impl MinBranching {
+    /// Returns the threshold for a difficulty level.
+    pub fn for_difficulty(&self, difficulty: Difficulty) -> usize {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Medium => self.medium,
+            Difficulty::Hard => self.hard,
+        }
+    }
+}
+
+/// Endpoint word lengths allowed for each difficulty level.
+///
+/// Batch generation only picks start and end words whose length (in bytes,
+/// like `PuzzleGeneratorBuilder::with_word_lengths`) is in the range for the
+/// requested difficulty. `None` allows any length. The ranges narrow the
+/// generator-wide word lengths rather than replace them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordLengthPolicy {
+    /// Word lengths of easy puzzles
+    pub easy: Option<RangeInclusive<usize>>,
+    /// Word lengths of medium puzzles
+    pub medium: Option<RangeInclusive<usize>>,
+    /// Word lengths of hard puzzles
+    pub hard: Option<RangeInclusive<usize>>,
+}
+
+impl WordLengthPolicy {
+    /// Returns the word lengths of a difficulty level, or `None` for any length.
+    pub fn for_difficulty(&self, difficulty: Difficulty) -> Option<&RangeInclusive<usize>> {
+        match difficulty {
+            Difficulty::Easy => self.easy.as_ref(),
+            Difficulty::Medium => self.medium.as_ref(),
+            Difficulty::Hard => self.hard.as_ref(),
+        }
+    }
+
+    /// Returns `true` if puzzles of a difficulty may use words of a length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{Difficulty, WordLengthPolicy};
+    ///
+    /// let policy = WordLengthPolicy {
+    ///     easy: Some(3..=4),
+    ///     hard: Some(5..=6),
+    ///     ..WordLengthPolicy::default()
+    /// };
+    /// assert!(policy.allows(Difficulty::Easy, 4));
+    /// assert!(!policy.allows(Difficulty::Hard, 4));
+    /// assert!(policy.allows(Difficulty::Medium, 8));
+    /// ```
+    pub fn allows(&self, difficulty: Difficulty, length: usize) -> bool {
+        self.for_difficulty(difficulty)
+            .is_none_or(|lengths| lengths.contains(&length))
+    }
+}
+
+/// Par and star thresholds of a puzzle, for Wordle-style ratings.
+///
+/// A player who solves the puzzle in at most `three_stars` steps earns three
+/// stars, in at most `two_stars` steps two stars, and otherwise one star.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StarRating {
+    /// Steps of the optimal solution
+    pub par: usize,
+    /// Most steps that still earn two stars
+    pub two_stars: usize,
+    /// Most steps that still earn three stars
+    pub three_stars: usize,
+}
+
+impl StarRating {
+    /// Returns the stars (1 to 3) earned by a solution with the given number of steps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::StarRating;
+    ///
+    /// let rating = StarRating { par: 4, two_stars: 6, three_stars: 4 };
+    /// assert_eq!(rating.stars_for(4), 3);
+    /// assert_eq!(rating.stars_for(6), 2);
+    /// assert_eq!(rating.stars_for(9), 1);
+    /// ```
+    pub fn stars_for(&self, steps: usize) -> u8 {
+        if steps <= self.three_stars {
+            3
+        } else if steps <= self.two_stars {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// Formula deriving a puzzle's star thresholds from its par.
+///
+/// The three-star threshold starts at par plus `three_star_slack`, and the
+/// two-star threshold at par times `two_star_factor`, rounded up. A puzzle
+/// whose par can only be reached one way is harder to solve perfectly, so
+/// both thresholds grow by `unique_solution_bonus`; one whose intermediate
+/// words all have at least `busy_branching` neighbors offers a wrong turn at
+/// every step, so the two-star threshold grows by one more. The two-star
+/// threshold always stays above the three-star one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StarFormula {
+    /// Steps over par that still earn three stars
+    pub three_star_slack: usize,
+    /// Two-star threshold as a multiple of par
+    pub two_star_factor: f64,
+    /// Extra steps at both thresholds when the puzzle has one shortest solution
+    pub unique_solution_bonus: usize,
+    /// Neighbor count every intermediate word needs for an extra two-star
+    /// step (see `PuzzleGenerator::branching`), or 0 to never add it
+    pub busy_branching: usize,
+}
+
+impl Default for StarFormula {
+    fn default() -> Self {
+        Self {
+            three_star_slack: 0,
+            two_star_factor: 1.5,
+            unique_solution_bonus: 1,
+            busy_branching: 6,
+        }
+    }
+}
+
+impl StarFormula {
+    /// Computes the star thresholds of a puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `par` - Steps of the optimal solution
+    /// * `shortest_paths` - Number of distinct solutions with `par` steps
+    /// * `branching` - Smallest neighbor count among the intermediate words,
+    ///   or `None` if the puzzle has none
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{StarFormula, StarRating};
+    ///
+    /// let formula = StarFormula::default();
+    /// assert_eq!(
+    ///     formula.rate(4, 3, Some(2)),
+    ///     StarRating { par: 4, two_stars: 6, three_stars: 4 }
+    /// );
+    /// // A single optimal solution earns one step of leeway
+    /// assert_eq!(
+    ///     formula.rate(4, 1, Some(2)),
+    ///     StarRating { par: 4, two_stars: 7, three_stars: 5 }
+    /// );
+    /// ```
+    pub fn rate(&self, par: usize, shortest_paths: u64, branching: Option<usize>) -> StarRating {
+        let bonus = if shortest_paths == 1 {
+            self.unique_solution_bonus
+        } else {
+            0
+        };
+        let busy = self.busy_branching > 0 && branching.is_some_and(|b| b >= self.busy_branching);
+        let three_stars = par + self.three_star_slack + bonus;
+        let two_stars = (par as f64 * self.two_star_factor).ceil() as usize + bonus + busy as usize;
+        StarRating {
+            par,
+            two_stars: two_stars.max(three_stars + 1),
+            three_stars,
+        }
+    }
+}
+
+/// Restricts which words may be picked as a random start or end word.
+///
+/// Filters are applied by narrowing the base words before any pair is tried,
+/// so they cost nothing per attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EndpointFilter {
+    /// The word starts with the given letters
+    StartsWith(String),
+    /// The word contains the given letters
+    Contains(String),
+    /// The word has the pattern's length and its letters, with `_` matching
+    /// any letter, e.g. `s_o_`
+    Pattern(String),
+}
+
+impl EndpointFilter {
+    /// Parses a filter from its command-line form.
+    ///
+    /// `s*` means starts with `s`, `*now*` means contains `now`, and anything
+    /// else is a pattern such as `s_o_`. Letters are lowercased.
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - Filter text
+    ///
+    /// # Returns
+    ///
+    /// The filter, or an error if the text is empty or uses `*` elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::EndpointFilter;
+    ///
+    /// assert_eq!(EndpointFilter::parse("S*").unwrap(), EndpointFilter::StartsWith("s".into()));
+    /// assert_eq!(EndpointFilter::parse("*now*").unwrap(), EndpointFilter::Contains("now".into()));
+    /// assert_eq!(EndpointFilter::parse("s_o_").unwrap(), EndpointFilter::Pattern("s_o_".into()));
+    /// assert!(EndpointFilter::parse("s*w").is_err());
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim().to_lowercase();
+        let filter = if let Some(inner) = spec
+            .strip_prefix('*')
+            .and_then(|rest| rest.strip_suffix('*'))
+        {
+            EndpointFilter::Contains(inner.to_string())
+        } else if let Some(prefix) = spec.strip_suffix('*') {
+            EndpointFilter::StartsWith(prefix.to_string())
+        } else {
+            EndpointFilter::Pattern(spec.clone())
+        };
+        match &filter {
+            EndpointFilter::StartsWith(letters)
+            | EndpointFilter::Contains(letters)
+            | EndpointFilter::Pattern(letters)
+                if letters.is_empty() || letters.contains('*') =>
+            {
+                Err(anyhow!(
+                    "Invalid endpoint filter '{}': use s*, *now*, or a pattern like s_o_",
+                    spec
+                ))
+            }
+            _ => Ok(filter),
+        }
+    }
+
+    /// Returns `true` if a word passes the filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to check
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::EndpointFilter;
+    ///
+    /// let filter = EndpointFilter::Pattern("s_o_".to_string());
+    /// assert!(filter.matches("snow"));
+    /// assert!(!filter.matches("snows"));
+    /// assert!(!filter.matches("slam"));
+    /// ```
+    pub fn matches(&self, word: &str) -> bool {
+        match self {
+            EndpointFilter::StartsWith(prefix) => word.starts_with(prefix.as_str()),
+            EndpointFilter::Contains(letters) => word.contains(letters.as_str()),
+            EndpointFilter::Pattern(pattern) => {
+                word.chars().count() == pattern.chars().count()
+                    && word
+                        .chars()
+                        .zip(pattern.chars())
+                        .all(|(letter, expected)| expected == '_' || letter == expected)
+            }
+        }
+    }
+}
+
+/// A caller-supplied check that generated puzzles must pass.
+type QualityFilter = Arc<dyn Fn(&Puzzle) -> bool + Send + Sync>;
+
+/// Start and end word candidates per word length.
+type PairPool = Vec<(Vec<String>, Vec<String>)>;
+
+/// A snapshot of batch generation progress passed to progress callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of puzzles accepted so far
+    pub generated: usize,
+    /// Number of puzzles requested
+    pub target: usize,
+    /// Number of word pairs tried so far, including rejected ones
+    pub attempts: usize,
+}
+
+/// Why `PuzzleGenerator::generate_puzzle` could not make a puzzle from two words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// No ladder connects the words (different lengths, a word missing from
+    /// the dictionary, or no path)
+    Ladder(LadderError),
+    /// The words are too similar for a puzzle (see `TrivialityRules`)
+    Trivial {
+        /// The start word
+        start: String,
+        /// The end word
+        end: String,
+    },
+    /// The shortest ladder has a step count no difficulty level accepts
+    /// (see `DifficultyThresholds`)
+    StepsOutOfRange {
+        /// Steps of the shortest ladder
+        steps: usize,
+    },
+}
+
+impl From<LadderError> for PuzzleError {
+    fn from(error: LadderError) -> Self {
+        PuzzleError::Ladder(error)
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleError::Ladder(error) => error.fmt(f),
+            PuzzleError::Trivial { start, end } => {
+                write!(f, "{} -> {} is too trivial for a puzzle", start, end)
+            }
+            PuzzleError::StepsOutOfRange { steps } => write!(
+                f,
+                "The shortest ladder has {} steps, outside every difficulty level",
+                steps
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+/// Why a word pair tried during batch generation did not yield a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    /// The pair selector found no word pair, e.g. no base word was far
+    /// enough from the start word for a hard puzzle
+    NoDistantEndpoint,
+    /// The two words are not connected by any ladder short enough for the
+    /// difficulty
+    NoPath,
+    /// The shortest ladder has the wrong number of steps for the difficulty
+    WrongDifficulty,
+    /// An intermediate word has fewer neighbors than the minimum branching
+    LowBranching,
+    /// The ladder passes through a banned word
+    BannedWord,
+    /// The ladder is not the only shortest solution
+    NotUnique,
+    /// No ladder of the same length leads back through different words
+    NotReversible,
+    /// A caller-supplied quality filter rejected the puzzle
+    QualityFilter,
+    /// The puzzle is too short or its endpoints too similar (see `TrivialityRules`)
+    Trivial,
+}
+
+impl RejectReason {
+    /// Returns the reason's snake_case name, matching its serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectReason::NoDistantEndpoint => "no_distant_endpoint",
+            RejectReason::NoPath => "no_path",
+            RejectReason::WrongDifficulty => "wrong_difficulty",
+            RejectReason::LowBranching => "low_branching",
+            RejectReason::BannedWord => "banned_word",
+            RejectReason::NotUnique => "not_unique",
+            RejectReason::NotReversible => "not_reversible",
+            RejectReason::QualityFilter => "quality_filter",
+            RejectReason::Trivial => "trivial",
+        }
+    }
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The outcome of a batch generation run.
+///
+/// Besides the puzzles, it records how many word pairs were tried and why the
+/// rejected ones failed, which shows how efficiently a dictionary satisfies a
+/// difficulty and why a batch came up short.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BatchResult {
+    /// Puzzles accepted, in generation order
+    pub puzzles: Vec<Puzzle>,
+    /// Puzzles accepted but handed to a callback instead of kept in `puzzles`
+    /// (see `PuzzleGenerator::generate_batch_streaming`)
+    pub streamed: usize,
+    /// Number of word pairs tried, including rejected ones
+    pub attempts: usize,
+    /// Number of rejected word pairs per reason
+    pub rejected_by_reason: HashMap<RejectReason, usize>,
+    /// Wall-clock time spent generating
+    pub elapsed: Duration,
+}
+
+impl BatchResult {
+    /// Returns the number of puzzles accepted, whether kept or streamed.
+    pub fn generated(&self) -> usize {
+        self.puzzles.len() + self.streamed
+    }
+
+    /// Returns the number of word pairs that were rejected.
+    pub fn rejected(&self) -> usize {
+        self.rejected_by_reason.values().sum()
+    }
+
+    /// Returns the fraction of attempts that produced a puzzle (0.0 with no attempts).
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.generated() as f64 / self.attempts as f64
+        }
+    }
+
+    /// Adds another result's puzzles, attempts, rejections, and time to this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Result of a later generation run
+    pub fn merge(&mut self, other: BatchResult) {
+        self.puzzles.extend(other.puzzles);
+        self.streamed += other.streamed;
+        self.attempts += other.attempts;
+        for (reason, count) in other.rejected_by_reason {
+            *self.rejected_by_reason.entry(reason).or_insert(0) += count;
+        }
+        self.elapsed += other.elapsed;
+    }
+}
+
+impl fmt::Display for BatchResult {
+    /// Formats a one-line summary, e.g.
+    /// `10 puzzles from 14 attempts in 0.02s (rejected: wrong_difficulty 4)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} puzzles from {} attempts in {:.2}s",
+            self.generated(),
+            self.attempts,
+            self.elapsed.as_secs_f64()
+        )?;
+        if !self.rejected_by_reason.is_empty() {
+            let mut reasons: Vec<_> = self.rejected_by_reason.iter().collect();
+            reasons.sort();
+            let reasons: Vec<String> = reasons
+                .into_iter()
+                .map(|(reason, count)| format!("{} {}", reason, count))
+                .collect();
+            write!(f, " (rejected: {})", reasons.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Endless iterator of random puzzles, created by `PuzzleGenerator::puzzles`.
+pub struct Puzzles<'a> {
+    /// Generator whose settings and graph are used
+    generator: &'a PuzzleGenerator,
+    /// Difficulty of every puzzle
+    difficulty: Difficulty,
+    /// Start and end candidates per word length
+    candidates: PairPool,
+    /// Random number generator, seeded once for the whole iterator
+    rng: StdRng,
+    /// Number of word pairs tried so far
+    attempts: usize,
+    /// Number of yielded puzzles per word that started or ended them
+    endpoint_uses: HashMap<String, usize>,
+}
+
+impl Puzzles<'_> {
+    /// Returns the number of word pairs tried so far, including rejected ones.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+}
+
+impl Iterator for Puzzles<'_> {
+    type Item = Puzzle;
+
+    fn next(&mut self) -> Option<Puzzle> {
+        if self.candidates.is_empty() {
+            return None;
+        }
+        while self
+            .generator
+            .max_attempts
+            .is_none_or(|max| self.attempts < max)
+        {
+            self.attempts += 1;
+            match self.generator.try_random_pair(
+                &self.candidates,
+                self.difficulty,
+                &mut self.rng,
+                &mut self.endpoint_uses,
+            ) {
+                Ok(puzzle) => return Some(puzzle),
+                Err(RejectReason::NoDistantEndpoint)
+                    if self.generator.is_selection_exhausted(
+                        &self.candidates,
+                        self.difficulty,
+                        &self.endpoint_uses,
+                    ) =>
+                {
+                    return None;
+                }
+                Err(_) => {}
+            }
+        }
+        None
+    }
+}
+
+/// A puzzle that failed verification in `PuzzleGenerator::verify_many`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VerifyFailure {
+    /// 1-based line number of the puzzle in the input
+    pub line: usize,
+    /// The puzzle as written on that line
+    pub puzzle: String,
+    /// Why the puzzle is invalid
+    pub reason: String,
+}
+
+/// The result of checking a solution against the shortest possible ladder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Optimality {
+    /// Whether every step of the solution is valid
+    pub valid: bool,
+    /// Number of steps the solution uses
+    pub steps: usize,
+    /// Number of steps in the shortest ladder between the endpoints, or
+    /// `None` if the endpoints are not connected in the dictionary
+    pub shortest_steps: Option<usize>,
+}
+
+impl Optimality {
+    /// Returns how many steps the solution uses beyond the shortest ladder.
+    ///
+    /// Returns `None` if the solution is invalid or no shortest ladder exists.
+    pub fn extra_steps(&self) -> Option<usize> {
+        self.shortest_steps
+            .filter(|_| self.valid)
+            .map(|shortest| self.steps.saturating_sub(shortest))
+    }
+
+    /// Returns `true` if the solution is valid and no longer than the shortest ladder.
+    pub fn is_optimal(&self) -> bool {
+        self.extra_steps() == Some(0)
+    }
+}
+
+/// The outcome of verifying many puzzles with `PuzzleGenerator::verify_many`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct VerifySummary {
+    /// Number of puzzles checked
+    pub total: usize,
+    /// Number of valid puzzles
+    pub valid: usize,
+    /// Invalid puzzles, in input order
+    pub failures: Vec<VerifyFailure>,
+}
+
+impl VerifySummary {
+    /// Returns `true` if every puzzle checked was valid.
+    pub fn all_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A problem that prevents a base word from producing good puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BaseWordIssue {
+    /// The word is not in the dictionary, so it is never used
+    NotInDictionary,
+    /// The word has no neighbors, so no ladder can start or end at it
+    Isolated,
+    /// The word belongs to a component with only `size` words
+    SmallComponent { size: usize },
+    /// The word has fewer neighbors than the configured minimum
+    LowDegree { degree: usize },
+}
+
+/// A base word paired with the most severe issue found for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BaseWordReport {
+    /// The offending base word
+    pub word: String,
+    /// What is wrong with it
+    pub issue: BaseWordIssue,
+}
+
+/// Whether a word pair can be joined by a ladder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PairStatus {
+    /// A ladder exists; the shortest one takes `steps` steps
+    Solvable { steps: usize },
+    /// Both words are in the dictionary but in different components
+    Disconnected,
+    /// `word` is not in the dictionary
+    NotInDictionary { word: String },
+}
+
+/// A word pair checked by `PuzzleGenerator::solvable_pairs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PairReport {
+    /// Start word, trimmed and lowercased
+    pub start: String,
+    /// End word, trimmed and lowercased
+    pub end: String,
+    /// Whether and how far apart the words are connected
+    pub status: PairStatus,
+}
+
+impl PairReport {
+    /// Returns `true` if a ladder joins the pair.
+    pub fn is_solvable(&self) -> bool {
+        matches!(self.status, PairStatus::Solvable { .. })
+    }
+}
+
+/// Thresholds used by `PuzzleGenerator::validate_base_words_with`.
+#[derive(Debug, Clone)]
+pub struct ValidationThresholds {
+    /// Components smaller than this are reported as `SmallComponent`
+    pub min_component_size: usize,
+    /// Words with fewer neighbors than this are reported as `LowDegree`
+    pub min_degree: usize,
+}
+
+impl Default for ValidationThresholds {
+    fn default() -> Self {
+        Self {
+            min_component_size: 10,
+            min_degree: 2,
+        }
+    }
+}
+
+/// How many distinct puzzles of one difficulty a generator can produce,
+/// as computed by `PuzzleGenerator::estimate_capacity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CapacityEstimate {
+    /// Difficulty the estimate is for
+    pub difficulty: Difficulty,
+    /// Number of distinct start and end word pairs within the difficulty's
+    /// step range, extrapolated from the sampled start words
+    pub pairs: usize,
+    /// Number of start words searched from
+    pub sampled_starts: usize,
+    /// Number of start words available to the difficulty
+    pub total_starts: usize,
+}
+
+impl CapacityEstimate {
+    /// Returns `true` if every start word was searched, so `pairs` is an exact count.
+    pub fn is_exact(&self) -> bool {
+        self.sampled_starts == self.total_starts
+    }
+}
+
+/// Generator for creating word ladder puzzles with various difficulty levels.
+///
+/// The `PuzzleGenerator` uses a loaded `WordGraph` to create puzzles by:
+/// 1. Selecting random start and end words from the base words
+/// 2. Finding the shortest path between them
+/// 3. Filtering by desired difficulty level
+/// 4. Ensuring puzzles meet quality criteria
+///
+/// The graph is held in an `Arc`, so cloning a generator is cheap and clones
+/// share one graph. All query methods take `&self` and the generator is
+/// `Send + Sync`, so a server can solve and generate from many threads at once.
+#[derive(Clone)]
+pub struct PuzzleGenerator {
+    /// The word graph containing dictionary and base words, shared between clones
+    graph: Arc<WordGraph>,
+    /// Minimum neighbors per intermediate word, per difficulty
+    min_branching: MinBranching,
+    /// Whether batch generation only accepts puzzles with one shortest path
+    unique_solution: bool,
+    /// Whether batch generation only accepts puzzles with a reverse path
+    require_reversible: bool,
+    /// Formula for the star thresholds attached to each puzzle, or `None` to
+    /// attach none
+    star_formula: Option<StarFormula>,
+    /// Tag of the dictionary source that random endpoints must come from
+    endpoint_source: Option<String>,
+    /// Filters every random start word must pass
+    start_filters: Vec<EndpointFilter>,
+    /// Filters every random end word must pass
+    end_filters: Vec<EndpointFilter>,
+    /// Dictionary hash recorded in each puzzle's metadata, or `None` to attach
+    /// no metadata
+    meta_dictionary_sha256: Option<String>,
+    /// Strategy choosing the word pairs batch generation tries
+    pair_selector: Arc<dyn PairSelector>,
+    /// Step counts separating the difficulty levels
+    thresholds: DifficultyThresholds,
+    /// Rules rejecting near-trivial puzzles
+    triviality: TrivialityRules,
+    /// Most word pairs a batch tries before giving up, or `None` for no limit
+    max_attempts: Option<usize>,
+    /// Word lengths random endpoints are restricted to, or `None` for any length
+    word_lengths: Option<RangeInclusive<usize>>,
+    /// Word lengths random endpoints are restricted to, per difficulty
+    length_policy: WordLengthPolicy,
+    /// Words that may not appear anywhere in a generated puzzle
+    banned_words: HashSet<String>,
+    /// Seed for random word selection, or `None` to seed from system entropy
+    seed: Option<u64>,
+    /// Extra checks batch-generated puzzles must pass
+    quality_filters: Vec<QualityFilter>,
+}
+
+impl PuzzleGenerator {
+    /// Creates a new puzzle generator with the given word graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - A word graph with loaded dictionary and base words, either
+    ///   owned or already shared as an `Arc<WordGraph>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use wordladder_core::{graph::WordGraph, puzzle::PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// // ... load dictionary and base words ...
+    /// # graph.load_dictionary("data/dictionary.txt").ok();
+    /// # graph.load_base_words("data/base_words.txt").ok();
+    ///
+    /// let graph = Arc::new(graph);
+    /// let generator = PuzzleGenerator::new(Arc::clone(&graph));
+    /// assert!(Arc::ptr_eq(&graph, generator.shared_graph()));
+    /// ```
+    pub fn new(graph: impl Into<Arc<WordGraph>>) -> Self {
+        Self {
+            graph: graph.into(),
+            min_branching: MinBranching::default(),
+            unique_solution: false,
+            require_reversible: false,
+            star_formula: None,
+            endpoint_source: None,
+            start_filters: Vec::new(),
+            end_filters: Vec::new(),
+            pair_selector: Arc::new(DistanceTargeted::new(RandomUniform)),
+            meta_dictionary_sha256: None,
+            thresholds: DifficultyThresholds::default(),
+            triviality: TrivialityRules::default(),
+            max_attempts: None,
+            word_lengths: None,
+            length_policy: WordLengthPolicy::default(),
+            banned_words: HashSet::new(),
+            seed: None,
+            quality_filters: Vec::new(),
+        }
+    }
+
+    /// Starts a `PuzzleGeneratorBuilder` for tuning the generation policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - A word graph with loaded dictionary and base words
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncold\ncord\nword\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncold\nword\n");
+    ///
+    /// let generator = PuzzleGenerator::builder(graph)
+    ///     .with_word_lengths(3..=3)
+    ///     .with_max_attempts(100)
+    ///     .with_seed(42)
+    ///     .build();
+    ///
+    /// let puzzles = generator.generate_batch(1, Difficulty::Easy).puzzles;
+    /// assert_eq!(puzzles[0].start.len(), 3);
+    /// ```
+    pub fn builder(graph: impl Into<Arc<WordGraph>>) -> PuzzleGeneratorBuilder {
+        PuzzleGeneratorBuilder {
+            generator: Self::new(graph),
+        }
+    }
+
+    /// Returns the seed used for random word selection, if one was set.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns the step counts separating the difficulty levels.
+    pub fn difficulty_thresholds(&self) -> &DifficultyThresholds {
+        &self.thresholds
+    }
+
+    /// Returns the rules rejecting near-trivial puzzles.
+    pub fn triviality_rules(&self) -> &TrivialityRules {
+        &self.triviality
+    }
+
+    /// Sets the minimum branching required of batch-generated puzzles.
+    ///
+    /// Puzzles whose intermediate words have fewer neighbors than the threshold
+    /// for their difficulty are rejected by `generate_batch`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_branching` - Per-difficulty minimum neighbor counts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{MinBranching, PuzzleGenerator};
+    ///
+    /// let generator = PuzzleGenerator::new(WordGraph::new())
+    ///     .with_min_branching(MinBranching { easy: 0, medium: 3, hard: 3 });
+    /// ```
+    pub fn with_min_branching(mut self, min_branching: MinBranching) -> Self {
+        self.min_branching = min_branching;
+        self
+    }
+
+    /// Seeds random word selection, replacing any previous seed.
+    ///
+    /// Useful for giving a clone of a seeded generator its own seed, so that
+    /// successive batches do not repeat the same word pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed for the random number generator
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Restricts randomly chosen endpoints to word lengths per difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Allowed word lengths of each difficulty level
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{PuzzleGenerator, WordLengthPolicy};
+    ///
+    /// let generator = PuzzleGenerator::new(WordGraph::new()).with_word_length_policy(
+    ///     WordLengthPolicy {
+    ///         easy: Some(3..=4),
+    ///         medium: None,
+    ///         hard: Some(5..=6),
+    ///     },
+    /// );
+    /// ```
+    pub fn with_word_length_policy(mut self, policy: WordLengthPolicy) -> Self {
+        self.length_policy = policy;
+        self
+    }
+
+    /// Bans more words from appearing anywhere in batch-generated puzzles.
+    ///
+    /// Useful for regenerating a set without the over-used intermediate words
+    /// reported by `usage::WordUsage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - Words to ban, added to any banned earlier
+    pub fn with_banned_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.banned_words.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets whether batch generation requires a unique shortest solution.
+    ///
+    /// When enabled, `generate_batch` only accepts puzzles whose path is the
+    /// only shortest ladder between its endpoints, and marks them with
+    /// `unique_solution: Some(true)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `unique_solution` - Whether to require a unique shortest path
+    pub fn with_unique_solution(mut self, unique_solution: bool) -> Self {
+        self.unique_solution = unique_solution;
+        self
+    }
+
+    /// Sets whether batch generation requires a reverse path ("mirror puzzles").
+    ///
+    /// When enabled, `generate_batch` only accepts puzzles that can also be
+    /// solved from the end word back to the start word in the same number of
+    /// steps through different intermediate words, and stores that ladder in
+    /// `reverse_path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `require_reversible` - Whether to require a reverse path
+    pub fn with_require_reversible(mut self, require_reversible: bool) -> Self {
+        self.require_reversible = require_reversible;
+        self
+    }
+
+    /// Requires randomly chosen endpoints to come from a tagged dictionary source.
+    ///
+    /// Base words missing from the source (see `WordGraph::load_dictionary_tagged`)
+    /// are never picked as start or end words. Intermediate words may still come
+    /// from any source, and `generate_puzzle` with explicit words is unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Tag of the source endpoints must belong to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_tagged_from_str("cat\ncot\ncog\ndog\ncwm\n", "tournament");
+    /// graph.load_dictionary_tagged_from_str("cat\ndog\n", "common");
+    /// graph.load_base_words_from_str("cat\ndog\ncwm\n");
+    ///
+    /// let generator = PuzzleGenerator::new(graph).with_endpoint_source("common");
+    /// let (start, end) = generator.pick_random_words().unwrap();
+    /// assert!(start != "cwm" && end != "cwm");
+    /// ```
+    pub fn with_endpoint_source(mut self, tag: impl Into<String>) -> Self {
+        self.endpoint_source = Some(tag.into());
+        self
+    }
+
+    /// Requires randomly chosen start words to pass a filter.
+    ///
+    /// Can be called repeatedly; a start word must then pass every filter.
+    /// As with `with_endpoint_source`, intermediate words and explicit
+    /// `generate_puzzle` words are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter start words must pass
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{EndpointFilter, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let generator = PuzzleGenerator::new(graph)
+    ///     .with_start_filter(EndpointFilter::StartsWith("d".to_string()))
+    ///     .with_end_filter(EndpointFilter::Pattern("c_t".to_string()));
+    /// let (start, end) = generator.pick_random_words().unwrap();
+    /// assert_eq!(start, "dog");
+    /// assert!(end == "cat" || end == "cot");
+    /// ```
+    pub fn with_start_filter(mut self, filter: EndpointFilter) -> Self {
+        self.start_filters.push(filter);
+        self
+    }
+
+    /// Requires randomly chosen end words to pass a filter.
+    ///
+    /// See `with_start_filter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter end words must pass
+    pub fn with_end_filter(mut self, filter: EndpointFilter) -> Self {
+        self.end_filters.push(filter);
+        self
+    }
+
+    /// Biases random endpoint selection towards common words.
+    ///
+    /// Each base word is picked with a weight of `1 / rank`, so the most common
+    /// word is twice as likely as the second and a hundred times as likely as
+    /// the hundredth. Base words missing from the ranks get the weight of one
+    /// rank past the last ranked word. Which word length is used is still
+    /// chosen uniformly, and hard puzzles still target distant end words.
+    ///
+    /// This is shorthand for a `DistanceTargeted` pair selector wrapping
+    /// `FrequencyWeighted`, and replaces any selector set earlier.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranks` - Frequency rank per word, 1 being the most common (see
+    ///   `WordMetadata::parse_frequency_list`)
+    ///
+    /// # Returns
+    ///
+    /// The generator, or an error if a rank is 0, which would give its word
+    /// an infinite weight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\n");
+    ///
+    /// let ranks = HashMap::from([("cat".to_string(), 1), ("dog".to_string(), 2)]);
+    /// let generator = PuzzleGenerator::new(graph)
+    ///     .with_endpoint_frequencies(ranks)
+    ///     .unwrap();
+    /// let (start, end) = generator.pick_random_words().unwrap();
+    /// assert_ne!(start, end);
+    /// ```
+    pub fn with_endpoint_frequencies(mut self, ranks: HashMap<String, usize>) -> Result<Self> {
+        self.pair_selector = Arc::new(DistanceTargeted::new(FrequencyWeighted::new(ranks)?));
+        Ok(self)
+    }
+
+    /// Sets the strategy choosing the word pairs batch generation tries.
+    ///
+    /// The default is `DistanceTargeted::new(RandomUniform)`: uniformly chosen
+    /// pairs, with hard puzzles drawing their end word among distant words.
+    /// The selector also drives `pick_random_words`.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Any `PairSelector`, built in (see `puzzle::selection`) or custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    /// use wordladder_core::puzzle::selection::{DistanceTargeted, RandomUniform};
+    ///
+    /// let generator = PuzzleGenerator::new(WordGraph::new())
+    ///     .with_pair_selector(DistanceTargeted::new(RandomUniform).with_min_steps(4));
+    /// ```
+    pub fn with_pair_selector(mut self, selector: impl PairSelector + 'static) -> Self {
+        self.pair_selector = Arc::new(selector);
+        self
+    }
+
+    /// Limits how many word pairs a batch tries before returning what it has.
+    ///
+    /// See `PuzzleGeneratorBuilder::with_max_attempts`; this sets the same
+    /// limit on an already built generator, e.g. one loaded by a reload.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Most word pairs tried per batch
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Lets each word start or end at most `max_uses` puzzles of a batch.
+    ///
+    /// Wraps the current pair selector in an `EndpointQuota`, so call it after
+    /// `with_endpoint_frequencies` or `with_pair_selector`. Batches that run
+    /// out of words under the quota end early with fewer puzzles.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_uses` - Most puzzles of a batch any one word may start or end
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ncog\ndog\n");
+    ///
+    /// // Every easy puzzle here starts or ends at "cat", so the first one
+    /// // leaves a single word under the quota
+    /// let generator = PuzzleGenerator::new(graph).with_max_endpoint_reuse(1);
+    /// let batch = generator.generate_batch(5, Difficulty::Easy);
+    /// assert_eq!(batch.puzzles.len(), 1);
+    /// ```
+    pub fn with_max_endpoint_reuse(mut self, max_uses: usize) -> Self {
+        self.pair_selector = Arc::new(EndpointQuota::new(
+            Arc::clone(&self.pair_selector),
+            max_uses,
+        ));
+        self
+    }
+
+    /// Attaches a `PuzzleMeta` to every generated puzzle.
+    ///
+    /// Puzzles from `generate_puzzle` and batch generation then record their
+    /// creation time, the engine version, the dictionary hash, and the seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary_sha256` - Hex SHA-256 of the dictionary the graph was loaded from
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// // Normally the hex SHA-256 of the dictionary file
+    /// let dictionary_sha256 = "ab12";
+    /// let generator = PuzzleGenerator::new(graph).with_puzzle_meta(dictionary_sha256);
+    /// let puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+    /// let meta = puzzle.meta.unwrap();
+    /// assert_eq!(meta.dictionary_sha256, dictionary_sha256);
+    /// ```
+    pub fn with_puzzle_meta(mut self, dictionary_sha256: impl Into<String>) -> Self {
+        self.meta_dictionary_sha256 = Some(dictionary_sha256.into());
+        self
+    }
+
+    /// Sets a puzzle's metadata if metadata is enabled.
+    fn attach_meta(&self, puzzle: &mut Puzzle) {
+        if let Some(hash) = &self.meta_dictionary_sha256 {
+            puzzle.meta = Some(PuzzleMeta::new(hash.clone(), self.seed));
+        }
+    }
+
+    /// Attaches a `StarRating` to every generated puzzle.
+    ///
+    /// Puzzles from `generate_puzzle` and batch generation then carry their
+    /// par and the most steps that still earn two and three stars.
+    ///
+    /// # Arguments
+    ///
+    /// * `formula` - How the star thresholds are derived from par
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{PuzzleGenerator, StarFormula};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let generator = PuzzleGenerator::new(graph).with_star_ratings(StarFormula::default());
+    /// let stars = generator.generate_puzzle("cat", "dog").unwrap().stars.unwrap();
+    /// assert_eq!(stars.par, 3);
+    /// assert_eq!(stars.stars_for(3), 3);
+    /// ```
+    pub fn with_star_ratings(mut self, formula: StarFormula) -> Self {
+        self.star_formula = Some(formula);
+        self
+    }
+
+    /// Sets a puzzle's star rating if star ratings are enabled.
+    fn attach_stars(&self, puzzle: &mut Puzzle) {
+        if let Some(formula) = &self.star_formula {
+            self.annotate_stars(puzzle, formula);
+        }
+    }
+
+    /// Records a puzzle's par and star thresholds.
+    ///
+    /// Par is the step count of the puzzle's path, which is a shortest path
+    /// for generated puzzles. The thresholds depend on how many shortest
+    /// paths there are and on the puzzle's branching (see `StarFormula`).
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle whose `stars` are set
+    /// * `formula` - How the star thresholds are derived from par
+    pub fn annotate_stars(&self, puzzle: &mut Puzzle, formula: &StarFormula) {
+        let shortest_paths = match puzzle.unique_solution {
+            Some(true) => 1,
+            _ => self.graph.count_shortest_paths(&puzzle.start, &puzzle.end),
+        };
+        puzzle.stars = Some(formula.rate(
+            puzzle.path.len().saturating_sub(1),
+            shortest_paths,
+            self.branching(puzzle),
+        ));
+    }
+
+    /// Records whether a puzzle's solution is its only shortest path.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle whose `unique_solution` flag is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let mut puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+    /// generator.annotate_uniqueness(&mut puzzle);
+    /// assert_eq!(puzzle.unique_solution, Some(true));
+    /// ```
+    pub fn annotate_uniqueness(&self, puzzle: &mut Puzzle) {
+        puzzle.unique_solution = Some(
+            self.graph
+                .has_unique_shortest_path(&puzzle.start, &puzzle.end),
+        );
+    }
+
+    /// Records a puzzle's reverse path, if it has one.
+    ///
+    /// The reverse path is a ladder from the end word back to the start word
+    /// with the same number of steps and none of the puzzle's intermediate
+    /// words (see `WordGraph::find_mirror_path`). If the puzzle's own path
+    /// blocks every way back, the path is replaced by another ladder of the
+    /// same length that leaves one open (see `WordGraph::find_mirror_ladders`).
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle whose `reverse_path` is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncag\ndag\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// // The shortest path cat -> cag -> cog -> dog blocks every way back
+    /// let mut puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+    /// assert_eq!(puzzle.path, vec!["cat", "cag", "cog", "dog"]);
+    ///
+    /// generator.annotate_reverse_path(&mut puzzle);
+    /// assert_eq!(puzzle.path, vec!["cat", "cag", "dag", "dog"]);
+    /// assert_eq!(puzzle.reverse_path.unwrap(), vec!["dog", "cog", "cot", "cat"]);
+    /// ```
+    pub fn annotate_reverse_path(&self, puzzle: &mut Puzzle) {
+        puzzle.reverse_path = match self.graph.find_mirror_path(&puzzle.path) {
+            Some(reverse) => Some(reverse),
+            None => self
+                .graph
+                .find_mirror_ladders(&puzzle.start, &puzzle.end)
+                .filter(|(path, _)| path.len() == puzzle.path.len())
+                .map(|(path, reverse)| {
+                    puzzle.path = path;
+                    reverse
+                }),
+        };
+    }
+
+    /// Returns the smallest neighbor count among a puzzle's intermediate words.
+    ///
+    /// A value of 2 means some step is forced: the word only connects to the
+    /// words before and after it in the ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to measure
+    ///
+    /// # Returns
+    ///
+    /// The minimum degree of the intermediate words, or `None` if the puzzle
+    /// has no intermediate words.
+    pub fn branching(&self, puzzle: &Puzzle) -> Option<usize> {
+        let intermediates = puzzle.path.get(1..puzzle.path.len().saturating_sub(1))?;
+        intermediates
+            .iter()
+            .map(|word| self.graph.degree(word).unwrap_or(0))
+            .min()
+    }
+
+    /// Returns a reference to the underlying word graph.
+    pub fn graph(&self) -> &WordGraph {
+        &self.graph
+    }
+
+    /// Returns the shared handle to the underlying word graph.
+    ///
+    /// Clone the `Arc` to hand the same graph to other generators or workers
+    /// without copying it.
+    pub fn shared_graph(&self) -> &Arc<WordGraph> {
+        &self.graph
+    }
+
+    /// Generates a single puzzle between the specified start and end words.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting word
+    /// * `end` - Ending word
+    ///
+    /// # Returns
+    ///
+    /// The puzzle, or a `PuzzleError` saying why the words cannot form one:
+    /// no ladder connects them, the puzzle is near-trivial (see
+    /// `TrivialityRules`), or its step count fits no difficulty level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::{LadderError, WordGraph};
+    /// use wordladder_core::puzzle::{PuzzleError, PuzzleGenerator};
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "cog", "dog"].map(String::from));
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+    /// assert_eq!(puzzle.path.len() - 1, 3);
+    ///
+    /// match generator.generate_puzzle("cat", "horse") {
+    ///     Err(PuzzleError::Ladder(LadderError::DifferentLengths { .. })) => {}
+    ///     other => panic!("unexpected {:?}", other),
+    /// }
+    /// ```
+    pub fn generate_puzzle(&self, start: &str, end: &str) -> Result<Puzzle, PuzzleError> {
+        self.graph.check_pair(start, end)?;
+        if self.triviality.is_trivial_pair(start, end) {
+            return Err(PuzzleError::Trivial {
+                start: start.to_string(),
+                end: end.to_string(),
+            });
+        }
+        let path = self.graph.solve(start, end)?;
+        let steps = path.len() - 1;
+        let mut puzzle =
+            Puzzle::with_thresholds(start.to_string(), end.to_string(), path, &self.thresholds)
+                .ok_or(PuzzleError::StepsOutOfRange { steps })?;
+        if self.triviality.is_trivial(&puzzle) {
+            return Err(PuzzleError::Trivial {
+                start: start.to_string(),
+                end: end.to_string(),
+            });
+        }
+        self.attach_stars(&mut puzzle);
+        self.attach_meta(&mut puzzle);
+        Ok(puzzle)
+    }
+
+    /// Generates a batch of puzzles with the specified difficulty level.
+    ///
+    /// This method creates multiple puzzles by randomly selecting word pairs
+    /// and filtering for the desired difficulty. It ensures that generated
+    /// puzzles are valid and meet the difficulty criteria. For hard puzzles the
+    /// end word is drawn from base words at least `hard_min` steps from the start
+    /// (see `WordGraph::farthest_words`) instead of sampling pairs blindly.
+    ///
+    /// With a seed set, every call draws the same sequence of word pairs, so
+    /// the same graph and settings reproduce the same batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of puzzles to generate
+    /// * `difficulty` - Desired difficulty level
+    ///
+    /// # Returns
+    ///
+    /// A `BatchResult` with the generated puzzles and generation statistics.
+    /// It may contain fewer puzzles than requested if sufficient valid puzzles
+    /// cannot be found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::{PuzzleGenerator, Difficulty};
+    ///
+    /// // Assuming generator is set up...
+    /// # let generator = PuzzleGenerator::new(wordladder_core::graph::WordGraph::new());
+    ///
+    /// let result = generator.generate_batch(10, Difficulty::Medium);
+    /// println!("Generated {} puzzles in {} attempts", result.puzzles.len(), result.attempts);
+    /// ```
+    pub fn generate_batch(&self, count: usize, difficulty: Difficulty) -> BatchResult {
+        self.generate_batch_with_progress(count, difficulty, &AtomicBool::new(false), |_| {})
+    }
+
+    /// Generates a batch of puzzles, reporting progress and honoring cancellation.
+    ///
+    /// This behaves like `generate_batch`, but invokes `on_progress` after every
+    /// attempted word pair and stops early once `cancel` is set, returning the
+    /// puzzles generated so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of puzzles to generate
+    /// * `difficulty` - Desired difficulty level
+    /// * `cancel` - Flag checked before each attempt; set it to stop generation
+    /// * `on_progress` - Callback receiving a `Progress` snapshot after each attempt
+    ///
+    /// # Returns
+    ///
+    /// A `BatchResult` whose puzzles are fewer than `count` if cancelled or if
+    /// the attempt limit was reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::atomic::AtomicBool;
+    /// use wordladder_core::puzzle::{PuzzleGenerator, Difficulty};
+    ///
+    /// # let generator = PuzzleGenerator::new(wordladder_core::graph::WordGraph::new());
+    /// let cancel = AtomicBool::new(false);
+    /// let result = generator.generate_batch_with_progress(10, Difficulty::Easy, &cancel, |p| {
+    ///     eprintln!("{}/{} after {} attempts", p.generated, p.target, p.attempts);
+    /// });
+    /// ```
+    pub fn generate_batch_with_progress(
+        &self,
+        count: usize,
+        difficulty: Difficulty,
+        cancel: &AtomicBool,
+        on_progress: impl FnMut(Progress),
+    ) -> BatchResult {
+        let mut puzzles = Vec::new();
+        let mut result = self
+            .generate_batch_streaming(count, difficulty, cancel, on_progress, |puzzle| {
+                puzzles.push(puzzle);
+                Ok(())
+            })
+            .expect("collecting puzzles cannot fail");
+        result.puzzles = puzzles;
+        result.streamed = 0;
+        result
+    }
+
+    /// Generates a batch of puzzles, handing each one to a callback as soon as
+    /// it is accepted.
+    ///
+    /// This behaves like `generate_batch_with_progress`, but the puzzles are
+    /// not collected: `on_puzzle` receives each one in generation order, so a
+    /// caller can write it out immediately and a huge batch never has to fit
+    /// in memory. The returned `BatchResult` has an empty `puzzles` list and
+    /// counts the puzzles in `streamed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of puzzles to generate
+    /// * `difficulty` - Desired difficulty level
+    /// * `cancel` - Flag checked before each attempt; set it to stop generation
+    /// * `on_progress` - Callback receiving a `Progress` snapshot after each attempt
+    /// * `on_puzzle` - Callback receiving each accepted puzzle
+    ///
+    /// # Returns
+    ///
+    /// The generation statistics, or the first error returned by `on_puzzle`,
+    /// which stops generation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::atomic::AtomicBool;
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ndog\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let mut lines = Vec::new();
+    /// let result = generator
+    ///     .generate_batch_streaming(2, Difficulty::Easy, &AtomicBool::new(false), |_| {}, |puzzle| {
+    ///         lines.push(puzzle.path.join(","));
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// assert!(result.puzzles.is_empty());
+    /// assert_eq!(result.streamed, lines.len());
+    /// ```
+    pub fn generate_batch_streaming(
+        &self,
+        count: usize,
+        difficulty: Difficulty,
+        cancel: &AtomicBool,
+        on_progress: impl FnMut(Progress),
+        on_puzzle: impl FnMut(Puzzle) -> Result<()>,
+    ) -> Result<BatchResult> {
+        self.continue_batch_streaming(
+            count,
+            difficulty,
+            &mut HashMap::new(),
+            cancel,
+            on_progress,
+            on_puzzle,
+        )
+    }
+
+    /// Continues a batch that is generated over several calls, e.g. in
+    /// checkpointed chunks, handing each puzzle to a callback.
+    ///
+    /// This behaves like `generate_batch_streaming`, but the endpoint usage
+    /// counted by `with_max_endpoint_reuse` carries over from earlier calls
+    /// through `endpoint_uses`, so the quota applies to the whole batch
+    /// rather than to each call.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of puzzles to generate in this call
+    /// * `difficulty` - Desired difficulty level
+    /// * `endpoint_uses` - Puzzles per start or end word generated so far,
+    ///   e.g. counted from resumed puzzles; updated with the new puzzles
+    /// * `cancel` - Flag checked before each attempt; set it to stop generation
+    /// * `on_progress` - Callback receiving a `Progress` snapshot after each attempt
+    /// * `on_puzzle` - Callback receiving each accepted puzzle
+    ///
+    /// # Returns
+    ///
+    /// The generation statistics of this call, or the first error returned by
+    /// `on_puzzle`, which stops generation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use std::sync::atomic::AtomicBool;
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ncog\ndog\n");
+    /// let generator = PuzzleGenerator::new(graph).with_max_endpoint_reuse(1);
+    ///
+    /// // A puzzle from an earlier chunk already used "cat" and "cog"
+    /// let mut endpoint_uses = HashMap::from([("cat".to_string(), 1), ("cog".to_string(), 1)]);
+    /// let cancel = AtomicBool::new(false);
+    /// let result = generator
+    ///     .continue_batch_streaming(1, Difficulty::Easy, &mut endpoint_uses, &cancel, |_| {}, |_| Ok(()))
+    ///     .unwrap();
+    /// assert_eq!(result.streamed, 0);
+    /// ```
+    pub fn continue_batch_streaming(
+        &self,
+        count: usize,
+        difficulty: Difficulty,
+        endpoint_uses: &mut HashMap<String, usize>,
+        cancel: &AtomicBool,
+        mut on_progress: impl FnMut(Progress),
+        mut on_puzzle: impl FnMut(Puzzle) -> Result<()>,
+    ) -> Result<BatchResult> {
+        let started = Instant::now();
+        let mut result = BatchResult::default();
+
+        let candidates = self.endpoint_candidates_for(difficulty);
+        if candidates.is_empty() {
+            return Ok(result);
+        }
+
+        let mut rng = self.rng();
+
+        while result.streamed < count
+            && !cancel.load(Ordering::Relaxed)
+            && self.max_attempts.is_none_or(|max| result.attempts < max)
+        {
+            let mut exhausted = false;
+            match self.try_random_pair(&candidates, difficulty, &mut rng, endpoint_uses) {
+                Ok(puzzle) => {
+                    on_puzzle(puzzle)?;
+                    result.streamed += 1;
+                }
+                Err(reason) => {
+                    *result.rejected_by_reason.entry(reason).or_insert(0) += 1;
+                    exhausted = reason == RejectReason::NoDistantEndpoint
+                        && self.is_selection_exhausted(&candidates, difficulty, endpoint_uses);
+                }
+            }
+
+            result.attempts += 1;
+            on_progress(Progress {
+                generated: result.streamed,
+                target: count,
+                attempts: result.attempts,
+            });
+            if exhausted {
+                break;
+            }
+        }
+
+        result.elapsed = started.elapsed();
+        Ok(result)
+    }
+
+    /// Generates puzzles of one difficulty that all start at the same word.
+    ///
+    /// Useful for a "word of the day" start. Instead of trying random pairs,
+    /// the end words are read off the start word's BFS layers (see
+    /// `WordGraph::distance_layers`): only words at a distance within the
+    /// difficulty's step range that may be used as end words are candidates.
+    /// Each candidate is tried at most once, picked with the pair selector's
+    /// weighting, and the batch quality checks still apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start word of every puzzle; need not be a base word
+    /// * `count` - Number of puzzles to generate
+    /// * `difficulty` - Desired difficulty level
+    ///
+    /// # Returns
+    ///
+    /// A `BatchResult` with at most one puzzle per end word. It holds fewer
+    /// puzzles than requested once the candidates run out, and none if the
+    /// start word is not in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\nbat\n");
+    ///
+    /// let result = PuzzleGenerator::new(graph).generate_from_start("cat", 5, Difficulty::Easy);
+    /// let mut ends: Vec<&str> = result.puzzles.iter().map(|p| p.end.as_str()).collect();
+    /// ends.sort();
+    /// assert_eq!(ends, vec!["cog", "dog"]);
+    /// ```
+    pub fn generate_from_start(
+        &self,
+        start: &str,
+        count: usize,
+        difficulty: Difficulty,
+    ) -> BatchResult {
+        let started = Instant::now();
+        let mut result = BatchResult::default();
+        let mut candidates = self.end_candidates(start, difficulty);
+
+        let mut rng = self.rng();
+        while result.puzzles.len() < count
+            && self.max_attempts.is_none_or(|max| result.attempts < max)
+        {
+            let Some(end) = self
+                .pair_selector
+                .choose_word(&candidates, &mut rng)
+                .cloned()
+            else {
+                break;
+            };
+            candidates.retain(|word| *word != end);
+
+            match self.evaluate_pair(start, &end, difficulty) {
+                Ok(puzzle) => result.puzzles.push(puzzle),
+                Err(reason) => *result.rejected_by_reason.entry(reason).or_insert(0) += 1,
+            }
+            result.attempts += 1;
+        }
+
+        result.elapsed = started.elapsed();
+        result
+    }
+
+    /// Generates a chain of puzzles for an "endless mode".
+    ///
+    /// The chain starts at a random start word, and each puzzle starts where
+    /// the previous one ended, so the words form one long sequence of
+    /// mini-ladders. Each next end word is picked like in
+    /// `generate_from_start`, skipping words already in the chain, and the
+    /// batch quality checks apply to every puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Number of puzzles in the chain
+    /// * `difficulty` - Difficulty of every puzzle, bounding its steps
+    ///
+    /// # Returns
+    ///
+    /// A `BatchResult` with the puzzles in chain order. The chain is shorter
+    /// than requested if it reaches a word from which no new end word works,
+    /// and empty if there are no start words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\nbat\n");
+    ///
+    /// let chain = PuzzleGenerator::new(graph).generate_chain(3, Difficulty::Easy);
+    /// for (leg, next) in chain.puzzles.iter().zip(chain.puzzles.iter().skip(1)) {
+    ///     assert_eq!(leg.end, next.start);
+    /// }
+    /// ```
+    pub fn generate_chain(&self, length: usize, difficulty: Difficulty) -> BatchResult {
+        let started = Instant::now();
+        let mut result = BatchResult::default();
+        let mut rng = self.rng();
+
+        let starts: Vec<String> = self
+            .endpoint_candidates_for(difficulty)
+            .into_iter()
+            .flat_map(|(starts, _)| starts)
+            .collect();
+        let Some(mut current) = self.pair_selector.choose_word(&starts, &mut rng).cloned() else {
+            result.elapsed = started.elapsed();
+            return result;
+        };
+        let mut chained = HashSet::from([current.clone()]);
+
+        'chain: while result.puzzles.len() < length {
+            let mut candidates = self.end_candidates(&current, difficulty);
+            candidates.retain(|word| !chained.contains(word));
+            let puzzle = loop {
+                if self.max_attempts.is_some_and(|max| result.attempts >= max) {
+                    break 'chain;
+                }
+                let Some(end) = self
+                    .pair_selector
+                    .choose_word(&candidates, &mut rng)
+                    .cloned()
+                else {
+                    break 'chain;
+                };
+                candidates.retain(|word| *word != end);
+
+                result.attempts += 1;
+                match self.evaluate_pair(&current, &end, difficulty) {
+                    Ok(puzzle) => break puzzle,
+                    Err(reason) => *result.rejected_by_reason.entry(reason).or_insert(0) += 1,
+                }
+            };
+            current = puzzle.end.clone();
+            chained.insert(current.clone());
+            result.puzzles.push(puzzle);
+        }
+
+        result.elapsed = started.elapsed();
+        result
+    }
+
+    /// Returns an endless iterator of random puzzles of one difficulty.
+    ///
+    /// Puzzles are generated lazily, one per call to `next`, with the same
+    /// pair selection and quality checks as `generate_batch`, so the iterator
+    /// can be filtered, chained, or cut off with `take`. A seeded generator
+    /// yields the same sequence as its batches.
+    ///
+    /// The iterator only ends when there are no base word pairs to try or
+    /// after `max_attempts` word pairs (counted over the whole iterator).
+    /// Without an attempt limit, `next` keeps trying until it finds a puzzle,
+    /// so set one when the dictionary may have no puzzle of the difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty of every puzzle
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let from_cat: Vec<_> = generator
+    ///     .puzzles(Difficulty::Easy)
+    ///     .filter(|puzzle| puzzle.start == "cat")
+    ///     .take(3)
+    ///     .collect();
+    /// assert_eq!(from_cat.len(), 3);
+    /// ```
+    pub fn puzzles(&self, difficulty: Difficulty) -> Puzzles<'_> {
+        Puzzles {
+            generator: self,
+            difficulty,
+            candidates: self.endpoint_candidates_for(difficulty),
+            rng: self.rng(),
+            attempts: 0,
+            endpoint_uses: HashMap::new(),
+        }
+    }
+
+    /// Returns an endless asynchronous stream of random puzzles of one difficulty.
+    ///
+    /// This is the `Stream` counterpart of `puzzles`: a clone of the generator
+    /// runs the iterator on its own thread and hands puzzles over through a
+    /// small buffer, so generation stays a few puzzles ahead of the consumer.
+    /// Dropping the stream stops generation after the puzzle in progress. The
+    /// stream can be created outside a runtime and polled from any executor.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty of every puzzle
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tokio_stream::StreamExt;
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ndog\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    /// let puzzles: Vec<_> =
+    ///     runtime.block_on(generator.puzzle_stream(Difficulty::Easy).take(2).collect());
+    /// assert_eq!(puzzles.len(), 2);
+    /// ```
+    #[cfg(all(feature = "stream", not(target_arch = "wasm32")))]
+    pub fn puzzle_stream(
+        &self,
+        difficulty: Difficulty,
+    ) -> impl tokio_stream::Stream<Item = Puzzle> + Send + 'static {
+        let (sender, receiver) = tokio::sync::mpsc::channel(PUZZLE_STREAM_BUFFER);
+        let generator = self.clone();
+        std::thread::spawn(move || {
+            for puzzle in generator.puzzles(difficulty) {
+                if sender.blocking_send(puzzle).is_err() {
+                    break;
+                }
+            }
+        });
+        tokio_stream::wrappers::ReceiverStream::new(receiver)
+    }
+
+    /// Estimates how many distinct puzzles of a difficulty can be generated.
+    ///
+    /// Counts the ordered start and end pairs of base words whose shortest
+    /// ladder falls in the difficulty's step range, honoring the endpoint
+    /// filters, word length policy, and triviality rules and skipping banned
+    /// endpoints. Each
+    /// start word takes one breadth-first search; with more than
+    /// `CAPACITY_SAMPLE_STARTS` start words, a random sample of them is
+    /// searched (seeded like generation) and the count is scaled up.
+    ///
+    /// Checks of the path itself, such as banned intermediate words,
+    /// `min_branching`, or unique solutions, are not applied, so the estimate
+    /// is an upper bound when they are enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty to count puzzles of
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\nbat\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// // bat -> dog and dog -> bat take four steps
+    /// let estimate = generator.estimate_capacity(Difficulty::Medium);
+    /// assert_eq!(estimate.pairs, 2);
+    /// assert!(estimate.is_exact());
+    /// ```
+    pub fn estimate_capacity(&self, difficulty: Difficulty) -> CapacityEstimate {
+        let steps =
+            self.thresholds
+                .min_steps(difficulty)
+                .max(self.triviality.min_steps)..=self.thresholds.max_steps(difficulty);
+        let candidates = self.endpoint_candidates_for(difficulty);
+        let mut starts: Vec<(&str, &[String])> = candidates
+            .iter()
+            .flat_map(|(starts, ends)| {
+                starts
+                    .iter()
+                    .map(move |start| (start.as_str(), ends.as_slice()))
+            })
+            .collect();
+        let total_starts = starts.len();
+        if total_starts > CAPACITY_SAMPLE_STARTS {
+            starts.shuffle(&mut self.rng());
+            starts.truncate(CAPACITY_SAMPLE_STARTS);
+        }
+
+        let counted: usize = starts
+            .iter()
+            .map(|(start, ends)| {
+                self.graph
+                    .distance_layers(start)
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(distance, _)| steps.contains(distance))
+                    .flat_map(|(_, layer)| layer)
+                    .filter(|word| ends.binary_search(word).is_ok())
+                    .filter(|word| !self.triviality.is_trivial_pair(start, word))
+                    .count()
+            })
+            .sum();
+        let pairs = if starts.is_empty() {
+            0
+        } else {
+            (counted as f64 * total_starts as f64 / starts.len() as f64).round() as usize
+        };
+
+        CapacityEstimate {
+            difficulty,
+            pairs,
+            sampled_starts: starts.len(),
+            total_starts,
+        }
+    }
+
+    /// Builds every puzzle between the base words instead of sampling pairs.
+    ///
+    /// Meant for small curated base word lists, where a random batch would
+    /// keep hitting the same pairs. Each start word takes one breadth-first
+    /// search to all of its end candidates (shared by the difficulties being
+    /// enumerated), and every pair whose shortest ladder falls in a
+    /// difficulty's step range goes through the same quality checks as
+    /// batch generation. Pairs with no ladder in range are not attempts.
+    ///
+    /// The search cost grows with the number of start words times the size
+    /// of their components, so use `generate_batch` for large lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty_filter` - Only enumerate puzzles of this difficulty, or
+    ///   `None` for every difficulty
+    ///
+    /// # Returns
+    ///
+    /// A `BatchResult` with the puzzles ordered by start word, difficulty,
+    /// and end word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{Difficulty, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+    /// graph.load_base_words_from_str("cat\ndog\ncog\nbat\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let all = generator.enumerate_all(None);
+    /// assert_eq!(all.puzzles.len(), 8);
+    ///
+    /// let medium = generator.enumerate_all(Some(Difficulty::Medium));
+    /// let pairs: Vec<(&str, &str)> = medium
+    ///     .puzzles
+    ///     .iter()
+    ///     .map(|p| (p.start.as_str(), p.end.as_str()))
+    ///     .collect();
+    /// assert_eq!(pairs, vec![("bat", "dog"), ("dog", "bat")]);
+    /// ```
+    pub fn enumerate_all(&self, difficulty_filter: Option<Difficulty>) -> BatchResult {
+        let started = Instant::now();
+        let mut result = BatchResult::default();
+        let levels = difficulty_filter.map_or(Difficulty::ALL.to_vec(), |level| vec![level]);
+        let candidates: Vec<(Difficulty, PairPool)> = levels
+            .into_iter()
+            .map(|level| (level, self.endpoint_candidates_for(level)))
+            .collect();
+
+        // The end candidates of each start word, per difficulty
+        let mut ends_by_start: BTreeMap<&str, Vec<(Difficulty, &[String])>> = BTreeMap::new();
+        for (level, groups) in &candidates {
+            for (starts, ends) in groups {
+                for start in starts {
+                    ends_by_start
+                        .entry(start)
+                        .or_default()
+                        .push((*level, ends.as_slice()));
+                }
+            }
+        }
+
+        for (start, levels) in ends_by_start {
+            let mut targets: Vec<&str> = levels
+                .iter()
+                .flat_map(|(_, ends)| ends.iter().map(String::as_str))
+                .filter(|end| *end != start)
+                .collect();
+            targets.sort_unstable();
+            targets.dedup();
+            let paths = self.graph.shortest_paths_to_many(start, &targets);
+
+            for (level, ends) in levels {
+                let steps = self.thresholds.min_steps(level)..=self.thresholds.max_steps(level);
+                for end in ends {
+                    let Some(path) = paths
+                        .get(end)
+                        .filter(|path| steps.contains(&(path.len() - 1)))
+                    else {
+                        continue;
+                    };
+                    let evaluated = if self.triviality.is_trivial_pair(start, end) {
+                        Err(RejectReason::Trivial)
+                    } else {
+                        self.evaluate_path(start, end, path.clone(), level)
+                    };
+                    match evaluated {
+                        Ok(puzzle) => result.puzzles.push(puzzle),
+                        Err(reason) => *result.rejected_by_reason.entry(reason).or_insert(0) += 1,
+                    }
+                    result.attempts += 1;
+                }
+            }
+        }
+
+        result.elapsed = started.elapsed();
+        result
+    }
+
+    /// Picks a random endpoint pair and builds its puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - Start and end candidates per word length, not empty
+    /// * `difficulty` - Desired difficulty level
+    /// * `rng` - Random number generator of the generation call
+    /// * `endpoint_uses` - Accepted puzzles per endpoint word so far, updated
+    ///   when the pair yields a puzzle
+    ///
+    /// # Returns
+    ///
+    /// The puzzle, or the reason the pair was rejected.
+    fn try_random_pair(
+        &self,
+        candidates: &[(Vec<String>, Vec<String>)],
+        difficulty: Difficulty,
+        rng: &mut StdRng,
+        endpoint_uses: &mut HashMap<String, usize>,
+    ) -> Result<Puzzle, RejectReason> {
+        let (starts, ends) = candidates.choose(rng).unwrap();
+        let context = PairContext::new(self, starts, ends, Some(difficulty), endpoint_uses);
+        let puzzle = self
+            .pair_selector
+            .select_pair(&context, rng)
+            .ok_or(RejectReason::NoDistantEndpoint)
+            .and_then(|(start, end)| self.evaluate_pair(&start, &end, difficulty))?;
+        for word in [&puzzle.start, &puzzle.end] {
+            *endpoint_uses.entry(word.clone()).or_insert(0) += 1;
+        }
+        Ok(puzzle)
+    }
+
+    /// Returns `true` if the pair selector can find no more pairs of any word
+    /// length for the rest of the batch (see `PairSelector::is_exhausted`).
+    fn is_selection_exhausted(
+        &self,
+        candidates: &[(Vec<String>, Vec<String>)],
+        difficulty: Difficulty,
+        endpoint_uses: &HashMap<String, usize>,
+    ) -> bool {
+        candidates.iter().all(|(starts, ends)| {
+            let context = PairContext::new(self, starts, ends, Some(difficulty), endpoint_uses);
+            self.pair_selector.is_exhausted(&context)
+        })
+    }
+
+    /// Builds the puzzle for a word pair and applies the batch quality checks.
+    ///
+    /// # Returns
+    ///
+    /// The puzzle, or the first reason it was rejected for.
+    fn evaluate_pair(
+        &self,
+        start: &str,
+        end: &str,
+        difficulty: Difficulty,
+    ) -> Result<Puzzle, RejectReason> {
+        if self.triviality.is_trivial_pair(start, end) {
+            return Err(RejectReason::Trivial);
+        }
+        let path = self
+            .graph
+            .find_path_within(start, end, self.thresholds.max_steps(difficulty))
+            .ok_or(RejectReason::NoPath)?;
+        self.evaluate_path(start, end, path, difficulty)
+    }
+
+    /// Builds the puzzle for a word pair's shortest path and applies the batch
+    /// quality checks after the endpoint triviality check.
+    ///
+    /// # Returns
+    ///
+    /// The puzzle, or the first reason it was rejected for.
+    fn evaluate_path(
+        &self,
+        start: &str,
+        end: &str,
+        path: Vec<String>,
+        difficulty: Difficulty,
+    ) -> Result<Puzzle, RejectReason> {
+        if path.len() - 1 < self.triviality.min_steps {
+            return Err(RejectReason::Trivial);
+        }
+        let mut puzzle =
+            Puzzle::with_thresholds(start.to_string(), end.to_string(), path, &self.thresholds)
+                .filter(|p| self.matches_difficulty(p, &difficulty))
+                .ok_or(RejectReason::WrongDifficulty)?;
+
+        // Checked first since it may switch to another path of the same length
+        if self.require_reversible {
+            self.annotate_reverse_path(&mut puzzle);
+            if puzzle.reverse_path.is_none() {
+                return Err(RejectReason::NotReversible);
+            }
+        }
+
+        let required = self.min_branching.for_difficulty(difficulty);
+        if required > 0 && self.branching(&puzzle).is_none_or(|b| b < required) {
+            return Err(RejectReason::LowBranching);
+        }
+        if puzzle
+            .path
+            .iter()
+            .chain(puzzle.reverse_path.iter().flatten())
+            .any(|word| self.banned_words.contains(word))
+        {
+            return Err(RejectReason::BannedWord);
+        }
+        if !self.quality_filters.iter().all(|filter| filter(&puzzle)) {
+            return Err(RejectReason::QualityFilter);
+        }
+        if self.unique_solution {
+            self.annotate_uniqueness(&mut puzzle);
+            if puzzle.unique_solution == Some(false) {
+                return Err(RejectReason::NotUnique);
+            }
+        }
+        self.attach_stars(&mut puzzle);
+        self.attach_meta(&mut puzzle);
+        Ok(puzzle)
+    }
+
+    /// Returns the random number generator for one generation call.
+    fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    /// Splits the valid base words of each length into start and end candidates.
+    ///
+    /// The start and end filters are applied here, once per generation call.
+    /// When an edge rule changes the word length, ladders may join words of
+    /// any two lengths, so all lengths form a single group.
+    ///
+    /// # Returns
+    ///
+    /// The start and end candidates of each word length (or of all lengths)
+    /// that allows at least one pair of different words, ordered by length.
+    fn endpoint_candidates_by_length(&self) -> PairPool {
+        let mut by_length = self.get_valid_base_words_by_length();
+        let mut lengths: Vec<usize> = by_length.keys().copied().collect();
+        lengths.sort_unstable();
+        let groups: Vec<Vec<String>> = if self.graph.crosses_lengths() {
+            let mut words: Vec<String> = by_length.into_values().flatten().collect();
+            words.sort_unstable();
+            vec![words]
+        } else {
+            lengths
+                .into_iter()
+                .filter_map(|length| by_length.remove(&length))
+                .collect()
+        };
+
+        let passing = |words: &[String], filters: &[EndpointFilter]| -> Vec<String> {
+            words
+                .iter()
+                .filter(|word| filters.iter().all(|filter| filter.matches(word)))
+                .cloned()
+                .collect()
+        };
+        groups
+            .into_iter()
+            .filter_map(|words| {
+                let starts = passing(&words, &self.start_filters);
+                let ends = passing(&words, &self.end_filters);
+                has_distinct_pair(&starts, &ends).then_some((starts, ends))
+            })
+            .collect()
+    }
+
+    /// Returns the endpoint candidates of the word lengths a difficulty allows.
+    fn endpoint_candidates_for(&self, difficulty: Difficulty) -> PairPool {
+        let mut candidates = self.endpoint_candidates_by_length();
+        for (starts, ends) in &mut candidates {
+            starts.retain(|word| {
+                self.length_policy
+                    .allows(difficulty, self.word_length(word))
+            });
+            ends.retain(|word| {
+                self.length_policy
+                    .allows(difficulty, self.word_length(word))
+            });
+        }
+        candidates.retain(|(starts, ends)| has_distinct_pair(starts, ends));
+        candidates
+    }
+
+    /// Groups valid base words by their length for efficient random selection.
+    ///
+    /// This method filters base words to ensure they exist in the dictionary
+    /// (and pass the length and banned-word restrictions) and groups them by
+    /// length in the graph's comparison unit (the length its subgraphs are
+    /// built for), sorted so seeded selection is reproducible. This enables
+    /// efficient random selection of words with matching lengths for puzzle
+    /// generation.
+    ///
+    /// # Returns
+    ///
+    /// A HashMap mapping word lengths to vectors of valid words of that length.
+    fn get_valid_base_words_by_length(&self) -> HashMap<usize, Vec<String>> {
+        let base_words: Vec<String> = self.graph.get_base_words().iter().cloned().collect();
+        if base_words.is_empty() {
+            return HashMap::new();
+        }
+
+        // Filter base words to only include those in the dictionary
+        // (and in the required endpoint source, if any)
+        let valid_words: Vec<String> = base_words
+            .into_iter()
+            .filter(|word| {
+                self.graph.get_words().contains(word)
+                    && self.in_endpoint_source(word)
+                    && self.is_allowed(word)
+            })
+            .collect();
+
+        if valid_words.len() < 2 {
+            return HashMap::new();
+        }
+
+        // Group by length
+        let mut by_length: HashMap<usize, Vec<String>> = HashMap::new();
+        for word in valid_words {
+            by_length
+                .entry(self.word_length(&word))
+                .or_default()
+                .push(word);
+        }
+        for words in by_length.values_mut() {
+            words.sort_unstable();
+        }
+
+        by_length
+    }
+
+    /// Lists the end words a puzzle of one difficulty from a start word may use.
+    ///
+    /// Reads the start word's BFS layers within the difficulty's step range
+    /// and keeps the words allowed as end words, sorted so seeded selection
+    /// is reproducible.
+    fn end_candidates(&self, start: &str, difficulty: Difficulty) -> Vec<String> {
+        let steps = self.thresholds.min_steps(difficulty)..=self.thresholds.max_steps(difficulty);
+        let mut candidates: Vec<String> = self
+            .graph
+            .distance_layers(start)
+            .into_iter()
+            .enumerate()
+            .filter(|(distance, _)| steps.contains(distance))
+            .flat_map(|(_, layer)| layer)
+            .filter(|word| self.is_end_word(word))
+            .filter(|word| {
+                self.length_policy
+                    .allows(difficulty, self.word_length(word))
+            })
+            .collect();
+        candidates.sort_unstable();
+        candidates
+    }
+
+    /// Returns `true` if a word may be used as a randomly chosen end word.
+    fn is_end_word(&self, word: &str) -> bool {
+        self.graph.get_base_words().contains(word)
+            && self.in_endpoint_source(word)
+            && self.is_allowed(word)
+            && self.end_filters.iter().all(|filter| filter.matches(word))
+    }
+
+    /// Returns the length of a word in the graph's comparison unit.
+    fn word_length(&self, word: &str) -> usize {
+        self.graph.comparison_unit().length(word)
+    }
+
+    /// Returns `true` if a word passes the length and banned-word restrictions.
+    fn is_allowed(&self, word: &str) -> bool {
+        self.word_lengths
+            .as_ref()
+            .is_none_or(|lengths| lengths.contains(&self.word_length(word)))
+            && !self.banned_words.contains(word)
+    }
+
+    /// Returns `true` if a word belongs to the required endpoint source (or none is required).
+    fn in_endpoint_source(&self, word: &str) -> bool {
+        self.endpoint_source
+            .as_deref()
+            .is_none_or(|tag| self.graph.source_contains(tag, word))
+    }
+
+    /// Checks if a puzzle matches the specified difficulty level.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to check
+    /// * `target` - The target difficulty level
+    ///
+    /// # Returns
+    ///
+    /// `true` if the puzzle matches the difficulty, `false` otherwise
+    fn matches_difficulty(&self, puzzle: &Puzzle, target: &Difficulty) -> bool {
+        matches!(
+            (puzzle.difficulty, target),
+            (Difficulty::Easy, Difficulty::Easy)
+                | (Difficulty::Medium, Difficulty::Medium)
+                | (Difficulty::Hard, Difficulty::Hard)
+        )
+    }
+
+    /// Verifies that a puzzle solution is valid.
+    ///
+    /// This method checks that:
+    /// 1. The puzzle contains at least 2 words
+    /// 2. Each consecutive pair of words is one step apart under the graph's
+    ///    edge rules (by default, differs by exactly one letter)
+    /// 3. Every word is accepted by the graph (see `WordGraph::is_accepted`),
+    ///    so players may use words from a broader accepted-word list than the
+    ///    dictionary generated paths come from
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle_str` - Comma-separated string of words (e.g., "cat,cot,cog,dog")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(true)` if valid, `Ok(false)` if invalid, or an error for malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// // Assuming generator is set up...
+    /// # let generator = PuzzleGenerator::new(wordladder_core::graph::WordGraph::new());
+    ///
+    /// match generator.verify_puzzle("cat,cot,cog,dog") {
+    ///     Ok(true) => println!("Valid puzzle!"),
+    ///     Ok(false) => println!("Invalid puzzle"),
+    ///     Err(e) => println!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn verify_puzzle(&self, puzzle_str: &str) -> Result<bool, String> {
+        let words = parse_ladder(puzzle_str, self.graph.normalization());
+
+        if words.len() < 2 {
+            return Err("Puzzle must have at least 2 words".to_string());
+        }
+
+        Ok(self.first_unaccepted_word(&words).is_none()
+            && self.first_invalid_step(&words).is_none())
+    }
+
+    /// Verifies many puzzles, one comma-separated ladder per line.
+    ///
+    /// Each line is checked like `verify_puzzle`. Blank lines and lines
+    /// starting with `#` are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Text with one puzzle per line (e.g., the contents of a file)
+    ///
+    /// # Returns
+    ///
+    /// A `VerifySummary` with the number of puzzles checked and one
+    /// `VerifyFailure` per invalid line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let generator = PuzzleGenerator::new(WordGraph::new());
+    /// let summary = generator.verify_many("cat,cot,cog,dog\n# comment\ncat,dog\n");
+    ///
+    /// assert_eq!(summary.total, 2);
+    /// assert_eq!(summary.valid, 1);
+    /// assert_eq!(summary.failures[0].line, 3);
+    /// assert_eq!(summary.failures[0].reason, "cat -> dog is not a valid step");
+    /// ```
+    pub fn verify_many(&self, input: &str) -> VerifySummary {
+        self.verify_lines(input, false)
+    }
+
+    /// Verifies many puzzles like `verify_many`, also rejecting non-optimal solutions.
+    ///
+    /// A valid ladder that uses more steps than the shortest ladder between its
+    /// endpoints is reported as a failure stating the number of extra steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Text with one puzzle per line
+    ///
+    /// # Returns
+    ///
+    /// A `VerifySummary` counting only optimal solutions as valid.
+    pub fn verify_many_optimal(&self, input: &str) -> VerifySummary {
+        self.verify_lines(input, true)
+    }
+
+    /// Verifies one puzzle per line, optionally requiring optimal solutions.
+    fn verify_lines(&self, input: &str, optimal: bool) -> VerifySummary {
+        let mut summary = VerifySummary::default();
+        for (index, line) in input.lines().enumerate() {
+            let puzzle = line.trim();
+            if puzzle.is_empty() || puzzle.starts_with('#') {
+                continue;
+            }
+            summary.total += 1;
+
+            let words = parse_ladder(puzzle, self.graph.normalization());
+            let reason = if words.len() < 2 {
+                Some("Puzzle must have at least 2 words".to_string())
+            } else if let Some(word) = self.first_unaccepted_word(&words) {
+                Some(format!("'{}' is not an accepted word", word))
+            } else if let Some(i) = self.first_invalid_step(&words) {
+                Some(format!(
+                    "{} -> {} is not a valid step",
+                    words[i],
+                    words[i + 1]
+                ))
+            } else if optimal {
+                let optimality = self.optimality(&words);
+                match (optimality.extra_steps(), optimality.shortest_steps) {
+                    (Some(0), _) => None,
+                    (Some(extra), Some(shortest)) => Some(format!(
+                        "uses {} extra step(s); the shortest ladder has {}",
+                        extra, shortest
+                    )),
+                    _ => Some("endpoints are not connected in the dictionary".to_string()),
+                }
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => summary.failures.push(VerifyFailure {
+                    line: index + 1,
+                    puzzle: puzzle.to_string(),
+                    reason,
+                }),
+                None => summary.valid += 1,
+            }
+        }
+        summary
+    }
+
+    /// Verifies a puzzle solution and compares it with the shortest ladder.
+    ///
+    /// The solution is checked like `verify_puzzle`, and the shortest distance
+    /// between its first and last words is computed in the dictionary graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle_str` - Comma-separated string of words (e.g., "cat,cot,cog,dog")
+    ///
+    /// # Returns
+    ///
+    /// An `Optimality` report, or an error for malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let report = generator.verify_optimal("cat,cot,dot,cot,cog,dog").unwrap();
+    /// assert!(report.valid);
+    /// assert_eq!(report.shortest_steps, Some(3));
+    /// assert_eq!(report.extra_steps(), Some(2));
+    /// ```
+    pub fn verify_optimal(&self, puzzle_str: &str) -> Result<Optimality, String> {
+        let words = parse_ladder(puzzle_str, self.graph.normalization());
+
+        if words.len() < 2 {
+            return Err("Puzzle must have at least 2 words".to_string());
+        }
+
+        Ok(self.optimality(&words))
+    }
+
+    /// Checks a parsed ladder's steps and its length against the shortest ladder.
+    fn optimality(&self, words: &[String]) -> Optimality {
+        let shortest_steps = self
+            .graph
+            .find_shortest_path_bidirectional(&words[0], &words[words.len() - 1])
+            .map(|path| path.len() - 1);
+        Optimality {
+            valid: self.first_unaccepted_word(words).is_none()
+                && self.first_invalid_step(words).is_none(),
+            steps: words.len() - 1,
+            shortest_steps,
+        }
+    }
+
+    /// Returns the first word players may not use, if any.
+    fn first_unaccepted_word<'w>(&self, words: &'w [String]) -> Option<&'w String> {
+        words.iter().find(|word| !self.graph.is_accepted(word))
+    }
+
+    /// Returns the index of the first word not one step from the next word, if any.
+    fn first_invalid_step(&self, words: &[String]) -> Option<usize> {
+        (0..words.len().saturating_sub(1)).find(|&i| !self.graph.is_step(&words[i], &words[i + 1]))
+    }
+
+    /// Selects a random pair of base words for puzzle generation.
+    ///
+    /// This method randomly selects two different words of the same length (of
+    /// any lengths when an edge rule changes word length) from the available
+    /// base words, ensuring they can be used as puzzle endpoints.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((start, end))` with two random words, or an error if insufficient words are available.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::PuzzleGenerator;
+    ///
+    /// // Assuming generator is set up with base words...
+    /// # let generator = PuzzleGenerator::new(wordladder_core::graph::WordGraph::new());
+    ///
+    /// match generator.pick_random_words() {
+    ///     Ok((start, end)) => println!("Selected: {} -> {}", start, end),
+    ///     Err(e) => println!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn pick_random_words(&self) -> Result<(String, String)> {
+        if self.get_valid_base_words_by_length().is_empty() {
+            return Err(anyhow!("No base words loaded"));
+        }
+        let candidates = self.endpoint_candidates_by_length();
+        if candidates.is_empty() {
+            return Err(anyhow!(
+                "No word length has a valid start and a different valid end word"
+            ));
+        }
+
+        let mut rng = self.rng();
+        let (starts, ends) = candidates.choose(&mut rng).unwrap();
+
+        let endpoint_uses = HashMap::new();
+        let context = PairContext::new(self, starts, ends, None, &endpoint_uses);
+        self.pair_selector
+            .select_pair(&context, &mut rng)
+            .ok_or_else(|| anyhow!("Pair selector found no word pair"))
+    }
+
+    /// Checks the base words for problems using the default thresholds.
+    ///
+    /// See `validate_base_words_with` for details.
+    pub fn validate_base_words(&self) -> Vec<BaseWordReport> {
+        self.validate_base_words_with(&ValidationThresholds::default())
+    }
+
+    /// Checks the base words for entries that can never make good puzzles.
+    ///
+    /// Batch generation silently skips base words that are missing from the
+    /// dictionary and wastes attempts on poorly connected ones. This reports
+    /// each such word with its most severe issue, in order: not in dictionary,
+    /// isolated, in a small component, or below the minimum degree.
+    ///
+    /// # Arguments
+    ///
+    /// * `thresholds` - Component size and degree limits to check against
+    ///
+    /// # Returns
+    ///
+    /// One report per problematic base word, sorted by word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{BaseWordIssue, PuzzleGenerator};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    /// graph.load_base_words_from_str("cat\ncta\n");
+    ///
+    /// let reports = PuzzleGenerator::new(graph).validate_base_words();
+    /// assert_eq!(reports[1].word, "cta");
+    /// assert_eq!(reports[1].issue, BaseWordIssue::NotInDictionary);
+    /// ```
+    pub fn validate_base_words_with(
+        &self,
+        thresholds: &ValidationThresholds,
+    ) -> Vec<BaseWordReport> {
+        let labels = self.graph.component_labels();
+        let mut component_sizes: HashMap<u32, usize> = HashMap::new();
+        for &label in &labels {
+            *component_sizes.entry(label).or_default() += 1;
+        }
+
+        let mut reports: Vec<BaseWordReport> = self
+            .graph
+            .get_base_words()
+            .iter()
+            .filter_map(|word| {
+                let issue = match self.graph.word_id(word) {
+                    None => BaseWordIssue::NotInDictionary,
+                    Some(id) => {
+                        let degree = self.graph.degree(word).unwrap_or(0);
+                        let size = component_sizes[&labels[id as usize]];
+                        if degree == 0 {
+                            BaseWordIssue::Isolated
+                        } else if size < thresholds.min_component_size {
+                            BaseWordIssue::SmallComponent { size }
+                        } else if degree < thresholds.min_degree {
+                            BaseWordIssue::LowDegree { degree }
+                        } else {
+                            return None;
+                        }
+                    }
+                };
+                Some(BaseWordReport {
+                    word: word.clone(),
+                    issue,
+                })
+            })
+            .collect();
+        reports.sort_by(|a, b| a.word.cmp(&b.word));
+        reports
+    }
+
+    /// Checks which word pairs can be joined by a ladder, and how far apart they are.
+    ///
+    /// Pairs whose words lie in different connected components are rejected
+    /// without a search. The remaining pairs are grouped by start word, so
+    /// each distinct start needs a single BFS no matter how many end words it
+    /// is paired with. Endpoint restrictions such as base words or length
+    /// limits are not applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - Start and end words to check
+    ///
+    /// # Returns
+    ///
+    /// One report per pair, in input order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{PairStatus, PuzzleGenerator};
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "cog", "dog", "fish"].map(String::from));
+    /// let generator = PuzzleGenerator::new(graph);
+    ///
+    /// let pairs = [("cat", "dog"), ("cat", "fish"), ("cat", "emu")]
+    ///     .map(|(start, end)| (start.to_string(), end.to_string()));
+    /// let reports = generator.solvable_pairs(&pairs);
+    ///
+    /// assert_eq!(reports[0].status, PairStatus::Solvable { steps: 3 });
+    /// assert_eq!(reports[1].status, PairStatus::Disconnected);
+    /// assert_eq!(
+    ///     reports[2].status,
+    ///     PairStatus::NotInDictionary { word: "emu".to_string() }
+    /// );
+    /// ```
+    pub fn solvable_pairs(&self, pairs: &[(String, String)]) -> Vec<PairReport> {
+        let labels = self.graph.component_labels();
+        let component = |word: &str| self.graph.word_id(word).map(|id| labels[id as usize]);
+        let normalization = self.graph.normalization();
+        let pairs: Vec<(String, String)> = pairs
+            .iter()
+            .map(|(start, end)| {
+                (
+                    normalization.normalize_query(start),
+                    normalization.normalize_query(end),
+                )
+            })
+            .collect();
+
+        let mut ends_by_start: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (start, end) in &pairs {
+            if let Some(label) = component(start)
+                && component(end) == Some(label)
+            {
+                ends_by_start.entry(start).or_default().push(end);
+            }
+        }
+        let mut steps: HashMap<(&str, String), usize> = HashMap::new();
+        for (start, ends) in &ends_by_start {
+            for (end, path) in self.graph.shortest_paths_to_many(start, ends) {
+                steps.insert((start, end), path.len() - 1);
+            }
+        }
+
+        pairs
+            .iter()
+            .map(|(start, end)| {
+                let status = if component(start).is_none() {
+                    PairStatus::NotInDictionary {
+                        word: start.clone(),
+                    }
+                } else if component(end).is_none() {
+                    PairStatus::NotInDictionary { word: end.clone() }
+                } else {
+                    match steps.get(&(start.as_str(), end.clone())) {
+                        Some(&steps) => PairStatus::Solvable { steps },
+                        None => PairStatus::Disconnected,
+                    }
+                };
+                PairReport {
+                    start: start.clone(),
+                    end: end.clone(),
+                    status,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns `true` if some start word differs from some end word.
+fn has_distinct_pair(starts: &[String], ends: &[String]) -> bool {
+    starts
+        .iter()
+        .any(|start| ends.iter().any(|end| end != start))
+}
+
+/// Splits a comma-separated ladder into words normalized like player input.
+fn parse_ladder(puzzle_str: &str, normalization: WordNormalization) -> Vec<String> {
+    puzzle_str
+        .split(',')
+        .map(|word| normalization.normalize_query(word))
+        .collect()
+}
+
+/// Builder for a `PuzzleGenerator` with a tuned generation policy.
+///
+/// Created with `PuzzleGenerator::builder`. Every option defaults to the
+/// behavior of `PuzzleGenerator::new`.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_core::graph::WordGraph;
+/// use wordladder_core::puzzle::{DifficultyThresholds, MinBranching, PuzzleGenerator};
+///
+/// let generator = PuzzleGenerator::builder(WordGraph::new())
+///     .with_difficulty_thresholds(DifficultyThresholds { easy_max: 3, medium_max: 6, hard_max: 12 })
+///     .with_max_attempts(10_000)
+///     .with_word_lengths(4..=5)
+///     .with_banned_words(["damn", "hell"])
+///     .with_seed(7)
+///     .with_min_branching(MinBranching { easy: 0, medium: 3, hard: 3 })
+///     .with_quality_filter(|puzzle| !puzzle.path.iter().any(|word| word.ends_with('s')))
+///     .build();
+///
+/// assert_eq!(generator.seed(), Some(7));
+/// ```
+#[derive(Clone)]
+pub struct PuzzleGeneratorBuilder {
+    /// The generator being configured
+    generator: PuzzleGenerator,
+}
+
+impl PuzzleGeneratorBuilder {
+    /// Sets the step counts separating the difficulty levels.
+    ///
+    /// # Arguments
+    ///
+    /// * `thresholds` - Most steps in easy, medium, and hard puzzles
+    pub fn with_difficulty_thresholds(mut self, thresholds: DifficultyThresholds) -> Self {
+        self.generator.thresholds = thresholds;
+        self
+    }
+
+    /// Sets the rules rejecting near-trivial puzzles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - Minimum steps and endpoint similarity limits
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::graph::WordGraph;
+    /// use wordladder_core::puzzle::{PuzzleError, PuzzleGenerator, TrivialityRules};
+    ///
+    /// let mut graph = WordGraph::new();
+    /// graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+    ///
+    /// let generator = PuzzleGenerator::builder(graph)
+    ///     .with_triviality_rules(TrivialityRules {
+    ///         min_steps: 4,
+    ///         ..TrivialityRules::default()
+    ///     })
+    ///     .build();
+    /// assert!(matches!(
+    ///     generator.generate_puzzle("cat", "dog"),
+    ///     Err(PuzzleError::Trivial { .. })
+    /// ));
+    /// ```
+    pub fn with_triviality_rules(mut self, rules: TrivialityRules) -> Self {
+        self.generator.triviality = rules;
+        self
+    }
+
+    /// Limits how many word pairs a batch tries before returning what it has.
+    ///
+    /// Without a limit, a batch keeps trying until it has `count` puzzles or
+    /// is cancelled, which never ends if the dictionary cannot supply them.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Most word pairs tried per batch
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.generator.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Restricts randomly chosen endpoints to the given word lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `lengths` - Allowed word lengths in letters
+    pub fn with_word_lengths(mut self, lengths: RangeInclusive<usize>) -> Self {
+        self.generator.word_lengths = Some(lengths);
+        self
+    }
+
+    /// Restricts randomly chosen endpoints to word lengths per difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Allowed word lengths of each difficulty level
+    pub fn with_word_length_policy(mut self, policy: WordLengthPolicy) -> Self {
+        self.generator.length_policy = policy;
+        self
+    }
+
+    /// Bans words from appearing anywhere in batch-generated puzzles.
+    ///
+    /// Banned words are never picked as endpoints, and puzzles whose shortest
+    /// path passes through a banned word are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - Words to ban, added to any banned earlier
+    pub fn with_banned_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.generator
+            .banned_words
+            .extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Seeds random word selection so generation is reproducible.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed for the random number generator
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.generator.seed = Some(seed);
+        self
+    }
+
+    /// Sets the minimum branching required of batch-generated puzzles.
+    ///
+    /// See `PuzzleGenerator::with_min_branching`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_branching` - Per-difficulty minimum neighbor counts
+    pub fn with_min_branching(mut self, min_branching: MinBranching) -> Self {
+        self.generator.min_branching = min_branching;
+        self
+    }
+
+    /// Sets whether batch generation requires a unique shortest solution.
+    ///
+    /// See `PuzzleGenerator::with_unique_solution`.
+    ///
+    /// # Arguments
+    ///
+    /// * `unique_solution` - Whether to require a unique shortest path
+    pub fn with_unique_solution(mut self, unique_solution: bool) -> Self {
+        self.generator.unique_solution = unique_solution;
+        self
+    }
+
+    /// Sets whether batch generation requires a reverse path.
+    ///
+    /// See `PuzzleGenerator::with_require_reversible`.
+    ///
+    /// # Arguments
+    ///
+    /// * `require_reversible` - Whether to require a reverse path
+    pub fn with_require_reversible(mut self, require_reversible: bool) -> Self {
+        self.generator.require_reversible = require_reversible;
+        self
+    }
+
+    /// Requires randomly chosen endpoints to come from a tagged dictionary source.
+    ///
+    /// See `PuzzleGenerator::with_endpoint_source`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Tag of the source endpoints must belong to
+    pub fn with_endpoint_source(mut self, tag: impl Into<String>) -> Self {
+        self.generator.endpoint_source = Some(tag.into());
+        self
+    }
+
+    /// Requires randomly chosen start words to pass a filter.
+    ///
+    /// See `PuzzleGenerator::with_start_filter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter start words must pass
+    pub fn with_start_filter(mut self, filter: EndpointFilter) -> Self {
+        self.generator.start_filters.push(filter);
+        self
+    }
+
+    /// Requires randomly chosen end words to pass a filter.
+    ///
+    /// See `PuzzleGenerator::with_start_filter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter end words must pass
+    pub fn with_end_filter(mut self, filter: EndpointFilter) -> Self {
+        self.generator.end_filters.push(filter);
+        self
+    }
+
+    /// Attaches a `PuzzleMeta` to every generated puzzle.
+    ///
+    /// See `PuzzleGenerator::with_puzzle_meta`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary_sha256` - Hex SHA-256 of the dictionary the graph was loaded from
+    pub fn with_puzzle_meta(mut self, dictionary_sha256: impl Into<String>) -> Self {
+        self.generator.meta_dictionary_sha256 = Some(dictionary_sha256.into());
+        self
+    }
+
+    /// Attaches a `StarRating` to every generated puzzle.
+    ///
+    /// See `PuzzleGenerator::with_star_ratings`.
+    ///
+    /// # Arguments
+    ///
+    /// * `formula` - How the star thresholds are derived from par
+    pub fn with_star_ratings(mut self, formula: StarFormula) -> Self {
+        self.generator.star_formula = Some(formula);
+        self
+    }
+
+    /// Biases random endpoint selection towards common words.
+    ///
+    /// See `PuzzleGenerator::with_endpoint_frequencies`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranks` - Frequency rank per word, 1 being the most common
+    ///
+    /// # Returns
+    ///
+    /// The builder, or an error if a rank is 0.
+    pub fn with_endpoint_frequencies(mut self, ranks: HashMap<String, usize>) -> Result<Self> {
+        self.generator = self.generator.with_endpoint_frequencies(ranks)?;
+        Ok(self)
+    }
+
+    /// Sets the strategy choosing the word pairs batch generation tries.
+    ///
+    /// See `PuzzleGenerator::with_pair_selector`.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Any `PairSelector`
+    pub fn with_pair_selector(mut self, selector: impl PairSelector + 'static) -> Self {
+        self.generator = self.generator.with_pair_selector(selector);
+        self
+    }
+
+    /// Lets each word start or end at most `max_uses` puzzles of a batch.
+    ///
+    /// See `PuzzleGenerator::with_max_endpoint_reuse`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_uses` - Most puzzles of a batch any one word may start or end
+    pub fn with_max_endpoint_reuse(mut self, max_uses: usize) -> Self {
+        self.generator = self.generator.with_max_endpoint_reuse(max_uses);
+        self
+    }
+
+    /// Adds a check that batch-generated puzzles must pass.
+    ///
+    /// Filters run after the built-in difficulty and branching checks; a
+    /// puzzle is kept only if every filter returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Returns `true` for puzzles to keep
+    pub fn with_quality_filter(
+        mut self,
+        filter: impl Fn(&Puzzle) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.generator.quality_filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Finishes configuration and returns the generator.
+    pub fn build(self) -> PuzzleGenerator {
+        self.generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ComparisonUnit, EdgeRule, WordGraph};
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    #[test]
+    fn test_verify_puzzle() {
+        let graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+
+        let generator = PuzzleGenerator::new(graph);
+        assert!(generator.verify_puzzle("cat,cot,cog,dog").unwrap());
+        assert!(!generator.verify_puzzle("cat,dog").unwrap());
+    }
+
+    #[test]
+    fn test_generate_puzzle_errors() {
+        let graph =
+            WordGraph::from_words(["cat", "cot", "cog", "dog", "emu", "horse"].map(String::from));
+        let generator = PuzzleGenerator::new(graph);
+
+        assert!(generator.generate_puzzle("cat", "dog").is_ok());
+        assert!(matches!(
+            generator.generate_puzzle("cat", "horse"),
+            Err(PuzzleError::Ladder(LadderError::DifferentLengths { .. }))
+        ));
+        assert_eq!(
+            generator.generate_puzzle("cab", "dog"),
+            Err(PuzzleError::Ladder(LadderError::StartNotInDictionary(
+                "cab".to_string()
+            )))
+        );
+        assert_eq!(
+            generator.generate_puzzle("cat", "dig"),
+            Err(PuzzleError::Ladder(LadderError::EndNotInDictionary(
+                "dig".to_string()
+            )))
+        );
+        assert!(matches!(
+            generator.generate_puzzle("cat", "emu"),
+            Err(PuzzleError::Ladder(LadderError::NoPath { .. }))
+        ));
+        assert_eq!(
+            generator
+                .generate_puzzle("cat", "cot")
+                .unwrap_err()
+                .to_string(),
+            "cat -> cot is too trivial for a puzzle"
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(500))]
+
+        #[test]
+        fn test_verify_puzzle_properties(
+            pieces in prop::collection::vec(
+                prop::sample::select(vec![
+                    "cat", "COG", " dog ", ",", ",,", " ", "\t", "\n", "#", "é", "猫", "\u{0}",
+                    "-", "",
+                ]),
+                0..12,
+            ),
+            start in any::<Index>(),
+            end in any::<Index>(),
+        ) {
+            let mut graph = WordGraph::new();
+            graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\ncod\ncold\ncord\n");
+            let generator = PuzzleGenerator::new(graph);
+            let mut words: Vec<&String> = generator.graph.get_words().iter().collect();
+            words.sort_unstable();
+
+            // Arbitrary input never panics, and is malformed exactly when it
+            // has fewer than two comma-separated fields
+            let input = pieces.concat();
+            let result = generator.verify_puzzle(&input);
+            prop_assert_eq!(result.is_err(), !input.contains(','), "{:?}", input);
+            generator.verify_many(&input);
+
+            // Every shortest path verifies, in both directions
+            let (start, end) = (*start.get(&words), *end.get(&words));
+            if let Some(mut path) = generator.graph.find_shortest_path(start, end)
+                && path.len() > 1
+            {
+                prop_assert_eq!(generator.verify_puzzle(&path.join(",")), Ok(true));
+                path.reverse();
+                prop_assert_eq!(generator.verify_puzzle(&path.join(", ")), Ok(true));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_many() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+        let generator = PuzzleGenerator::new(graph);
+
+        let summary = generator.verify_many("cat,cot,cog,dog\n  \nCAT, COT\ncat,cog,dog\ncat\n");
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.valid, 2);
+        assert!(!summary.all_valid());
+        assert_eq!(
+            summary.failures,
+            vec![
+                VerifyFailure {
+                    line: 4,
+                    puzzle: "cat,cog,dog".to_string(),
+                    reason: "cat -> cog is not a valid step".to_string(),
+                },
+                VerifyFailure {
+                    line: 5,
+                    puzzle: "cat".to_string(),
+                    reason: "Puzzle must have at least 2 words".to_string(),
+                },
+            ]
+        );
+        assert!(generator.verify_many("").all_valid());
+    }
+
+    #[test]
+    fn test_verify_optimal() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+        let generator = PuzzleGenerator::new(graph);
+
+        let optimal = generator.verify_optimal("cat,cot,cog,dog").unwrap();
+        assert!(optimal.is_optimal());
+        assert_eq!(optimal.extra_steps(), Some(0));
+
+        let detour = generator.verify_optimal("cat,cot,dot,cot,cog,dog").unwrap();
+        assert!(!detour.is_optimal());
+        assert_eq!(detour.steps, 5);
+        assert_eq!(detour.extra_steps(), Some(2));
+
+        let invalid = generator.verify_optimal("cat,cog,dog").unwrap();
+        assert!(!invalid.valid);
+        assert_eq!(invalid.extra_steps(), None);
+        assert!(generator.verify_optimal("cat").is_err());
+
+        let summary = generator.verify_many_optimal("cat,cot,cog,dog\ncat,cot,dot,cot,cog,dog\n");
+        assert_eq!(summary.valid, 1);
+        assert_eq!(summary.failures[0].line, 2);
+        assert_eq!(
+            summary.failures[0].reason,
+            "uses 2 extra step(s); the shortest ladder has 3"
+        );
+    }
+
+    #[test]
+    fn test_endpoint_frequencies() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\ncut\n");
+        graph.load_base_words_from_str("cat\ndog\ncog\ndot\ncut\n");
+        let graph = Arc::new(graph);
+        let ranks = HashMap::from([("cat".to_string(), 1), ("dog".to_string(), 2)]);
+
+        let mut common = 0;
+        for seed in 0..200 {
+            let generator = PuzzleGenerator::builder(Arc::clone(&graph))
+                .with_endpoint_frequencies(ranks.clone())
+                .unwrap()
+                .with_seed(seed)
+                .build();
+            let (start, end) = generator.pick_random_words().unwrap();
+            common += [start, end]
+                .iter()
+                .filter(|w| *w == "cat" || *w == "dog")
+                .count();
+        }
+        // A uniform choice averages 160 common endpoints in 200 pairs; with
+        // weights 1, 1/2 and 1/3 for the three unranked words it is about 220.
+        assert!(
+            common > 190,
+            "common endpoints picked {} of 400 times",
+            common
+        );
+    }
+
+    #[test]
+    fn test_triviality_rules() {
+        assert!(within_one_edit("cat", "bat"));
+        assert!(within_one_edit("cat", "cats"));
+        assert!(within_one_edit("cats", "cat"));
+        assert!(within_one_edit("cart", "cat"));
+        assert!(!within_one_edit("cat", "act"));
+        assert!(!within_one_edit("cat", "dog"));
+        assert!(!within_one_edit("cat", "casts"));
+
+        let mut graph = WordGraph::new().with_edge_rules(vec![EdgeRule::InsertOrDeleteOne]);
+        graph.load_dictionary_from_str("cat\ncats\ncart\ncarts\n");
+        let graph = Arc::new(graph);
+
+        let generator = PuzzleGenerator::new(Arc::clone(&graph));
+        assert!(matches!(
+            generator.generate_puzzle("cat", "cats"),
+            Err(PuzzleError::Trivial { .. })
+        ));
+        assert!(generator.generate_puzzle("cat", "carts").is_ok());
+
+        let prefix = TrivialityRules {
+            max_shared_prefix_ratio: Some(0.3),
+            ..TrivialityRules::default()
+        };
+        let generator = PuzzleGenerator::builder(Arc::clone(&graph))
+            .with_triviality_rules(prefix)
+            .build();
+        assert!(generator.generate_puzzle("cat", "carts").is_err());
+        assert_eq!(
+            generator.evaluate_pair("cat", "carts", Difficulty::Easy),
+            Err(RejectReason::Trivial)
+        );
+
+        let lenient = TrivialityRules {
+            min_steps: 1,
+            reject_single_edit: false,
+            max_shared_prefix_ratio: None,
+        };
+        let generator = PuzzleGenerator::builder(graph)
+            .with_triviality_rules(lenient)
+            .build();
+        assert_eq!(
+            generator.evaluate_pair("cat", "cats", Difficulty::Easy),
+            Err(RejectReason::WrongDifficulty)
+        );
+    }
+
+    #[test]
+    fn test_generate_with_non_ascii_base_words() {
+        // Endpoints are grouped by the graph's unit length, not bytes: "café"
+        // is 5 bytes, and "cafe\u{301}" 5 chars but 4 graphemes
+        for (unit, cafe) in [
+            (ComparisonUnit::Char, "café"),
+            (ComparisonUnit::Grapheme, "cafe\u{301}"),
+        ] {
+            let mut graph = WordGraph::new().with_comparison_unit(unit);
+            graph.load_dictionary_from_str(&format!("{}\ncafs\ncars\ncats\n", cafe));
+            graph.load_base_words_from_str(&format!("{}\ncats\n", cafe));
+            let generator = PuzzleGenerator::new(graph)
+                .with_seed(1)
+                .with_max_attempts(200);
+
+            let batch = generator.generate_batch(2, Difficulty::Easy);
+            assert_eq!(batch.puzzles.len(), 2, "{:?}", unit);
+            assert!(
+                batch
+                    .puzzles
+                    .iter()
+                    .all(|puzzle| [puzzle.start.as_str(), puzzle.end.as_str()].contains(&cafe))
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_policy() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\ncold\ncord\nword\nworm\nwarm\n");
+        graph.load_base_words_from_str("cat\ndog\ndot\ncold\nword\nwarm\n");
+        let graph = Arc::new(graph);
+
+        let seeded = |seed| {
+            PuzzleGenerator::builder(Arc::clone(&graph))
+                .with_seed(seed)
+                .with_max_attempts(50)
+                .build()
+                .generate_batch(5, Difficulty::Easy)
+                .puzzles
+        };
+        assert_eq!(seeded(1), seeded(1));
+
+        let four_letter = PuzzleGenerator::builder(Arc::clone(&graph))
+            .with_word_lengths(4..=4)
+            .with_max_attempts(50)
+            .build()
+            .generate_batch(3, Difficulty::Easy)
+            .puzzles;
+        assert!(!four_letter.is_empty());
+        assert!(four_letter.iter().all(|p| p.start.len() == 4));
+
+        let banned = PuzzleGenerator::builder(Arc::clone(&graph))
+            .with_banned_words(["cot", "cord"])
+            .with_max_attempts(200)
+            .build()
+            .generate_batch(5, Difficulty::Easy)
+            .puzzles;
+        assert!(banned.iter().all(|p| !p.path.contains(&"cot".to_string())));
+        assert!(banned.iter().all(|p| !p.path.contains(&"cord".to_string())));
+
+        let filtered = PuzzleGenerator::builder(Arc::clone(&graph))
+            .with_quality_filter(|p| p.start != "cat" && p.end != "cat")
+            .with_max_attempts(200)
+            .build()
+            .generate_batch(5, Difficulty::Easy)
+            .puzzles;
+        assert!(filtered.iter().all(|p| p.start != "cat" && p.end != "cat"));
+
+        let strict = PuzzleGenerator::builder(Arc::clone(&graph))
+            .with_difficulty_thresholds(DifficultyThresholds {
+                easy_max: 2,
+                medium_max: 3,
+                hard_max: 4,
+            })
+            .build();
+        assert_eq!(
+            strict.generate_puzzle("cat", "dog").unwrap().difficulty,
+            Difficulty::Medium
+        );
+        assert_eq!(
+            strict.generate_puzzle("cold", "warm").unwrap().difficulty,
+            Difficulty::Hard
+        );
+    }
+
+    #[test]
+    fn test_word_length_policy() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\ncold\ncord\nword\nworm\nwarm\n");
+        graph.load_base_words_from_str("cat\ndog\ndot\ncold\nword\nwarm\n");
+        let graph = Arc::new(graph);
+        let generator = |easy| {
+            PuzzleGenerator::new(Arc::clone(&graph))
+                .with_word_length_policy(WordLengthPolicy {
+                    easy,
+                    medium: None,
+                    hard: Some(3..=3),
+                })
+                .with_seed(2)
+        };
+
+        let four_letter = generator(Some(4..=4)).generate_batch(3, Difficulty::Easy);
+        assert!(!four_letter.puzzles.is_empty());
+        assert!(four_letter.puzzles.iter().all(|p| p.start.len() == 4));
+        assert!(
+            generator(Some(4..=4))
+                .generate_from_start("cat", 3, Difficulty::Easy)
+                .puzzles
+                .is_empty()
+        );
+
+        let none = generator(Some(5..=6)).generate_batch(3, Difficulty::Easy);
+        assert!(none.puzzles.is_empty());
+        assert_eq!(none.attempts, 0);
+    }
+
+    #[test]
+    fn test_cross_length_ladders() {
+        let rules = vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne];
+        let mut graph = WordGraph::new().with_edge_rules(rules);
+        graph.load_dictionary_from_str("cat\ncot\ncoat\nboat\n");
+        graph.load_base_words_from_str("cat\nboat\n");
+        let graph = Arc::new(graph);
+
+        let generator = PuzzleGenerator::new(Arc::clone(&graph)).with_seed(4);
+        let result = generator.generate_batch(1, Difficulty::Easy);
+        assert_eq!(result.puzzles.len(), 1);
+        let puzzle = &result.puzzles[0];
+        assert_ne!(puzzle.start.len(), puzzle.end.len());
+        assert_eq!(puzzle.path.len(), 3);
+        assert!(generator.verify_puzzle("cat,coat,boat").unwrap());
+
+        let three_letter = generator.with_word_length_policy(WordLengthPolicy {
+            easy: Some(3..=3),
+            medium: None,
+            hard: None,
+        });
+        let none = three_letter.generate_batch(1, Difficulty::Easy);
+        assert!(none.puzzles.is_empty());
+        assert_eq!(none.attempts, 0);
+    }
+
+    #[test]
+    fn test_estimate_capacity() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+        graph.load_base_words_from_str("cat\ndog\ncog\nbat\n");
+        let generator = PuzzleGenerator::new(graph).with_seed(4);
+
+        let easy = generator.estimate_capacity(Difficulty::Easy);
+        assert_eq!(easy.pairs, 6);
+        assert_eq!((easy.sampled_starts, easy.total_starts), (4, 4));
+        assert!(easy.is_exact());
+        assert_eq!(generator.estimate_capacity(Difficulty::Hard).pairs, 0);
+
+        // Every counted pair is one a batch can produce
+        let batch = generator.generate_batch(20, Difficulty::Easy);
+        let pairs: HashSet<(&str, &str)> = batch
+            .puzzles
+            .iter()
+            .map(|p| (p.start.as_str(), p.end.as_str()))
+            .collect();
+        assert!(pairs.len() <= easy.pairs);
+
+        let banned = generator.with_banned_words(["bat"]);
+        assert_eq!(banned.estimate_capacity(Difficulty::Easy).pairs, 4);
+    }
+
+    #[test]
+    fn test_enumerate_all() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\ncold\ncord\nword\n");
+        graph.load_base_words_from_str("cat\ndog\ncog\nbat\ncold\nword\n");
+        let generator = PuzzleGenerator::new(graph);
+
+        let easy = generator.enumerate_all(Some(Difficulty::Easy));
+        assert_eq!(easy.puzzles.len(), 8);
+        assert!(
+            easy.puzzles
+                .iter()
+                .all(|p| p.difficulty == Difficulty::Easy)
+        );
+        assert_eq!(easy.puzzles[0].start, "bat");
+        // cog -> dog is one step, so it is never an attempt
+        assert_eq!(easy.attempts, 8);
+
+        // Every pair a batch finds is in the catalog
+        let catalog: HashSet<(String, String)> = easy
+            .puzzles
+            .iter()
+            .map(|p| (p.start.clone(), p.end.clone()))
+            .collect();
+        let batch = generator
+            .clone()
+            .with_seed(9)
+            .generate_batch(10, Difficulty::Easy);
+        assert!(
+            batch
+                .puzzles
+                .iter()
+                .all(|p| catalog.contains(&(p.start.clone(), p.end.clone())))
+        );
+        assert_eq!(
+            generator.enumerate_all(None).puzzles.len(),
+            easy.puzzles.len() + 2
+        );
+
+        let banned = generator.with_banned_words(["cot"]);
+        assert!(
+            banned
+                .enumerate_all(None)
+                .puzzles
+                .iter()
+                .all(|p| !p.path.contains(&"cot".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_accepted_words() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+        graph.load_accepted_words_from_str("cag\nDAG\n");
+        assert_eq!(graph.get_accepted_words().len(), 2);
+        let generator = PuzzleGenerator::new(graph);
+
+        assert_eq!(generator.verify_puzzle("cat,cag,dag,dog"), Ok(true));
+        assert_eq!(generator.verify_puzzle("cat,cot,cog,dog"), Ok(true));
+        assert_eq!(generator.verify_puzzle("cat,cag,cog,dog"), Ok(true));
+        assert_eq!(generator.verify_puzzle("cat,bat,bag,dag,dog"), Ok(false));
+
+        let summary = generator.verify_many("cat,bat,bag,dag,dog\n");
+        assert_eq!(summary.failures[0].reason, "'bat' is not an accepted word");
+        // Paths come from the dictionary only
+        let report = generator.verify_optimal("cat,cag,dag,dog").unwrap();
+        assert!(report.valid);
+        assert_eq!(report.shortest_steps, Some(3));
+        assert_eq!(
+            generator.generate_puzzle("cat", "dog").unwrap().path,
+            vec!["cat", "cot", "cog", "dog"]
+        );
+    }
+
+    #[test]
+    fn test_shared_graph_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<WordGraph>();
+        assert_send_sync::<PuzzleGenerator>();
+
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncold\ncord\nword\n");
+        let generator = PuzzleGenerator::new(graph);
+
+        std::thread::scope(|scope| {
+            for (start, end, steps) in [("cat", "dog", 3), ("cold", "word", 2)] {
+                let worker = generator.clone();
+                scope.spawn(move || {
+                    let puzzle = worker.generate_puzzle(start, end).unwrap();
+                    assert_eq!(puzzle.path.len() - 1, steps);
+                });
+            }
+        });
+
+        assert_eq!(Arc::strong_count(generator.shared_graph()), 1);
+    }
+
+    #[test]
+    fn test_generate_batch_with_progress() {
+        let mut graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+        graph.set_base_words(["cat", "dog"].map(String::from));
+
+        let generator = PuzzleGenerator::new(graph);
+        let mut reports = Vec::new();
+        let puzzles = generator.generate_batch_with_progress(
+            2,
+            Difficulty::Easy,
+            &AtomicBool::new(false),
+            |progress| reports.push(progress),
+        );
+
+        assert_eq!(puzzles.puzzles.len(), 2);
+        assert_eq!(puzzles.attempts, 2);
+        assert_eq!(puzzles.rejected(), 0);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports.last().unwrap(),
+            &Progress {
+                generated: 2,
+                target: 2,
+                attempts: 2,
+            }
+        );
+
+        // A pre-cancelled run stops before the first attempt
+        let cancelled = generator.generate_batch_with_progress(
+            5,
+            Difficulty::Easy,
+            &AtomicBool::new(true),
+            |_| panic!("no progress expected after cancellation"),
+        );
+        assert!(cancelled.puzzles.is_empty());
+        assert_eq!(cancelled.attempts, 0);
+    }
+
+    #[test]
+    fn test_generate_batch_streaming() {
+        let mut graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+        graph.set_base_words(["cat", "dog"].map(String::from));
+        let generator = PuzzleGenerator::new(graph).with_seed(7);
+
+        let mut streamed = Vec::new();
+        let result = generator
+            .generate_batch_streaming(
+                3,
+                Difficulty::Easy,
+                &AtomicBool::new(false),
+                |_| {},
+                |p| {
+                    streamed.push(p);
+                    Ok(())
+                },
+            )
+            .unwrap();
+        assert!(result.puzzles.is_empty());
+        assert_eq!(result.streamed, 3);
+        assert_eq!(result.generated(), 3);
+        assert_eq!(
+            streamed,
+            generator.generate_batch(3, Difficulty::Easy).puzzles
+        );
+
+        // An error from the callback stops generation
+        let mut calls = 0;
+        let failed = generator.generate_batch_streaming(
+            3,
+            Difficulty::Easy,
+            &AtomicBool::new(false),
+            |_| {},
+            |_| {
+                calls += 1;
+                Err(anyhow!("disk full"))
+            },
+        );
+        assert!(failed.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_puzzles_iterator() {
+        let mut graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+        graph.set_base_words(["cat", "dog"].map(String::from));
+        let generator = PuzzleGenerator::new(graph).with_seed(3);
+
+        let taken: Vec<Puzzle> = generator.puzzles(Difficulty::Easy).take(4).collect();
+        assert_eq!(taken, generator.generate_batch(4, Difficulty::Easy).puzzles);
+
+        // No hard puzzle exists, so only the attempt limit ends the iterator
+        let limited = PuzzleGenerator::builder(Arc::clone(generator.shared_graph()))
+            .with_max_attempts(5)
+            .build();
+        let mut hard = limited.puzzles(Difficulty::Hard);
+        assert_eq!(hard.next(), None);
+        assert_eq!(hard.attempts(), 5);
+
+        let empty = PuzzleGenerator::new(WordGraph::new());
+        assert_eq!(empty.puzzles(Difficulty::Easy).next(), None);
+    }
+
+    #[cfg(all(feature = "stream", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_puzzle_stream() {
+        use tokio_stream::StreamExt;
+
+        let mut graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+        graph.set_base_words(["cat", "dog"].map(String::from));
+        let generator = PuzzleGenerator::new(graph).with_seed(3);
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let streamed: Vec<Puzzle> =
+            runtime.block_on(generator.puzzle_stream(Difficulty::Easy).take(3).collect());
+        assert_eq!(
+            streamed,
+            generator.generate_batch(3, Difficulty::Easy).puzzles
+        );
+    }
+
+    #[test]
+    fn test_generate_batch_hard_uses_distant_endpoints() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("aaa\nbaa\nbba\nbbb\ncbb\nccb\nccc\n");
+        graph.load_base_words_from_str("aaa\nbbb\nccc\n");
+
+        let generator = PuzzleGenerator::new(graph);
+        let puzzles = generator.generate_batch(3, Difficulty::Hard).puzzles;
+
+        assert_eq!(puzzles.len(), 3);
+        for puzzle in puzzles {
+            assert_eq!(puzzle.difficulty, Difficulty::Hard);
+            assert_eq!(puzzle.path.len(), 7);
+        }
+    }
+
+    #[test]
+    fn test_generate_from_start() {
+        // A chain aaa-baa-bba-bbb-cbb-ccb-ccc with every word a base word
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("aaa\nbaa\nbba\nbbb\ncbb\nccb\nccc\n");
+        graph.load_base_words_from_str("aaa\nbaa\nbba\nbbb\ncbb\nccb\nccc\n");
+        let generator = PuzzleGenerator::new(graph).with_seed(3);
+
+        let result = generator.generate_from_start("aaa", 10, Difficulty::Medium);
+        let mut ends: Vec<&str> = result.puzzles.iter().map(|p| p.end.as_str()).collect();
+        ends.sort();
+        assert_eq!(ends, vec!["cbb", "ccb"]);
+        assert_eq!(result.attempts, 2);
+        assert!(result.puzzles.iter().all(|p| p.start == "aaa"));
+        assert!(
+            result
+                .puzzles
+                .iter()
+                .all(|p| p.difficulty == Difficulty::Medium)
+        );
+
+        let first = generator.generate_from_start("aaa", 1, Difficulty::Easy);
+        let second = generator.generate_from_start("aaa", 1, Difficulty::Easy);
+        assert_eq!(first.puzzles, second.puzzles);
+        assert_eq!(
+            generator
+                .generate_from_start("aaa", 5, Difficulty::Hard)
+                .puzzles[0]
+                .end,
+            "ccc"
+        );
+        assert!(
+            generator
+                .generate_from_start("zzz", 5, Difficulty::Easy)
+                .puzzles
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_canonical_hash() {
+        let words = |w: &[&str]| w.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut forward = Puzzle::new(
+            "cat".to_string(),
+            "dog".to_string(),
+            words(&["cat", "cot", "cog", "dog"]),
+        )
+        .unwrap();
+        let other_path = Puzzle::new(
+            "cat".to_string(),
+            "dog".to_string(),
+            words(&["cat", "cot", "dot", "dog"]),
+        )
+        .unwrap();
+        let backward = Puzzle::new(
+            "dog".to_string(),
+            "cat".to_string(),
+            words(&["dog", "cog", "cot", "cat"]),
+        )
+        .unwrap();
+
+        let directed = CanonicalHashOptions::default();
+        let unordered = CanonicalHashOptions {
+            unordered: true,
+            path_independent: false,
+        };
+        let pair = CanonicalHashOptions {
+            unordered: false,
+            path_independent: true,
+        };
+        let unordered_pair = CanonicalHashOptions {
+            unordered: true,
+            path_independent: true,
+        };
+
+        let hash = forward.canonical_hash(directed);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(
+            hash,
+            blake3::hash(b"cat|dog|cat,cot,cog,dog").to_hex().as_str()
+        );
+        // Annotations do not change the hash
+        forward.unique_solution = Some(true);
+        forward.meta = Some(PuzzleMeta::new("ab12", Some(7)));
+        assert_eq!(forward.canonical_hash(directed), hash);
+
+        assert_ne!(other_path.canonical_hash(directed), hash);
+        assert_ne!(backward.canonical_hash(directed), hash);
+        assert_eq!(
+            backward.canonical_hash(unordered),
+            forward.canonical_hash(unordered)
+        );
+        assert_eq!(
+            other_path.canonical_hash(pair),
+            forward.canonical_hash(pair)
+        );
+        assert_ne!(backward.canonical_hash(pair), forward.canonical_hash(pair));
+        assert_eq!(
+            backward.canonical_hash(unordered_pair),
+            other_path.canonical_hash(unordered_pair)
+        );
+    }
+
+    #[test]
+    fn test_to_clue_format() {
+        let path: Vec<String> = ["cold", "cord", "card", "ward", "warm"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let puzzle = Puzzle::new("cold".to_string(), "warm".to_string(), path).unwrap();
+        assert_eq!(
+            puzzle.to_clue_format(),
+            vec!["cold", "co_d", "c_rd", "_ard", "warm"]
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&puzzle.to_json_with_clues().unwrap()).unwrap();
+        assert_eq!(json["path"][1], "cord");
+        assert_eq!(json["clues"][1], "co_d");
+
+        // Inserted letters shift the remaining positions, so they are masked too
+        let golf = Puzzle::new(
+            "cat".to_string(),
+            "card".to_string(),
+            vec!["cat".to_string(), "cart".to_string(), "card".to_string()],
+        )
+        .unwrap();
+        assert_eq!(golf.to_clue_format(), vec!["cat", "ca__", "card"]);
+    }
+
+    #[test]
+    fn test_min_branching_rejects_forced_ladders() {
+        // cat -> cot -> cog -> dog is forced at every step
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+        graph.load_base_words_from_str("cat\ndog\n");
+        let forced = PuzzleGenerator::new(graph);
+
+        let puzzle = forced.generate_puzzle("cat", "dog").unwrap();
+        assert_eq!(forced.branching(&puzzle), Some(2));
+        assert_eq!(forced.generate_batch(1, Difficulty::Easy).puzzles.len(), 1);
+
+        let strict = forced.with_min_branching(MinBranching {
+            easy: 3,
+            medium: 3,
+            hard: 3,
+        });
+        let cancel = AtomicBool::new(false);
+        let result = strict.generate_batch_with_progress(1, Difficulty::Easy, &cancel, |p| {
+            if p.attempts >= 20 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        });
+        assert!(result.puzzles.is_empty());
+        assert_eq!(result.attempts, 20);
+        assert_eq!(result.rejected_by_reason[&RejectReason::LowBranching], 20);
+        assert!(result.to_string().contains("(rejected: low_branching 20)"));
+    }
+
+    #[test]
+    fn test_unique_solution_constraint() {
+        // cat -> dog has two shortest paths (via cog or dot); cat -> cog has one
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+        graph.load_base_words_from_str("cat\ndog\ncog\n");
+        let generator = PuzzleGenerator::new(graph).with_unique_solution(true);
+
+        let puzzles = generator.generate_batch(5, Difficulty::Easy).puzzles;
+        assert_eq!(puzzles.len(), 5);
+        for puzzle in &puzzles {
+            assert_eq!(puzzle.unique_solution, Some(true));
+            assert!(
+                [puzzle.start.as_str(), puzzle.end.as_str()].contains(&"cog"),
+                "{:?}",
+                puzzle
+            );
+        }
+
+        let json = puzzles[0].to_json().unwrap();
+        assert!(json.contains("\"unique_solution\": true"));
+    }
+
+    #[test]
+    fn test_require_reversible() {
+        // cat -> dog goes via cot and cog or via cag and dag, so it works both
+        // ways; cat -> dag only goes via cag
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncag\ndag\n");
+        graph.load_base_words_from_str("cat\ndog\ndag\n");
+        let generator = PuzzleGenerator::new(graph)
+            .with_require_reversible(true)
+            .with_seed(1);
+
+        let result = generator.generate_batch(5, Difficulty::Easy);
+        assert_eq!(result.puzzles.len(), 5);
+        assert!(result.rejected_by_reason[&RejectReason::NotReversible] > 0);
+        for puzzle in &result.puzzles {
+            let reverse = puzzle.reverse_path.as_ref().unwrap();
+            assert_eq!(reverse.len(), puzzle.path.len());
+            assert_eq!(reverse.first(), Some(&puzzle.end));
+            assert_eq!(reverse.last(), Some(&puzzle.start));
+            assert!(reverse[1..3].iter().all(|word| !puzzle.path.contains(word)));
+        }
+
+        let json = result.puzzles[0].to_json().unwrap();
+        assert!(json.contains("\"reverse_path\": ["));
+    }
+
+    #[test]
+    fn test_star_ratings() {
+        // cat -> dog goes via cot and cog or via cot and dot
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+        graph.load_base_words_from_str("cat\ndog\n");
+        let generator = PuzzleGenerator::new(graph).with_star_ratings(StarFormula::default());
+
+        let puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+        let stars = puzzle.stars.unwrap();
+        assert_eq!(
+            stars,
+            StarRating {
+                par: 3,
+                two_stars: 5,
+                three_stars: 3
+            }
+        );
+        assert_eq!(stars.stars_for(4), 2);
+        assert!(puzzle.to_json().unwrap().contains("\"three_stars\": 3"));
+
+        // Only one way to reach par: both thresholds get a step of leeway
+        let mut unique = puzzle.clone();
+        unique.unique_solution = Some(true);
+        generator.annotate_stars(&mut unique, &StarFormula::default());
+        assert_eq!(unique.stars.unwrap().three_stars, 4);
+        assert_eq!(unique.stars.unwrap().two_stars, 6);
+
+        let strict = StarFormula {
+            two_star_factor: 1.0,
+            unique_solution_bonus: 0,
+            ..StarFormula::default()
+        };
+        assert_eq!(strict.rate(3, 1, None).two_stars, 4);
+        assert!(
+            generator.generate_batch(1, Difficulty::Easy).puzzles[0]
+                .stars
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_generate_chain() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\nbag\nbog\n");
+        graph.load_base_words_from_str("cat\ndog\ncog\nbat\nbog\n");
+        let generator = PuzzleGenerator::new(graph).with_seed(3);
+
+        let chain = generator.generate_chain(10, Difficulty::Easy);
+        assert!(!chain.puzzles.is_empty());
+        // The chain never revisits a word, so it ends before 10 puzzles
+        assert!(chain.puzzles.len() < 5);
+        let mut words = vec![chain.puzzles[0].start.clone()];
+        for puzzle in &chain.puzzles {
+            assert_eq!(Some(&puzzle.start), words.last());
+            assert!(puzzle.path.len() - 1 <= 3);
+            words.push(puzzle.end.clone());
+        }
+        let distinct: HashSet<&String> = words.iter().collect();
+        assert_eq!(distinct.len(), words.len());
+
+        assert_eq!(
+            generator.generate_chain(10, Difficulty::Easy).puzzles,
+            chain.puzzles
+        );
+        assert!(
+            generator
+                .generate_chain(0, Difficulty::Easy)
+                .puzzles
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_endpoint_source() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_tagged_from_str("cat\ncot\ncog\ndog\ndot\n", "tournament");
+        graph.load_dictionary_tagged_from_str("cat\ncog\ndog\n", "common");
+        graph.load_base_words_from_str("cat\ncot\ncog\ndog\ndot\n");
+        let generator = PuzzleGenerator::new(graph).with_endpoint_source("common");
+
+        let puzzles = generator.generate_batch(10, Difficulty::Easy).puzzles;
+        assert_eq!(puzzles.len(), 10);
+        for puzzle in &puzzles {
+            for word in [&puzzle.start, &puzzle.end] {
+                assert!(
+                    ["cat", "cog", "dog"].contains(&word.as_str()),
+                    "{:?}",
+                    puzzle
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_endpoint_filters() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+        graph.load_base_words_from_str("cat\ncot\ncog\ndog\ndot\n");
+        let generator = PuzzleGenerator::new(graph)
+            .with_start_filter(EndpointFilter::parse("c*").unwrap())
+            .with_end_filter(EndpointFilter::parse("d_g").unwrap());
+
+        let puzzles = generator.generate_batch(5, Difficulty::Easy).puzzles;
+        assert_eq!(puzzles.len(), 5);
+        for puzzle in &puzzles {
+            assert!(puzzle.start.starts_with('c'), "{:?}", puzzle);
+            assert_eq!(puzzle.end, "dog");
+        }
+
+        // No end word contains "x", so no pair can be picked
+        let generator = generator.with_end_filter(EndpointFilter::Contains("x".to_string()));
+        assert!(generator.pick_random_words().is_err());
+        assert!(
+            generator
+                .generate_batch(5, Difficulty::Easy)
+                .puzzles
+                .is_empty()
+        );
+
+        assert!(EndpointFilter::parse("").is_err());
+        assert!(EndpointFilter::parse("**").is_err());
+        assert!(EndpointFilter::parse("*a*b*").is_err());
+    }
+
+    #[test]
+    fn test_validate_base_words() {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\nbat\nzzz\nfish\nfist\n");
+        graph.load_base_words_from_str("cat\ncot\ndog\nzzz\nfish\nxyzzy\n");
+
+        let generator = PuzzleGenerator::new(graph);
+        let reports = generator.validate_base_words_with(&ValidationThresholds {
+            min_component_size: 3,
+            min_degree: 2,
+        });
+
+        let issues: Vec<(&str, BaseWordIssue)> =
+            reports.iter().map(|r| (r.word.as_str(), r.issue)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("dog", BaseWordIssue::LowDegree { degree: 1 }),
+                ("fish", BaseWordIssue::SmallComponent { size: 2 }),
+                ("xyzzy", BaseWordIssue::NotInDictionary),
+                ("zzz", BaseWordIssue::Isolated),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solvable_pairs() {
+        let graph = WordGraph::from_words(
+            ["cat", "cot", "cog", "dog", "bat", "fish", "fist"].map(String::from),
+        );
+        let generator = PuzzleGenerator::new(graph);
+        let pairs: Vec<(String, String)> = [
+            ("cat", "dog"),
+            (" CAT ", "bat"),
+            ("cat", "cat"),
+            ("dog", "fish"),
+            ("fish", "fist"),
+            ("xyzzy", "cat"),
+        ]
+        .iter()
+        .map(|(start, end)| (start.to_string(), end.to_string()))
+        .collect();
+
+        let reports = generator.solvable_pairs(&pairs);
+        let statuses: Vec<(&str, &str, &PairStatus)> = reports
+            .iter()
+            .map(|r| (r.start.as_str(), r.end.as_str(), &r.status))
+            .collect();
+        let missing = PairStatus::NotInDictionary {
+            word: "xyzzy".to_string(),
+        };
+        assert_eq!(
+            statuses,
+            vec![
+                ("cat", "dog", &PairStatus::Solvable { steps: 3 }),
+                ("cat", "bat", &PairStatus::Solvable { steps: 1 }),
+                ("cat", "cat", &PairStatus::Solvable { steps: 0 }),
+                ("dog", "fish", &PairStatus::Disconnected),
+                ("fish", "fist", &PairStatus::Solvable { steps: 1 }),
+                ("xyzzy", "cat", &missing),
+            ]
+        );
+        assert_eq!(reports.iter().filter(|r| r.is_solvable()).count(), 4);
+    }
+
+    #[test]
+    fn test_puzzle_difficulty() {
+        let puzzle = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(puzzle.difficulty, Difficulty::Easy)); // 3 steps = Easy
+    }
+
+    #[test]
+    fn test_parse_difficulty() {
+        for difficulty in Difficulty::ALL {
+            assert_eq!(difficulty.name().parse::<Difficulty>(), Ok(difficulty));
+        }
+        assert_eq!(" MEDIUM ".parse::<Difficulty>(), Ok(Difficulty::Medium));
+        let error = "mixed".parse::<Difficulty>().unwrap_err();
+        assert_eq!(error.name, "mixed");
+        assert!(error.to_string().contains("easy, medium, hard"));
+    }
+
+    #[test]
+    fn test_puzzle_difficulty_ranges() {
+        // Test Easy: 2-3 steps
+        let easy_2 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        )
+        .unwrap();
+        assert!(matches!(easy_2.difficulty, Difficulty::Easy));
+
+        let easy_3 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(easy_3.difficulty, Difficulty::Easy));
+
+        // Test Medium: 4-5 steps
+        let medium_4 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+                "e".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(medium_4.difficulty, Difficulty::Medium));
+
+        let medium_5 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+                "e".to_string(),
+                "f".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(medium_5.difficulty, Difficulty::Medium));
+
+        // Test Hard: 6-10 steps
+        let hard_6 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+                "e".to_string(),
+                "f".to_string(),
+                "g".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(hard_6.difficulty, Difficulty::Hard));
+
+        let hard_10 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            (0..=10).map(|i| i.to_string()).collect(),
+        )
+        .unwrap();
+        assert!(matches!(hard_10.difficulty, Difficulty::Hard));
+
+        // Test that puzzles with more than 10 steps are rejected
+        let too_hard = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            (0..=11).map(|i| i.to_string()).collect(),
+        );
+        assert!(too_hard.is_none()); // 11 steps should be rejected
+
+        // Test that puzzles with 1 or 0 steps are rejected
+        let too_easy_1 = Puzzle::new(
+            "a".to_string(),
+            "b".to_string(),
+            vec!["a".to_string(), "b".to_string()],
+        );
+        assert!(too_easy_1.is_none()); // 1 step should be rejected
+
+        let too_easy_0 = Puzzle::new("a".to_string(), "b".to_string(), vec!["a".to_string()]);
+        assert!(too_easy_0.is_none()); // 0 steps should be rejected
+    }
+}
@@ -0,0 +1,340 @@
+//! # Difficulty Calibration
+//!
+//! This module relabels puzzle difficulty from how players actually did,
+//! instead of from ladder length alone. Player data is read from a CSV with
+//! one row per exported puzzle ID:
+//!
+//! ```text
+//! puzzle_id,solve_rate,avg_time
+//! cat_dog_001,0.94,21.5
+//! cold_warm_002,0.41,118.0
+//! ```
+//!
+//! `solve_rate` is the share of players who solved the puzzle (0 to 1) and
+//! `avg_time` the average solve time in seconds. Each puzzle gets a hardness
+//! score: the mean of its fail rate and the percentile of its solve time among
+//! the observed puzzles. A `CalibrationModel` sorts the observed puzzles by
+//! hardness and reassigns labels so that the share of easy, medium, and hard
+//! puzzles stays the same as before calibration; only which puzzles carry
+//! which label changes.
+//!
+//! Puzzles are matched to rows by their `start_end` word pair, so both
+//! counter IDs and content-hash IDs work. Puzzles without player data are
+//! labeled from the mean hardness of observed puzzles with the same number
+//! of steps, or keep their label if there are none.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_core::puzzle::calibration::{CalibrationModel, parse_player_stats};
+//! use wordladder_core::puzzle::{Difficulty, Puzzle};
+//!
+//! let ladder = |words: &[&str]| {
+//!     let path: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+//!     Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+//! };
+//! let puzzles = vec![ladder(&["cat", "cot", "dot"]), ladder(&["cat", "cot", "cog", "dog"])];
+//!
+//! let stats = parse_player_stats("puzzle_id,solve_rate,avg_time\n\
+//!                                 cat_dot_001,0.35,140\n\
+//!                                 cat_dog_002,0.98,12\n").unwrap();
+//! let model = CalibrationModel::fit(&stats, &puzzles);
+//!
+//! // Both puzzles were easy, so both stay easy
+//! assert_eq!(Difficulty::from_calibration(&model, &puzzles[0]), Difficulty::Easy);
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle};
+use anyhow::{Result, anyhow};
+use std::collections::{BTreeMap, HashMap};
+
+/// Observed player results for one exported puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerStats {
+    /// Puzzle ID as exported, e.g. `cat_dog_001`
+    pub puzzle_id: String,
+    /// Share of players who solved the puzzle, from 0 to 1
+    pub solve_rate: f64,
+    /// Average solve time in seconds
+    pub avg_time: f64,
+}
+
+impl PlayerStats {
+    /// Returns the `start_end` word pair the puzzle ID refers to.
+    ///
+    /// Accepts IDs with a counter or content-hash suffix (`cat_dog_001`) as
+    /// well as bare pairs (`cat_dog`).
+    pub fn pair_key(&self) -> &str {
+        match self.puzzle_id.matches('_').count() {
+            0 | 1 => &self.puzzle_id,
+            _ => self
+                .puzzle_id
+                .rsplit_once('_')
+                .map_or(self.puzzle_id.as_str(), |(pair, _)| pair),
+        }
+    }
+}
+
+/// Parses player statistics from CSV.
+///
+/// Rows are `puzzle_id,solve_rate,avg_time`. A header row starting with
+/// `puzzle_id`, blank lines, and lines starting with `#` are skipped.
+///
+/// # Arguments
+///
+/// * `csv` - Contents of the CSV file
+///
+/// # Returns
+///
+/// The rows in file order, or an error naming the first malformed line.
+pub fn parse_player_stats(csv: &str) -> Result<Vec<PlayerStats>> {
+    csv.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .filter(|(_, line)| !line.starts_with("puzzle_id"))
+        .map(|(line_number, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let &[puzzle_id, solve_rate, avg_time] = fields.as_slice() else {
+                return Err(anyhow!(
+                    "line {}: expected puzzle_id,solve_rate,avg_time",
+                    line_number
+                ));
+            };
+            let solve_rate: f64 = solve_rate
+                .parse()
+                .ok()
+                .filter(|rate: &f64| (0.0..=1.0).contains(rate))
+                .ok_or_else(|| {
+                    anyhow!("line {}: solve_rate must be between 0 and 1", line_number)
+                })?;
+            let avg_time: f64 = avg_time
+                .parse()
+                .ok()
+                .filter(|time: &f64| time.is_finite() && *time >= 0.0)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "line {}: avg_time must be a non-negative number",
+                        line_number
+                    )
+                })?;
+            Ok(PlayerStats {
+                puzzle_id: puzzle_id.to_string(),
+                solve_rate,
+                avg_time,
+            })
+        })
+        .collect()
+}
+
+/// Difficulty labels fitted to observed player results.
+#[derive(Debug, Clone, Default)]
+pub struct CalibrationModel {
+    /// Hardness score (0 to 1) per `start_end` key of observed puzzles
+    hardness: HashMap<String, f64>,
+    /// Highest hardness labeled easy, or `None` if no observed puzzle is easy
+    easy_max: Option<f64>,
+    /// Highest hardness labeled medium, or `None` if no observed puzzle is medium
+    medium_max: Option<f64>,
+    /// Mean hardness of observed puzzles per step count
+    step_hardness: BTreeMap<usize, f64>,
+}
+
+impl CalibrationModel {
+    /// Fits a model to player statistics for a puzzle set.
+    ///
+    /// Rows for puzzles that are not in `puzzles` are ignored. Rows repeating
+    /// a word pair are averaged.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Player statistics (see `parse_player_stats`)
+    /// * `puzzles` - The puzzle set the statistics were collected on
+    pub fn fit(stats: &[PlayerStats], puzzles: &[Puzzle]) -> Self {
+        let by_pair: HashMap<String, &Puzzle> = puzzles
+            .iter()
+            .map(|puzzle| (format!("{}_{}", puzzle.start, puzzle.end), puzzle))
+            .collect();
+
+        // Average repeated rows per pair: (fail rate sum, time sum, rows)
+        let mut totals: BTreeMap<&str, (f64, f64, usize)> = BTreeMap::new();
+        for row in stats
+            .iter()
+            .filter(|row| by_pair.contains_key(row.pair_key()))
+        {
+            let entry = totals.entry(row.pair_key()).or_default();
+            entry.0 += 1.0 - row.solve_rate;
+            entry.1 += row.avg_time;
+            entry.2 += 1;
+        }
+        let observed: Vec<(&str, f64, f64)> = totals
+            .into_iter()
+            .map(|(pair, (fail, time, rows))| (pair, fail / rows as f64, time / rows as f64))
+            .collect();
+
+        let mut times: Vec<f64> = observed.iter().map(|&(_, _, time)| time).collect();
+        times.sort_by(f64::total_cmp);
+        let time_percentile = |time: f64| {
+            if times.len() < 2 {
+                0.5
+            } else {
+                times.partition_point(|&other| other < time) as f64 / (times.len() - 1) as f64
+            }
+        };
+
+        let mut model = Self::default();
+        let mut ranked: Vec<(f64, Difficulty)> = Vec::new();
+        let mut step_totals: BTreeMap<usize, (f64, usize)> = BTreeMap::new();
+        for (pair, fail, time) in observed {
+            let hardness = (fail + time_percentile(time)) / 2.0;
+            let puzzle = by_pair[pair];
+            model.hardness.insert(pair.to_string(), hardness);
+            ranked.push((hardness, puzzle.difficulty));
+            let entry = step_totals
+                .entry(puzzle.path.len().saturating_sub(1))
+                .or_default();
+            entry.0 += hardness;
+            entry.1 += 1;
+        }
+        model.step_hardness = step_totals
+            .into_iter()
+            .map(|(steps, (sum, count))| (steps, sum / count as f64))
+            .collect();
+
+        // Keep the label shares: the easiest `easy` puzzles stay easy, and so on
+        let easy = ranked
+            .iter()
+            .filter(|(_, d)| *d == Difficulty::Easy)
+            .count();
+        let medium = ranked
+            .iter()
+            .filter(|(_, d)| *d == Difficulty::Medium)
+            .count();
+        ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+        model.easy_max = easy.checked_sub(1).map(|i| ranked[i].0);
+        model.medium_max = (easy + medium).checked_sub(1).map(|i| ranked[i].0);
+        model
+    }
+
+    /// Returns the number of puzzles with player data.
+    pub fn observed(&self) -> usize {
+        self.hardness.len()
+    }
+
+    /// Returns the hardness score (0 to 1) of a puzzle with player data.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to look up
+    pub fn hardness(&self, puzzle: &Puzzle) -> Option<f64> {
+        self.hardness
+            .get(&format!("{}_{}", puzzle.start, puzzle.end))
+            .copied()
+    }
+
+    /// Returns the calibrated difficulty of a puzzle.
+    ///
+    /// See `Difficulty::from_calibration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to label
+    pub fn classify(&self, puzzle: &Puzzle) -> Difficulty {
+        let hardness = self.hardness(puzzle).or_else(|| {
+            self.step_hardness
+                .get(&(puzzle.path.len().saturating_sub(1)))
+                .copied()
+        });
+        let Some(hardness) = hardness else {
+            return puzzle.difficulty;
+        };
+        if self.easy_max.is_some_and(|max| hardness <= max) {
+            Difficulty::Easy
+        } else if self.medium_max.is_some_and(|max| hardness <= max) {
+            Difficulty::Medium
+        } else {
+            Difficulty::Hard
+        }
+    }
+
+    /// Returns copies of the puzzles relabeled with their calibrated difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - The puzzles to relabel
+    pub fn apply(&self, puzzles: &[Puzzle]) -> Vec<Puzzle> {
+        puzzles
+            .iter()
+            .map(|puzzle| Puzzle {
+                difficulty: self.classify(puzzle),
+                ..puzzle.clone()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_parse_player_stats() {
+        let stats = parse_player_stats(
+            "puzzle_id,solve_rate,avg_time\n# comment\n\ncat_dog_001, 0.9, 20\ncold_warm,0.5,75.5\n",
+        )
+        .unwrap();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].pair_key(), "cat_dog");
+        assert_eq!(stats[1].pair_key(), "cold_warm");
+        assert_eq!(stats[1].avg_time, 75.5);
+
+        assert!(parse_player_stats("cat_dog_001,1.5,20\n").is_err());
+        assert!(parse_player_stats("cat_dog_001,0.5\n").is_err());
+        assert!(parse_player_stats("cat_dog_001,0.5,-1\n").is_err());
+    }
+
+    #[test]
+    fn test_fit_swaps_labels_by_observed_hardness() {
+        // A short ladder players struggled with and a long one they breezed through
+        let short = create_test_puzzle(&["cat", "cot", "cog", "dog"]);
+        let long = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        let unobserved = create_test_puzzle(&["hat", "hot", "hog", "dog"]);
+        assert_eq!(short.difficulty, Difficulty::Easy);
+        assert_eq!(long.difficulty, Difficulty::Medium);
+
+        let stats = parse_player_stats(
+            "cat_dog_001,0.30,180\ncold_warm_001,0.95,25\nmissing_pair_001,0.1,999\n",
+        )
+        .unwrap();
+        let model = CalibrationModel::fit(&stats, &[short.clone(), long.clone()]);
+
+        assert_eq!(model.observed(), 2);
+        assert!(model.hardness(&short).unwrap() > model.hardness(&long).unwrap());
+        assert_eq!(
+            Difficulty::from_calibration(&model, &short),
+            Difficulty::Medium
+        );
+        assert_eq!(
+            Difficulty::from_calibration(&model, &long),
+            Difficulty::Easy
+        );
+        // Same step count as the hard-playing short ladder
+        assert_eq!(
+            Difficulty::from_calibration(&model, &unobserved),
+            Difficulty::Medium
+        );
+
+        let relabeled = model.apply(&[short, long]);
+        assert_eq!(relabeled[0].difficulty, Difficulty::Medium);
+        assert_eq!(relabeled[1].difficulty, Difficulty::Easy);
+    }
+
+    #[test]
+    fn test_empty_model_keeps_labels() {
+        let puzzle = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        let model = CalibrationModel::fit(&[], std::slice::from_ref(&puzzle));
+        assert_eq!(model.observed(), 0);
+        assert_eq!(model.classify(&puzzle), Difficulty::Medium);
+    }
+}
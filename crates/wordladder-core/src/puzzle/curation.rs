@@ -0,0 +1,971 @@
+//! # Puzzle Curation
+//!
+//! This module selects a balanced subset of generated puzzles, independent of
+//! the format they are exported in. `BalancedSetBuilder` picks puzzles to match
+//! a difficulty distribution, optionally dropping repeated word pairs,
+//! capping how many puzzles use each word length, and capping how many
+//! puzzles share any one word so a pack does not keep revisiting the same
+//! hub words.
+//!
+//! `build_progression` picks tournament packs instead: one puzzle per requested
+//! optimal length, in increasing order, optionally with no word shared between
+//! the puzzles of a pack.
+//!
+//! `ExportHistory` remembers the puzzle IDs shipped by earlier exports, so a
+//! builder given the history avoids re-shipping the same word pairs within a
+//! lookback window of recent exports. The history is stored as JSON Lines,
+//! one export per line:
+//!
+//! ```json
+//! {"exported_at":1760000000,"ids":["cat_dog_001","cold_warm_002"]}
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_core::puzzle::curation::DifficultyDistribution;
+//! use wordladder_core::puzzle::curation::{BalancedSetBuilder, DedupPolicy, ExportHistory};
+//! use wordladder_core::puzzle::Puzzle;
+//!
+//! let all_puzzles: Vec<Puzzle> = vec![/* generated puzzles */];
+//!
+//! let balanced = BalancedSetBuilder::new()
+//!     .with_ratios(DifficultyDistribution { easy: 0.5, medium: 0.3, hard: 0.2 })
+//!     .with_dedup(DedupPolicy::UniquePairs)
+//!     .with_length_quota(3, 100)
+//!     .with_max_word_reuse(20)
+//!     .with_history(&ExportHistory::new(), Some(4))
+//!     .with_seed(42)
+//!     .build(&all_puzzles, 1000)
+//!     .unwrap();
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle};
+use anyhow::{Context, Result, bail};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Difficulty distribution configuration for mobile puzzle generation.
+///
+/// This struct defines the ratios of easy, medium, and hard puzzles to generate
+/// for mobile applications, ensuring a balanced gameplay experience.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DifficultyDistribution {
+    /// Ratio of easy puzzles (0.0 to 1.0)
+    pub easy: f64,
+    /// Ratio of medium puzzles (0.0 to 1.0)
+    pub medium: f64,
+    /// Ratio of hard puzzles (0.0 to 1.0)
+    pub hard: f64,
+}
+
+impl Default for DifficultyDistribution {
+    fn default() -> Self {
+        Self {
+            easy: 0.4,   // 40% easy
+            medium: 0.4, // 40% medium
+            hard: 0.2,   // 20% hard
+        }
+    }
+}
+
+impl DifficultyDistribution {
+    /// How far the ratios may sum from 1.0 and still be accepted.
+    pub const SUM_TOLERANCE: f64 = 0.01;
+
+    /// Checks that every ratio is within [0, 1] and that they sum to about 1.0.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error naming the offending ratio or the actual sum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::DifficultyDistribution;
+    ///
+    /// assert!(DifficultyDistribution::default().validate().is_ok());
+    ///
+    /// let skewed = DifficultyDistribution { easy: 0.7, medium: 0.7, hard: 0.2 };
+    /// assert!(skewed.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        for (name, ratio) in self.named_ratios() {
+            if !(0.0..=1.0).contains(&ratio) {
+                bail!("The {} ratio must be between 0 and 1, got {}", name, ratio);
+            }
+        }
+        let sum = self.easy + self.medium + self.hard;
+        if (sum - 1.0).abs() > Self::SUM_TOLERANCE {
+            bail!(
+                "Difficulty ratios must sum to 1.0, got {} + {} + {} = {:.2}",
+                self.easy,
+                self.medium,
+                self.hard,
+                sum
+            );
+        }
+        Ok(())
+    }
+
+    /// Scales the ratios so they sum to exactly 1.0, keeping their proportions.
+    ///
+    /// # Returns
+    ///
+    /// The normalized distribution, or an error if a ratio is negative or not
+    /// a finite number, or if all ratios are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::DifficultyDistribution;
+    ///
+    /// let skewed = DifficultyDistribution { easy: 0.7, medium: 0.7, hard: 0.2 };
+    /// let normalized = skewed.normalized().unwrap();
+    /// assert!((normalized.easy - 0.4375).abs() < 1e-9);
+    /// assert!(normalized.validate().is_ok());
+    /// ```
+    pub fn normalized(&self) -> Result<Self> {
+        for (name, ratio) in self.named_ratios() {
+            if !ratio.is_finite() || ratio < 0.0 {
+                bail!(
+                    "The {} ratio must be a non-negative number, got {}",
+                    name,
+                    ratio
+                );
+            }
+        }
+        let sum = self.easy + self.medium + self.hard;
+        if sum == 0.0 {
+            bail!("At least one difficulty ratio must be greater than 0");
+        }
+        Ok(Self {
+            easy: self.easy / sum,
+            medium: self.medium / sum,
+            hard: self.hard / sum,
+        })
+    }
+
+    /// Splits a puzzle count into easy, medium, and hard counts.
+    ///
+    /// Each count is its ratio's share of `total_count`, rounded; rounding
+    /// differences go to medium (or are taken from hard), so the counts
+    /// always sum to `total_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::DifficultyDistribution;
+    ///
+    /// assert_eq!(DifficultyDistribution::default().split(10), [4, 4, 2]);
+    /// let thirds = DifficultyDistribution { easy: 0.34, medium: 0.33, hard: 0.33 };
+    /// assert_eq!(thirds.split(10), [3, 4, 3]);
+    /// ```
+    pub fn split(&self, total_count: usize) -> [usize; 3] {
+        let share = |ratio: f64| (total_count as f64 * ratio).round() as usize;
+        let (easy, mut medium, mut hard) = (share(self.easy), share(self.medium), share(self.hard));
+
+        // Adjust for rounding errors
+        let actual_total = easy + medium + hard;
+        if actual_total < total_count {
+            medium += total_count - actual_total;
+        } else {
+            let mut excess = actual_total - total_count;
+            let from_hard = excess.min(hard);
+            hard -= from_hard;
+            excess -= from_hard;
+            medium -= excess.min(medium);
+        }
+        [easy, medium, hard]
+    }
+
+    /// Returns each ratio with its difficulty name, for error messages.
+    fn named_ratios(&self) -> [(&'static str, f64); 3] {
+        [
+            ("easy", self.easy),
+            ("medium", self.medium),
+            ("hard", self.hard),
+        ]
+    }
+}
+
+/// How repeated puzzles are treated when building a balanced set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DedupPolicy {
+    /// Repeat puzzles as needed to reach the requested count (default)
+    #[default]
+    AllowRepeats,
+    /// Use each start/end word pair at most once, in either direction; the set
+    /// may come out smaller than requested
+    UniquePairs,
+}
+
+/// Builds a subset of puzzles matching a difficulty distribution.
+#[derive(Debug, Clone, Default)]
+pub struct BalancedSetBuilder {
+    /// Share of easy, medium, and hard puzzles
+    ratios: DifficultyDistribution,
+    /// Whether puzzles may repeat
+    dedup: DedupPolicy,
+    /// Most puzzles per endpoint word length; lengths not listed are unlimited
+    length_quotas: HashMap<usize, usize>,
+    /// Most puzzles any one word may appear in, as endpoint or intermediate
+    max_word_reuse: Option<usize>,
+    /// Seed for shuffling, or `None` to seed from system entropy
+    seed: Option<u64>,
+    /// `start_end` keys of puzzles shipped by recent exports, never selected
+    shipped_pairs: HashSet<String>,
+}
+
+impl BalancedSetBuilder {
+    /// Creates a builder using the default 40/40/20 distribution, allowing
+    /// repeats, with no length quotas or word reuse cap, no export history,
+    /// and unseeded shuffling.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the share of easy, medium, and hard puzzles.
+    ///
+    /// # Arguments
+    ///
+    /// * `ratios` - Ratios within [0, 1] that sum to 1.0
+    pub fn with_ratios(mut self, ratios: DifficultyDistribution) -> Self {
+        self.ratios = ratios;
+        self
+    }
+
+    /// Sets how repeated puzzles are treated.
+    ///
+    /// # Arguments
+    ///
+    /// * `dedup` - Whether puzzles may repeat to fill the requested count
+    pub fn with_dedup(mut self, dedup: DedupPolicy) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Caps the number of selected puzzles whose endpoints have a given length.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Word length of the start word, in characters
+    /// * `max` - Most puzzles of that length in the set
+    pub fn with_length_quota(mut self, length: usize, max: usize) -> Self {
+        self.length_quotas.insert(length, max);
+        self
+    }
+
+    /// Caps the number of selected puzzles any single word may appear in.
+    ///
+    /// Every word of a puzzle's path counts, endpoints and intermediate words
+    /// alike, once per puzzle. Puzzles that would push a word over the cap are
+    /// skipped, so the set may come out smaller than requested. A repeated
+    /// puzzle uses its words again, so with `DedupPolicy::AllowRepeats` the
+    /// cap also limits how often one puzzle repeats.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Most selected puzzles sharing one word
+    pub fn with_max_word_reuse(mut self, max: usize) -> Self {
+        self.max_word_reuse = Some(max);
+        self
+    }
+
+    /// Skips puzzles whose word pair was shipped by a recent export.
+    ///
+    /// Pairs are matched in the direction they were exported, so `cat -> dog`
+    /// does not block `dog -> cat`. Skipped puzzles are not used to fill
+    /// shortfalls either, so the set may come out smaller than requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `history` - Puzzle IDs of earlier exports
+    /// * `within` - Number of most recent exports to avoid, or `None` for all
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::{BalancedSetBuilder, ExportHistory};
+    /// use wordladder_core::puzzle::{Difficulty, Puzzle};
+    ///
+    /// let mut history = ExportHistory::new();
+    /// history.record(vec!["cat_dog_001".to_string()], 1_760_000_000);
+    ///
+    /// let puzzle = |start: &str, end: &str| Puzzle {
+    ///     start: start.to_string(),
+    ///     end: end.to_string(),
+    ///     path: vec![start.to_string(), end.to_string()],
+    ///     difficulty: Difficulty::Easy,
+    ///     unique_solution: None,
+    ///     reverse_path: None,
+    ///     stars: None,
+    ///     meta: None,
+    /// };
+    /// let puzzles = vec![puzzle("cat", "dog"), puzzle("cold", "warm")];
+    ///
+    /// let fresh = BalancedSetBuilder::new()
+    ///     .with_history(&history, None)
+    ///     .build(&puzzles, 2)
+    ///     .unwrap();
+    /// assert!(fresh.iter().all(|p| p.start == "cold"));
+    /// ```
+    pub fn with_history(mut self, history: &ExportHistory, within: Option<usize>) -> Self {
+        self.shipped_pairs = history.recent_pairs(within);
+        self
+    }
+
+    /// Seeds the shuffle, so the same input gives the same selection.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed for the random number generator
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Selects a balanced set of puzzles.
+    ///
+    /// Puzzles are shuffled within each difficulty and taken up to that
+    /// difficulty's share of `total_count`, with rounding differences going to
+    /// medium (or taken from hard). Any shortfall is filled from all puzzles.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - All available puzzles to select from
+    /// * `total_count` - Number of puzzles wanted
+    ///
+    /// # Returns
+    ///
+    /// The selected puzzles, or an error if the ratios are invalid (see
+    /// `DifficultyDistribution::validate`). With `DedupPolicy::UniquePairs`,
+    /// length quotas, or a word reuse cap, fewer than `total_count` puzzles
+    /// may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::DifficultyDistribution;
+    /// use wordladder_core::puzzle::curation::BalancedSetBuilder;
+    ///
+    /// let builder = BalancedSetBuilder::new()
+    ///     .with_ratios(DifficultyDistribution { easy: 0.7, medium: 0.7, hard: 0.2 });
+    /// assert!(builder.build(&[], 10).is_err());
+    /// ```
+    pub fn build(&self, puzzles: &[Puzzle], total_count: usize) -> Result<Vec<Puzzle>> {
+        self.ratios.validate()?;
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let groups: Vec<Vec<&Puzzle>> = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+            .iter()
+            .map(|difficulty| {
+                let mut group: Vec<&Puzzle> = puzzles
+                    .iter()
+                    .filter(|p| p.difficulty == *difficulty)
+                    .collect();
+                group.shuffle(&mut rng);
+                group
+            })
+            .collect();
+        let counts = self.ratios.split(total_count);
+
+        let mut selection = Selection::new(self);
+        for (group, count) in groups.iter().zip(counts) {
+            selection.take(group, count);
+        }
+
+        // If we still don't have enough, fill with any available puzzles
+        let all: Vec<&Puzzle> = puzzles.iter().collect();
+        selection.take(&all, total_count.saturating_sub(selection.selected.len()));
+
+        Ok(selection.selected)
+    }
+}
+
+/// Puzzles chosen so far, with the bookkeeping for dedup, length quotas, and
+/// word reuse.
+struct Selection<'a> {
+    /// The builder's policy
+    builder: &'a BalancedSetBuilder,
+    /// Puzzles selected, in order
+    selected: Vec<Puzzle>,
+    /// Word pairs already selected, for `DedupPolicy::UniquePairs`
+    pairs: HashSet<(String, String)>,
+    /// Number of selected puzzles per word length
+    per_length: HashMap<usize, usize>,
+    /// Number of selected puzzles per word, for the word reuse cap
+    per_word: HashMap<String, usize>,
+}
+
+impl<'a> Selection<'a> {
+    fn new(builder: &'a BalancedSetBuilder) -> Self {
+        Self {
+            builder,
+            selected: Vec::new(),
+            pairs: HashSet::new(),
+            per_length: HashMap::new(),
+            per_word: HashMap::new(),
+        }
+    }
+
+    /// Adds up to `count` puzzles from `candidates`, cycling through them if
+    /// repeats are allowed.
+    fn take(&mut self, candidates: &[&Puzzle], count: usize) {
+        let mut added = 0;
+        let mut misses = 0;
+        let mut index = 0;
+        while added < count && !candidates.is_empty() && misses < candidates.len() {
+            if index == candidates.len() {
+                if self.builder.dedup == DedupPolicy::UniquePairs {
+                    break;
+                }
+                index = 0;
+            }
+            let puzzle = candidates[index];
+            index += 1;
+            if self.admit(puzzle) {
+                added += 1;
+                misses = 0;
+            } else {
+                misses += 1;
+            }
+        }
+    }
+
+    /// Selects a puzzle unless it breaks the dedup policy, a length quota, or
+    /// the word reuse cap, or was shipped by a recent export.
+    fn admit(&mut self, puzzle: &Puzzle) -> bool {
+        if self
+            .builder
+            .shipped_pairs
+            .contains(&format!("{}_{}", puzzle.start, puzzle.end))
+        {
+            return false;
+        }
+        let length = puzzle.start.chars().count();
+        let used = self.per_length.get(&length).copied().unwrap_or(0);
+        if self
+            .builder
+            .length_quotas
+            .get(&length)
+            .is_some_and(|&max| used >= max)
+        {
+            return false;
+        }
+        let words: HashSet<&String> = puzzle.path.iter().collect();
+        if let Some(max) = self.builder.max_word_reuse
+            && words
+                .iter()
+                .any(|word| self.per_word.get(*word).is_some_and(|&used| used >= max))
+        {
+            return false;
+        }
+        if self.builder.dedup == DedupPolicy::UniquePairs {
+            let pair = if puzzle.start <= puzzle.end {
+                (puzzle.start.clone(), puzzle.end.clone())
+            } else {
+                (puzzle.end.clone(), puzzle.start.clone())
+            };
+            if !self.pairs.insert(pair) {
+                return false;
+            }
+        }
+        *self.per_length.entry(length).or_insert(0) += 1;
+        if self.builder.max_word_reuse.is_some() {
+            for word in words {
+                *self.per_word.entry(word.clone()).or_insert(0) += 1;
+            }
+        }
+        self.selected.push(puzzle.clone());
+        true
+    }
+}
+
+/// Most candidate puzzles `build_progression` tries before giving up.
+const MAX_PROGRESSION_TRIES: usize = 100_000;
+
+/// Shape of a tournament pack built by `build_progression`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgressionSpec {
+    /// Optimal step count of each puzzle, in pack order, e.g. `[4, 5, 6, 7, 8]`
+    pub steps: Vec<usize>,
+    /// Whether no word may appear in more than one puzzle of the pack,
+    /// counting endpoints and intermediate words
+    pub disjoint_words: bool,
+    /// Seed for shuffling the candidates, or `None` to seed from system entropy
+    pub seed: Option<u64>,
+}
+
+impl ProgressionSpec {
+    /// Creates a spec for puzzles of the given step counts that share no words.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - Optimal step count of each puzzle, in pack order
+    pub fn new(steps: Vec<usize>) -> Self {
+        Self {
+            steps,
+            disjoint_words: true,
+            seed: None,
+        }
+    }
+}
+
+/// Selects a tournament pack: one puzzle per step count of a spec, in order.
+///
+/// A puzzle's step count is the length of its path, which is the shortest
+/// ladder for generated puzzles. Candidates are shuffled, then combined by
+/// backtracking search until every position is filled without reusing a
+/// word pair (or, with `disjoint_words`, any word).
+///
+/// # Arguments
+///
+/// * `puzzles` - All available puzzles to select from
+/// * `spec` - Step counts and constraints of the pack
+///
+/// # Returns
+///
+/// The pack in spec order, or an error if some step count has no puzzle or
+/// no combination satisfies the constraints.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_core::puzzle::curation::{ProgressionSpec, build_progression};
+/// use wordladder_core::puzzle::Puzzle;
+///
+/// let puzzle = |words: &[&str]| {
+///     let path: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+///     Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+/// };
+/// let puzzles = vec![
+///     puzzle(&["cat", "cot", "cog", "dog"]),
+///     puzzle(&["cat", "bat", "bag"]),
+///     puzzle(&["pin", "pan", "man"]),
+/// ];
+///
+/// // "cat -> bag" shares "cat" with the only three-step puzzle
+/// let pack = build_progression(&puzzles, &ProgressionSpec::new(vec![2, 3])).unwrap();
+/// assert_eq!(pack[0].start, "pin");
+/// assert_eq!(pack[1].start, "cat");
+/// ```
+pub fn build_progression(puzzles: &[Puzzle], spec: &ProgressionSpec) -> Result<Vec<Puzzle>> {
+    let mut rng = match spec.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut slots = Vec::with_capacity(spec.steps.len());
+    for (position, &steps) in spec.steps.iter().enumerate() {
+        let mut candidates: Vec<&Puzzle> = puzzles
+            .iter()
+            .filter(|p| p.path.len().saturating_sub(1) == steps)
+            .collect();
+        if candidates.is_empty() {
+            bail!("No {}-step puzzle for position {}", steps, position + 1);
+        }
+        candidates.shuffle(&mut rng);
+        slots.push(candidates);
+    }
+
+    let mut search = ProgressionSearch {
+        disjoint_words: spec.disjoint_words,
+        chosen: Vec::with_capacity(slots.len()),
+        used_words: HashSet::new(),
+        tries: 0,
+    };
+    if search.fill(&slots) {
+        Ok(search.chosen.into_iter().cloned().collect())
+    } else if search.tries >= MAX_PROGRESSION_TRIES {
+        bail!(
+            "Gave up after trying {} puzzles; generate more candidates",
+            MAX_PROGRESSION_TRIES
+        )
+    } else {
+        bail!("No combination of the candidate puzzles satisfies the progression")
+    }
+}
+
+/// Backtracking state of `build_progression`.
+struct ProgressionSearch<'a> {
+    /// Whether puzzles may not share words
+    disjoint_words: bool,
+    /// Puzzles chosen for the filled positions, in order
+    chosen: Vec<&'a Puzzle>,
+    /// Words of the chosen puzzles, for `disjoint_words`
+    used_words: HashSet<&'a str>,
+    /// Candidates tried so far, capped at `MAX_PROGRESSION_TRIES`
+    tries: usize,
+}
+
+impl<'a> ProgressionSearch<'a> {
+    /// Fills the positions after the chosen puzzles, returning `true` once all are filled.
+    fn fill(&mut self, slots: &[Vec<&'a Puzzle>]) -> bool {
+        let Some(candidates) = slots.get(self.chosen.len()) else {
+            return true;
+        };
+        for &puzzle in candidates {
+            if self.tries >= MAX_PROGRESSION_TRIES {
+                return false;
+            }
+            self.tries += 1;
+            if !self.fits(puzzle) {
+                continue;
+            }
+            self.chosen.push(puzzle);
+            if self.disjoint_words {
+                self.used_words
+                    .extend(puzzle.path.iter().map(String::as_str));
+            }
+            if self.fill(slots) {
+                return true;
+            }
+            self.chosen.pop();
+            if self.disjoint_words {
+                for word in &puzzle.path {
+                    self.used_words.remove(word.as_str());
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if a puzzle can join the chosen ones.
+    fn fits(&self, puzzle: &Puzzle) -> bool {
+        if self.disjoint_words {
+            return puzzle
+                .path
+                .iter()
+                .all(|word| !self.used_words.contains(word.as_str()));
+        }
+        !self
+            .chosen
+            .iter()
+            .any(|chosen| chosen.start == puzzle.start && chosen.end == puzzle.end)
+    }
+}
+
+/// One export recorded in an `ExportHistory`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When the export was made, in seconds since the Unix epoch
+    pub exported_at: u64,
+    /// Puzzle IDs shipped by the export, e.g. `cat_dog_001`
+    pub ids: Vec<String>,
+}
+
+impl HistoryEntry {
+    /// Serializes the entry as one JSON Lines record, including the newline.
+    pub fn to_jsonl(&self) -> Result<String> {
+        Ok(format!("{}\n", serde_json::to_string(self)?))
+    }
+}
+
+/// Puzzle IDs shipped by earlier exports, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportHistory {
+    /// Recorded exports, oldest first
+    entries: Vec<HistoryEntry>,
+}
+
+impl ExportHistory {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a history from JSON Lines, one export per line.
+    ///
+    /// Blank lines are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Contents of the history file
+    ///
+    /// # Returns
+    ///
+    /// The history, or an error naming the first line that is not a valid
+    /// export entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::curation::ExportHistory;
+    ///
+    /// let history = ExportHistory::from_jsonl(
+    ///     "{\"exported_at\":1,\"ids\":[\"cat_dog_001\"]}\n\
+    ///      {\"exported_at\":2,\"ids\":[\"cold_warm_a1b2c3d4e5f6\"]}\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(history.len(), 2);
+    /// assert!(history.recent_pairs(Some(1)).contains("cold_warm"));
+    /// assert!(!history.recent_pairs(Some(1)).contains("cat_dog"));
+    /// ```
+    pub fn from_jsonl(content: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = serde_json::from_str(line)
+                .with_context(|| format!("Invalid export history entry on line {}", index + 1))?;
+            entries.push(entry);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Records an export and returns its entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Puzzle IDs shipped by the export
+    /// * `exported_at` - When the export was made, in seconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The new entry; `HistoryEntry::to_jsonl` gives the line to append to the
+    /// history file.
+    pub fn record(&mut self, ids: Vec<String>, exported_at: u64) -> &HistoryEntry {
+        self.entries.push(HistoryEntry { exported_at, ids });
+        self.entries.last().unwrap()
+    }
+
+    /// Returns the recorded exports, oldest first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of recorded exports.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no exports are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the `start_end` word pairs shipped by recent exports.
+    ///
+    /// Works with both counter IDs (`cat_dog_001`) and content-hash IDs
+    /// (`cat_dog_93288671e9fe`); IDs without a suffix are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `within` - Number of most recent exports to include, or `None` for all
+    pub fn recent_pairs(&self, within: Option<usize>) -> HashSet<String> {
+        let skip = within.map_or(0, |within| self.entries.len().saturating_sub(within));
+        self.entries[skip..]
+            .iter()
+            .flat_map(|entry| &entry.ids)
+            .filter_map(|id| id.rsplit_once('_').map(|(pair, _)| pair.to_string()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_puzzle;
+
+    fn test_puzzles() -> Vec<Puzzle> {
+        vec![
+            create_test_puzzle(&["a", "a1", "b"]),
+            create_test_puzzle(&["c", "c1", "c2", "d"]),
+            create_test_puzzle(&["f", "f1", "f2", "f3", "f4", "g"]),
+            create_test_puzzle(&["l", "l1", "l2", "l3", "l4", "l5", "l6", "l7", "m"]),
+        ]
+    }
+
+    fn count(puzzles: &[Puzzle], difficulty: Difficulty) -> usize {
+        puzzles
+            .iter()
+            .filter(|p| p.difficulty == difficulty)
+            .count()
+    }
+
+    #[test]
+    fn test_balanced_set() {
+        let puzzles = test_puzzles();
+        let balanced = BalancedSetBuilder::new()
+            .with_ratios(DifficultyDistribution {
+                easy: 0.5,
+                medium: 0.3,
+                hard: 0.2,
+            })
+            .build(&puzzles, 10)
+            .unwrap();
+
+        // Only 2 easy, 1 medium, and 1 hard puzzle exist, so they repeat to
+        // fill the 5/3/2 split
+        assert_eq!(balanced.len(), 10);
+        assert_eq!(count(&balanced, Difficulty::Easy), 5);
+        assert_eq!(count(&balanced, Difficulty::Medium), 3);
+        assert_eq!(count(&balanced, Difficulty::Hard), 2);
+
+        let invalid = BalancedSetBuilder::new().with_ratios(DifficultyDistribution {
+            easy: 1.5,
+            medium: -0.3,
+            hard: -0.2,
+        });
+        assert!(invalid.build(&puzzles, 10).is_err());
+    }
+
+    #[test]
+    fn test_balanced_set_dedup_and_quotas() {
+        let mut puzzles = test_puzzles();
+        puzzles.push(create_test_puzzle(&["b", "b1", "a"]));
+        puzzles.push(create_test_puzzle(&["xy", "xy1", "zw"]));
+
+        let unique = BalancedSetBuilder::new()
+            .with_dedup(DedupPolicy::UniquePairs)
+            .with_seed(7)
+            .build(&puzzles, 10)
+            .unwrap();
+        assert_eq!(unique.len(), 5);
+        assert_eq!(count(&unique, Difficulty::Easy), 3);
+
+        let quota = BalancedSetBuilder::new()
+            .with_length_quota(1, 2)
+            .build(&puzzles, 10)
+            .unwrap();
+        assert_eq!(quota.iter().filter(|p| p.start.len() == 1).count(), 2);
+        assert!(quota.iter().all(|p| p.start.len() == 1 || p.start == "xy"));
+
+        let first = BalancedSetBuilder::new()
+            .with_seed(3)
+            .build(&puzzles, 6)
+            .unwrap();
+        let second = BalancedSetBuilder::new()
+            .with_seed(3)
+            .build(&puzzles, 6)
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_balanced_set_max_word_reuse() {
+        let puzzle = |words: &str, difficulty: Difficulty| {
+            let path: Vec<String> = words.split(',').map(String::from).collect();
+            Puzzle {
+                difficulty,
+                ..Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+            }
+        };
+        let puzzles = vec![
+            puzzle("bore,core,care", Difficulty::Easy),
+            puzzle("more,core,cure", Difficulty::Easy),
+            puzzle("fore,core,cord", Difficulty::Easy),
+            puzzle("pin,pan,man", Difficulty::Easy),
+            puzzle("cold,cord,card,ward,warm", Difficulty::Medium),
+        ];
+        let ratios = DifficultyDistribution {
+            easy: 0.8,
+            medium: 0.2,
+            hard: 0.0,
+        };
+
+        let varied = BalancedSetBuilder::new()
+            .with_ratios(ratios.clone())
+            .with_dedup(DedupPolicy::UniquePairs)
+            .with_max_word_reuse(2)
+            .with_seed(5)
+            .build(&puzzles, 5)
+            .unwrap();
+        assert_eq!(varied.len(), 4);
+        let uses = |word: &str| {
+            varied
+                .iter()
+                .filter(|p| p.path.iter().any(|w| w == word))
+                .count()
+        };
+        assert_eq!(uses("core"), 2);
+        assert!(uses("cord") <= 2);
+
+        // With repeats allowed, each puzzle is used at most once under a cap of 1
+        let once = BalancedSetBuilder::new()
+            .with_ratios(ratios)
+            .with_max_word_reuse(1)
+            .build(&puzzles, 10)
+            .unwrap();
+        let mut words = HashSet::new();
+        assert!(once.iter().flat_map(|p| &p.path).all(|w| words.insert(w)));
+    }
+
+    #[test]
+    fn test_build_progression() {
+        let puzzle = |words: &str| {
+            let path: Vec<String> = words.split(',').map(String::from).collect();
+            Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+        };
+        let puzzles = vec![
+            puzzle("cold,cord,card,ward,warm"),
+            puzzle("lead,load,goad,gold"),
+            puzzle("bake,lake,like,line,lint,mint"),
+            puzzle("cold,bold,bolt,boat"),
+            puzzle("fish,dish,dash,cash,case,cast,mast"),
+            puzzle("hint,mint,mine,mane,cane,cone,code"),
+        ];
+
+        let mut spec = ProgressionSpec::new(vec![3, 4, 5, 6]);
+        spec.seed = Some(1);
+        let pack = build_progression(&puzzles, &spec).unwrap();
+        let steps: Vec<usize> = pack.iter().map(|p| p.path.len() - 1).collect();
+        assert_eq!(steps, vec![3, 4, 5, 6]);
+        // "cold -> boat" shares "cold" with the only four-step puzzle, and
+        // "fish -> mast" is the only six-step puzzle without "mint"
+        assert_eq!(pack[0].start, "lead");
+        assert_eq!(pack[3].start, "fish");
+        let mut words = HashSet::new();
+        assert!(pack.iter().flat_map(|p| &p.path).all(|w| words.insert(w)));
+
+        // Without the disjoint constraint any three-step puzzle works
+        spec.disjoint_words = false;
+        assert_eq!(build_progression(&puzzles, &spec).unwrap().len(), 4);
+
+        let missing = build_progression(&puzzles, &ProgressionSpec::new(vec![3, 9]));
+        assert!(missing.unwrap_err().to_string().contains("9-step"));
+        let clash = build_progression(&puzzles, &ProgressionSpec::new(vec![5, 6, 6]));
+        assert!(clash.is_err());
+    }
+
+    #[test]
+    fn test_export_history() {
+        let mut history = ExportHistory::new();
+        history.record(vec!["a_b_001".to_string(), "c_d_001".to_string()], 1);
+        history.record(vec!["f_g_4f2a9c1b7e3d".to_string()], 2);
+        let content: String = history
+            .entries()
+            .iter()
+            .map(|entry| entry.to_jsonl().unwrap())
+            .collect();
+        let history = ExportHistory::from_jsonl(&format!("{}\n", content)).unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(ExportHistory::from_jsonl("{\"ids\": 3}\n").is_err());
+
+        let mut puzzles = test_puzzles();
+        puzzles.push(create_test_puzzle(&["b", "b1", "a"]));
+        let selected_starts = |within: Option<usize>| {
+            BalancedSetBuilder::new()
+                .with_history(&history, within)
+                .build(&puzzles, 10)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.start)
+                .collect::<std::collections::BTreeSet<String>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        // "b -> a" is not blocked by the shipped "a -> b"
+        assert_eq!(selected_starts(None), vec!["b", "l"]);
+        assert_eq!(selected_starts(Some(1)), vec!["a", "b", "c", "l"]);
+        assert_eq!(selected_starts(Some(0)).len(), 5);
+    }
+}
@@ -0,0 +1,601 @@
+//! # Endpoint Pair Selection
+//!
+//! This module decides which start and end words batch generation tries next.
+//! `PuzzleGenerator` delegates the choice to a `PairSelector`, so strategies
+//! can be swapped without touching the rest of the generation pipeline:
+//!
+//! - **`RandomUniform`**: every candidate word is equally likely
+//! - **`FrequencyWeighted`**: common words are picked more often
+//! - **`DistanceTargeted`**: hard puzzles draw the end word among words known to
+//!   be far from the start, using another selector's weighting (the default)
+//! - **`EndpointQuota`**: caps how many puzzles of a batch any one word may
+//!   start or end, so high-degree words cannot dominate a batch
+//!
+//! Custom strategies implement `PairSelector` and are installed with
+//! `PuzzleGenerator::with_pair_selector`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use rand::RngCore;
+//! use rand::seq::IteratorRandom;
+//! use wordladder_core::graph::WordGraph;
+//! use wordladder_core::puzzle::PuzzleGenerator;
+//! use wordladder_core::puzzle::selection::{PairContext, PairSelector};
+//!
+//! /// Starts from the word with the most neighbors and ends anywhere else.
+//! struct BestConnectedStart;
+//!
+//! impl PairSelector for BestConnectedStart {
+//!     fn select_pair(
+//!         &self,
+//!         context: &PairContext<'_>,
+//!         rng: &mut dyn RngCore,
+//!     ) -> Option<(String, String)> {
+//!         let start = context
+//!             .starts
+//!             .iter()
+//!             .max_by_key(|word| context.graph().get_neighbors(word).map_or(0, |n| n.count()))?;
+//!         let end = context.ends.iter().filter(|w| *w != start).choose(rng)?;
+//!         Some((start.clone(), end.clone()))
+//!     }
+//! }
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary_from_str("cat\ncot\ncog\ndog\n");
+//! graph.load_base_words_from_str("cat\ncot\ndog\n");
+//!
+//! let generator = PuzzleGenerator::new(graph).with_pair_selector(BestConnectedStart);
+//! let (start, _) = generator.pick_random_words().unwrap();
+//! assert_eq!(start, "cot");
+//! ```
+
+use crate::graph::WordGraph;
+use crate::puzzle::{Difficulty, DifficultyThresholds, PuzzleGenerator};
+use anyhow::{Result, bail};
+use rand::RngCore;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Draws a selector makes among all candidates before falling back to
+/// copying the ones it may pick.
+const REDRAWS: usize = 8;
+
+/// What a `PairSelector` may look at when choosing a word pair.
+pub struct PairContext<'a> {
+    /// Candidate start words of one randomly chosen word length (of every
+    /// length when an edge rule changes word length), sorted and already
+    /// filtered by the generator's restrictions
+    pub starts: &'a [String],
+    /// Candidate end words of the same length, or of every length; at least
+    /// one differs from a start word
+    pub ends: &'a [String],
+    /// Difficulty being generated, or `None` outside batch generation
+    pub difficulty: Option<Difficulty>,
+    /// The generator asking for the pair
+    generator: &'a PuzzleGenerator,
+    /// Number of accepted puzzles per word that started or ended them in the
+    /// current batch
+    endpoint_uses: &'a HashMap<String, usize>,
+    /// Uses after which a word is no longer an end word, set by `EndpointQuota`
+    max_endpoint_uses: Option<usize>,
+}
+
+impl<'a> PairContext<'a> {
+    /// Creates the context for one pair selection.
+    pub(super) fn new(
+        generator: &'a PuzzleGenerator,
+        starts: &'a [String],
+        ends: &'a [String],
+        difficulty: Option<Difficulty>,
+        endpoint_uses: &'a HashMap<String, usize>,
+    ) -> Self {
+        Self {
+            starts,
+            ends,
+            difficulty,
+            generator,
+            endpoint_uses,
+            max_endpoint_uses: None,
+        }
+    }
+
+    /// Returns the word graph puzzles are generated from.
+    pub fn graph(&self) -> &WordGraph {
+        &self.generator.graph
+    }
+
+    /// Returns the generator's step counts separating the difficulty levels.
+    pub fn thresholds(&self) -> &DifficultyThresholds {
+        &self.generator.thresholds
+    }
+
+    /// Returns `true` if a word of any length may be used as an end word.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to check against the base words, the generator's
+    ///   endpoint source, length, banned-word, and end filter restrictions,
+    ///   and any `EndpointQuota` wrapping the selector
+    pub fn is_end_word(&self, word: &str) -> bool {
+        self.generator.is_end_word(word)
+            && self
+                .max_endpoint_uses
+                .is_none_or(|max| self.endpoint_uses(word) < max)
+    }
+
+    /// Returns how many puzzles accepted so far in the current batch start or
+    /// end at a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to look up
+    pub fn endpoint_uses(&self, word: &str) -> usize {
+        self.endpoint_uses.get(word).copied().unwrap_or(0)
+    }
+}
+
+/// Strategy choosing the start and end words batch generation tries.
+///
+/// All methods have defaults: `choose_word` picks uniformly,
+/// `select_pair` picks a start word and a different end word from the
+/// candidates with `choose_word` (redrawing the end word when it equals the
+/// start), and `is_exhausted` never gives up.
+/// Weighting strategies only override `choose_word`.
+pub trait PairSelector: Send + Sync {
+    /// Picks one word from a list.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - Words to choose from
+    /// * `rng` - Random number generator of the current generation call
+    ///
+    /// # Returns
+    ///
+    /// The chosen word, or `None` if the list is empty.
+    fn choose_word<'w>(&self, words: &'w [String], rng: &mut dyn RngCore) -> Option<&'w String> {
+        words.choose(rng)
+    }
+
+    /// Picks the start and end words of the next attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Candidate words and generation settings
+    /// * `rng` - Random number generator of the current generation call
+    ///
+    /// # Returns
+    ///
+    /// Two distinct words, or `None` if no suitable pair was found; batch
+    /// generation counts that as a `RejectReason::NoDistantEndpoint`.
+    fn select_pair(
+        &self,
+        context: &PairContext<'_>,
+        rng: &mut dyn RngCore,
+    ) -> Option<(String, String)> {
+        let start = self.choose_word(context.starts, rng)?;
+        for _ in 0..REDRAWS {
+            let end = self.choose_word(context.ends, rng)?;
+            if end != start {
+                return Some((start.clone(), end.clone()));
+            }
+        }
+        // The start word keeps being drawn, e.g. by a heavy weight
+        let others: Vec<String> = context
+            .ends
+            .iter()
+            .filter(|word| *word != start)
+            .cloned()
+            .collect();
+        let end = self.choose_word(&others, rng)?;
+        Some((start.clone(), end.clone()))
+    }
+
+    /// Returns `true` if `select_pair` can find no more pairs among these
+    /// candidates for the rest of the batch.
+    ///
+    /// Batch generation asks after `select_pair` returned `None`, and stops
+    /// early once every word length is exhausted instead of retrying until
+    /// the attempt limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Candidate words and generation settings
+    fn is_exhausted(&self, _context: &PairContext<'_>) -> bool {
+        false
+    }
+}
+
+impl<S: PairSelector + ?Sized> PairSelector for Arc<S> {
+    fn choose_word<'w>(&self, words: &'w [String], rng: &mut dyn RngCore) -> Option<&'w String> {
+        (**self).choose_word(words, rng)
+    }
+
+    fn select_pair(
+        &self,
+        context: &PairContext<'_>,
+        rng: &mut dyn RngCore,
+    ) -> Option<(String, String)> {
+        (**self).select_pair(context, rng)
+    }
+
+    fn is_exhausted(&self, context: &PairContext<'_>) -> bool {
+        (**self).is_exhausted(context)
+    }
+}
+
+/// Picks every candidate word equally often.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomUniform;
+
+impl PairSelector for RandomUniform {}
+
+/// Picks common words more often, weighting each word by `1 / rank`.
+///
+/// A word of rank 1 is drawn a hundred times as often as the word of rank
+/// 100. Words missing from the ranks get the weight of one rank past the last
+/// ranked word.
+#[derive(Debug, Clone, Default)]
+pub struct FrequencyWeighted {
+    /// Frequency rank per word, 1 being the most common
+    ranks: HashMap<String, usize>,
+}
+
+impl FrequencyWeighted {
+    /// Creates a selector from word frequency ranks.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranks` - Frequency rank per word, 1 being the most common (see
+    ///   `WordMetadata::parse_frequency_list`)
+    ///
+    /// # Returns
+    ///
+    /// The selector, or an error if a rank is 0, which would give its word
+    /// an infinite weight.
+    pub fn new(ranks: HashMap<String, usize>) -> Result<Self> {
+        if let Some((word, _)) = ranks.iter().find(|&(_, &rank)| rank == 0) {
+            bail!("Frequency rank of {} must be at least 1", word);
+        }
+        Ok(Self { ranks })
+    }
+}
+
+impl PairSelector for FrequencyWeighted {
+    fn choose_word<'w>(&self, words: &'w [String], rng: &mut dyn RngCore) -> Option<&'w String> {
+        let unranked = self.ranks.len() + 1;
+        words
+            .choose_weighted(rng, |word| {
+                1.0 / *self.ranks.get(word).unwrap_or(&unranked) as f64
+            })
+            .ok()
+    }
+}
+
+/// Picks end words known to be far from the start word.
+///
+/// Random pairs are rarely far apart, so for hard puzzles the end word is
+/// drawn among endpoints at least `hard_min` steps from the start (see
+/// `WordGraph::farthest_words`), of any length. Other difficulties use the
+/// inner selector's pair unchanged, unless `with_min_steps` sets a distance
+/// for every difficulty. Words are chosen with the inner selector's weighting.
+#[derive(Debug, Clone, Default)]
+pub struct DistanceTargeted<S = RandomUniform> {
+    /// Selector whose weighting picks the words
+    inner: S,
+    /// Distance required for every difficulty, or `None` to target hard only
+    min_steps: Option<usize>,
+}
+
+impl<S: PairSelector> DistanceTargeted<S> {
+    /// Wraps a selector, targeting distance for hard puzzles only.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Selector whose weighting picks the start and end words
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use wordladder_core::puzzle::selection::{DistanceTargeted, FrequencyWeighted};
+    ///
+    /// let ranks = HashMap::from([("cold".to_string(), 1), ("warm".to_string(), 2)]);
+    /// let selector = DistanceTargeted::new(FrequencyWeighted::new(ranks).unwrap()).with_min_steps(4);
+    /// ```
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            min_steps: None,
+        }
+    }
+
+    /// Requires the end word to be at least `min_steps` from the start for
+    /// every difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_steps` - Fewest steps between the chosen start and end words
+    pub fn with_min_steps(mut self, min_steps: usize) -> Self {
+        self.min_steps = Some(min_steps);
+        self
+    }
+}
+
+impl<S: PairSelector> PairSelector for DistanceTargeted<S> {
+    fn choose_word<'w>(&self, words: &'w [String], rng: &mut dyn RngCore) -> Option<&'w String> {
+        self.inner.choose_word(words, rng)
+    }
+
+    fn select_pair(
+        &self,
+        context: &PairContext<'_>,
+        rng: &mut dyn RngCore,
+    ) -> Option<(String, String)> {
+        let min_steps = match (self.min_steps, context.difficulty) {
+            (Some(min_steps), _) => min_steps,
+            (None, Some(Difficulty::Hard)) => context.thresholds().hard_min(),
+            (None, _) => return self.inner.select_pair(context, rng),
+        };
+
+        let start = self.inner.choose_word(context.starts, rng)?;
+        let distant: Vec<String> = context
+            .graph()
+            .farthest_words(start, min_steps)
+            .into_iter()
+            .filter(|word| context.is_end_word(word))
+            .collect();
+        let end = self.inner.choose_word(&distant, rng)?;
+        Some((start.clone(), end.clone()))
+    }
+
+    fn is_exhausted(&self, context: &PairContext<'_>) -> bool {
+        self.inner.is_exhausted(context)
+    }
+}
+
+/// Lets each word start or end at most `max_uses` puzzles of a batch.
+///
+/// Uniform sampling keeps returning to hub words: a word with many neighbors
+/// yields a puzzle from most pairs it is drawn in, so it ends up in far more
+/// puzzles than its share. This selector hides every word that already
+/// starts or ends `max_uses` accepted puzzles of the batch from the inner
+/// selector, which picks among the remaining words as usual. With
+/// `max_uses` of 1 every puzzle of a batch has its own two endpoints, and
+/// low limits spread a batch round-robin over the base words.
+///
+/// Uses are counted per call to `PuzzleGenerator::generate_batch` (or over
+/// the whole iterator returned by `PuzzleGenerator::puzzles`). Once too few
+/// words are left to form a pair, the batch ends early with fewer puzzles
+/// than requested. Words that can still form pairs are tried until the
+/// attempt limit even if none of their pairs makes a puzzle, so set one with
+/// `PuzzleGeneratorBuilder::with_max_attempts` for small word lists.
+#[derive(Debug, Clone)]
+pub struct EndpointQuota<S = RandomUniform> {
+    /// Selector picking among the words still under their quota
+    inner: S,
+    /// Most puzzles of a batch any one word may start or end
+    max_uses: usize,
+}
+
+impl<S: PairSelector> EndpointQuota<S> {
+    /// Wraps a selector, capping how often each word may be an endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Selector picking the start and end words
+    /// * `max_uses` - Most puzzles of a batch any one word may start or end
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_core::puzzle::selection::{DistanceTargeted, EndpointQuota, RandomUniform};
+    ///
+    /// let selector = EndpointQuota::new(DistanceTargeted::new(RandomUniform), 2);
+    /// ```
+    pub fn new(inner: S, max_uses: usize) -> Self {
+        Self { inner, max_uses }
+    }
+
+    /// Returns `true` if a word is still under its quota.
+    fn is_available(&self, context: &PairContext<'_>, word: &str) -> bool {
+        context.endpoint_uses(word) < self.max_uses
+    }
+
+    /// Returns the words of a list still under their quota.
+    fn available(&self, context: &PairContext<'_>, words: &[String]) -> Vec<String> {
+        words
+            .iter()
+            .filter(|word| self.is_available(context, word))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<S: PairSelector> PairSelector for EndpointQuota<S> {
+    fn choose_word<'w>(&self, words: &'w [String], rng: &mut dyn RngCore) -> Option<&'w String> {
+        self.inner.choose_word(words, rng)
+    }
+
+    fn select_pair(
+        &self,
+        context: &PairContext<'_>,
+        rng: &mut dyn RngCore,
+    ) -> Option<(String, String)> {
+        let context = PairContext {
+            max_endpoint_uses: Some(self.max_uses),
+            ..*context
+        };
+        // Redrawing a pair over the quota picks from the same distribution as
+        // the words under it, without copying them while most still are
+        for _ in 0..REDRAWS {
+            let (start, end) = self.inner.select_pair(&context, rng)?;
+            if self.is_available(&context, &start) && self.is_available(&context, &end) {
+                return Some((start, end));
+            }
+        }
+        let starts = self.available(&context, context.starts);
+        let ends = self.available(&context, context.ends);
+        let context = PairContext {
+            starts: &starts,
+            ends: &ends,
+            ..context
+        };
+        self.inner.select_pair(&context, rng)
+    }
+
+    fn is_exhausted(&self, context: &PairContext<'_>) -> bool {
+        let mut ends = context
+            .ends
+            .iter()
+            .filter(|word| self.is_available(context, word));
+        let mut starts = context
+            .starts
+            .iter()
+            .filter(|word| self.is_available(context, word));
+        let has_pair = match (ends.next(), ends.next()) {
+            (None, _) => false,
+            (Some(end), None) => starts.any(|start| start != end),
+            _ => starts.next().is_some(),
+        };
+        !has_pair || self.inner.is_exhausted(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn generator() -> PuzzleGenerator {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cold\ncord\ncard\nward\nwarm\nworm\nword\n");
+        graph.load_base_words_from_str("cold\ncord\nwarm\nworm\n");
+        PuzzleGenerator::new(graph)
+    }
+
+    #[test]
+    fn test_pair_selectors() {
+        let generator = generator();
+        let candidates: Vec<String> = ["cold", "cord", "warm", "worm"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let mut rng = StdRng::seed_from_u64(1);
+        let uses = HashMap::new();
+
+        let context = PairContext::new(
+            &generator,
+            &candidates,
+            &candidates,
+            Some(Difficulty::Easy),
+            &uses,
+        );
+        for _ in 0..20 {
+            let (start, end) = RandomUniform.select_pair(&context, &mut rng).unwrap();
+            assert_ne!(start, end);
+        }
+
+        // Unranked words weigh 1/3, so "cold" dominates
+        let weighted = FrequencyWeighted::new(HashMap::from([
+            ("cold".to_string(), 1),
+            ("worm".to_string(), 2),
+        ]))
+        .unwrap();
+        let starts = (0..200)
+            .filter(|_| weighted.select_pair(&context, &mut rng).unwrap().0 == "cold")
+            .count();
+        assert!(starts > 70, "cold picked {} times", starts);
+        assert!(FrequencyWeighted::new(HashMap::from([("cold".to_string(), 0)])).is_err());
+
+        // cold-cord-card-ward-warm is the only pair 4 steps apart
+        let distant = DistanceTargeted::new(RandomUniform).with_min_steps(4);
+        let ends = vec!["cold".to_string(), "warm".to_string()];
+        let context = PairContext::new(&generator, &ends, &ends, Some(Difficulty::Easy), &uses);
+        for _ in 0..20 {
+            let (start, end) = distant.select_pair(&context, &mut rng).unwrap();
+            let steps = generator
+                .graph
+                .find_shortest_path(&start, &end)
+                .unwrap()
+                .len()
+                - 1;
+            assert_eq!(steps, 4, "{} -> {}", start, end);
+        }
+        // Without a fixed distance, only hard puzzles (6+ steps) are targeted,
+        // and no pair in this graph is that far apart
+        let hard_only = DistanceTargeted::new(RandomUniform);
+        let easy = PairContext::new(
+            &generator,
+            &candidates,
+            &candidates,
+            Some(Difficulty::Easy),
+            &uses,
+        );
+        let hard = PairContext::new(
+            &generator,
+            &candidates,
+            &candidates,
+            Some(Difficulty::Hard),
+            &uses,
+        );
+        assert!(hard_only.select_pair(&easy, &mut rng).is_some());
+        assert!(hard_only.select_pair(&hard, &mut rng).is_none());
+
+        let lonely = vec!["cold".to_string()];
+        let context = PairContext::new(&generator, &lonely, &lonely, Some(Difficulty::Easy), &uses);
+        assert!(RandomUniform.select_pair(&context, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_endpoint_quota() {
+        let generator = generator();
+        let candidates: Vec<String> = ["cold", "cord", "warm", "worm"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let uses = HashMap::from([("cold".to_string(), 2), ("warm".to_string(), 1)]);
+        let context = PairContext::new(
+            &generator,
+            &candidates,
+            &candidates,
+            Some(Difficulty::Easy),
+            &uses,
+        );
+        let quota = EndpointQuota::new(RandomUniform, 2);
+        for _ in 0..20 {
+            let (start, end) = quota.select_pair(&context, &mut rng).unwrap();
+            assert!(start != "cold" && end != "cold", "{} -> {}", start, end);
+        }
+        assert!(!quota.is_exhausted(&context));
+
+        // warm is cold's only end word 4 steps away, and it is used up
+        let ends = vec!["cold".to_string(), "warm".to_string()];
+        let uses = HashMap::from([("warm".to_string(), 1)]);
+        let context = PairContext::new(&generator, &ends, &ends, Some(Difficulty::Easy), &uses);
+        let distant = EndpointQuota::new(DistanceTargeted::new(RandomUniform).with_min_steps(4), 1);
+        assert!(distant.select_pair(&context, &mut rng).is_none());
+        assert!(distant.is_exhausted(&context));
+
+        // Batches stop once no pair is left and never reuse an endpoint
+        let mut generator = generator;
+        generator.max_attempts = Some(200);
+        let batch = generator
+            .with_max_endpoint_reuse(1)
+            .generate_batch(10, Difficulty::Easy);
+        let mut endpoints: Vec<&String> = batch
+            .puzzles
+            .iter()
+            .flat_map(|puzzle| [&puzzle.start, &puzzle.end])
+            .collect();
+        let total = endpoints.len();
+        endpoints.sort();
+        endpoints.dedup();
+        assert!(total > 0);
+        assert_eq!(endpoints.len(), total);
+    }
+}
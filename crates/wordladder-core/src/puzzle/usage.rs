@@ -0,0 +1,210 @@
+//! # Word Usage Statistics
+//!
+//! This module counts how often each word appears across the paths of a
+//! puzzle set. A hub word such as "core" can end up in a large share of the
+//! generated ladders, which makes a pack feel repetitive; `WordUsage` finds
+//! such words so curators can drop puzzles or regenerate without them.
+//!
+//! Each word is counted at most once per puzzle, separately as an endpoint
+//! (start or end word) and as an intermediate word. Only intermediate words
+//! are flagged as over-used, since endpoints are picked from the base words
+//! and are spread out by the pair selector already.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_core::puzzle::Puzzle;
+//! use wordladder_core::puzzle::usage::WordUsage;
+//!
+//! let ladder = |words: &[&str]| {
+//!     let path: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+//!     Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+//! };
+//! let puzzles = vec![
+//!     ladder(&["cat", "cot", "cog", "dog"]),
+//!     ladder(&["hat", "hot", "cot", "cog"]),
+//!     ladder(&["pin", "pan", "man"]),
+//! ];
+//!
+//! let usage = WordUsage::from_puzzles(&puzzles);
+//! let cot = usage.get("cot").unwrap();
+//! assert_eq!(cot.intermediate, 2);
+//!
+//! // "cot" is an intermediate word of two of the three puzzles
+//! let overused: Vec<&str> = usage
+//!     .overused_intermediates(0.5)
+//!     .iter()
+//!     .map(|count| count.word.as_str())
+//!     .collect();
+//! assert_eq!(overused, vec!["cot"]);
+//! ```
+
+use crate::puzzle::Puzzle;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// How often one word appears across a puzzle set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WordCount {
+    /// The word
+    pub word: String,
+    /// Number of puzzles whose path contains the word
+    pub puzzles: usize,
+    /// Number of puzzles with the word as an intermediate word
+    pub intermediate: usize,
+    /// Number of puzzles with the word as start or end word
+    pub endpoint: usize,
+}
+
+/// Word occurrence counts across the paths of a puzzle set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordUsage {
+    /// Number of puzzles counted
+    puzzle_count: usize,
+    /// Counts of every word that appears in some path
+    counts: HashMap<String, WordCount>,
+}
+
+impl WordUsage {
+    /// Counts the words of every puzzle's path.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - The puzzle set to analyze
+    pub fn from_puzzles(puzzles: &[Puzzle]) -> Self {
+        let mut usage = Self {
+            puzzle_count: puzzles.len(),
+            counts: HashMap::new(),
+        };
+        for puzzle in puzzles {
+            let endpoints = [puzzle.start.as_str(), puzzle.end.as_str()];
+            let words: HashSet<&str> = puzzle.path.iter().map(String::as_str).collect();
+            let intermediates: HashSet<&str> = puzzle
+                .path
+                .get(1..puzzle.path.len().saturating_sub(1))
+                .unwrap_or(&[])
+                .iter()
+                .map(String::as_str)
+                .collect();
+            for word in words {
+                let count = usage
+                    .counts
+                    .entry(word.to_string())
+                    .or_insert_with(|| WordCount {
+                        word: word.to_string(),
+                        puzzles: 0,
+                        intermediate: 0,
+                        endpoint: 0,
+                    });
+                count.puzzles += 1;
+                if intermediates.contains(word) {
+                    count.intermediate += 1;
+                }
+                if endpoints.contains(&word) {
+                    count.endpoint += 1;
+                }
+            }
+        }
+        usage
+    }
+
+    /// Returns the number of puzzles counted.
+    pub fn puzzle_count(&self) -> usize {
+        self.puzzle_count
+    }
+
+    /// Returns the counts of a word, or `None` if no path contains it.
+    pub fn get(&self, word: &str) -> Option<&WordCount> {
+        self.counts.get(word)
+    }
+
+    /// Returns the share of puzzles (0 to 1) with a word as an intermediate word.
+    pub fn intermediate_share(&self, word: &str) -> f64 {
+        match (self.counts.get(word), self.puzzle_count) {
+            (Some(count), total) if total > 0 => count.intermediate as f64 / total as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the most used words, by number of puzzles and then alphabetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Most words to return
+    pub fn most_used(&self, limit: usize) -> Vec<&WordCount> {
+        let mut counts: Vec<&WordCount> = self.counts.values().collect();
+        counts.sort_by(|a, b| b.puzzles.cmp(&a.puzzles).then_with(|| a.word.cmp(&b.word)));
+        counts.truncate(limit);
+        counts
+    }
+
+    /// Returns the intermediate words used by more than a share of the puzzles.
+    ///
+    /// A word that is an intermediate word of only one puzzle is never
+    /// flagged, so small sets do not flag every word. The words can be banned
+    /// from regeneration with `PuzzleGenerator::with_banned_words`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_share` - Highest acceptable share of puzzles (0 to 1) for one
+    ///   intermediate word
+    ///
+    /// # Returns
+    ///
+    /// The flagged words, most used first.
+    pub fn overused_intermediates(&self, max_share: f64) -> Vec<&WordCount> {
+        let mut overused: Vec<&WordCount> = self
+            .counts
+            .values()
+            .filter(|count| {
+                count.intermediate > 1 && self.intermediate_share(&count.word) > max_share
+            })
+            .collect();
+        overused.sort_by(|a, b| {
+            b.intermediate
+                .cmp(&a.intermediate)
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        overused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder(words: &str) -> Puzzle {
+        let path: Vec<String> = words.split(',').map(String::from).collect();
+        Puzzle::new(path[0].clone(), path[path.len() - 1].clone(), path).unwrap()
+    }
+
+    #[test]
+    fn test_word_usage() {
+        let puzzles = vec![
+            ladder("cold,core,care,cart"),
+            ladder("bore,core,cord,word"),
+            ladder("more,core,cure,pure"),
+            ladder("pin,pan,man"),
+            ladder("cart,card,hard"),
+        ];
+        let usage = WordUsage::from_puzzles(&puzzles);
+        assert_eq!(usage.puzzle_count(), 5);
+
+        let core = usage.get("core").unwrap();
+        assert_eq!((core.puzzles, core.intermediate, core.endpoint), (3, 3, 0));
+        let cart = usage.get("cart").unwrap();
+        assert_eq!((cart.puzzles, cart.intermediate, cart.endpoint), (2, 0, 2));
+        assert!((usage.intermediate_share("core") - 0.6).abs() < 1e-9);
+        assert_eq!(usage.intermediate_share("zzz"), 0.0);
+
+        assert_eq!(usage.most_used(1)[0].word, "core");
+        let overused: Vec<&str> = usage
+            .overused_intermediates(0.4)
+            .iter()
+            .map(|count| count.word.as_str())
+            .collect();
+        assert_eq!(overused, vec!["core"]);
+        assert!(usage.overused_intermediates(0.6).is_empty());
+        assert!(WordUsage::from_puzzles(&[]).most_used(5).is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+//! # Test Support
+//!
+//! Fixtures shared by the tests of this crate and of the engine, which
+//! enables the `test-support` feature for its own tests. Not part of the
+//! public API.
+
+use crate::puzzle::{Difficulty, DifficultyThresholds, Puzzle};
+
+/// Builds a puzzle from its ladder, with the difficulty its step count gets.
+///
+/// Unlike `Puzzle::new`, any ladder of two or more words is accepted; one too
+/// short or too long for a difficulty is marked easy.
+///
+/// # Arguments
+///
+/// * `path` - The ladder, from the start word to the end word
+///
+/// # Returns
+///
+/// The puzzle; panics if the path has fewer than two words.
+pub fn create_test_puzzle(path: &[&str]) -> Puzzle {
+    assert!(path.len() >= 2, "a ladder needs a start and an end word");
+    let path: Vec<String> = path.iter().map(|word| word.to_string()).collect();
+    Puzzle {
+        start: path[0].clone(),
+        end: path[path.len() - 1].clone(),
+        difficulty: DifficultyThresholds::default()
+            .classify(path.len() - 1)
+            .unwrap_or(Difficulty::Easy),
+        path,
+        unique_solution: None,
+        reverse_path: None,
+        stars: None,
+        meta: None,
+    }
+}
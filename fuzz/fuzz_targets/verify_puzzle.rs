@@ -0,0 +1,32 @@
+//! Fuzzes ladder parsing in `verify_puzzle` and `verify_many`.
+//!
+//! Run with `cargo +nightly fuzz run verify_puzzle` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::sync::OnceLock;
+use wordladder_engine::graph::WordGraph;
+use wordladder_engine::puzzle::PuzzleGenerator;
+
+fn generator() -> &'static PuzzleGenerator {
+    static GENERATOR: OnceLock<PuzzleGenerator> = OnceLock::new();
+    GENERATOR.get_or_init(|| {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ncold\ncord\ncard\n");
+        PuzzleGenerator::new(graph)
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let generator = generator();
+
+    // Malformed input must be reported, never panic
+    let result = generator.verify_puzzle(input);
+    assert_eq!(result.is_err(), !input.contains(','));
+    let summary = generator.verify_many(input);
+    assert!(summary.valid <= summary.total);
+});
@@ -0,0 +1,49 @@
+/*
+ * C bindings for wordladder-engine.
+ *
+ * Build the shared library with:
+ *     cargo build --release --features ffi
+ *
+ * Ownership: engines returned by wl_engine_new must be released with
+ * wl_engine_free, and every char * returned by this API must be released
+ * with wl_string_free.
+ */
+
+#ifndef WORDLADDER_H
+#define WORDLADDER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct WlEngine WlEngine;
+
+/* Builds an engine from UTF-8 word lists (one word per line).
+ * base_words may be NULL. Returns NULL on invalid UTF-8. */
+WlEngine *wl_engine_new(const uint8_t *dictionary, size_t dictionary_len,
+                        const uint8_t *base_words, size_t base_words_len);
+
+/* Releases an engine. NULL is a no-op. */
+void wl_engine_free(WlEngine *engine);
+
+/* Returns the shortest ladder as "cat,cot,cog,dog", or NULL if none exists. */
+char *wl_solve(const WlEngine *engine, const char *start, const char *end);
+
+/* Returns 1 if the comma-separated ladder is valid, 0 if invalid, -1 on error. */
+int32_t wl_verify(const WlEngine *engine, const char *puzzle);
+
+/* Returns a random puzzle as JSON (difficulty: 0 easy, 1 medium, 2 hard),
+ * or NULL if none could be generated. */
+char *wl_generate(const WlEngine *engine, int32_t difficulty);
+
+/* Releases a string returned by this API. NULL is a no-op. */
+void wl_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* WORDLADDER_H */
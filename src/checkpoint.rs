@@ -0,0 +1,330 @@
+//! # Generation Checkpoints
+//!
+//! This module lets long generation jobs survive a crash or Ctrl-C. While a
+//! job runs, completed puzzles are appended to a JSON Lines file next to the
+//! output, and a small state file records which job they belong to and how
+//! far it got. A later run with `--resume` reads both back and only generates
+//! the puzzles that are still missing.
+//!
+//! For `output/batch_medium.txt` the files are
+//! `output/batch_medium.checkpoint.jsonl` and
+//! `output/batch_medium.checkpoint.json`. They are removed once the job
+//! completes and its output is written.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use std::collections::BTreeMap;
+//! use std::path::Path;
+//! use wordladder_engine::checkpoint::Checkpoint;
+//!
+//! let output = Path::new("output/batch_medium.txt");
+//! let targets = BTreeMap::from([("medium".to_string(), 1000)]);
+//!
+//! let (mut checkpoint, done) = match Checkpoint::resume(output, "batch", &targets).unwrap() {
+//!     Some(resumed) => resumed,
+//!     None => (Checkpoint::start(output, "batch", &targets).unwrap(), Vec::new()),
+//! };
+//! println!("{} puzzles already done", done.len());
+//! // ... generate, calling checkpoint.record(&new_puzzles) after each chunk ...
+//! checkpoint.remove().unwrap();
+//! ```
+
+use crate::puzzle::Puzzle;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Extension of the JSON Lines file holding completed puzzles.
+pub const PUZZLES_EXTENSION: &str = "checkpoint.jsonl";
+
+/// Extension of the JSON file holding the job state.
+pub const STATE_EXTENSION: &str = "checkpoint.json";
+
+/// Which job a checkpoint belongs to and how far it got.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointState {
+    /// Name of the command that started the job, e.g. `batch`
+    pub command: String,
+    /// Number of puzzles wanted per difficulty (lowercase name)
+    pub targets: BTreeMap<String, usize>,
+    /// Number of puzzles completed per difficulty (lowercase name)
+    pub completed: BTreeMap<String, usize>,
+}
+
+/// An open checkpoint for a running generation job.
+#[derive(Debug)]
+pub struct Checkpoint {
+    /// Current job state, rewritten after each recorded chunk
+    state: CheckpointState,
+    /// Path of the state file
+    state_path: PathBuf,
+    /// Path of the puzzles file
+    puzzles_path: PathBuf,
+    /// Puzzles file, opened for appending
+    puzzles_file: File,
+    /// Puzzles per start or end word, per difficulty (lowercase name)
+    endpoint_uses: BTreeMap<String, HashMap<String, usize>>,
+}
+
+impl Checkpoint {
+    /// Returns the puzzles and state file paths for an output file.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_path` - Path of the job's final output file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use wordladder_engine::checkpoint::Checkpoint;
+    ///
+    /// let (puzzles, state) = Checkpoint::paths(Path::new("output/puzzles.sql"));
+    /// assert_eq!(puzzles, Path::new("output/puzzles.checkpoint.jsonl"));
+    /// assert_eq!(state, Path::new("output/puzzles.checkpoint.json"));
+    /// ```
+    pub fn paths(output_path: &Path) -> (PathBuf, PathBuf) {
+        (
+            output_path.with_extension(PUZZLES_EXTENSION),
+            output_path.with_extension(STATE_EXTENSION),
+        )
+    }
+
+    /// Starts a new checkpoint, discarding any previous one for the same output.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_path` - Path of the job's final output file
+    /// * `command` - Name of the command running the job
+    /// * `targets` - Number of puzzles wanted per difficulty
+    pub fn start(
+        output_path: &Path,
+        command: &str,
+        targets: &BTreeMap<String, usize>,
+    ) -> Result<Self> {
+        Self::create(output_path, command, targets, &[])
+    }
+
+    /// Reopens the checkpoint of an interrupted job.
+    ///
+    /// A puzzle line cut off by a crash is dropped, so it is simply generated
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_path` - Path of the job's final output file
+    /// * `command` - Name of the command running the job
+    /// * `targets` - Number of puzzles wanted per difficulty
+    ///
+    /// # Returns
+    ///
+    /// The checkpoint and the puzzles completed so far, `None` if there is no
+    /// checkpoint for this output, or an error if the checkpoint belongs to a
+    /// different command or different targets.
+    pub fn resume(
+        output_path: &Path,
+        command: &str,
+        targets: &BTreeMap<String, usize>,
+    ) -> Result<Option<(Self, Vec<Puzzle>)>> {
+        let (puzzles_path, state_path) = Self::paths(output_path);
+        if !state_path.exists() {
+            return Ok(None);
+        }
+
+        let state: CheckpointState =
+            serde_json::from_str(&std::fs::read_to_string(&state_path)?)
+                .with_context(|| format!("Cannot read checkpoint {}", state_path.display()))?;
+        if state.command != command || &state.targets != targets {
+            bail!(
+                "Checkpoint {} belongs to a different job ({} with targets {:?})",
+                state_path.display(),
+                state.command,
+                state.targets
+            );
+        }
+
+        let puzzles: Vec<Puzzle> = std::fs::read_to_string(&puzzles_path)
+            .unwrap_or_default()
+            .lines()
+            .map_while(|line| serde_json::from_str(line).ok())
+            .collect();
+        let checkpoint = Self::create(output_path, command, targets, &puzzles)?;
+        Ok(Some((checkpoint, puzzles)))
+    }
+
+    /// Writes a fresh checkpoint holding the given puzzles.
+    fn create(
+        output_path: &Path,
+        command: &str,
+        targets: &BTreeMap<String, usize>,
+        puzzles: &[Puzzle],
+    ) -> Result<Self> {
+        let (puzzles_path, state_path) = Self::paths(output_path);
+        let puzzles_file = File::create(&puzzles_path)?;
+        let mut checkpoint = Self {
+            state: CheckpointState {
+                command: command.to_string(),
+                targets: targets.clone(),
+                completed: BTreeMap::new(),
+            },
+            state_path,
+            puzzles_path,
+            puzzles_file,
+            endpoint_uses: BTreeMap::new(),
+        };
+        checkpoint.record(puzzles)?;
+        Ok(checkpoint)
+    }
+
+    /// Appends completed puzzles and saves the updated state.
+    ///
+    /// The puzzles are flushed before the state is replaced, so the state
+    /// never counts puzzles that are not on disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles completed since the last call
+    pub fn record(&mut self, puzzles: &[Puzzle]) -> Result<()> {
+        let mut lines = String::new();
+        for puzzle in puzzles {
+            lines.push_str(&serde_json::to_string(puzzle)?);
+            lines.push('\n');
+            let name = format!("{:?}", puzzle.difficulty).to_lowercase();
+            let uses = self.endpoint_uses.entry(name.clone()).or_default();
+            for word in [&puzzle.start, &puzzle.end] {
+                *uses.entry(word.clone()).or_insert(0) += 1;
+            }
+            *self.state.completed.entry(name).or_insert(0) += 1;
+        }
+        self.puzzles_file.write_all(lines.as_bytes())?;
+        self.puzzles_file.sync_data()?;
+
+        // Replace the state atomically so a crash never leaves it half-written
+        let temp_path = self.state_path.with_extension("json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string_pretty(&self.state)?)?;
+        std::fs::rename(&temp_path, &self.state_path)?;
+        Ok(())
+    }
+
+    /// Returns the number of completed puzzles of a difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Lowercase difficulty name, e.g. `medium`
+    pub fn completed(&self, difficulty: &str) -> usize {
+        self.state.completed.get(difficulty).copied().unwrap_or(0)
+    }
+
+    /// Returns how many completed puzzles of a difficulty start or end at
+    /// each word, to continue an endpoint reuse quota where the job left off.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Lowercase difficulty name, e.g. `medium`
+    pub fn endpoint_uses(&self, difficulty: &str) -> HashMap<String, usize> {
+        self.endpoint_uses
+            .get(difficulty)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the job state.
+    pub fn state(&self) -> &CheckpointState {
+        &self.state
+    }
+
+    /// Deletes the checkpoint files once the job's output has been written.
+    pub fn remove(self) -> Result<()> {
+        drop(self.puzzles_file);
+        std::fs::remove_file(&self.puzzles_path)?;
+        std::fs::remove_file(&self.state_path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::Difficulty;
+    use std::fs::OpenOptions;
+
+    fn puzzle(start: &str, end: &str, difficulty: Difficulty) -> Puzzle {
+        Puzzle {
+            start: start.to_string(),
+            end: end.to_string(),
+            path: vec![start.to_string(), end.to_string()],
+            difficulty,
+            unique_solution: None,
+            reverse_path: None,
+            stars: None,
+            meta: None,
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let dir = std::env::temp_dir().join(format!("wl_checkpoint_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("batch.txt");
+        let targets = BTreeMap::from([("easy".to_string(), 3), ("hard".to_string(), 1)]);
+
+        assert!(
+            Checkpoint::resume(&output, "batch", &targets)
+                .unwrap()
+                .is_none()
+        );
+
+        let mut checkpoint = Checkpoint::start(&output, "batch", &targets).unwrap();
+        checkpoint
+            .record(&[
+                puzzle("cat", "dog", Difficulty::Easy),
+                puzzle("cold", "warm", Difficulty::Hard),
+            ])
+            .unwrap();
+        checkpoint
+            .record(&[puzzle("hot", "pie", Difficulty::Easy)])
+            .unwrap();
+        drop(checkpoint);
+
+        // Simulate a crash in the middle of writing a line
+        let (puzzles_path, state_path) = Checkpoint::paths(&output);
+        let mut file = OpenOptions::new().append(true).open(&puzzles_path).unwrap();
+        file.write_all(b"{\"start\":\"bi").unwrap();
+        drop(file);
+
+        let (checkpoint, done) = Checkpoint::resume(&output, "batch", &targets)
+            .unwrap()
+            .unwrap();
+        assert_eq!(done.len(), 3);
+        assert_eq!(checkpoint.completed("easy"), 2);
+        assert_eq!(checkpoint.completed("hard"), 1);
+        assert_eq!(checkpoint.completed("medium"), 0);
+        let easy_uses = checkpoint.endpoint_uses("easy");
+        assert_eq!(easy_uses.get("cat"), Some(&1));
+        assert_eq!(easy_uses.get("cold"), None);
+        assert_eq!(
+            std::fs::read_to_string(&puzzles_path)
+                .unwrap()
+                .lines()
+                .count(),
+            3
+        );
+        drop(checkpoint);
+
+        assert!(Checkpoint::resume(&output, "generate-mobile", &targets).is_err());
+        let other_targets = BTreeMap::from([("easy".to_string(), 5)]);
+        assert!(Checkpoint::resume(&output, "batch", &other_targets).is_err());
+
+        let (checkpoint, _) = Checkpoint::resume(&output, "batch", &targets)
+            .unwrap()
+            .unwrap();
+        checkpoint.remove().unwrap();
+        assert!(!puzzles_path.exists());
+        assert!(!state_path.exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -5,12 +5,31 @@
 //!
 //! ## Commands
 //!
-//! The application supports four main commands:
+//! The application supports the following commands:
 //!
 //! - `generate`: Generate puzzles (bulk or single with arguments)
+//! - `solve`: Find the shortest ladder between two words
 //! - `batch`: Generate multiple puzzles of specified difficulty to a file
 //! - `generate-mobile`: Generate balanced puzzles optimized for mobile apps
+//! - `enumerate`: Export every puzzle between the base words
+//! - `fetch-dict`: Download and normalize a word list (`fetch` feature)
+//! - `export-dict`: Export the dictionary to SQL
+//! - `export-graph`: Export the word graph as DOT, GraphML, or CSV
+//! - `check-words`: Report unusable or poorly connected base words
+//! - `curate-base`: Write a base words file of common, well-connected dictionary words
+//! - `capacity`: Estimate how many distinct puzzles each difficulty supports
+//! - `stats`: Report dictionary size and estimated graph memory
+//! - `calibrate`: Relabel puzzle difficulty from player solve rates and times
+//! - `analyze-set`: Count word usage across a puzzle set and flag over-used words
+//! - `review`: Accept or reject a generated batch in a terminal UI (`tui` feature)
 //! - `verify`: Verify puzzle sequence validity
+//! - `sign-pack`: Write a detached ed25519 signature for an export (`sign` feature)
+//! - `verify-pack`: Check an export against its manifest checksum and signature
+//! - `verify-export`: Re-solve the puzzles of a SQL export and check their step counts
+//! - `daemon`: Serve newline-delimited JSON requests on stdin with a warm graph
+//! - `serve`: Serve the daemon's methods over HTTP with API keys and rate limiting
+//! - `grpc-serve`: Serve the gRPC service for backend integration (`grpc` feature)
+//! - `completions`: Print shell completions, or a man page with `--man`
 //!
 //! ## Output Formats
 //!
@@ -18,7 +37,29 @@
 //!
 //! - `text`: Human-readable text format (default)
 //! - `json`: JSON format for programmatic consumption
+//! - `jsonl`: JSON Lines, one puzzle per line
 //! - `sql`: SQLite-compatible SQL format for mobile integration
+//! - `svg`: Ladder graphic of a puzzle, for social sharing
+//! - `anki`: Anki flashcard import file for vocabulary practice
+//!
+//! Independently of the output format, the global `--json` flag replaces the
+//! human-readable status messages printed to stdout with one structured
+//! `CliReport` object (counts, output paths, timings, warnings) per command.
+//!
+//! ## Exit Codes
+//!
+//! Every subcommand exits with one of the codes of `CliExit`, so scripts can
+//! tell failures apart without parsing output:
+//!
+//! - `0`: Success
+//! - `1`: Unexpected failure, such as an unwritable output file
+//! - `2`: No ladder connects the requested words
+//! - `3`: Invalid words: an input word is not in the dictionary, or a puzzle
+//!   passed to `verify` is not a valid ladder, or a file passed to
+//!   `verify-pack` fails its checksum or signature check
+//! - `4`: Configuration error: invalid arguments, or a dictionary or other
+//!   input file that cannot be read
+//! - `5`: A `solve` search hit its `--max-visited` or `--timeout-ms` budget
 //!
 //! ## Configuration Integration
 //!
@@ -34,6 +75,9 @@
 //! // Generate single puzzle with custom words
 //! wordladder-engine generate --start cat --end dog
 //!
+//! // Solve a word golf ladder that may add or remove letters
+//! wordladder-engine solve --start cat --end card --rules substitute,insert-delete
+//!
 //! // Generate batch with specific parameters
 //! wordladder-engine batch --count 50 --difficulty medium --output puzzles.txt
 //!
@@ -43,17 +87,94 @@
 //! // Generate mobile-optimized puzzles
 //! wordladder-engine generate-mobile --count 1000 --output mobile_puzzles.sql
 //!
+//! // Export the 4-letter word graph for Gephi
+//! wordladder-engine export-graph --format graphml --length 4
+//!
+//! // Find typos and dead-end words in the base words file
+//! wordladder-engine check-words --min-degree 3
+//!
 //! // Verify a puzzle solution
 //! wordladder-engine verify --puzzle "cat,cot,cog,dog"
+//!
+//! // Verify every ladder in a file (or `--file -` for stdin)
+//! wordladder-engine verify --file puzzles.txt
+//!
+//! // Report the result of a batch as JSON for scripts
+//! wordladder-engine --json batch --count 50 --difficulty medium
+//!
+//! // Keep the graph warm and answer JSON requests on stdin
+//! echo '{"id":1,"method":"solve","params":{"start":"cat","end":"dog"}}' | wordladder-engine daemon
+//!
+//! // Serve the same methods over HTTP to clients holding an API key
+//! wordladder-engine serve --bind 0.0.0.0:8080 --api-keys-file keys.txt
 //! ```
 
-use crate::config::Config;
-use crate::exporters::sql::{SqlExportConfig, SqlExporter};
-use crate::graph::WordGraph;
-use crate::puzzle::{Difficulty, PuzzleGenerator};
-use anyhow::Result;
-use clap::{Parser, Subcommand, ValueEnum};
+use crate::checkpoint::Checkpoint;
+use crate::config::{Config, DifficultyDistribution};
+use crate::daemon::Daemon;
+use crate::exporters::anki::AnkiExporter;
+use crate::exporters::binary::{self, BinaryExporter, BinaryFormat};
+#[cfg(feature = "encrypt")]
+use crate::exporters::encryption::SolutionKey;
+use crate::exporters::graph::{GraphExporter, GraphFormat};
+use crate::exporters::incremental::{self, ExistingPuzzles};
+use crate::exporters::manifest::{ExportManifest, PuzzleCounts, sha256_hex};
+use crate::exporters::markdown::MarkdownExporter;
+use crate::exporters::masked::MaskedPuzzle;
+#[cfg(feature = "proto")]
+use crate::exporters::proto::{self, PuzzlePack};
+use crate::exporters::sql::{
+    self, IdStrategy, SchemaMode, SchemaVersion, SqlExportConfig, SqlExporter, WordMetadata,
+};
+use crate::exporters::stream::StreamExporter;
+use crate::exporters::svg::SvgExporter;
+#[cfg(feature = "sign")]
+use crate::exporters::{hex, signing};
+#[cfg(feature = "fetch")]
+use crate::fetch::{self, WordList};
+use crate::graph::landmarks::{Landmarks, landmarks_path};
+use crate::graph::{
+    BaseWordCuration, CaseMapping, ComparisonUnit, EdgeRule, LadderError, LoadReport,
+    PunctuationPolicy, SolveOptions, SolveOutcome, WordGraph,
+};
+#[cfg(feature = "grpc")]
+use crate::grpc;
+use crate::puzzle::calibration::{CalibrationModel, parse_player_stats};
+use crate::puzzle::curation::{
+    BalancedSetBuilder, DedupPolicy, ExportHistory, ProgressionSpec, build_progression,
+};
+use crate::puzzle::usage::WordUsage;
+use crate::puzzle::{
+    BaseWordIssue, BatchResult, CanonicalHashOptions, Difficulty, EndpointFilter, PairStatus,
+    Puzzle, PuzzleError, PuzzleGenerator, ValidationThresholds,
+};
+use crate::report::CliReport;
+use crate::server::{self, Server};
+use anyhow::{Context, Result};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use indicatif::{ProgressBar, ProgressStyle};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Output path that writes an export to stdout instead of a file.
+const STDOUT: &str = "-";
+
+/// Number of puzzles generated between checkpoint writes.
+const CHECKPOINT_INTERVAL: usize = 100;
+
+/// Most edits between an unknown word and the corrections suggested for it.
+const SUGGESTION_DISTANCE: usize = 2;
+
+/// Most corrections suggested for one unknown word.
+const MAX_SUGGESTIONS: usize = 5;
 
 /// Output format for generated puzzles.
 #[derive(Debug, Clone, ValueEnum)]
@@ -62,8 +183,340 @@ pub enum OutputFormat {
     Text,
     /// JSON format for programmatic consumption
     Json,
+    /// JSON Lines, one puzzle per line, written as puzzles are generated
+    Jsonl,
     /// SQLite-compatible SQL format for mobile integration
     Sql,
+    /// Markdown table with collapsible solutions for blogs and newsletters
+    Markdown,
+    /// SVG ladder graphic with the changed letters highlighted, for social sharing
+    Svg,
+    /// Anki flashcard import file (start and end on the front, solution on the back)
+    Anki,
+    /// Compact MessagePack puzzle array for over-the-air updates
+    Msgpack,
+    /// Compact CBOR puzzle array for over-the-air updates
+    Cbor,
+    /// Binary protobuf puzzle pack for game clients (see `dump-schema`)
+    #[cfg(feature = "proto")]
+    Proto,
+}
+
+/// Well-known word lists for `fetch-dict`.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone, ValueEnum)]
+pub enum WordListArg {
+    /// ENABLE word list (about 173,000 words)
+    Enable,
+    /// Popular subset of ENABLE (about 25,000 common words)
+    Popular,
+}
+
+#[cfg(feature = "fetch")]
+impl From<WordListArg> for WordList {
+    fn from(list: WordListArg) -> Self {
+        match list {
+            WordListArg::Enable => WordList::Enable,
+            WordListArg::Popular => WordList::Popular,
+        }
+    }
+}
+
+/// Output format for word graph exports.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum GraphOutputFormat {
+    /// Graphviz DOT format (default)
+    Dot,
+    /// GraphML XML format (Gephi, yEd, NetworkX)
+    Graphml,
+    /// Edge-list CSV with source,target columns
+    Csv,
+    /// SVG drawing of a puzzle's neighborhood (requires --around)
+    Svg,
+}
+
+impl From<GraphOutputFormat> for GraphFormat {
+    fn from(format: GraphOutputFormat) -> Self {
+        match format {
+            GraphOutputFormat::Dot => GraphFormat::Dot,
+            GraphOutputFormat::Graphml => GraphFormat::GraphMl,
+            GraphOutputFormat::Csv => GraphFormat::Csv,
+            GraphOutputFormat::Svg => GraphFormat::Svg,
+        }
+    }
+}
+
+/// Edge rule selectable with `--rules`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum RuleArg {
+    /// Change exactly one letter (classic word ladder)
+    Substitute,
+    /// Insert or delete one letter (word golf)
+    InsertDelete,
+    /// Swap two adjacent letters
+    Swap,
+    /// Rearrange the letters into another word
+    Anagram,
+}
+
+impl From<RuleArg> for EdgeRule {
+    fn from(rule: RuleArg) -> Self {
+        match rule {
+            RuleArg::Substitute => EdgeRule::SubstituteOne,
+            RuleArg::InsertDelete => EdgeRule::InsertOrDeleteOne,
+            RuleArg::Swap => EdgeRule::SwapAdjacent,
+            RuleArg::Anagram => EdgeRule::Anagram,
+        }
+    }
+}
+
+/// Case mapping selectable with `--case-mapping`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum CaseMappingArg {
+    /// Unicode lowercasing
+    Lowercase,
+    /// Turkish/Azerbaijani lowercasing (I -> ı, İ -> i)
+    Turkic,
+    /// Keep letter case
+    Preserve,
+}
+
+impl From<CaseMappingArg> for CaseMapping {
+    fn from(case: CaseMappingArg) -> Self {
+        match case {
+            CaseMappingArg::Lowercase => CaseMapping::Lowercase,
+            CaseMappingArg::Turkic => CaseMapping::Turkic,
+            CaseMappingArg::Preserve => CaseMapping::Preserve,
+        }
+    }
+}
+
+/// Apostrophe and hyphen policy selectable with `--punctuation`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum PunctuationArg {
+    /// Skip words containing apostrophes or hyphens
+    Reject,
+    /// Remove apostrophes and hyphens (don't -> dont)
+    Strip,
+    /// Keep apostrophes and hyphens as part of the word
+    Keep,
+}
+
+impl From<PunctuationArg> for PunctuationPolicy {
+    fn from(punctuation: PunctuationArg) -> Self {
+        match punctuation {
+            PunctuationArg::Reject => PunctuationPolicy::Reject,
+            PunctuationArg::Strip => PunctuationPolicy::Strip,
+            PunctuationArg::Keep => PunctuationPolicy::Keep,
+        }
+    }
+}
+
+/// Comparison unit selectable with `--comparison-unit`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum ComparisonUnitArg {
+    /// One char per letter, substituting a-z
+    Char,
+    /// One grapheme cluster per letter, substituting the dictionary's graphemes
+    Grapheme,
+}
+
+impl From<ComparisonUnitArg> for ComparisonUnit {
+    fn from(unit: ComparisonUnitArg) -> Self {
+        match unit {
+            ComparisonUnitArg::Char => ComparisonUnit::Char,
+            ComparisonUnitArg::Grapheme => ComparisonUnit::Grapheme,
+        }
+    }
+}
+
+/// Puzzle ID scheme selectable with `--id-strategy`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum IdStrategyArg {
+    /// Per-run counters (start_end_001)
+    Counter,
+    /// Stable content hashes (start_end_<hash>)
+    Hash,
+}
+
+impl From<IdStrategyArg> for IdStrategy {
+    fn from(strategy: IdStrategyArg) -> Self {
+        match strategy {
+            IdStrategyArg::Counter => IdStrategy::Counter,
+            IdStrategyArg::Hash => IdStrategy::ContentHash,
+        }
+    }
+}
+
+/// Canonical puzzle hash selectable with `--canonical-hash`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CanonicalHashArg {
+    /// Start word, end word, and path, in puzzle order
+    Directed,
+    /// Start word, end word, and path, with the endpoints sorted
+    Unordered,
+    /// Start and end word only, in puzzle order
+    Endpoints,
+    /// Start and end word only, sorted
+    UnorderedEndpoints,
+}
+
+impl From<CanonicalHashArg> for CanonicalHashOptions {
+    fn from(mode: CanonicalHashArg) -> Self {
+        CanonicalHashOptions {
+            unordered: matches!(
+                mode,
+                CanonicalHashArg::Unordered | CanonicalHashArg::UnorderedEndpoints
+            ),
+            path_independent: matches!(
+                mode,
+                CanonicalHashArg::Endpoints | CanonicalHashArg::UnorderedEndpoints
+            ),
+        }
+    }
+}
+
+/// SQL schema output selectable with `--schema-mode`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum SchemaModeArg {
+    /// CREATE TABLE for a new database (default)
+    Full,
+    /// ALTER TABLE statements upgrading a v1 puzzles table to the current schema
+    MigrateFromV1,
+    /// ALTER TABLE statements upgrading a v2 puzzles table to the current schema
+    MigrateFromV2,
+    /// ALTER TABLE statements upgrading a v3 puzzles table to the current schema
+    MigrateFromV3,
+    /// ALTER TABLE statements upgrading a v4 puzzles table to the current schema
+    MigrateFromV4,
+    /// ALTER TABLE statements upgrading a v5 puzzles table to the current schema
+    MigrateFromV5,
+}
+
+impl From<SchemaModeArg> for SchemaMode {
+    fn from(mode: SchemaModeArg) -> Self {
+        match mode {
+            SchemaModeArg::Full => SchemaMode::Full,
+            SchemaModeArg::MigrateFromV1 => SchemaMode::MigrateFrom(SchemaVersion::V1),
+            SchemaModeArg::MigrateFromV2 => SchemaMode::MigrateFrom(SchemaVersion::V2),
+            SchemaModeArg::MigrateFromV3 => SchemaMode::MigrateFrom(SchemaVersion::V3),
+            SchemaModeArg::MigrateFromV4 => SchemaMode::MigrateFrom(SchemaVersion::V4),
+            SchemaModeArg::MigrateFromV5 => SchemaMode::MigrateFrom(SchemaVersion::V5),
+        }
+    }
+}
+
+/// Difficulty selectable with `batch --difficulty`, including every level at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DifficultyArg {
+    /// Easy puzzles (2-3 steps)
+    Easy,
+    /// Medium puzzles (4-5 steps)
+    Medium,
+    /// Hard puzzles (6-10 steps)
+    Hard,
+    /// Every difficulty level, `--count` puzzles each
+    All,
+    /// `--count` puzzles mixing every level in the `--distribution` ratios
+    Mixed,
+}
+
+impl DifficultyArg {
+    /// Returns the difficulty levels to generate, easiest first.
+    pub fn levels(self) -> Vec<Difficulty> {
+        match self {
+            DifficultyArg::Easy => vec![Difficulty::Easy],
+            DifficultyArg::Medium => vec![Difficulty::Medium],
+            DifficultyArg::Hard => vec![Difficulty::Hard],
+            DifficultyArg::All | DifficultyArg::Mixed => Difficulty::ALL.to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for DifficultyArg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DifficultyArg::Easy => f.write_str(Difficulty::Easy.name()),
+            DifficultyArg::Medium => f.write_str(Difficulty::Medium.name()),
+            DifficultyArg::Hard => f.write_str(Difficulty::Hard.name()),
+            DifficultyArg::All => f.write_str("all"),
+            DifficultyArg::Mixed => f.write_str("mixed"),
+        }
+    }
+}
+
+/// Random endpoint selection selectable with `--endpoint-weighting`.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum EndpointWeighting {
+    /// Every base word is equally likely (default)
+    Uniform,
+    /// Common words are more likely, using the `--frequencies` list
+    Frequency,
+}
+
+/// Documented exit status of a CLI command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliExit {
+    /// The command completed successfully (code 0)
+    Success,
+    /// An unexpected error occurred, such as a failed write (code 1)
+    Failure,
+    /// No ladder connects the requested words (code 2)
+    NoPath,
+    /// An input word is not in the dictionary, or a verified puzzle is invalid (code 3)
+    InvalidWords,
+    /// Invalid arguments, or configuration or input files that cannot be read (code 4)
+    ConfigError,
+    /// A search hit its `--max-visited` or `--timeout-ms` budget (code 5)
+    BudgetExceeded,
+}
+
+impl CliExit {
+    /// Returns the numeric process exit code.
+    pub fn code(self) -> u8 {
+        match self {
+            CliExit::Success => 0,
+            CliExit::Failure => 1,
+            CliExit::NoPath => 2,
+            CliExit::InvalidWords => 3,
+            CliExit::ConfigError => 4,
+            CliExit::BudgetExceeded => 5,
+        }
+    }
+
+    /// Classifies an error returned by `run`.
+    ///
+    /// Errors carrying a `ConfigError` context map to `ConfigError`; all
+    /// others map to `Failure`.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error returned by `run`
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        if error.downcast_ref::<ConfigError>().is_some() {
+            CliExit::ConfigError
+        } else {
+            CliExit::Failure
+        }
+    }
+}
+
+impl From<CliExit> for ExitCode {
+    fn from(exit: CliExit) -> Self {
+        ExitCode::from(exit.code())
+    }
+}
+
+/// Error context marking a failure as caused by configuration or input files.
+///
+/// Attach it with `anyhow::Context` so `CliExit::from_error` reports exit code 4.
+#[derive(Debug)]
+pub struct ConfigError(pub String);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
 /// Main CLI structure for the word ladder engine.
@@ -74,6 +527,86 @@ pub enum OutputFormat {
 #[command(name = "wordladder-engine")]
 #[command(about = "A CLI tool for generating word ladder puzzles")]
 pub struct Cli {
+    /// Print one JSON report to stdout instead of human-readable messages
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Print a summary of each dictionary and base words file loaded (lines
+    /// accepted, rejected, and duplicated, and words per length) to stderr
+    #[arg(long, global = true)]
+    pub verbose: bool,
+
+    /// Find paths with A* guided by this many landmarks per word length
+    /// (saved next to the dictionary and reused while it is unchanged)
+    #[arg(long, global = true, value_name = "N")]
+    pub landmarks: Option<usize>,
+
+    /// Letter case mapping for dictionary words and input words
+    #[arg(long, global = true)]
+    pub case_mapping: Option<CaseMappingArg>,
+
+    /// How apostrophes and hyphens in words are treated (default: reject)
+    #[arg(long, global = true)]
+    pub punctuation: Option<PunctuationArg>,
+
+    /// The unit of a word one ladder step changes (default: char); grapheme
+    /// treats letters written with combining marks as one letter
+    #[arg(long, global = true)]
+    pub comparison_unit: Option<ComparisonUnitArg>,
+
+    /// Skip dictionary and base words matching this regular expression when
+    /// loading, e.g. '^[A-Z]' for proper nouns (matched before lowercasing)
+    #[arg(long, global = true, value_name = "REGEX")]
+    pub exclude_regex: Option<String>,
+
+    /// Extra words players may use in verified ladders, one per line
+    /// (generated paths still only use the dictionary)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub accepted_words: Option<PathBuf>,
+
+    /// Leave solutions out of JSON, JSON Lines, and SQL puzzle exports,
+    /// keeping only IDs, endpoints, step counts, and difficulties
+    #[arg(long, global = true)]
+    pub omit_solutions: bool,
+
+    /// Add a `canonical_hash` field (JSON) or column (SQL) to puzzle exports
+    /// for deduplicating submissions; endpoint-only modes ignore the path
+    #[arg(long, global = true, value_name = "MODE")]
+    pub canonical_hash: Option<CanonicalHashArg>,
+
+    /// Write SQL puzzle exports with an `encrypted_solution` column instead of
+    /// plain solutions, keyed per puzzle ID from the master key in `--key-file`
+    #[cfg(feature = "encrypt")]
+    #[arg(
+        long,
+        global = true,
+        requires = "key_file",
+        conflicts_with = "omit_solutions"
+    )]
+    pub encrypt_solutions: bool,
+
+    /// File containing the 64-hex-character master key for `--encrypt-solutions`
+    #[cfg(feature = "encrypt")]
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        requires = "encrypt_solutions"
+    )]
+    pub key_file: Option<PathBuf>,
+
+    /// Word lengths of easy puzzle endpoints, e.g. 3,4 (shortest to longest)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "MIN,MAX")]
+    pub easy_lengths: Vec<usize>,
+
+    /// Word lengths of medium puzzle endpoints, e.g. 4,5 (shortest to longest)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "MIN,MAX")]
+    pub medium_lengths: Vec<usize>,
+
+    /// Word lengths of hard puzzle endpoints, e.g. 5,6 (shortest to longest)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "MIN,MAX")]
+    pub hard_lengths: Vec<usize>,
+
     /// The subcommand to execute
     #[command(subcommand)]
     pub command: Commands,
@@ -101,13 +634,16 @@ pub enum Commands {
         /// Starting word (optional, will pick random if not provided)
         #[arg(short, long)]
         start: Option<String>,
-        /// Ending word (optional, will pick random if not provided)
+        /// Ending word (optional; with only --start, an end word at --difficulty is picked)
         #[arg(short, long)]
         end: Option<String>,
+        /// Difficulty when only --start is given (easy, medium, hard)
+        #[arg(long, requires = "start", conflicts_with = "end")]
+        difficulty: Option<Difficulty>,
         /// Output format: text, json, or sql
         #[arg(short, long, default_value = "text")]
         format: OutputFormat,
-        /// Output file path (optional, defaults to output/ directory)
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
         /// Include CREATE TABLE schema in SQL output
@@ -116,11 +652,91 @@ pub enum Commands {
         /// Batch size for SQL INSERT statements
         #[arg(long, default_value = "100")]
         batch_size: usize,
+        /// Include masked clue words (and the full solution in SQL output)
+        #[arg(long)]
+        clues: bool,
+        /// Only accept puzzles whose solution is the unique shortest path
+        #[arg(long)]
+        unique_solution: bool,
+        /// Only accept puzzles that also work backwards through different words
+        #[arg(long)]
+        require_reversible: bool,
+        /// Record generation time, engine version, dictionary hash, and seed in JSON/SQL
+        #[arg(long)]
+        meta: bool,
+        /// Attach a par and two-/three-star step thresholds to each puzzle
+        #[arg(long)]
+        stars: bool,
+        /// SQL schema to emit: full (CREATE TABLE) or migrate-from-v1 to v6 (ALTER TABLE)
+        #[arg(long, default_value = "full")]
+        schema_mode: SchemaModeArg,
+        /// Also export a puzzle_steps table with one row per ladder word (SQL only)
+        #[arg(long)]
+        steps_table: bool,
+        /// Puzzle ID scheme for SQL output (defaults to config value, i.e. counter)
+        #[arg(long)]
+        id_strategy: Option<IdStrategyArg>,
+        /// How random endpoints are picked: uniform, or frequency (needs --frequencies)
+        #[arg(long, default_value = "uniform")]
+        endpoint_weighting: EndpointWeighting,
+        /// Word frequency list (most common first) for frequency endpoint weighting
+        #[arg(long)]
+        frequencies: Option<PathBuf>,
+        /// Most puzzles of a batch any one word may start or end
+        #[arg(long)]
+        max_endpoint_reuse: Option<usize>,
+        /// Only pick start words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        start_pattern: Vec<String>,
+        /// Only pick end words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        end_pattern: Vec<String>,
+        /// Comma-separated edge rules (defaults to config value, i.e. substitute)
+        #[arg(long, value_delimiter = ',')]
+        rules: Vec<RuleArg>,
+        /// Write a sidecar .manifest.json with checksums and puzzle counts
+        #[arg(long)]
+        manifest: bool,
+    },
+    /// Find the shortest ladder between two words
+    ///
+    /// Prints every step of the shortest path, or reports that the words
+    /// are not connected under the selected edge rules.
+    Solve {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Starting word
+        #[arg(short, long)]
+        start: String,
+        /// Ending word
+        #[arg(short, long, required_unless_present = "ends")]
+        end: Option<String>,
+        /// Comma-separated ending words, all solved with one search
+        #[arg(long, value_delimiter = ',', conflicts_with_all = ["end", "avoid"])]
+        ends: Vec<String>,
+        /// Comma-separated edge rules (defaults to config value, i.e. substitute)
+        #[arg(long, value_delimiter = ',')]
+        rules: Vec<RuleArg>,
+        /// Comma-separated words the ladder may not pass through
+        #[arg(long, value_delimiter = ',')]
+        avoid: Vec<String>,
+        /// Print the BFS frontier size, visited words, and time per depth
+        #[arg(long, conflicts_with = "ends")]
+        trace: bool,
+        /// Give up (exit code 5) after visiting this many words
+        #[arg(long, conflicts_with_all = ["ends", "trace"])]
+        max_visited: Option<usize>,
+        /// Give up (exit code 5) after searching for this many milliseconds
+        #[arg(long, conflicts_with_all = ["ends", "trace"])]
+        timeout_ms: Option<u64>,
     },
     /// Generate multiple puzzles of specified difficulty to a file
     ///
     /// Creates a batch of puzzles with consistent difficulty and saves them
-    /// to a file. Supports text, JSON, and SQL output formats.
+    /// to a file. Supports text, JSON, and SQL output formats. Text, JSON
+    /// Lines, and SQL output is written as puzzles are generated, so an
+    /// interrupted run keeps everything generated so far.
     Batch {
         /// Path to dictionary file (defaults to config value)
         #[arg(short, long, default_value = "data/dictionary.txt")]
@@ -131,13 +747,20 @@ pub enum Commands {
         /// Number of puzzles to generate
         #[arg(short, long, default_value = "10")]
         count: usize,
-        /// Difficulty level (easy, medium, hard)
+        /// Difficulty level: easy, medium, hard, all (--count puzzles of each),
+        /// or mixed (--count puzzles in the --distribution ratios)
         #[arg(long, default_value = "medium")]
-        difficulty: String,
+        difficulty: DifficultyArg,
+        /// Easy, medium, and hard ratios of a mixed batch (defaults to 0.4,0.4,0.2)
+        #[arg(long, value_delimiter = ',', value_name = "EASY,MEDIUM,HARD")]
+        distribution: Vec<f64>,
+        /// Start every puzzle at this word, picking end words from its distance layers
+        #[arg(short, long, conflicts_with_all = ["start_pattern", "resume"])]
+        start: Option<String>,
         /// Output format: text, json, or sql
         #[arg(short, long, default_value = "text")]
         format: OutputFormat,
-        /// Output file path (optional, defaults to output/ directory)
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
         /// Include CREATE TABLE schema in SQL output
@@ -146,6 +769,57 @@ pub enum Commands {
         /// Batch size for SQL INSERT statements
         #[arg(long, default_value = "100")]
         batch_size: usize,
+        /// Include masked clue words (and the full solution in SQL output)
+        #[arg(long)]
+        clues: bool,
+        /// Only accept puzzles whose solution is the unique shortest path
+        #[arg(long)]
+        unique_solution: bool,
+        /// Only accept puzzles that also work backwards through different words
+        #[arg(long)]
+        require_reversible: bool,
+        /// Record generation time, engine version, dictionary hash, and seed in JSON/SQL
+        #[arg(long)]
+        meta: bool,
+        /// Attach a par and two-/three-star step thresholds to each puzzle
+        #[arg(long)]
+        stars: bool,
+        /// SQL schema to emit: full (CREATE TABLE) or migrate-from-v1 to v6 (ALTER TABLE)
+        #[arg(long, default_value = "full")]
+        schema_mode: SchemaModeArg,
+        /// Also export a puzzle_steps table with one row per ladder word (SQL only)
+        #[arg(long)]
+        steps_table: bool,
+        /// Puzzle ID scheme for SQL output (defaults to config value, i.e. counter)
+        #[arg(long)]
+        id_strategy: Option<IdStrategyArg>,
+        /// How random endpoints are picked: uniform, or frequency (needs --frequencies)
+        #[arg(long, default_value = "uniform")]
+        endpoint_weighting: EndpointWeighting,
+        /// Word frequency list (most common first) for frequency endpoint weighting
+        #[arg(long)]
+        frequencies: Option<PathBuf>,
+        /// Most puzzles of a batch any one word may start or end
+        #[arg(long)]
+        max_endpoint_reuse: Option<usize>,
+        /// Only pick start words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        start_pattern: Vec<String>,
+        /// Only pick end words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        end_pattern: Vec<String>,
+        /// Word list (one per line) to keep out of every puzzle, e.g. from analyze-set
+        #[arg(long)]
+        banned_words: Option<PathBuf>,
+        /// Append to the output file, skipping puzzles it already contains
+        #[arg(long)]
+        append: bool,
+        /// Write a sidecar .manifest.json with checksums and puzzle counts
+        #[arg(long)]
+        manifest: bool,
+        /// Continue an interrupted run from its checkpoint instead of starting over
+        #[arg(long)]
+        resume: bool,
     },
     /// Generate balanced puzzles optimized for mobile applications
     ///
@@ -161,7 +835,7 @@ pub enum Commands {
         /// Total number of puzzles to generate
         #[arg(short, long, default_value = "1000")]
         count: usize,
-        /// Output file path for the SQL export (optional, defaults to output/ directory)
+        /// Output file path for the SQL export (optional, defaults to output/ directory, `-` for stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
         /// Ratio of easy puzzles (0.0 to 1.0)
@@ -173,12 +847,176 @@ pub enum Commands {
         /// Ratio of hard puzzles (0.0 to 1.0)
         #[arg(long, default_value = "0.2")]
         hard_ratio: f64,
+        /// Scale the ratios to sum to 1.0 instead of rejecting them
+        #[arg(long)]
+        normalize_ratios: bool,
         /// Include CREATE TABLE schema in SQL output
         #[arg(long)]
         include_schema: Option<bool>,
         /// Batch size for SQL INSERT statements
         #[arg(long, default_value = "100")]
         batch_size: usize,
+        /// Only accept puzzles whose solution is the unique shortest path
+        #[arg(long)]
+        unique_solution: bool,
+        /// Only accept puzzles that also work backwards through different words
+        #[arg(long)]
+        require_reversible: bool,
+        /// Record generation time, engine version, dictionary hash, and seed in JSON/SQL
+        #[arg(long)]
+        meta: bool,
+        /// Attach a par and two-/three-star step thresholds to each puzzle
+        #[arg(long)]
+        stars: bool,
+        /// SQL schema to emit: full (CREATE TABLE) or migrate-from-v1 to v6 (ALTER TABLE)
+        #[arg(long, default_value = "full")]
+        schema_mode: SchemaModeArg,
+        /// Also export a puzzle_steps table with one row per ladder word (SQL only)
+        #[arg(long)]
+        steps_table: bool,
+        /// Puzzle ID scheme for SQL output (defaults to config value, i.e. counter)
+        #[arg(long)]
+        id_strategy: Option<IdStrategyArg>,
+        /// How random endpoints are picked: uniform, or frequency (needs --frequencies)
+        #[arg(long, default_value = "uniform")]
+        endpoint_weighting: EndpointWeighting,
+        /// Word frequency list (most common first) for frequency endpoint weighting
+        #[arg(long)]
+        frequencies: Option<PathBuf>,
+        /// Most puzzles of a batch any one word may start or end
+        #[arg(long)]
+        max_endpoint_reuse: Option<usize>,
+        /// Only pick start words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        start_pattern: Vec<String>,
+        /// Only pick end words matching s* (prefix), *now* (substring), or s_o_ (pattern)
+        #[arg(long)]
+        end_pattern: Vec<String>,
+        /// Word list (one per line) to keep out of every puzzle, e.g. from analyze-set
+        #[arg(long)]
+        banned_words: Option<PathBuf>,
+        /// Append to the output file, skipping puzzles it already contains
+        #[arg(long)]
+        append: bool,
+        /// Write a sidecar .manifest.json with checksums and puzzle counts
+        #[arg(long)]
+        manifest: bool,
+        /// Continue an interrupted run from its checkpoint instead of starting over
+        #[arg(long)]
+        resume: bool,
+        /// JSON Lines file of earlier exports; their word pairs are not shipped
+        /// again, and this export's puzzle IDs are appended to it
+        #[arg(long)]
+        history_file: Option<PathBuf>,
+        /// Only avoid pairs shipped by the last N exports in the history file
+        #[arg(long, requires = "history_file")]
+        no_repeat_within: Option<usize>,
+        /// Most puzzles any one word (endpoint or intermediate) may appear in
+        #[arg(long)]
+        max_word_reuse: Option<usize>,
+    },
+    /// Generate an endless-mode chain of puzzles
+    ///
+    /// Each puzzle starts at the end word of the previous one, so the chain
+    /// reads as one long sequence of mini-ladders of the chosen difficulty.
+    /// Every `--checkpoint-every` chain words a checkpoint is marked, where
+    /// players can resume.
+    Chain {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Number of puzzles in the chain
+        #[arg(short, long, default_value = "50")]
+        length: usize,
+        /// Difficulty of every puzzle (easy, medium, hard), bounding its steps
+        #[arg(long, default_value = "easy")]
+        difficulty: Difficulty,
+        /// Mark a checkpoint every N chain words
+        #[arg(long, default_value = "10")]
+        checkpoint_every: usize,
+        /// Output format: text or json
+        #[arg(short, long, default_value = "json")]
+        format: OutputFormat,
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Build tournament packs of puzzles with increasing optimal lengths
+    ///
+    /// Generates candidate puzzles for every difficulty the requested step
+    /// counts fall into, then picks one puzzle per step count for each pack.
+    /// By default the puzzles of a pack share no words, and no puzzle is used
+    /// in two packs.
+    Tournament {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Comma-separated optimal step counts of each pack's puzzles, in order
+        #[arg(long, value_delimiter = ',', default_value = "4,5,6,7,8")]
+        steps: Vec<usize>,
+        /// Number of packs to build
+        #[arg(long, default_value = "1")]
+        packs: usize,
+        /// Candidate puzzles to generate per difficulty level
+        #[arg(long, default_value = "200")]
+        pool: usize,
+        /// Let the puzzles of a pack share words
+        #[arg(long)]
+        allow_shared_words: bool,
+        /// Output format: text, json, or sql
+        #[arg(short, long, default_value = "json")]
+        format: OutputFormat,
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Export every puzzle between the base words
+    ///
+    /// Builds the full catalog of solvable base word pairs, with one search
+    /// per start word, instead of sampling random pairs. Meant for small
+    /// curated base word lists of a few hundred words.
+    Enumerate {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Only export puzzles of this difficulty (easy, medium, hard)
+        #[arg(long)]
+        difficulty: Option<Difficulty>,
+        /// Output format: text, json, jsonl, markdown, msgpack, cbor, or sql
+        #[arg(short, long, default_value = "json")]
+        format: OutputFormat,
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Include masked clue words (and the full solution in SQL output)
+        #[arg(long)]
+        clues: bool,
+    },
+    /// Download a word list into the data directory
+    ///
+    /// Fetches a well-known list (enable, popular) or any URL, normalizes it
+    /// to lowercase alphabetic words, and writes it with a `.source.json`
+    /// record of the URL and hashes.
+    #[cfg(feature = "fetch")]
+    FetchDict {
+        /// Well-known word list to download
+        #[arg(long, default_value = "enable", conflicts_with = "url")]
+        list: WordListArg,
+        /// Download a plain-text word list from this URL instead
+        #[arg(long)]
+        url: Option<String>,
+        /// Dictionary file to write (defaults to config value)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
     /// Export dictionary to SQL format for mobile applications
     ///
@@ -188,7 +1026,7 @@ pub enum Commands {
         /// Path to dictionary file (defaults to config value)
         #[arg(short, long, default_value = "data/dictionary.txt")]
         dict: PathBuf,
-        /// Output file path for the SQL export (optional, defaults to output/ directory)
+        /// Output file path for the SQL export (optional, defaults to output/ directory, `-` for stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
         /// Include CREATE TABLE schema in SQL output
@@ -197,49 +1035,575 @@ pub enum Commands {
         /// Batch size for SQL INSERT statements
         #[arg(long, default_value = "100")]
         batch_size: usize,
+        /// Word frequency list (most common first) for a frequency_rank column
+        #[arg(long)]
+        frequencies: Option<PathBuf>,
+        /// Base words file for an is_base_word column
+        #[arg(short = 'b', long)]
+        base_words: Option<PathBuf>,
+        /// Leave out words that have no neighbors and so appear in no ladder
+        #[arg(long)]
+        exclude_isolated: bool,
+        /// Write a sidecar .manifest.json with checksums and puzzle counts
+        #[arg(long)]
+        manifest: bool,
     },
-    /// Verify that a puzzle sequence is valid
+    /// Export the word graph (adjacency structure) for external analysis
     ///
-    /// Checks whether a comma-separated sequence of words forms a valid
-    /// word ladder where each consecutive pair differs by exactly one letter.
-    Verify {
+    /// Writes every edge between words one step apart under the edge rules in
+    /// DOT, GraphML, or CSV edge-list format, optionally restricted to a single
+    /// word length.
+    /// With `--around`, only the words near a puzzle's shortest ladder are
+    /// exported, with the ladder highlighted in DOT and drawn in SVG.
+    ExportGraph {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Graph format: dot, graphml, csv, or svg
+        #[arg(short, long, default_value = "dot")]
+        format: GraphOutputFormat,
+        /// Only export words of this length
+        #[arg(short, long, conflicts_with = "around")]
+        length: Option<usize>,
+        /// Only export the neighborhood of the shortest ladder between two
+        /// words, e.g. cat..dog
+        #[arg(long, value_name = "START..END")]
+        around: Option<String>,
+        /// Most steps a neighborhood word may be from the ladder
+        #[arg(long, default_value = "2", requires = "around")]
+        radius: usize,
+        /// Comma-separated edge rules (defaults to config value, i.e. substitute)
+        #[arg(long, value_delimiter = ',')]
+        rules: Vec<RuleArg>,
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Run a long-lived daemon answering JSON requests on stdin
+    ///
+    /// Loads the dictionary once, then reads newline-delimited JSON requests
+    /// (solve, verify, generate, stats, reload) from stdin and writes one JSON
+    /// response per line to stdout. Status messages are written to stderr.
+    /// `reload` rereads the dictionary and base words in the background.
+    Daemon {
         /// Path to dictionary file (defaults to config value)
         #[arg(short, long, default_value = "data/dictionary.txt")]
         dict: PathBuf,
         /// Path to base words file (defaults to config value)
         #[arg(short = 'b', long, default_value = "data/base_words.txt")]
         base_words: PathBuf,
-        /// Puzzle as comma-separated words (e.g., "cat,cot,cog,dog")
-        #[arg(short, long)]
-        puzzle: String,
+        /// Number of solve answers to keep in the LRU cache (0 disables it; defaults to config value)
+        #[arg(long)]
+        cache_size: Option<usize>,
     },
-}
-
-/// Resolves the output path, providing a default if none is specified.
-///
-/// If no output path is provided, generates a default filename based on the format
-/// and places it in the output directory. If a relative path is provided, resolves
-/// it relative to the output directory.
-///
-/// # Arguments
-///
-/// * `output` - Optional user-specified output path
-/// * `config` - Configuration containing output directory
-/// * `format` - Output format for default filename generation
-/// * `default_name` - Default filename prefix (without extension)
-///
-/// # Returns
-///
-/// Returns the resolved output path.
-fn resolve_output_path(
-    output: Option<PathBuf>,
+    /// Serve the daemon's methods over HTTP
+    ///
+    /// Answers POST /v1/solve, /v1/verify, /v1/generate, /v1/reload and
+    /// GET /v1/stats with a warm graph. With API keys configured, each request
+    /// must send one as `Authorization: Bearer <key>`; every key is rate limited.
+    /// Reloading is only available to admin keys.
+    Serve {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Address to listen on (defaults to config value, 127.0.0.1:8080)
+        #[arg(long)]
+        bind: Option<String>,
+        /// File with one accepted API key per line (authentication is disabled without keys)
+        #[arg(long)]
+        api_keys_file: Option<PathBuf>,
+        /// File with one admin API key per line; only admin keys may reload (disabled without keys)
+        #[arg(long)]
+        admin_keys_file: Option<PathBuf>,
+        /// Requests allowed per minute for each API key or client address (0 disables limiting)
+        #[arg(long)]
+        rate_limit: Option<u32>,
+        /// Most puzzles one /v1/generate request may ask for (defaults to config value, 50)
+        #[arg(long)]
+        max_count: Option<usize>,
+        /// Most word pairs one /v1/generate request tries (defaults to config value, 10000)
+        #[arg(long)]
+        max_attempts: Option<usize>,
+        /// Most words one /v1/solve request may visit (defaults to config value, 1000000)
+        #[arg(long)]
+        solve_max_visited: Option<usize>,
+        /// Milliseconds one /v1/solve request may search (defaults to config value, 5000)
+        #[arg(long)]
+        solve_timeout_ms: Option<u64>,
+        /// Number of solve answers to keep in the LRU cache (0 disables it; defaults to config value)
+        #[arg(long)]
+        cache_size: Option<usize>,
+        /// Print the OpenAPI document of the HTTP API to stdout and exit
+        #[arg(long)]
+        dump_openapi: bool,
+    },
+    /// Serve the gRPC service for backend integration
+    ///
+    /// Exposes Solve, Verify, GeneratePuzzle, and GenerateBatch (streamed one
+    /// puzzle per message) as defined in `proto/wordladder_service.proto`.
+    #[cfg(feature = "grpc")]
+    GrpcServe {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:50051")]
+        bind: std::net::SocketAddr,
+    },
+    /// Report base words that cannot produce good puzzles
+    ///
+    /// Lists base words that are missing from the dictionary, have no
+    /// neighbors, sit in a tiny connected component, or have fewer
+    /// neighbors than the minimum degree.
+    CheckWords {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Report words in components with fewer words than this
+        #[arg(long, default_value = "10")]
+        min_component: usize,
+        /// Report words with fewer neighbors than this
+        #[arg(long, default_value = "2")]
+        min_degree: usize,
+    },
+    /// Write a base words file picked from the dictionary by word frequency
+    ///
+    /// Keeps the most common words of each length that have enough neighbors
+    /// and lie in the largest component of their length, so any two curated
+    /// words of one length are connected by a ladder.
+    CurateBase {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Word frequency list, most common first (first word of each line)
+        #[arg(long, value_name = "PATH")]
+        frequencies: PathBuf,
+        /// Most words kept per word length
+        #[arg(long, default_value = "500")]
+        per_length: usize,
+        /// Skip words with fewer neighbors than this
+        #[arg(long, default_value = "2")]
+        min_neighbors: usize,
+        /// Output file path (optional, defaults to output/base_words.txt, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Estimate how many distinct puzzles each difficulty supports
+    ///
+    /// Counts the base word pairs whose shortest ladder falls in each
+    /// difficulty's step range, so batches don't ask for more puzzles than
+    /// the dictionary can produce. Large base word lists are sampled.
+    Capacity {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Only estimate this difficulty (easy, medium, hard)
+        #[arg(long)]
+        difficulty: Option<Difficulty>,
+    },
+    /// Report dictionary size and estimated graph memory
+    ///
+    /// Builds the word graph for every length and estimates the heap memory
+    /// held by the words, the adjacency, and the lookup indices. Pass
+    /// `--compact` to measure the CSR adjacency layout instead of lists.
+    Stats {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Measure the compact CSR adjacency layout
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Check which word pairs can be joined by a ladder
+    ///
+    /// Reads one `start,end` pair per line and reports the shortest ladder
+    /// length of each pair, or why no ladder exists. Blank lines and lines
+    /// starting with `#` are skipped.
+    CheckPairs {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// File with one comma-separated word pair per line, or `-` for stdin
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+    /// Relabel puzzle difficulty from observed player results
+    ///
+    /// Reads a JSON puzzle set (as written by `batch --format json`) and a CSV
+    /// of `puzzle_id,solve_rate,avg_time` rows, reassigns difficulty labels by
+    /// how hard players found each puzzle, and writes the relabeled set.
+    Calibrate {
+        /// JSON file containing the puzzle set
+        #[arg(short, long)]
+        input: PathBuf,
+        /// CSV file with puzzle_id,solve_rate,avg_time rows
+        #[arg(long)]
+        stats: PathBuf,
+        /// Output format: json or sql
+        #[arg(short, long, default_value = "json")]
+        format: OutputFormat,
+        /// Output file path (optional, defaults to output/ directory, `-` for stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Count word usage across a puzzle set and flag over-used intermediate words
+    ///
+    /// Reads a JSON puzzle set (as written by `batch --format json`), lists the
+    /// words appearing in the most puzzles, and flags intermediate words used
+    /// by more than `--max-share` of the puzzles. The flagged words can be
+    /// written to a file and passed back to `batch` or `generate-mobile` with
+    /// `--banned-words` to regenerate a more varied set.
+    AnalyzeSet {
+        /// JSON file containing the puzzle set
+        #[arg(short, long)]
+        input: PathBuf,
+        /// Highest share of puzzles (0 to 1) one intermediate word may appear in
+        #[arg(long, default_value = "0.1")]
+        max_share: f64,
+        /// Number of most used words to list
+        #[arg(long, default_value = "20")]
+        top: usize,
+        /// Write the flagged words to this file, one per line
+        #[arg(long)]
+        ban_file: Option<PathBuf>,
+    },
+    /// Interactively review a generated batch and keep the approved puzzles
+    ///
+    /// Pages through a JSON batch (as written by `batch --format json`),
+    /// showing each path, quality metrics, and alternative paths. Accepted
+    /// puzzles are written to a new JSON file when the review ends.
+    #[cfg(feature = "tui")]
+    Review {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// JSON file containing the puzzles to review
+        #[arg(short, long)]
+        input: PathBuf,
+        /// Output file for approved puzzles (optional, defaults to output/approved.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Verify that a puzzle sequence is valid
+    ///
+    /// Checks whether a comma-separated sequence of words forms a valid
+    /// word ladder where each consecutive pair differs by exactly one letter.
+    /// With `--file`, verifies one ladder per line and prints the failures
+    /// and a summary. With `--optimal`, solutions longer than the shortest
+    /// ladder are reported with their number of extra steps and count as invalid.
+    Verify {
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+        /// Puzzle as comma-separated words (e.g., "cat,cot,cog,dog")
+        #[arg(short, long, required_unless_present = "file", conflicts_with = "file")]
+        puzzle: Option<String>,
+        /// File with one comma-separated puzzle per line, or `-` for stdin
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        /// Also require the shortest solution and report any extra steps
+        #[arg(long)]
+        optimal: bool,
+    },
+    /// Sign an exported file with an ed25519 key
+    ///
+    /// Writes a detached hex signature next to the file (`puzzles.sql` gets
+    /// `puzzles.sql.sig`). With `--generate-key`, a new signing key is written
+    /// to `--key` first and its public key to `--key` with a `.pub` extension.
+    #[cfg(feature = "sign")]
+    SignPack {
+        /// Exported file to sign
+        file: PathBuf,
+        /// File containing the hex signing key
+        #[arg(short, long)]
+        key: PathBuf,
+        /// Create a new signing key at --key instead of reading one
+        #[arg(long)]
+        generate_key: bool,
+    },
+    /// Check a downloaded pack against its manifest and signature
+    ///
+    /// Compares the file's SHA-256 with its sidecar manifest (as written by
+    /// `--manifest`) and, with `--public-key` (`sign` feature), checks its
+    /// detached signature. Exits with code 3 if any check fails.
+    VerifyPack {
+        /// Exported file to check
+        file: PathBuf,
+        /// Manifest to check against (defaults to the file's sidecar manifest)
+        #[arg(short, long)]
+        manifest: Option<PathBuf>,
+        /// File containing the signer's hex public key
+        #[cfg(feature = "sign")]
+        #[arg(long)]
+        public_key: Option<PathBuf>,
+    },
+    /// Re-solve the puzzles of a SQL export and check their step counts
+    ///
+    /// Reads every row of the puzzles table in a `.sql` export (or a SQLite
+    /// `.db` with the `sqlite` feature), solves each pair with the current
+    /// dictionary and rules, and compares the shortest ladder and its
+    /// difficulty with the exported `min_steps` and `difficulty`. Exported
+    /// solutions must be valid shortest ladders. Exits with code 3 if any
+    /// puzzle does not match.
+    VerifyExport {
+        /// SQL export or SQLite database to check
+        file: PathBuf,
+        /// Path to dictionary file (defaults to config value)
+        #[arg(short, long, default_value = "data/dictionary.txt")]
+        dict: PathBuf,
+        /// Path to base words file (defaults to config value)
+        #[arg(short = 'b', long, default_value = "data/base_words.txt")]
+        base_words: PathBuf,
+    },
+    /// Print the protobuf schema of `--format proto` puzzle packs
+    ///
+    /// Game clients can compile the schema with `protoc` to read packs
+    /// written by `generate` and `batch`.
+    #[cfg(feature = "proto")]
+    DumpSchema {
+        /// File to write the schema to instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print shell completions or a man page
+    ///
+    /// Packagers can install the output as the completion script of a shell
+    /// (bash, zsh, fish, powershell, or elvish) or, with `--man`, as the
+    /// `wordladder-engine(1)` man page.
+    Completions {
+        /// Shell to print completions for
+        #[arg(required_unless_present = "man")]
+        shell: Option<Shell>,
+        /// Print a man page in roff format instead of completions
+        #[arg(long, conflicts_with = "shell")]
+        man: bool,
+        /// File to write to instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+impl Commands {
+    /// Returns the subcommand's name as typed on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Commands::Generate { .. } => "generate",
+            Commands::Solve { .. } => "solve",
+            Commands::Batch { .. } => "batch",
+            Commands::GenerateMobile { .. } => "generate-mobile",
+            Commands::Chain { .. } => "chain",
+            Commands::Tournament { .. } => "tournament",
+            Commands::Enumerate { .. } => "enumerate",
+            #[cfg(feature = "fetch")]
+            Commands::FetchDict { .. } => "fetch-dict",
+            Commands::ExportDict { .. } => "export-dict",
+            Commands::ExportGraph { .. } => "export-graph",
+            Commands::Daemon { .. } => "daemon",
+            Commands::Serve { .. } => "serve",
+            #[cfg(feature = "grpc")]
+            Commands::GrpcServe { .. } => "grpc-serve",
+            Commands::CheckWords { .. } => "check-words",
+            Commands::CurateBase { .. } => "curate-base",
+            Commands::Capacity { .. } => "capacity",
+            Commands::Stats { .. } => "stats",
+            Commands::CheckPairs { .. } => "check-pairs",
+            Commands::Calibrate { .. } => "calibrate",
+            Commands::AnalyzeSet { .. } => "analyze-set",
+            #[cfg(feature = "tui")]
+            Commands::Review { .. } => "review",
+            #[cfg(feature = "sign")]
+            Commands::SignPack { .. } => "sign-pack",
+            Commands::VerifyPack { .. } => "verify-pack",
+            Commands::VerifyExport { .. } => "verify-export",
+            #[cfg(feature = "proto")]
+            Commands::DumpSchema { .. } => "dump-schema",
+            Commands::Verify { .. } => "verify",
+            Commands::Completions { .. } => "completions",
+        }
+    }
+
+    /// Returns `true` if the subcommand exports puzzles in a format that can
+    /// leave out the solutions (`--omit-solutions`) or carry canonical hashes
+    /// (`--canonical-hash`): JSON, JSON Lines, or SQL.
+    pub fn can_omit_solutions(&self) -> bool {
+        match self {
+            Commands::Generate { format, .. }
+            | Commands::Batch { format, .. }
+            | Commands::Enumerate { format, .. } => matches!(
+                format,
+                OutputFormat::Json | OutputFormat::Jsonl | OutputFormat::Sql
+            ),
+            // Mobile exports are always SQL
+            Commands::GenerateMobile { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the subcommand exports puzzles as SQL, whose solutions
+    /// can be encrypted (`--encrypt-solutions`).
+    #[cfg(feature = "encrypt")]
+    pub fn can_encrypt_solutions(&self) -> bool {
+        match self {
+            Commands::Generate { format, .. }
+            | Commands::Batch { format, .. }
+            | Commands::Enumerate { format, .. } => matches!(format, OutputFormat::Sql),
+            Commands::GenerateMobile { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the subcommand writes its export to stdout (`--output -`,
+    /// or `completions` without `--output`).
+    pub fn writes_to_stdout(&self) -> bool {
+        let output = match self {
+            // Single text puzzles are printed as status messages
+            Commands::Generate { output, format, .. } if !matches!(format, OutputFormat::Text) => {
+                output
+            }
+            Commands::Batch { output, .. }
+            | Commands::GenerateMobile { output, .. }
+            | Commands::Chain { output, .. }
+            | Commands::Tournament { output, .. }
+            | Commands::Enumerate { output, .. }
+            | Commands::ExportDict { output, .. }
+            | Commands::ExportGraph { output, .. }
+            | Commands::CurateBase { output, .. }
+            | Commands::Calibrate { output, .. } => output,
+            #[cfg(feature = "proto")]
+            Commands::DumpSchema { output } => output,
+            Commands::Completions { output, .. } => return output.as_deref().is_none_or(is_stdout),
+            _ => &None,
+        };
+        output.as_deref().is_some_and(is_stdout)
+    }
+}
+
+impl OutputFormat {
+    /// Returns the file extension used for default output filenames.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::Jsonl => "jsonl",
+            OutputFormat::Sql => "sql",
+            OutputFormat::Markdown => "md",
+            OutputFormat::Svg => "svg",
+            OutputFormat::Anki => "tsv",
+            OutputFormat::Msgpack => "msgpack",
+            OutputFormat::Cbor => "cbor",
+            #[cfg(feature = "proto")]
+            OutputFormat::Proto => "pb",
+        }
+    }
+
+    /// Returns the compact binary format written by this output format, if any.
+    pub fn binary_format(&self) -> Option<BinaryFormat> {
+        match self {
+            OutputFormat::Msgpack => Some(BinaryFormat::MessagePack),
+            OutputFormat::Cbor => Some(BinaryFormat::Cbor),
+            _ => None,
+        }
+    }
+}
+
+/// Overrides the configured edge rules when any are given on the command line.
+fn resolve_edge_rules(rules: Vec<RuleArg>, config: &Config) -> Config {
+    if rules.is_empty() {
+        config.clone()
+    } else {
+        config
+            .clone()
+            .with_edge_rules(rules.into_iter().map(EdgeRule::from).collect())
+    }
+}
+
+/// Overrides the configured SQL ID strategy when one is given on the command line.
+fn resolve_id_strategy(id_strategy: Option<IdStrategyArg>, config: &Config) -> Config {
+    match id_strategy {
+        Some(strategy) => config.clone().with_sql_id_strategy(strategy.into()),
+        None => config.clone(),
+    }
+}
+
+/// Resolves the dictionary path, substituting the config value for the CLI default.
+fn resolve_dict_path(dict: PathBuf, config: &Config) -> PathBuf {
+    if dict == Path::new("data/dictionary.txt") {
+        config.dictionary_path.clone()
+    } else {
+        dict
+    }
+}
+
+/// Resolves the base words path, substituting the config value for the CLI default.
+fn resolve_base_words_path(base_words: PathBuf, config: &Config) -> PathBuf {
+    if base_words == Path::new("data/base_words.txt") {
+        config.base_words_path.clone()
+    } else {
+        base_words
+    }
+}
+
+/// Resolves the output path, providing a default if none is specified.
+///
+/// If no output path is provided, generates a default filename based on the format
+/// and places it in the output directory. If a relative path is provided, resolves
+/// it relative to the output directory. A path of `-`, for stdout, is returned as is.
+///
+/// # Arguments
+///
+/// * `output` - Optional user-specified output path
+/// * `config` - Configuration containing output directory
+/// * `format` - Output format for default filename generation
+/// * `default_name` - Default filename prefix (without extension)
+///
+/// # Returns
+///
+/// Returns the resolved output path.
+fn resolve_output_path(
+    output: Option<PathBuf>,
     config: &Config,
     format: &OutputFormat,
     default_name: &str,
+) -> Result<PathBuf> {
+    resolve_output_path_with_extension(output, config, format.extension(), default_name)
+}
+
+/// Resolves the output path like `resolve_output_path`, using an explicit extension
+/// for the default filename. Used by exports whose formats are not `OutputFormat`s.
+fn resolve_output_path_with_extension(
+    output: Option<PathBuf>,
+    config: &Config,
+    extension: &str,
+    default_name: &str,
 ) -> Result<PathBuf> {
     use std::fs;
 
     let output_path = match output {
+        Some(path) if is_stdout(&path) => return Ok(path),
         Some(path) => {
             // If it's an absolute path, use it as-is
             if path.is_absolute() {
@@ -251,11 +1615,6 @@ fn resolve_output_path(
         }
         _ => {
             // Generate default filename based on format
-            let extension = match format {
-                OutputFormat::Text => "txt",
-                OutputFormat::Json => "json",
-                OutputFormat::Sql => "sql",
-            };
             config
                 .output_dir
                 .join(format!("{}.{}", default_name, extension))
@@ -282,7 +1641,9 @@ fn resolve_output_path(
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` on successful execution, or an error if something fails.
+/// Returns the `CliExit` status of a completed command (which may still be a
+/// failure such as `NoPath`), or an error if something fails. Pass errors to
+/// `CliExit::from_error` to get their exit status.
 ///
 /// # Examples
 ///
@@ -296,8 +1657,90 @@ fn resolve_output_path(
 /// let cli = Cli::parse_from(args);
 /// // Note: This would normally run the CLI, but we skip execution in doctest
 /// ```
-pub fn run(cli: Cli) -> Result<()> {
-    let config = Config::default();
+pub fn run(cli: Cli) -> Result<CliExit> {
+    let mut config = Config::default().with_verbose(cli.verbose);
+    if let Some(landmarks) = cli.landmarks {
+        config = config.with_landmarks_per_length(landmarks);
+    }
+    if let Some(accepted_words) = cli.accepted_words {
+        config = config.with_accepted_words_path(accepted_words);
+    }
+    if cli.omit_solutions {
+        if !cli.command.can_omit_solutions() {
+            return Err(anyhow::Error::msg(ConfigError(format!(
+                "--omit-solutions only applies to JSON, JSON Lines, and SQL puzzle exports, not {}",
+                cli.command.name()
+            ))));
+        }
+        config = config.with_omit_solutions(true);
+    }
+    #[cfg(feature = "encrypt")]
+    if let Some(key_file) = cli.key_file {
+        if !cli.command.can_encrypt_solutions() {
+            return Err(anyhow::Error::msg(ConfigError(format!(
+                "--encrypt-solutions only applies to SQL puzzle exports, not {}",
+                cli.command.name()
+            ))));
+        }
+        let key = std::fs::read_to_string(&key_file)
+            .map_err(anyhow::Error::from)
+            .and_then(|hex| SolutionKey::from_hex(&hex))
+            .with_context(|| {
+                ConfigError(format!(
+                    "Cannot read solution key from {}",
+                    key_file.display()
+                ))
+            })?;
+        config = config.with_solution_key(key);
+    }
+    if let Some(mode) = cli.canonical_hash {
+        if !cli.command.can_omit_solutions() {
+            return Err(anyhow::Error::msg(ConfigError(format!(
+                "--canonical-hash only applies to JSON, JSON Lines, and SQL puzzle exports, not {}",
+                cli.command.name()
+            ))));
+        }
+        let options = CanonicalHashOptions::from(mode);
+        // A hash over the path could be matched against candidate ladders
+        if hides_solutions(&config) && !options.path_independent {
+            return Err(anyhow::Error::msg(ConfigError(
+                "Hidden solutions need an endpoint-only --canonical-hash \
+                 (endpoints or unordered-endpoints)"
+                    .to_string(),
+            )));
+        }
+        config = config.with_canonical_hash(Some(options));
+    }
+    let mut normalization = config.word_normalization;
+    if let Some(case) = cli.case_mapping {
+        normalization.case = case.into();
+    }
+    if let Some(punctuation) = cli.punctuation {
+        normalization.punctuation = punctuation.into();
+    }
+    config = config.with_word_normalization(normalization);
+    if let Some(unit) = cli.comparison_unit {
+        config = config.with_comparison_unit(unit.into());
+    }
+    if cli.exclude_regex.is_some() {
+        config = config.with_exclude_regex(cli.exclude_regex);
+    }
+    let mut word_lengths = config.word_lengths.clone();
+    for (lengths, policy) in [
+        (&cli.easy_lengths, &mut word_lengths.easy),
+        (&cli.medium_lengths, &mut word_lengths.medium),
+        (&cli.hard_lengths, &mut word_lengths.hard),
+    ] {
+        if let (Some(&min), Some(&max)) = (lengths.iter().min(), lengths.iter().max()) {
+            *policy = Some(min..=max);
+        }
+    }
+    config = config.with_word_lengths(word_lengths);
+    let mut report = CliReport::new(cli.command.name(), cli.json);
+    if cli.command.writes_to_stdout() {
+        report.use_stderr();
+    }
+    let mut exit = CliExit::Success;
 
     match cli.command {
         Commands::Generate {
@@ -305,79 +1748,370 @@ pub fn run(cli: Cli) -> Result<()> {
             base_words,
             start,
             end,
+            difficulty,
             format,
             output,
             include_schema,
             batch_size,
+            clues,
+            unique_solution,
+            require_reversible,
+            meta,
+            stars,
+            schema_mode,
+            steps_table,
+            id_strategy,
+            endpoint_weighting,
+            frequencies,
+            max_endpoint_reuse,
+            start_pattern,
+            end_pattern,
+            rules,
+            manifest,
         } => {
-            let dict_path = if dict == PathBuf::from("data/dictionary.txt") {
-                config.dictionary_path.clone()
-            } else {
-                dict
-            };
-            let base_words_path = if base_words == PathBuf::from("data/base_words.txt") {
-                config.base_words_path.clone()
-            } else {
-                base_words
-            };
+            let config = resolve_id_strategy(id_strategy, &resolve_edge_rules(rules, &config))
+                .with_unique_solution(unique_solution)
+                .with_require_reversible(require_reversible)
+                .with_puzzle_meta(meta)
+                .with_star_ratings(stars);
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
 
-            let generator = load_generator(dict_path.as_path(), base_words_path.as_path())?;
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let generator =
+                apply_endpoint_weighting(generator, endpoint_weighting, frequencies.as_deref())?;
+            let generator = apply_max_endpoint_reuse(generator, max_endpoint_reuse)?;
+            let generator = apply_endpoint_filters(generator, &start_pattern, &end_pattern)?;
+            let manifest_dict = manifest.then_some(dict_path.as_path());
 
             // If no specific arguments provided, generate bulk puzzles
             if start.is_none() && end.is_none() {
+                let cancel = install_cancel_handler()?;
                 match format {
                     OutputFormat::Sql => {
                         let output_path =
                             resolve_output_path(output, &config, &format, "bulk_puzzles")?;
+                        let sql_config = SqlExportConfig {
+                            batch_size,
+                            include_schema: include_schema
+                                .unwrap_or(config.include_schema_by_default),
+                            include_comments: true,
+                            include_clues: clues,
+                            include_unique_solution: config.unique_solution,
+                            include_meta: config.puzzle_meta,
+                            include_reverse_path: config.require_reversible,
+                            include_stars: config.star_ratings,
+                            schema_mode: schema_mode.clone().into(),
+                            include_steps_table: steps_table,
+                            id_strategy: config.sql_id_strategy,
+                            omit_solutions: config.omit_solutions,
+                            canonical_hash: config.canonical_hash,
+                            #[cfg(feature = "encrypt")]
+                            solution_key: config.solution_key,
+                        };
                         generate_bulk_sql(
                             &generator,
                             &config,
                             &output_path,
-                            include_schema.unwrap_or(config.include_schema_by_default),
-                            batch_size,
+                            sql_config,
+                            manifest_dict,
+                            &cancel,
+                            &mut report,
                         )?;
                     }
-                    _ => generate_bulk_puzzles(&generator, &config, &format)?,
+                    _ => generate_bulk_puzzles(
+                        &generator,
+                        &config,
+                        &format,
+                        clues,
+                        manifest_dict,
+                        &cancel,
+                        &mut report,
+                    )?,
                 }
             } else {
-                let (start_word, end_word) = if let (Some(s), Some(e)) = (start, end) {
-                    (s.to_lowercase(), e.to_lowercase())
-                } else {
-                    generator.pick_random_words()?
+                let normalization = generator.graph().normalization();
+                let (start_word, end_word) = match (start, end) {
+                    (Some(s), Some(e)) => (
+                        normalization.normalize_query(&s),
+                        Some(normalization.normalize_query(&e)),
+                    ),
+                    (Some(s), None) => (normalization.normalize_query(&s), None),
+                    _ => {
+                        let (s, e) = generator.pick_random_words()?;
+                        (s, Some(e))
+                    }
+                };
+                let difficulty = difficulty.unwrap_or(Difficulty::Medium);
+                let generated = match &end_word {
+                    Some(end_word) => {
+                        generator
+                            .generate_puzzle(&start_word, end_word)
+                            .map_err(|e| {
+                                let message = match &e {
+                                    PuzzleError::Ladder(error) => {
+                                        describe_ladder_error(generator.graph(), error)
+                                    }
+                                    _ => e.to_string(),
+                                };
+                                (puzzle_error_exit(&e), message)
+                            })
+                    }
+                    None => match missing_words(generator.graph(), &[&start_word]) {
+                        Some(missing) => Err((CliExit::InvalidWords, missing)),
+                        None => generator
+                            .generate_from_start(&start_word, 1, difficulty)
+                            .puzzles
+                            .pop()
+                            .ok_or_else(|| {
+                                (
+                                    CliExit::NoPath,
+                                    format!(
+                                        "No {} puzzle starts at {}",
+                                        format!("{:?}", difficulty).to_lowercase(),
+                                        start_word
+                                    ),
+                                )
+                            }),
+                    },
                 };
 
-                if let Some(puzzle) = generator.generate_puzzle(&start_word, &end_word) {
-                    match format {
-                        OutputFormat::Json => {
-                            println!("{}", puzzle.to_json()?);
+                match generated {
+                    Err((code, message)) => {
+                        report.warn(message);
+                        exit = code;
+                    }
+                    Ok(mut puzzle) => {
+                        if config.unique_solution {
+                            generator.annotate_uniqueness(&mut puzzle);
+                        }
+                        if config.require_reversible {
+                            generator.annotate_reverse_path(&mut puzzle);
                         }
-                        OutputFormat::Sql => {
-                            let output_path = resolve_output_path(
-                                output,
-                                &config,
-                                &format,
-                                &format!("{}_{}", start_word, end_word),
-                            )?;
-                            let sql_config = SqlExportConfig {
-                                batch_size,
-                                include_schema: include_schema
-                                    .unwrap_or(config.include_schema_by_default),
-                                include_comments: true,
-                            };
-                            let mut exporter = SqlExporter::with_config(sql_config);
-                            let sql = exporter.export_puzzles(&[puzzle])?;
-                            std::fs::write(&output_path, sql)?;
-                            println!("SQL puzzle exported to {}", output_path.display());
+                        report.count("puzzles", 1);
+                        if report.is_json() {
+                            if config.omit_solutions {
+                                report.set_result(MaskedPuzzle::from(&puzzle))?;
+                            } else {
+                                report.set_result(&puzzle)?;
+                            }
                         }
-                        OutputFormat::Text => {
-                            println!("Start: {}", puzzle.start);
-                            println!("End: {}", puzzle.end);
-                            println!("Path: {}", puzzle.path.join(" -> "));
-                            println!("Difficulty: {:?}", puzzle.difficulty);
+                        match format {
+                            OutputFormat::Json => {
+                                if !report.is_json() {
+                                    println!(
+                                        "{}",
+                                        puzzle_to_json(
+                                            &puzzle,
+                                            clues,
+                                            config.omit_solutions,
+                                            config.canonical_hash
+                                        )?
+                                    );
+                                }
+                            }
+                            OutputFormat::Jsonl => {
+                                if !report.is_json() {
+                                    json_lines_exporter(
+                                        std::io::stdout(),
+                                        clues,
+                                        config.omit_solutions,
+                                        config.canonical_hash,
+                                    )
+                                    .write_puzzle(puzzle.clone())?;
+                                }
+                            }
+                            OutputFormat::Markdown => {
+                                if !report.is_json() {
+                                    print!(
+                                        "{}",
+                                        MarkdownExporter::new()
+                                            .with_include_clues(clues)
+                                            .export_puzzles(std::slice::from_ref(&puzzle))
+                                    );
+                                }
+                            }
+                            #[cfg(feature = "proto")]
+                            OutputFormat::Proto => {
+                                let output_path = resolve_output_path(
+                                    output,
+                                    &config,
+                                    &format,
+                                    &format!("{}_{}", puzzle.start, puzzle.end),
+                                )?;
+                                let pack = PuzzlePack::new(vec![puzzle.clone()]);
+                                write_output(&output_path, pack.encode())?;
+                                report.output(&output_path);
+                                report.info(format!(
+                                    "Protobuf puzzle pack exported to {}",
+                                    output_path.display()
+                                ));
+                                if let Some(dict) = manifest_dict {
+                                    write_manifest(
+                                        &output_path,
+                                        dict,
+                                        &[puzzle],
+                                        false,
+                                        &mut report,
+                                    )?;
+                                }
+                            }
+                            OutputFormat::Msgpack | OutputFormat::Cbor => {
+                                let output_path = resolve_output_path(
+                                    output,
+                                    &config,
+                                    &format,
+                                    &format!("{}_{}", puzzle.start, puzzle.end),
+                                )?;
+                                let bytes = binary_exporter(&format, clues)
+                                    .export_puzzles(std::slice::from_ref(&puzzle))?;
+                                write_output(&output_path, bytes)?;
+                                report.output(&output_path);
+                                report.info(format!(
+                                    "Binary puzzle exported to {}",
+                                    output_path.display()
+                                ));
+                                if let Some(dict) = manifest_dict {
+                                    write_manifest(
+                                        &output_path,
+                                        dict,
+                                        &[puzzle],
+                                        false,
+                                        &mut report,
+                                    )?;
+                                }
+                            }
+                            OutputFormat::Sql => {
+                                let output_path = resolve_output_path(
+                                    output,
+                                    &config,
+                                    &format,
+                                    &format!("{}_{}", puzzle.start, puzzle.end),
+                                )?;
+                                let sql_config = SqlExportConfig {
+                                    batch_size,
+                                    include_schema: include_schema
+                                        .unwrap_or(config.include_schema_by_default),
+                                    include_comments: true,
+                                    include_clues: clues,
+                                    include_unique_solution: config.unique_solution,
+                                    include_meta: config.puzzle_meta,
+                                    include_reverse_path: config.require_reversible,
+                                    include_stars: config.star_ratings,
+                                    schema_mode: schema_mode.clone().into(),
+                                    include_steps_table: steps_table,
+                                    id_strategy: config.sql_id_strategy,
+                                    omit_solutions: config.omit_solutions,
+                                    canonical_hash: config.canonical_hash,
+                                    #[cfg(feature = "encrypt")]
+                                    solution_key: config.solution_key,
+                                };
+                                let mut exporter = SqlExporter::with_config(sql_config);
+                                let sql = exporter.export_puzzles(std::slice::from_ref(&puzzle))?;
+                                write_output(&output_path, sql)?;
+                                report.output(&output_path);
+                                report.info(format!(
+                                    "SQL puzzle exported to {}",
+                                    output_path.display()
+                                ));
+                                if let Some(dict) = manifest_dict {
+                                    write_manifest(
+                                        &output_path,
+                                        dict,
+                                        &[puzzle],
+                                        false,
+                                        &mut report,
+                                    )?;
+                                }
+                            }
+                            OutputFormat::Svg => {
+                                let output_path = resolve_output_path(
+                                    output,
+                                    &config,
+                                    &format,
+                                    &format!("{}_{}", puzzle.start, puzzle.end),
+                                )?;
+                                let svg = SvgExporter::new()
+                                    .with_include_clues(clues)
+                                    .export_puzzle(&puzzle);
+                                write_output(&output_path, svg)?;
+                                report.output(&output_path);
+                                report.info(format!(
+                                    "SVG ladder exported to {}",
+                                    output_path.display()
+                                ));
+                                if let Some(dict) = manifest_dict {
+                                    write_manifest(
+                                        &output_path,
+                                        dict,
+                                        &[puzzle],
+                                        false,
+                                        &mut report,
+                                    )?;
+                                }
+                            }
+                            OutputFormat::Anki => {
+                                let output_path = resolve_output_path(
+                                    output,
+                                    &config,
+                                    &format,
+                                    &format!("{}_{}", puzzle.start, puzzle.end),
+                                )?;
+                                let deck = AnkiExporter::new()
+                                    .with_include_clues(clues)
+                                    .export_puzzles(std::slice::from_ref(&puzzle));
+                                write_output(&output_path, deck)?;
+                                report.output(&output_path);
+                                report.info(format!(
+                                    "Anki flashcard exported to {}",
+                                    output_path.display()
+                                ));
+                                if let Some(dict) = manifest_dict {
+                                    write_manifest(
+                                        &output_path,
+                                        dict,
+                                        &[puzzle],
+                                        false,
+                                        &mut report,
+                                    )?;
+                                }
+                            }
+                            OutputFormat::Text => {
+                                report.info(format!("Start: {}", puzzle.start));
+                                report.info(format!("End: {}", puzzle.end));
+                                report.info(format!("Path: {}", puzzle.path.join(" -> ")));
+                                report.info(format!("Difficulty: {:?}", puzzle.difficulty));
+                                if clues {
+                                    report.info(format!(
+                                        "Clues: {}",
+                                        puzzle.to_clue_format().join(" -> ")
+                                    ));
+                                }
+                                if let Some(unique) = puzzle.unique_solution {
+                                    report.info(format!(
+                                        "Unique solution: {}",
+                                        if unique { "yes" } else { "no" }
+                                    ));
+                                }
+                                if let Some(stars) = puzzle.stars {
+                                    report.info(format!(
+                                        "Par: {} (3 stars up to {} steps, 2 stars up to {})",
+                                        stars.par, stars.three_stars, stars.two_stars
+                                    ));
+                                }
+                                if config.require_reversible {
+                                    report.info(format!(
+                                        "Reverse path: {}",
+                                        puzzle
+                                            .reverse_path
+                                            .as_ref()
+                                            .map_or("none".to_string(), |path| path.join(" -> "))
+                                    ));
+                                }
+                            }
                         }
                     }
-                } else {
-                    println!("No path found between {} and {}", start_word, end_word);
                 }
             }
         }
@@ -386,82 +2120,352 @@ pub fn run(cli: Cli) -> Result<()> {
             base_words,
             count,
             difficulty,
+            distribution,
+            start,
             format,
             output,
             include_schema,
             batch_size,
+            clues,
+            unique_solution,
+            require_reversible,
+            meta,
+            stars,
+            schema_mode,
+            steps_table,
+            id_strategy,
+            endpoint_weighting,
+            frequencies,
+            max_endpoint_reuse,
+            start_pattern,
+            end_pattern,
+            banned_words,
+            append,
+            manifest,
+            resume,
         } => {
-            let dict_path = if dict == PathBuf::from("data/dictionary.txt") {
-                config.dictionary_path.clone()
-            } else {
-                dict
+            let config = resolve_id_strategy(id_strategy, &config)
+                .with_unique_solution(unique_solution)
+                .with_require_reversible(require_reversible)
+                .with_puzzle_meta(meta)
+                .with_star_ratings(stars);
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let output_path =
+                resolve_output_path(output, &config, &format, &format!("batch_{}", difficulty))?;
+            let existing = load_existing_puzzles(append, &output_path, &format, &report)?;
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let generator =
+                apply_endpoint_weighting(generator, endpoint_weighting, frequencies.as_deref())?;
+            let generator = apply_max_endpoint_reuse(generator, max_endpoint_reuse)?;
+            let generator = apply_endpoint_filters(generator, &start_pattern, &end_pattern)?;
+            let generator = apply_banned_words(generator, banned_words.as_deref())?;
+
+            let distribution = match difficulty {
+                DifficultyArg::Mixed => Some(resolve_distribution(&distribution, &config)?),
+                _ if !distribution.is_empty() => {
+                    return Err(anyhow::Error::msg(ConfigError(
+                        "--distribution requires --difficulty mixed".to_string(),
+                    )));
+                }
+                _ => None,
             };
-            let base_words_path = if base_words == PathBuf::from("data/base_words.txt") {
-                config.base_words_path.clone()
-            } else {
-                base_words
+            // A mixed batch generates each level's share of --count, then
+            // balances the pool like generate-mobile
+            let levels: Vec<(Difficulty, usize)> = match &distribution {
+                Some(distribution) => difficulty
+                    .levels()
+                    .into_iter()
+                    .zip(distribution.split(count))
+                    .collect(),
+                None => difficulty
+                    .levels()
+                    .into_iter()
+                    .map(|level| (level, count))
+                    .collect(),
             };
+            let targets: BTreeMap<String, usize> = levels
+                .iter()
+                .map(|(level, count)| (level.name().to_string(), *count))
+                .collect();
+            let start =
+                start.map(|start| generator.graph().normalization().normalize_query(&start));
+            if let Some(missing) = start
+                .as_deref()
+                .and_then(|start| missing_words(generator.graph(), &[start]))
+            {
+                report.warn(missing);
+                report.finish()?;
+                return Ok(CliExit::InvalidWords);
+            }
 
-            let generator = load_generator(dict_path.as_path(), base_words_path.as_path())?;
-
-            let diff = match difficulty.as_str() {
-                "easy" => Difficulty::Easy,
-                "medium" => Difficulty::Medium,
-                "hard" => Difficulty::Hard,
-                _ => Difficulty::Medium,
+            let cancel = install_cancel_handler()?;
+            let sql_config = SqlExportConfig {
+                batch_size,
+                include_schema: include_schema.unwrap_or(config.include_schema_by_default)
+                    && existing.as_ref().is_none_or(ExistingPuzzles::is_empty),
+                include_comments: true,
+                include_clues: clues,
+                include_unique_solution: config.unique_solution,
+                include_meta: config.puzzle_meta,
+                include_reverse_path: config.require_reversible,
+                include_stars: config.star_ratings,
+                schema_mode: schema_mode.clone().into(),
+                include_steps_table: steps_table,
+                id_strategy: config.sql_id_strategy,
+                omit_solutions: config.omit_solutions,
+                canonical_hash: config.canonical_hash,
+                #[cfg(feature = "encrypt")]
+                solution_key: config.solution_key,
             };
 
-            let puzzles = generator.generate_batch(count, diff);
-            let puzzle_count = puzzles.len();
+            // Formats that can grow record by record are written as puzzles
+            // are generated, so an interrupted run keeps its partial output
+            if start.is_none()
+                && distribution.is_none()
+                && let Some(exporter) = open_stream_exporter(
+                    &format,
+                    &output_path,
+                    append,
+                    clues,
+                    sql_config.clone(),
+                    existing.as_ref(),
+                )?
+            {
+                let (mut checkpoint, resumed) = open_checkpoint(
+                    &output_path,
+                    "batch",
+                    &targets,
+                    resume,
+                    hides_solutions(&config),
+                    &mut report,
+                )?;
+                let mut output = StreamedOutput::new(exporter, existing);
+                for puzzle in resumed {
+                    output.write(puzzle)?;
+                }
+                for &(level, count) in &levels {
+                    stream_with_progress(
+                        &generator,
+                        count,
+                        level,
+                        &cancel,
+                        &mut report,
+                        checkpoint.as_mut(),
+                        |puzzle| output.write(puzzle),
+                    )?;
+                }
+                let counts = output.finish(&output_path, &mut report)?;
+                let label = match format {
+                    OutputFormat::Sql => "SQL",
+                    OutputFormat::Jsonl => "JSON Lines",
+                    _ => "text",
+                };
+                report.count("puzzles", counts.total());
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles and saved to {}",
+                    counts.total(),
+                    label,
+                    output_path.display()
+                ));
+                if manifest {
+                    write_manifest_counts(&output_path, &dict_path, counts, append, &mut report)?;
+                }
+                finish_checkpoint(checkpoint, &cancel, &mut report)?;
+                report.finish()?;
+                return Ok(exit);
+            }
 
-            let output_path =
-                resolve_output_path(output, &config, &format, &format!("batch_{}", difficulty))?;
+            let (checkpoint, puzzles) = match &start {
+                Some(start) => {
+                    let mut puzzles = Vec::new();
+                    for &(level, count) in &levels {
+                        let name = level.name();
+                        let result = generator.generate_from_start(start, count, level);
+                        eprintln!("{:>8}: {}", name, result);
+                        report.count(&format!("{}_attempts", name), result.attempts);
+                        report.timing(name, result.elapsed);
+                        if result.puzzles.len() < count {
+                            report.warn(format!(
+                                "Only {} {} puzzles start at {}",
+                                result.puzzles.len(),
+                                name,
+                                start
+                            ));
+                        }
+                        puzzles.extend(result.puzzles);
+                    }
+                    (None, puzzles)
+                }
+                None => {
+                    let (mut checkpoint, mut puzzles) = open_checkpoint(
+                        &output_path,
+                        "batch",
+                        &targets,
+                        resume,
+                        hides_solutions(&config),
+                        &mut report,
+                    )?;
+                    for &(level, count) in &levels {
+                        puzzles.extend(generate_with_progress(
+                            &generator,
+                            count,
+                            level,
+                            &cancel,
+                            &mut report,
+                            checkpoint.as_mut(),
+                        )?);
+                    }
+                    (checkpoint, puzzles)
+                }
+            };
+            let puzzles = match &distribution {
+                Some(distribution) => {
+                    let mut builder = BalancedSetBuilder::new()
+                        .with_ratios(distribution.clone())
+                        .with_dedup(DedupPolicy::UniquePairs);
+                    if let Some(seed) = generator.seed() {
+                        builder = builder.with_seed(seed);
+                    }
+                    builder.build(&puzzles, count)?
+                }
+                None => puzzles,
+            };
+            let puzzles =
+                skip_existing_puzzles(existing.as_ref(), puzzles, &output_path, &mut report);
+            let puzzle_count = puzzles.len();
+            report.count("puzzles", puzzle_count);
+            report.output(&output_path);
 
             match format {
                 OutputFormat::Sql => {
-                    let sql_config = SqlExportConfig {
-                        batch_size,
-                        include_schema: include_schema.unwrap_or(config.include_schema_by_default),
-                        include_comments: true,
-                    };
-                    let mut exporter = SqlExporter::with_config(sql_config);
+                    let mut exporter = sql_exporter_for(sql_config, existing.as_ref());
                     let sql = exporter.export_puzzles(&puzzles)?;
-                    std::fs::write(&output_path, sql)?;
-                    println!(
+                    write_sql_output(&output_path, &sql, append)?;
+                    report.info(format!(
                         "Generated {} SQL puzzles and saved to {}",
                         puzzle_count,
                         output_path.display()
-                    );
+                    ));
                 }
                 OutputFormat::Json => {
-                    let json_array: Result<Vec<_>, _> =
-                        puzzles.iter().map(|p| p.to_json()).collect();
+                    let json_array: Result<Vec<_>, _> = puzzles
+                        .iter()
+                        .map(|p| {
+                            puzzle_to_json(p, clues, config.omit_solutions, config.canonical_hash)
+                        })
+                        .collect();
                     let json_array = json_array?;
-                    let json_output = format!("[\n{}\n]", json_array.join(",\n"));
-                    std::fs::write(&output_path, json_output)?;
-                    println!(
+                    let json_output = if append && output_path.exists() {
+                        incremental::append_json(
+                            &std::fs::read_to_string(&output_path)?,
+                            &json_array,
+                        )?
+                    } else {
+                        format!("[\n{}\n]", json_array.join(",\n"))
+                    };
+                    write_output(&output_path, json_output)?;
+                    report.info(format!(
                         "Generated {} JSON puzzles and saved to {}",
                         puzzle_count,
                         output_path.display()
+                    ));
+                }
+                OutputFormat::Jsonl => {
+                    let mut exporter = json_lines_exporter(
+                        open_output_file(&output_path, append)?,
+                        clues,
+                        config.omit_solutions,
+                        config.canonical_hash,
                     );
+                    for puzzle in &puzzles {
+                        exporter.write_puzzle(puzzle.clone())?;
+                    }
+                    exporter.finish()?;
+                    report.info(format!(
+                        "Generated {} JSON Lines puzzles and saved to {}",
+                        puzzle_count,
+                        output_path.display()
+                    ));
                 }
                 OutputFormat::Text => {
                     let mut output_content = String::new();
-                    for puzzle in puzzles {
-                        let solution = puzzle.path.join(" -> ");
-                        output_content.push_str(&format!(
-                            "{} -> {}: {}\n",
-                            puzzle.start, puzzle.end, solution
-                        ));
+                    for puzzle in &puzzles {
+                        output_content.push_str(&puzzle_to_text_line(puzzle, clues));
                     }
-                    std::fs::write(&output_path, output_content)?;
-                    println!(
+                    write_output(&output_path, output_content)?;
+                    report.info(format!(
                         "Generated {} text puzzles and saved to {}",
                         puzzle_count,
                         output_path.display()
-                    );
+                    ));
+                }
+                OutputFormat::Markdown => {
+                    let markdown = MarkdownExporter::new()
+                        .with_include_clues(clues)
+                        .export_puzzles(&puzzles);
+                    write_output(&output_path, markdown)?;
+                    report.info(format!(
+                        "Generated {} Markdown puzzles and saved to {}",
+                        puzzle_count,
+                        output_path.display()
+                    ));
+                }
+                OutputFormat::Svg => {
+                    let svg = SvgExporter::new()
+                        .with_include_clues(clues)
+                        .export_puzzles(&puzzles);
+                    write_output(&output_path, svg)?;
+                    report.info(format!(
+                        "Generated {} SVG ladders and saved to {}",
+                        puzzle_count,
+                        output_path.display()
+                    ));
                 }
+                OutputFormat::Anki => {
+                    let deck = AnkiExporter::new()
+                        .with_include_clues(clues)
+                        .export_puzzles(&puzzles);
+                    write_output(&output_path, deck)?;
+                    report.info(format!(
+                        "Generated {} Anki flashcards and saved to {}",
+                        puzzle_count,
+                        output_path.display()
+                    ));
+                }
+                OutputFormat::Msgpack | OutputFormat::Cbor => {
+                    let exporter = binary_exporter(&format, clues);
+                    let bytes = if append && output_path.exists() {
+                        exporter.append_puzzles(&std::fs::read(&output_path)?, &puzzles)?
+                    } else {
+                        exporter.export_puzzles(&puzzles)?
+                    };
+                    write_output(&output_path, bytes)?;
+                    report.info(format!(
+                        "Generated {} {} puzzles and saved to {}",
+                        puzzle_count,
+                        format.extension(),
+                        output_path.display()
+                    ));
+                }
+                #[cfg(feature = "proto")]
+                OutputFormat::Proto => {
+                    write_output(&output_path, PuzzlePack::new(puzzles.clone()).encode())?;
+                    report.info(format!(
+                        "Generated {} protobuf puzzles and saved to {}",
+                        puzzle_count,
+                        output_path.display()
+                    ));
+                }
+            }
+
+            if manifest {
+                write_manifest(&output_path, &dict_path, &puzzles, append, &mut report)?;
             }
+            finish_checkpoint(checkpoint, &cancel, &mut report)?;
         }
         Commands::GenerateMobile {
             dict,
@@ -471,142 +2475,2447 @@ pub fn run(cli: Cli) -> Result<()> {
             easy_ratio,
             medium_ratio,
             hard_ratio,
+            normalize_ratios,
             include_schema,
             batch_size,
+            unique_solution,
+            require_reversible,
+            meta,
+            stars,
+            schema_mode,
+            steps_table,
+            id_strategy,
+            endpoint_weighting,
+            frequencies,
+            max_endpoint_reuse,
+            start_pattern,
+            end_pattern,
+            banned_words,
+            append,
+            manifest,
+            resume,
+            history_file,
+            no_repeat_within,
+            max_word_reuse,
         } => {
-            let dict_path = if dict == PathBuf::from("data/dictionary.txt") {
-                config.dictionary_path.clone()
-            } else {
-                dict
+            let ratios = DifficultyDistribution {
+                easy: easy_ratio,
+                medium: medium_ratio,
+                hard: hard_ratio,
             };
-            let base_words_path = if base_words == PathBuf::from("data/base_words.txt") {
-                config.base_words_path.clone()
+            let ratios = if normalize_ratios {
+                ratios
+                    .normalized()
+                    .context(ConfigError("Invalid difficulty ratios".to_string()))?
             } else {
-                base_words
+                ratios.validate().context(ConfigError(
+                    "Invalid difficulty ratios (pass --normalize-ratios to scale them to sum to 1.0)"
+                        .to_string(),
+                ))?;
+                ratios
             };
 
-            let generator = load_generator(dict_path.as_path(), base_words_path.as_path())?;
+            let config = resolve_id_strategy(id_strategy, &config)
+                .with_unique_solution(unique_solution)
+                .with_require_reversible(require_reversible)
+                .with_puzzle_meta(meta)
+                .with_star_ratings(stars);
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let output_path =
+                resolve_output_path(output, &config, &OutputFormat::Sql, "mobile_puzzles")?;
+            let existing =
+                load_existing_puzzles(append, &output_path, &OutputFormat::Sql, &report)?;
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let generator =
+                apply_endpoint_weighting(generator, endpoint_weighting, frequencies.as_deref())?;
+            let generator = apply_max_endpoint_reuse(generator, max_endpoint_reuse)?;
+            let generator = apply_endpoint_filters(generator, &start_pattern, &end_pattern)?;
+            let generator = apply_banned_words(generator, banned_words.as_deref())?;
+
+            let targets = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+                .into_iter()
+                .map(|difficulty| {
+                    (
+                        format!("{:?}", difficulty).to_lowercase(),
+                        config.bulk_puzzle_count * 2,
+                    )
+                })
+                .collect();
+            let (mut checkpoint, mut all_puzzles) = open_checkpoint(
+                &output_path,
+                "generate-mobile",
+                &targets,
+                resume,
+                hides_solutions(&config),
+                &mut report,
+            )?;
 
             // Generate all possible puzzles first
-            println!("Generating base puzzles for mobile optimization...");
-            let all_puzzles = generate_all_puzzles_for_mobile(&generator, &config)?;
-            println!("Generated {} base puzzles", all_puzzles.len());
+            report.info("Generating base puzzles for mobile optimization...");
+            let cancel = install_cancel_handler()?;
+            all_puzzles.extend(generate_all_puzzles_for_mobile(
+                &generator,
+                &config,
+                &cancel,
+                &mut report,
+                checkpoint.as_mut(),
+            )?);
+            report.info(format!("Generated {} base puzzles", all_puzzles.len()));
+            report.count("base_puzzles", all_puzzles.len());
+            let all_puzzles =
+                skip_existing_puzzles(existing.as_ref(), all_puzzles, &output_path, &mut report);
 
             // Create balanced set
             let sql_config = SqlExportConfig {
                 batch_size,
-                include_schema: include_schema.unwrap_or(config.include_schema_by_default),
+                include_schema: include_schema.unwrap_or(config.include_schema_by_default)
+                    && existing.as_ref().is_none_or(ExistingPuzzles::is_empty),
                 include_comments: true,
+                include_clues: false,
+                include_unique_solution: config.unique_solution,
+                include_meta: config.puzzle_meta,
+                include_reverse_path: config.require_reversible,
+                include_stars: config.star_ratings,
+                schema_mode: schema_mode.clone().into(),
+                include_steps_table: steps_table,
+                id_strategy: config.sql_id_strategy,
+                omit_solutions: config.omit_solutions,
+                canonical_hash: config.canonical_hash,
+                #[cfg(feature = "encrypt")]
+                solution_key: config.solution_key,
+            };
+            let mut history = match &history_file {
+                Some(path) => load_export_history(path)?,
+                None => ExportHistory::new(),
+            };
+            let mut builder = BalancedSetBuilder::new()
+                .with_ratios(ratios.clone())
+                .with_history(&history, no_repeat_within);
+            if let Some(max) = max_word_reuse {
+                builder = builder.with_max_word_reuse(max);
+            }
+            let balanced_puzzles = builder.build(&all_puzzles, count)?;
+            let reason = match (history_file.is_some(), max_word_reuse.is_some()) {
+                (true, true) => Some("are not in the export history and fit --max-word-reuse"),
+                (true, false) => Some("are not in the export history"),
+                (false, true) => Some("fit --max-word-reuse"),
+                (false, false) => None,
+            };
+            if let Some(reason) = reason
+                && balanced_puzzles.len() < count
+            {
+                report.warn(format!(
+                    "Only {} of {} puzzles {}",
+                    balanced_puzzles.len(),
+                    count,
+                    reason
+                ));
+            }
+
+            // Export to SQL
+            let mut sql_exporter = sql_exporter_for(sql_config, existing.as_ref());
+            let sql = sql_exporter.export_puzzles(&balanced_puzzles)?;
+            write_sql_output(&output_path, &sql, append)?;
+            report.output(&output_path);
+            if manifest {
+                write_manifest(
+                    &output_path,
+                    &dict_path,
+                    &balanced_puzzles,
+                    append,
+                    &mut report,
+                )?;
+            }
+            if let Some(path) = &history_file {
+                record_export_history(path, &mut history, &sql, &mut report)?;
+            }
+
+            report.count("puzzles", balanced_puzzles.len());
+            report.info(format!(
+                "Generated {} balanced mobile puzzles and saved to {}",
+                balanced_puzzles.len(),
+                output_path.display()
+            ));
+            report.info(format!(
+                "Distribution: Easy: {:.1}%, Medium: {:.1}%, Hard: {:.1}%",
+                ratios.easy * 100.0,
+                ratios.medium * 100.0,
+                ratios.hard * 100.0
+            ));
+            finish_checkpoint(checkpoint, &cancel, &mut report)?;
+        }
+        Commands::Chain {
+            dict,
+            base_words,
+            length,
+            difficulty,
+            checkpoint_every,
+            format,
+            output,
+        } => {
+            if !matches!(format, OutputFormat::Text | OutputFormat::Json) {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "chain supports text and json output only".to_string(),
+                )));
+            }
+            if checkpoint_every == 0 {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "--checkpoint-every must be at least 1".to_string(),
+                )));
+            }
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let output_path = resolve_output_path(output, &config, &format, "chain")?;
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+
+            let result = generator.generate_chain(length, difficulty);
+            let chain = result.puzzles;
+            let content = match format {
+                OutputFormat::Json => chain_to_json(&chain, checkpoint_every)?,
+                _ => chain_to_text(&chain, checkpoint_every),
+            };
+            write_output(&output_path, content)?;
+            report.output(&output_path);
+
+            report.count("puzzles", chain.len());
+            if chain.len() < length {
+                report.warn(format!(
+                    "The chain reached a dead end after {} of {} puzzles",
+                    chain.len(),
+                    length
+                ));
+                if chain.is_empty() {
+                    exit = CliExit::NoPath;
+                }
+            }
+            report.info(format!(
+                "Generated a chain of {} puzzles and saved to {}",
+                chain.len(),
+                output_path.display()
+            ));
+        }
+        Commands::Tournament {
+            dict,
+            base_words,
+            steps,
+            packs,
+            pool,
+            allow_shared_words,
+            format,
+            output,
+        } => {
+            if !matches!(
+                format,
+                OutputFormat::Text | OutputFormat::Json | OutputFormat::Sql
+            ) {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "tournament supports text, json, and sql output only".to_string(),
+                )));
+            }
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let output_path = resolve_output_path(output, &config, &format, "tournament")?;
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+
+            let mut difficulties = Vec::new();
+            for &step in &steps {
+                let difficulty = generator
+                    .difficulty_thresholds()
+                    .classify(step)
+                    .with_context(|| {
+                        ConfigError(format!("{} steps is outside every difficulty level", step))
+                    })?;
+                if !difficulties.contains(&difficulty) {
+                    difficulties.push(difficulty);
+                }
+            }
+            let mut candidates = Vec::new();
+            for difficulty in difficulties {
+                let result = generator.generate_batch(pool, difficulty);
+                report.info(format!(
+                    "Generated {} {:?} candidates",
+                    result.puzzles.len(),
+                    difficulty
+                ));
+                candidates.extend(result.puzzles);
+            }
+            report.count("candidates", candidates.len());
+
+            let mut spec = ProgressionSpec::new(steps);
+            spec.disjoint_words = !allow_shared_words;
+            let mut built: Vec<Vec<Puzzle>> = Vec::new();
+            while built.len() < packs {
+                match build_progression(&candidates, &spec) {
+                    Ok(pack) => {
+                        candidates.retain(|candidate| {
+                            !pack.iter().any(|used| {
+                                used.start == candidate.start && used.end == candidate.end
+                            })
+                        });
+                        built.push(pack);
+                    }
+                    Err(e) => {
+                        report.warn(format!("Built {} of {} packs: {}", built.len(), packs, e));
+                        break;
+                    }
+                }
+            }
+
+            match format {
+                OutputFormat::Sql => {
+                    let sql = SqlExporter::new()
+                        .with_batch_size(config.sql_batch_size)
+                        .with_include_schema(config.include_schema_by_default)
+                        .export_puzzles(&built.concat())?;
+                    write_output(&output_path, sql)?;
+                }
+                OutputFormat::Json => {
+                    write_output(&output_path, serde_json::to_string_pretty(&built)?)?;
+                }
+                _ => {
+                    let mut text = String::new();
+                    for (index, pack) in built.iter().enumerate() {
+                        text.push_str(&format!("# Pack {}\n", index + 1));
+                        for puzzle in pack {
+                            text.push_str(&puzzle_to_text_line(puzzle, false));
+                        }
+                    }
+                    write_output(&output_path, text)?;
+                }
+            }
+            report.output(&output_path);
+            report.count("packs", built.len());
+            if built.is_empty() && packs > 0 {
+                exit = CliExit::NoPath;
+            }
+            report.info(format!(
+                "Built {} tournament packs and saved to {}",
+                built.len(),
+                output_path.display()
+            ));
+        }
+        Commands::Enumerate {
+            dict,
+            base_words,
+            difficulty,
+            format,
+            output,
+            clues,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let name = difficulty.map_or("all", |level| level.name());
+            let output_path =
+                resolve_output_path(output, &config, &format, &format!("catalog_{}", name))?;
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let result = generator.enumerate_all(difficulty);
+            eprintln!("{:>8}: {}", name, result);
+            report.count("attempts", result.attempts);
+            report.timing("enumerate", result.elapsed);
+            for level in Difficulty::ALL {
+                let count = result
+                    .puzzles
+                    .iter()
+                    .filter(|puzzle| puzzle.difficulty == level)
+                    .count();
+                if count > 0 {
+                    report.count(level.name(), count);
+                }
+            }
+
+            write_puzzles(&output_path, &format, &result.puzzles, clues, &config)?;
+            report.count("puzzles", result.puzzles.len());
+            report.output(&output_path);
+            report.info(format!(
+                "Enumerated {} puzzles and saved to {}",
+                result.puzzles.len(),
+                output_path.display()
+            ));
+        }
+        Commands::Solve {
+            dict,
+            start,
+            end,
+            ends,
+            rules,
+            avoid,
+            trace,
+            max_visited,
+            timeout_ms,
+        } => {
+            let config = resolve_edge_rules(rules, &config);
+            let dict_path = resolve_dict_path(dict, &config);
+
+            let mut graph = apply_exclude_regex(
+                WordGraph::new()
+                    .with_edge_rules(config.edge_rules.clone())
+                    .with_normalization(config.word_normalization)
+                    .with_comparison_unit(config.comparison_unit),
+                &config,
+            )?;
+            load_dictionary(&mut graph, &dict_path, config.verbose)?;
+
+            let normalization = graph.normalization();
+            let start = normalization.normalize_query(&start);
+            let avoid: HashSet<String> = avoid
+                .iter()
+                .map(|word| normalization.normalize_query(word))
+                .filter(|word| !word.is_empty())
+                .collect();
+            match end {
+                None => exit = solve_to_many(&graph, &start, &ends, &mut report)?,
+                Some(end) => {
+                    let end = normalization.normalize_query(&end);
+                    if let Err(e) = graph.check_pair(&start, &end) {
+                        report.warn(describe_ladder_error(&graph, &e));
+                        exit = CliExit::InvalidWords;
+                    } else if avoid.contains(&start) || avoid.contains(&end) {
+                        report.warn("Cannot avoid the start or end word");
+                        exit = CliExit::InvalidWords;
+                    } else {
+                        let options = SolveOptions {
+                            max_visited,
+                            timeout: timeout_ms.map(Duration::from_millis),
+                        };
+                        let (outcome, solve_trace) = if trace {
+                            let (path, solve_trace) =
+                                graph.find_shortest_path_avoiding_traced(&start, &end, &avoid);
+                            report.info(solve_trace.to_string());
+                            let outcome = path.map_or(SolveOutcome::NoPath, SolveOutcome::Found);
+                            (outcome, Some(solve_trace))
+                        } else {
+                            let outcome = graph
+                                .find_shortest_path_avoiding_with(&start, &end, &avoid, &options);
+                            (outcome, None)
+                        };
+                        match outcome {
+                            SolveOutcome::Found(path) => {
+                                report.info(path.join(" -> "));
+                                report.info(format!("Steps: {}", path.len() - 1));
+                                report.count("steps", path.len() - 1);
+                                match solve_trace {
+                                    Some(solve_trace) => report.set_result(serde_json::json!({
+                                        "path": path,
+                                        "trace": solve_trace,
+                                    }))?,
+                                    None => report.set_result(&path)?,
+                                }
+                            }
+                            SolveOutcome::NoPath => {
+                                report.warn(format!("No path found between {} and {}", start, end));
+                                exit = CliExit::NoPath;
+                            }
+                            SolveOutcome::BudgetExceeded => {
+                                report.warn(format!(
+                                    "Search budget exceeded between {} and {}",
+                                    start, end
+                                ));
+                                exit = CliExit::BudgetExceeded;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "sign")]
+        Commands::SignPack {
+            file,
+            key,
+            generate_key,
+        } => {
+            let signing_key = if generate_key {
+                if key.exists() {
+                    return Err(anyhow::Error::msg(ConfigError(format!(
+                        "Refusing to overwrite existing key {}",
+                        key.display()
+                    ))));
+                }
+                let signing_key = signing::generate_key();
+                write_private_key(&key, &hex::encode(&signing_key.to_bytes()))?;
+                let public_path = key.with_extension("pub");
+                std::fs::write(
+                    &public_path,
+                    hex::encode(signing_key.verifying_key().as_bytes()),
+                )?;
+                report.output(&key);
+                report.output(&public_path);
+                report.info(format!(
+                    "Signing key written to {} and public key to {}",
+                    key.display(),
+                    public_path.display()
+                ));
+                signing_key
+            } else {
+                std::fs::read_to_string(&key)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|hex| signing::signing_key_from_hex(&hex))
+                    .with_context(|| {
+                        ConfigError(format!("Cannot read signing key from {}", key.display()))
+                    })?
+            };
+
+            let bytes = std::fs::read(&file)
+                .with_context(|| ConfigError(format!("Cannot read {}", file.display())))?;
+            let signature_path = signing::signature_path(&file);
+            std::fs::write(&signature_path, signing::sign(&bytes, &signing_key))?;
+            report.output(&signature_path);
+            report.info(format!("Signature written to {}", signature_path.display()));
+        }
+        Commands::VerifyPack {
+            file,
+            manifest,
+            #[cfg(feature = "sign")]
+            public_key,
+        } => {
+            let bytes = std::fs::read(&file)
+                .with_context(|| ConfigError(format!("Cannot read {}", file.display())))?;
+            // An explicit manifest must exist; the default sidecar is optional
+            let manifest_required = manifest.is_some();
+            let manifest_path = manifest.unwrap_or_else(|| ExportManifest::sidecar_path(&file));
+            let mut checks = 0;
+            let mut failures = Vec::new();
+
+            if manifest_required || manifest_path.exists() {
+                let manifest = std::fs::read_to_string(&manifest_path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|json| ExportManifest::from_json(&json))
+                    .with_context(|| {
+                        ConfigError(format!("Cannot read manifest {}", manifest_path.display()))
+                    })?;
+                checks += 1;
+                if manifest.matches_output(&bytes) {
+                    report.info(format!("Checksum matches {}", manifest_path.display()));
+                } else {
+                    failures.push(format!(
+                        "Checksum does not match {}",
+                        manifest_path.display()
+                    ));
+                }
+            }
+
+            #[cfg(feature = "sign")]
+            if let Some(public_key) = public_key {
+                let verifying_key = std::fs::read_to_string(&public_key)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|hex| signing::verifying_key_from_hex(&hex))
+                    .with_context(|| {
+                        ConfigError(format!(
+                            "Cannot read public key from {}",
+                            public_key.display()
+                        ))
+                    })?;
+                let signature_path = signing::signature_path(&file);
+                checks += 1;
+                match std::fs::read_to_string(&signature_path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|signature| signing::verify(&bytes, &signature, &verifying_key))
+                {
+                    Ok(()) => {
+                        report.info(format!("Signature {} is valid", signature_path.display()))
+                    }
+                    Err(e) => failures.push(format!("{}: {}", signature_path.display(), e)),
+                }
+            }
+
+            if checks == 0 {
+                return Err(anyhow::Error::msg(ConfigError(format!(
+                    "Nothing to verify: {} has no manifest",
+                    file.display()
+                ))));
+            }
+            report.count("checks", checks);
+            report.count("failed", failures.len());
+            for failure in &failures {
+                report.warn(failure.clone());
+            }
+            if failures.is_empty() {
+                report.info(format!("{} passed {} check(s)", file.display(), checks));
+            } else {
+                exit = CliExit::InvalidWords;
+            }
+        }
+        Commands::VerifyExport {
+            file,
+            dict,
+            base_words,
+        } => {
+            let is_database = file
+                .extension()
+                .is_some_and(|extension| extension == "db" || extension == "sqlite");
+            let rows = if is_database {
+                #[cfg(feature = "sqlite")]
+                {
+                    sql::read_puzzle_rows_from_db(&file).with_context(|| {
+                        ConfigError(format!("Cannot read puzzles from {}", file.display()))
+                    })?
+                }
+                #[cfg(not(feature = "sqlite"))]
+                {
+                    return Err(anyhow::Error::msg(ConfigError(format!(
+                        "Reading {} needs the `sqlite` feature",
+                        file.display()
+                    ))));
+                }
+            } else {
+                std::fs::read_to_string(&file)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|contents| sql::parse_puzzle_rows(&contents))
+                    .with_context(|| {
+                        ConfigError(format!("Cannot read puzzles from {}", file.display()))
+                    })?
             };
-            let exporter = SqlExporter::with_config(sql_config.clone());
-            let balanced_puzzles = exporter.create_balanced_set(
-                &all_puzzles,
-                count,
-                easy_ratio,
-                medium_ratio,
-                hard_ratio,
+
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let pairs: Vec<(String, String)> = rows
+                .iter()
+                .map(|row| (row.start.clone(), row.end.clone()))
+                .collect();
+            let thresholds = generator.difficulty_thresholds();
+
+            let mut mismatched = 0;
+            for (row, pair) in rows.iter().zip(generator.solvable_pairs(&pairs)) {
+                let problem = match pair.status {
+                    PairStatus::NotInDictionary { word } => {
+                        Some(format!("'{}' is not in the dictionary", word))
+                    }
+                    PairStatus::Disconnected => Some("no ladder exists".to_string()),
+                    PairStatus::Solvable { steps } if steps != row.min_steps => Some(format!(
+                        "min_steps is {} but the shortest ladder has {} steps",
+                        row.min_steps, steps
+                    )),
+                    PairStatus::Solvable { steps } => match thresholds.classify(steps) {
+                        Some(level) if level != row.difficulty => Some(format!(
+                            "difficulty is {} but {} steps is {}",
+                            row.difficulty.name(),
+                            steps,
+                            level.name()
+                        )),
+                        None => Some(format!("{} steps is outside every difficulty range", steps)),
+                        Some(_) => row
+                            .solution
+                            .as_ref()
+                            .filter(|solution| {
+                                solution.len() != steps + 1
+                                    || solution.first() != Some(&row.start)
+                                    || solution.last() != Some(&row.end)
+                                    || generator.verify_puzzle(&solution.join(",")) != Ok(true)
+                            })
+                            .map(|_| "solution is not a valid shortest ladder".to_string()),
+                    },
+                };
+                if let Some(problem) = problem {
+                    mismatched += 1;
+                    report.warn(format!("{}: {}", row.id, problem));
+                }
+            }
+
+            report.count("puzzles", rows.len());
+            report.count("mismatched", mismatched);
+            if mismatched == 0 {
+                report.info(format!(
+                    "All {} puzzles in {} match the current engine",
+                    rows.len(),
+                    file.display()
+                ));
+            } else {
+                exit = CliExit::InvalidWords;
+                report.info(format!(
+                    "{} of {} puzzles in {} do not match the current engine",
+                    mismatched,
+                    rows.len(),
+                    file.display()
+                ));
+            }
+        }
+        #[cfg(feature = "proto")]
+        Commands::DumpSchema { output } => match output {
+            Some(output_path) => {
+                write_output(&output_path, proto::SCHEMA)?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Protobuf schema v{} written to {}",
+                    proto::SCHEMA_VERSION,
+                    output_path.display()
+                ));
+            }
+            None => print!("{}", proto::SCHEMA),
+        },
+        Commands::Completions { shell, man, output } => {
+            let mut command = Cli::command();
+            let mut buffer = Vec::new();
+            match shell {
+                Some(shell) if !man => {
+                    let name = command.get_name().to_string();
+                    clap_complete::generate(shell, &mut command, name, &mut buffer);
+                }
+                _ => clap_mangen::Man::new(command).render(&mut buffer)?,
+            }
+            let output_path = output.unwrap_or_else(|| PathBuf::from(STDOUT));
+            write_output(&output_path, buffer)?;
+            if !is_stdout(&output_path) {
+                report.output(&output_path);
+                report.info(format!(
+                    "{} written to {}",
+                    if man { "Man page" } else { "Completions" },
+                    output_path.display()
+                ));
+            }
+        }
+        Commands::Calibrate {
+            input,
+            stats,
+            format,
+            output,
+        } => {
+            if !matches!(format, OutputFormat::Json | OutputFormat::Sql) {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "calibrate supports json and sql output only".to_string(),
+                )));
+            }
+            let puzzles: Vec<Puzzle> = std::fs::read_to_string(&input)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(serde_json::from_str(&content)?))
+                .with_context(|| {
+                    ConfigError(format!("Cannot read puzzles from {}", input.display()))
+                })?;
+            let player_stats = std::fs::read_to_string(&stats)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| parse_player_stats(&content))
+                .with_context(|| {
+                    ConfigError(format!("Cannot read player stats from {}", stats.display()))
+                })?;
+
+            let model = CalibrationModel::fit(&player_stats, &puzzles);
+            let calibrated = model.apply(&puzzles);
+            let relabeled = puzzles
+                .iter()
+                .zip(&calibrated)
+                .filter(|(before, after)| before.difficulty != after.difficulty)
+                .count();
+
+            let output_path = resolve_output_path(output, &config, &format, "calibrated")?;
+            match format {
+                OutputFormat::Sql => {
+                    let sql = SqlExporter::new().export_puzzles(&calibrated)?;
+                    write_output(&output_path, sql)?;
+                }
+                _ => {
+                    let json_array: Result<Vec<_>, _> =
+                        calibrated.iter().map(|p| p.to_json()).collect();
+                    write_output(&output_path, format!("[\n{}\n]", json_array?.join(",\n")))?;
+                }
+            }
+            report.output(&output_path);
+            report.count("puzzles", calibrated.len());
+            report.count("observed", model.observed());
+            report.count("relabeled", relabeled);
+            report.info(format!(
+                "Matched player data for {} of {} puzzles; relabeled {}",
+                model.observed(),
+                calibrated.len(),
+                relabeled
+            ));
+            report.info(format!(
+                "Calibrated puzzles saved to {}",
+                output_path.display()
+            ));
+        }
+        Commands::AnalyzeSet {
+            input,
+            max_share,
+            top,
+            ban_file,
+        } => {
+            if !(0.0..=1.0).contains(&max_share) {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "--max-share must be between 0 and 1".to_string(),
+                )));
+            }
+            let puzzles: Vec<Puzzle> = std::fs::read_to_string(&input)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(serde_json::from_str(&content)?))
+                .with_context(|| {
+                    ConfigError(format!("Cannot read puzzles from {}", input.display()))
+                })?;
+
+            let usage = WordUsage::from_puzzles(&puzzles);
+            let most_used = usage.most_used(top);
+            let overused = usage.overused_intermediates(max_share);
+            for count in &most_used {
+                report.info(format!(
+                    "{}: {} puzzles ({} as intermediate, {} as endpoint)",
+                    count.word, count.puzzles, count.intermediate, count.endpoint
+                ));
+            }
+            for count in &overused {
+                report.warn(format!(
+                    "'{}' is an intermediate word of {:.0}% of puzzles",
+                    count.word,
+                    usage.intermediate_share(&count.word) * 100.0
+                ));
+            }
+
+            if let Some(ban_file) = &ban_file {
+                let words: String = overused
+                    .iter()
+                    .map(|count| format!("{}\n", count.word))
+                    .collect();
+                std::fs::write(ban_file, words)?;
+                report.output(ban_file);
+            }
+            report.count("puzzles", usage.puzzle_count());
+            report.count("overused", overused.len());
+            report.set_result(serde_json::json!({
+                "most_used": most_used,
+                "overused": overused,
+            }))?;
+            report.info(format!(
+                "Analyzed {} puzzles; {} over-used intermediate words",
+                usage.puzzle_count(),
+                overused.len()
+            ));
+        }
+        #[cfg(feature = "tui")]
+        Commands::Review {
+            dict,
+            base_words,
+            input,
+            output,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let puzzles: Vec<Puzzle> = std::fs::read_to_string(&input)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(serde_json::from_str(&content)?))
+                .with_context(|| {
+                    ConfigError(format!("Cannot read puzzles from {}", input.display()))
+                })?;
+            let total = puzzles.len();
+
+            let approved = crate::review::run(&generator, puzzles)?;
+
+            let output_path =
+                resolve_output_path(output, &config, &OutputFormat::Json, "approved")?;
+            let json_array: Result<Vec<_>, _> = approved.iter().map(|p| p.to_json()).collect();
+            std::fs::write(&output_path, format!("[\n{}\n]", json_array?.join(",\n")))?;
+            report.output(&output_path);
+            report.count("approved", approved.len());
+            report.count("reviewed", total);
+            report.info(format!(
+                "Approved {} of {} puzzles; saved to {}",
+                approved.len(),
+                total,
+                output_path.display()
+            ));
+        }
+        Commands::Verify {
+            dict,
+            base_words,
+            puzzle,
+            file,
+            optimal,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+
+            if let Some(file) = file {
+                let input = read_input(&file)?;
+                let summary = if optimal {
+                    generator.verify_many_optimal(&input)
+                } else {
+                    generator.verify_many(&input)
+                };
+                for failure in &summary.failures {
+                    report.info(format!(
+                        "line {}: {}: {}",
+                        failure.line, failure.puzzle, failure.reason
+                    ));
+                }
+                report.info(format!(
+                    "{} of {} puzzles are valid",
+                    summary.valid, summary.total
+                ));
+                report.count("puzzles", summary.total);
+                report.count("valid", summary.valid);
+                report.count("invalid", summary.failures.len());
+                if !summary.all_valid() {
+                    exit = CliExit::InvalidWords;
+                }
+                report.set_result(&summary)?;
+            } else if let Some(puzzle) = puzzle.as_deref().filter(|_| optimal) {
+                match generator.verify_optimal(puzzle) {
+                    Ok(optimality) => {
+                        report.info(if optimality.valid {
+                            "Puzzle is valid"
+                        } else {
+                            "Puzzle is invalid"
+                        });
+                        if !optimality.valid {
+                            warn_unknown_ladder_words(generator.graph(), puzzle, &mut report);
+                        }
+                        match (optimality.extra_steps(), optimality.shortest_steps) {
+                            (Some(0), _) => report
+                                .info(format!("Solution is optimal ({} steps)", optimality.steps)),
+                            (Some(extra), Some(shortest)) => report.info(format!(
+                                "Solution uses {} extra step(s): {} steps, shortest is {}",
+                                extra, optimality.steps, shortest
+                            )),
+                            _ => {}
+                        }
+                        if !optimality.is_optimal() {
+                            exit = CliExit::InvalidWords;
+                        }
+                        report.set_result(serde_json::json!({
+                            "valid": optimality.valid,
+                            "steps": optimality.steps,
+                            "shortest_steps": optimality.shortest_steps,
+                            "extra_steps": optimality.extra_steps(),
+                        }))?;
+                    }
+                    Err(e) => {
+                        report.warn(format!("Error: {}", e));
+                        exit = CliExit::InvalidWords;
+                    }
+                }
+            } else if let Some(puzzle) = puzzle {
+                match generator.verify_puzzle(&puzzle) {
+                    Ok(valid) => {
+                        report.info(if valid {
+                            "Puzzle is valid"
+                        } else {
+                            "Puzzle is invalid"
+                        });
+                        report.set_result(serde_json::json!({ "valid": valid }))?;
+                        if !valid {
+                            warn_unknown_ladder_words(generator.graph(), &puzzle, &mut report);
+                            exit = CliExit::InvalidWords;
+                        }
+                    }
+                    Err(e) => {
+                        report.warn(format!("Error: {}", e));
+                        exit = CliExit::InvalidWords;
+                    }
+                }
+            }
+        }
+        Commands::CheckWords {
+            dict,
+            base_words,
+            min_component,
+            min_degree,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let reports = generator.validate_base_words_with(&ValidationThresholds {
+                min_component_size: min_component,
+                min_degree,
+            });
+
+            for issue in &reports {
+                let reason = match issue.issue {
+                    BaseWordIssue::NotInDictionary => "not in dictionary".to_string(),
+                    BaseWordIssue::Isolated => "isolated (no neighbors)".to_string(),
+                    BaseWordIssue::SmallComponent { size } => {
+                        format!("in a component of only {} words", size)
+                    }
+                    BaseWordIssue::LowDegree { degree } => {
+                        format!("only {} neighbor(s)", degree)
+                    }
+                };
+                report.info(format!("{}: {}", issue.word, reason));
+            }
+            report.count("issues", reports.len());
+            report.count("base_words", generator.graph().get_base_words().len());
+            report.set_result(&reports)?;
+            report.info(format!(
+                "{} of {} base words have issues",
+                reports.len(),
+                generator.graph().get_base_words().len()
+            ));
+        }
+        Commands::CurateBase {
+            dict,
+            frequencies,
+            per_length,
+            min_neighbors,
+            output,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+
+            let mut graph = apply_exclude_regex(
+                WordGraph::new()
+                    .with_edge_rules(config.edge_rules.clone())
+                    .with_normalization(config.word_normalization)
+                    .with_comparison_unit(config.comparison_unit),
+                &config,
+            )?;
+            load_dictionary(&mut graph, &dict_path, config.verbose)?;
+            let ranks = load_frequency_ranks(&frequencies)?;
+            let words = graph.curate_base_words(
+                &ranks,
+                &BaseWordCuration {
+                    per_length,
+                    min_neighbors,
+                },
             );
 
-            // Export to SQL
-            let output_path =
-                resolve_output_path(output, &config, &OutputFormat::Sql, "mobile_puzzles")?;
-            let mut sql_exporter = SqlExporter::with_config(sql_config);
-            let sql = sql_exporter.export_puzzles(&balanced_puzzles)?;
-            std::fs::write(&output_path, sql)?;
+            let output_path =
+                resolve_output_path_with_extension(output, &config, "txt", "base_words")?;
+            let contents: String = words.iter().map(|word| format!("{}\n", word)).collect();
+            write_output(&output_path, contents)?;
+            report.output(&output_path);
+
+            let mut by_length: BTreeMap<usize, usize> = BTreeMap::new();
+            for word in &words {
+                *by_length.entry(word.chars().count()).or_default() += 1;
+            }
+            for (length, count) in &by_length {
+                report.info(format!("{}-letter words: {}", length, count));
+            }
+            report.count("base_words", words.len());
+            report.info(format!(
+                "Wrote {} base words to {}",
+                words.len(),
+                output_path.display()
+            ));
+        }
+        Commands::Capacity {
+            dict,
+            base_words,
+            difficulty,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let levels = difficulty.map_or(Difficulty::ALL.to_vec(), |level| vec![level]);
+            let mut estimates = Vec::new();
+            for level in levels {
+                let estimate = generator.estimate_capacity(level);
+                report.count(level.name(), estimate.pairs);
+                report.info(if estimate.is_exact() {
+                    format!("{:>8}: {} puzzles", level.name(), estimate.pairs)
+                } else {
+                    format!(
+                        "{:>8}: about {} puzzles (sampled {} of {} start words)",
+                        level.name(),
+                        estimate.pairs,
+                        estimate.sampled_starts,
+                        estimate.total_starts
+                    )
+                });
+                estimates.push(estimate);
+            }
+            report.set_result(&estimates)?;
+        }
+        Commands::Stats {
+            dict,
+            base_words,
+            compact,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let mut graph = load_graph(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            graph.build_all();
+            if compact {
+                graph.compact();
+            }
+            let memory = graph.memory_footprint();
+
+            report.count("dictionary_words", graph.get_words().len());
+            report.count("base_words", graph.get_base_words().len());
+            report.info(format!(
+                "{} dictionary words, {} base words, {} layout",
+                graph.get_words().len(),
+                graph.get_base_words().len(),
+                if graph.is_compact() {
+                    "compact"
+                } else {
+                    "list"
+                }
+            ));
+            for (part, bytes) in [
+                ("words", memory.words),
+                ("adjacency", memory.adjacency),
+                ("indices", memory.indices),
+                ("landmarks", memory.landmarks),
+                ("total", memory.total()),
+            ] {
+                report.info(format!("{:>10}: {:>12} bytes", part, bytes));
+            }
+            report.set_result(memory)?;
+        }
+        Commands::CheckPairs {
+            dict,
+            base_words,
+            file,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            let pairs = parse_pairs(&read_input(&file)?)?;
+            let reports = generator.solvable_pairs(&pairs);
+
+            for pair in &reports {
+                let status = match &pair.status {
+                    PairStatus::Solvable { steps } => format!("{} steps", steps),
+                    PairStatus::Disconnected => "no ladder exists".to_string(),
+                    PairStatus::NotInDictionary { word } => {
+                        exit = CliExit::InvalidWords;
+                        format!("'{}' is not in the dictionary", word)
+                    }
+                };
+                report.info(format!("{} -> {}: {}", pair.start, pair.end, status));
+            }
+            let solvable = reports.iter().filter(|pair| pair.is_solvable()).count();
+            if exit == CliExit::Success && solvable < reports.len() {
+                exit = CliExit::NoPath;
+            }
+            report.count("pairs", reports.len());
+            report.count("solvable", solvable);
+            report.set_result(&reports)?;
+            report.info(format!(
+                "{} of {} pairs are solvable",
+                solvable,
+                reports.len()
+            ));
+        }
+        Commands::Daemon {
+            dict,
+            base_words,
+            cache_size,
+        } => {
+            let daemon = load_daemon(dict, base_words, cache_size, &config)?;
+            eprintln!("Daemon ready; reading requests from stdin");
+
+            let stdin = std::io::stdin();
+            let stdout = std::io::stdout();
+            daemon.serve(stdin.lock(), stdout.lock())?;
+            // Responses are already JSON lines; a trailing report would corrupt the stream
+            return Ok(exit);
+        }
+        Commands::Serve {
+            dict,
+            base_words,
+            bind,
+            api_keys_file,
+            admin_keys_file,
+            rate_limit,
+            max_count,
+            max_attempts,
+            solve_max_visited,
+            solve_timeout_ms,
+            cache_size,
+            dump_openapi,
+        } => {
+            if dump_openapi {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&server::openapi::openapi_document())?
+                );
+                // The document is the output; a trailing report would corrupt it
+                return Ok(exit);
+            }
+            let mut server_config = config.server.clone();
+            if let Some(bind) = bind {
+                server_config.bind = bind;
+            }
+            if let Some(path) = api_keys_file {
+                server_config.api_keys = read_api_keys(&path)?;
+            }
+            if let Some(path) = admin_keys_file {
+                server_config.admin_keys = read_api_keys(&path)?;
+            }
+            if let Some(rate_limit) = rate_limit {
+                server_config.requests_per_minute = rate_limit;
+            }
+            if let Some(max_count) = max_count {
+                server_config.max_count = max_count;
+            }
+            if let Some(max_attempts) = max_attempts {
+                server_config.max_attempts = max_attempts;
+            }
+            if let Some(solve_max_visited) = solve_max_visited {
+                server_config.solve_max_visited = solve_max_visited;
+            }
+            if let Some(solve_timeout_ms) = solve_timeout_ms {
+                server_config.solve_timeout_ms = solve_timeout_ms;
+            }
+
+            let daemon = load_daemon(dict, base_words, cache_size, &config)?;
+            let listener = std::net::TcpListener::bind(&server_config.bind)
+                .with_context(|| ConfigError(format!("Cannot listen on {}", server_config.bind)))?;
+            if server_config.api_keys.is_empty() {
+                eprintln!("Warning: no API keys configured; authentication is disabled");
+            }
+            eprintln!("Serving on http://{}", server_config.bind);
+            Server::new(daemon, server_config).run(listener)?;
+            return Ok(exit);
+        }
+        #[cfg(feature = "grpc")]
+        Commands::GrpcServe {
+            dict,
+            base_words,
+            bind,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+            let base_words_path = resolve_base_words_path(base_words, &config);
+            let generator =
+                load_generator(dict_path.as_path(), base_words_path.as_path(), &config)?;
+            // Build every length up front so the first request is not slower
+            generator.graph().build_all();
+            eprintln!("Serving gRPC on {}", bind);
+            tokio::runtime::Runtime::new()?.block_on(grpc::serve(generator, bind))?;
+            return Ok(exit);
+        }
+        Commands::ExportGraph {
+            dict,
+            format,
+            length,
+            around,
+            radius,
+            rules,
+            output,
+        } => {
+            let config = resolve_edge_rules(rules, &config);
+            let format = GraphFormat::from(format);
+            if format == GraphFormat::Svg && around.is_none() {
+                return Err(anyhow::Error::msg(ConfigError(
+                    "--format svg draws a puzzle neighborhood and requires --around".to_string(),
+                )));
+            }
+            let ends = around.as_deref().map(parse_word_range).transpose()?;
+            let dict_path = resolve_dict_path(dict, &config);
+
+            let mut graph = apply_exclude_regex(
+                WordGraph::new()
+                    .with_edge_rules(config.edge_rules.clone())
+                    .with_normalization(config.word_normalization)
+                    .with_comparison_unit(config.comparison_unit),
+                &config,
+            )?;
+            load_dictionary(&mut graph, &dict_path, config.verbose)?;
+
+            let mut exporter = GraphExporter::new()
+                .with_format(format)
+                .with_word_length(length);
+            let mut default_name = match length {
+                Some(len) => format!("graph_{}", len),
+                None => "graph".to_string(),
+            };
+            if let Some((start, end)) = ends {
+                let normalization = graph.normalization();
+                let start = normalization.normalize_query(&start);
+                let end = normalization.normalize_query(&end);
+                if let Err(e) = graph.check_pair(&start, &end) {
+                    report.warn(describe_ladder_error(&graph, &e));
+                    exit = CliExit::InvalidWords;
+                } else if let Some(path) = graph.find_shortest_path(&start, &end) {
+                    report.count("steps", path.len() - 1);
+                    exporter = exporter.with_neighborhood(path, radius);
+                    default_name = format!("graph_{}_{}", start, end);
+                } else {
+                    report.warn(format!("No path found between {} and {}", start, end));
+                    exit = CliExit::NoPath;
+                }
+            }
+
+            if exit == CliExit::Success {
+                let output_path = resolve_output_path_with_extension(
+                    output,
+                    &config,
+                    format.extension(),
+                    &default_name,
+                )?;
+                let document = exporter.export(&graph)?;
+                write_output(&output_path, document)?;
+                report.output(&output_path);
+
+                report.info(format!("Exported word graph to {}", output_path.display()));
+            }
+        }
+        #[cfg(feature = "fetch")]
+        Commands::FetchDict { list, url, output } => {
+            let url = url.unwrap_or_else(|| WordList::from(list).url().to_string());
+            let output_path = output.unwrap_or_else(|| config.dictionary_path.clone());
+
+            report.info(format!("Downloading {}", url));
+            let fetched = fetch::fetch_word_list(&url)?;
+            let source_path = fetched.write(&output_path)?;
+            report.output(&output_path);
+            report.output(&source_path);
+            report.count("words", fetched.words.len());
+            report.info(format!(
+                "Wrote {} words to {} (source recorded in {})",
+                fetched.words.len(),
+                output_path.display(),
+                source_path.display()
+            ));
+        }
+        Commands::ExportDict {
+            dict,
+            output,
+            include_schema,
+            batch_size,
+            frequencies,
+            base_words,
+            exclude_isolated,
+            manifest,
+        } => {
+            let dict_path = resolve_dict_path(dict, &config);
+
+            // Load the dictionary
+            let mut graph = apply_exclude_regex(
+                WordGraph::new()
+                    .with_normalization(config.word_normalization)
+                    .with_comparison_unit(config.comparison_unit),
+                &config,
+            )?;
+            load_dictionary(&mut graph, &dict_path, config.verbose)?;
+            if exclude_isolated {
+                let removed = graph.prune_isolated();
+                report.count("excluded_isolated", removed);
+                report.info(format!("Excluded {} isolated words", removed));
+            }
+
+            // Load optional per-word metadata
+            let mut metadata = WordMetadata::new().with_comparison_unit(graph.comparison_unit());
+            if let Some(path) = frequencies {
+                metadata = metadata.with_frequency_ranks(load_frequency_ranks(&path)?);
+            }
+            if let Some(path) = base_words {
+                load_base_words(&mut graph, &path, config.verbose)?;
+                metadata = metadata.with_base_words(graph.get_base_words().clone());
+            }
+
+            // Export to SQL
+            let output_path =
+                resolve_output_path(output, &config, &OutputFormat::Sql, "dictionary")?;
+            let sql_config = SqlExportConfig {
+                batch_size,
+                include_schema: include_schema.unwrap_or(config.include_schema_by_default),
+                include_comments: true,
+                include_clues: false,
+                include_unique_solution: false,
+                include_meta: false,
+                include_reverse_path: false,
+                include_stars: false,
+                schema_mode: SchemaMode::Full,
+                include_steps_table: false,
+                id_strategy: config.sql_id_strategy,
+                omit_solutions: false,
+                canonical_hash: None,
+                #[cfg(feature = "encrypt")]
+                solution_key: None,
+            };
+            let mut exporter = SqlExporter::with_config(sql_config);
+            let words = graph.get_words();
+            let sql = exporter.export_dictionary_with_metadata(words, &metadata)?;
+            write_output(&output_path, sql)?;
+            report.output(&output_path);
+            report.count("words", words.len());
+
+            report.info(format!(
+                "Exported {} dictionary words to {}",
+                words.len(),
+                output_path.display()
+            ));
+            if manifest {
+                write_manifest(&output_path, &dict_path, &[], false, &mut report)?;
+            }
+        }
+    }
+    report.finish()?;
+    Ok(exit)
+}
+
+/// Reads a whole input file, or stdin when the path is `-`.
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        return Ok(std::io::read_to_string(std::io::stdin())?);
+    }
+    std::fs::read_to_string(path)
+        .with_context(|| ConfigError(format!("Cannot read {}", path.display())))
+}
+
+/// Parses a `START..END` word range, as given to `export-graph --around`.
+fn parse_word_range(range: &str) -> Result<(String, String)> {
+    match range.split_once("..") {
+        Some((start, end)) if !start.trim().is_empty() && !end.trim().is_empty() => {
+            Ok((start.to_string(), end.to_string()))
+        }
+        _ => Err(anyhow::Error::msg(ConfigError(format!(
+            "expected a START..END word range, got '{}'",
+            range
+        )))),
+    }
+}
+
+/// Parses one comma-separated `start,end` word pair per line.
+///
+/// Blank lines and lines starting with `#` are skipped; any other line
+/// without exactly two words is a configuration error.
+fn parse_pairs(input: &str) -> Result<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split(',').collect::<Vec<_>>()[..] {
+            [start, end] if !start.trim().is_empty() && !end.trim().is_empty() => {
+                pairs.push((start.to_string(), end.to_string()))
+            }
+            _ => {
+                return Err(anyhow::Error::msg(ConfigError(format!(
+                    "line {}: expected a `start,end` word pair, got '{}'",
+                    index + 1,
+                    line
+                ))));
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+/// Reads `--distribution` as easy, medium, and hard ratios, defaulting to the
+/// configured mobile distribution.
+fn resolve_distribution(ratios: &[f64], config: &Config) -> Result<DifficultyDistribution> {
+    let distribution = match *ratios {
+        [] => config.mobile_difficulty_distribution.clone(),
+        [easy, medium, hard] => DifficultyDistribution { easy, medium, hard },
+        _ => {
+            return Err(anyhow::Error::msg(ConfigError(
+                "--distribution needs three ratios: easy,medium,hard".to_string(),
+            )));
+        }
+    };
+    distribution
+        .validate()
+        .context(ConfigError("Invalid --distribution".to_string()))?;
+    Ok(distribution)
+}
+
+/// Reads an export history file; a missing file is an empty history.
+fn load_export_history(path: &Path) -> Result<ExportHistory> {
+    if !path.exists() {
+        return Ok(ExportHistory::new());
+    }
+    std::fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|content| ExportHistory::from_jsonl(&content))
+        .with_context(|| ConfigError(format!("Cannot read export history {}", path.display())))
+}
+
+/// Appends the puzzle IDs of a SQL export to the export history file.
+fn record_export_history(
+    path: &Path,
+    history: &mut ExportHistory,
+    sql: &str,
+    report: &mut CliReport,
+) -> Result<()> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let ids = incremental::puzzle_ids_from_sql(sql)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let exported_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let entry = history.record(ids, exported_at);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(entry.to_jsonl()?.as_bytes())?;
+
+    report.output(path);
+    report.info(format!(
+        "Recorded {} puzzle IDs in export history {}",
+        entry.ids.len(),
+        path.display()
+    ));
+    Ok(())
+}
+
+/// Loads a dictionary file, reporting a failure as a configuration error.
+fn load_dictionary(graph: &mut WordGraph, path: &Path, verbose: bool) -> Result<()> {
+    let report = graph
+        .load_dictionary(path.to_str().unwrap())
+        .with_context(|| ConfigError(format!("Cannot read dictionary {}", path.display())))?;
+    print_load_report(path, &report, verbose);
+    Ok(())
+}
+
+/// Prints the warnings of a word list load to stderr, and with `--verbose`
+/// its summary.
+fn print_load_report(path: &Path, report: &LoadReport, verbose: bool) {
+    if verbose {
+        eprintln!("Loaded {}: {}", path.display(), report);
+    }
+    for warning in &report.warnings {
+        eprintln!("Warning: {}: {}", path.display(), warning);
+    }
+}
+
+/// Reads API keys for the `serve` subcommand, one per line, skipping blank
+/// lines and `#` comments.
+fn read_api_keys(path: &Path) -> Result<Vec<String>> {
+    let keys = std::fs::read_to_string(path)
+        .with_context(|| ConfigError(format!("Cannot read API keys {}", path.display())))?;
+    Ok(keys
+        .lines()
+        .map(str::trim)
+        .filter(|key| !key.is_empty() && !key.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// Loads a warm daemon for the `daemon` and `serve` subcommands, with the
+/// solve cache and a reloader that rereads the same files.
+fn load_daemon(
+    dict: PathBuf,
+    base_words: PathBuf,
+    cache_size: Option<usize>,
+    config: &Config,
+) -> Result<Daemon> {
+    let dict_path = resolve_dict_path(dict, config);
+    let base_words_path = resolve_base_words_path(base_words, config);
+
+    let generator = load_generator(dict_path.as_path(), base_words_path.as_path(), config)?;
+    // Build every length up front so the first request is not slower
+    generator.graph().build_all();
+    eprintln!(
+        "Loaded {} dictionary words",
+        generator.graph().get_words().len()
+    );
+
+    let reload_config = config.clone();
+    Ok(Daemon::new(generator)
+        .with_solve_cache(cache_size.unwrap_or(config.solve_cache_size))
+        .with_reloader(move || {
+            let generator = load_generator(
+                dict_path.as_path(),
+                base_words_path.as_path(),
+                &reload_config,
+            )?;
+            generator.graph().build_all();
+            Ok(generator)
+        }))
+}
+
+/// Sets landmark distances on a loaded graph, reading them from the file next
+/// to the dictionary or building and saving them if that file is missing or stale.
+fn load_landmarks(graph: &mut WordGraph, dict: &Path, per_length: usize) -> Result<()> {
+    let path = landmarks_path(dict);
+    let landmarks = match Landmarks::load(&path) {
+        Ok(landmarks) if landmarks.matches(graph) => landmarks,
+        _ => {
+            let landmarks = Landmarks::build(graph, per_length);
+            if let Err(e) = landmarks.save(&path) {
+                eprintln!(
+                    "Warning: cannot save landmarks to {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+            landmarks
+        }
+    };
+    graph.set_landmarks(landmarks)
+}
+
+/// Loads a base words file, reporting a failure as a configuration error.
+fn load_base_words(graph: &mut WordGraph, path: &Path, verbose: bool) -> Result<()> {
+    let report = graph
+        .load_base_words(path.to_str().unwrap())
+        .with_context(|| ConfigError(format!("Cannot read base words {}", path.display())))?;
+    print_load_report(path, &report, verbose);
+    Ok(())
+}
+
+/// Loads an accepted words file, reporting a failure as a configuration error.
+fn load_accepted_words(graph: &mut WordGraph, path: &Path) -> Result<()> {
+    graph
+        .load_accepted_words(path.to_str().unwrap())
+        .with_context(|| ConfigError(format!("Cannot read accepted words {}", path.display())))
+}
+
+/// Writes a new private key file readable only by its owner.
+///
+/// # Arguments
+///
+/// * `path` - Key file to create; an existing file is an error
+/// * `contents` - The hex-encoded key
+#[cfg(feature = "sign")]
+fn write_private_key(path: &Path, contents: &str) -> Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+/// Reads a word frequency list into ranks, reporting a failure as a configuration error.
+fn load_frequency_ranks(path: &Path) -> Result<HashMap<String, usize>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| ConfigError(format!("Cannot read frequency list {}", path.display())))?;
+    Ok(WordMetadata::parse_frequency_list(&content))
+}
+
+/// Applies `--endpoint-weighting` to a generator.
+///
+/// # Arguments
+///
+/// * `generator` - The generator to configure
+/// * `weighting` - How random endpoints are picked
+/// * `frequencies` - Frequency list required by `EndpointWeighting::Frequency`
+fn apply_endpoint_weighting(
+    generator: PuzzleGenerator,
+    weighting: EndpointWeighting,
+    frequencies: Option<&Path>,
+) -> Result<PuzzleGenerator> {
+    match weighting {
+        EndpointWeighting::Uniform => Ok(generator),
+        EndpointWeighting::Frequency => {
+            let path = frequencies.with_context(|| {
+                ConfigError("--endpoint-weighting frequency requires --frequencies".to_string())
+            })?;
+            generator
+                .with_endpoint_frequencies(load_frequency_ranks(path)?)
+                .map_err(|e| anyhow::Error::msg(ConfigError(e.to_string())))
+        }
+    }
+}
+
+/// Applies `--max-endpoint-reuse` to a generator.
+///
+/// # Arguments
+///
+/// * `generator` - The generator to configure, with its endpoint weighting applied
+/// * `max_uses` - Most puzzles of a batch any one word may start or end, if given
+fn apply_max_endpoint_reuse(
+    generator: PuzzleGenerator,
+    max_uses: Option<usize>,
+) -> Result<PuzzleGenerator> {
+    match max_uses {
+        None => Ok(generator),
+        Some(0) => Err(anyhow::Error::msg(ConfigError(
+            "--max-endpoint-reuse must be at least 1".to_string(),
+        ))),
+        Some(max_uses) => Ok(generator.with_max_endpoint_reuse(max_uses)),
+    }
+}
+
+/// Applies `--banned-words` to a generator.
+///
+/// # Arguments
+///
+/// * `generator` - The generator to configure
+/// * `path` - Word list with one banned word per line, if given
+fn apply_banned_words(generator: PuzzleGenerator, path: Option<&Path>) -> Result<PuzzleGenerator> {
+    let Some(path) = path else {
+        return Ok(generator);
+    };
+    let content = std::fs::read_to_string(path)
+        .with_context(|| ConfigError(format!("Cannot read banned words {}", path.display())))?;
+    let normalization = generator.graph().normalization();
+    let words: Vec<String> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|word| normalization.normalize_query(word))
+        .collect();
+    Ok(generator.with_banned_words(words))
+}
+
+/// Applies `--start-pattern` and `--end-pattern` to a generator.
+///
+/// # Arguments
+///
+/// * `generator` - The generator to configure
+/// * `start_patterns` - Filters random start words must match
+/// * `end_patterns` - Filters random end words must match
+fn apply_endpoint_filters(
+    mut generator: PuzzleGenerator,
+    start_patterns: &[String],
+    end_patterns: &[String],
+) -> Result<PuzzleGenerator> {
+    let parse = |spec: &String| {
+        EndpointFilter::parse(spec).context(ConfigError("Invalid endpoint pattern".to_string()))
+    };
+    for spec in start_patterns {
+        generator = generator.with_start_filter(parse(spec)?);
+    }
+    for spec in end_patterns {
+        generator = generator.with_end_filter(parse(spec)?);
+    }
+    Ok(generator)
+}
+
+/// Solves ladders from one start word to several end words with a single search.
+///
+/// Each ladder is reported on its own line, and the JSON result maps every
+/// end word to its path, or `null` if it is unreachable.
+///
+/// # Arguments
+///
+/// * `graph` - The loaded word graph
+/// * `start` - Starting word, already normalized
+/// * `ends` - Ending words as given on the command line
+/// * `report` - Report receiving the ladders
+///
+/// # Returns
+///
+/// `InvalidWords` if any word is not in the dictionary, `NoPath` if any end
+/// word is unreachable, and `Success` otherwise.
+fn solve_to_many(
+    graph: &WordGraph,
+    start: &str,
+    ends: &[String],
+    report: &mut CliReport,
+) -> Result<CliExit> {
+    let ends: Vec<String> = ends
+        .iter()
+        .map(|word| graph.normalization().normalize_query(word))
+        .filter(|word| !word.is_empty())
+        .collect();
+    let mut words: Vec<&str> = vec![start];
+    words.extend(ends.iter().map(String::as_str));
+    if let Some(missing) = missing_words(graph, &words) {
+        report.warn(missing);
+        return Ok(CliExit::InvalidWords);
+    }
+
+    let paths = graph.shortest_paths_to_many(start, &words[1..]);
+    let mut exit = CliExit::Success;
+    for end in &ends {
+        match paths.get(end) {
+            Some(path) => report.info(format!("{} ({} steps)", path.join(" -> "), path.len() - 1)),
+            None => {
+                report.warn(format!("No path found between {} and {}", start, end));
+                exit = CliExit::NoPath;
+            }
+        }
+    }
+    report.count("solved", paths.len());
+    let result: BTreeMap<&String, Option<&Vec<String>>> =
+        ends.iter().map(|end| (end, paths.get(end))).collect();
+    report.set_result(&result)?;
+    Ok(exit)
+}
+
+/// Returns the exit code for a puzzle that could not be generated.
+///
+/// Unsuitable words (different lengths, not in the dictionary) are
+/// `InvalidWords`; a missing path or a puzzle rejected as trivial or outside
+/// the difficulty levels is `NoPath`.
+fn puzzle_error_exit(error: &PuzzleError) -> CliExit {
+    match error {
+        PuzzleError::Ladder(
+            LadderError::DifferentLengths { .. }
+            | LadderError::StartNotInDictionary(_)
+            | LadderError::EndNotInDictionary(_),
+        ) => CliExit::InvalidWords,
+        PuzzleError::Ladder(LadderError::NoPath { .. })
+        | PuzzleError::Trivial { .. }
+        | PuzzleError::StepsOutOfRange { .. } => CliExit::NoPath,
+    }
+}
+
+/// Describes the words missing from the dictionary, or returns `None` if all are present.
+///
+/// Each missing word is followed by close dictionary words, if any.
+fn missing_words(graph: &WordGraph, words: &[&str]) -> Option<String> {
+    let missing: Vec<String> = words
+        .iter()
+        .filter(|word| !graph.get_words().contains(**word))
+        .map(|word| format!("{}{}", word, suggestion_hint(graph, word)))
+        .collect();
+    (!missing.is_empty()).then(|| format!("Not in dictionary: {}", missing.join(", ")))
+}
+
+/// Returns ` (did you mean ...?)` with close dictionary words, or an empty
+/// string if none are within `SUGGESTION_DISTANCE` edits.
+fn suggestion_hint(graph: &WordGraph, word: &str) -> String {
+    let mut suggestions = graph.suggest_similar(word, SUGGESTION_DISTANCE);
+    if suggestions.is_empty() {
+        return String::new();
+    }
+    suggestions.truncate(MAX_SUGGESTIONS);
+    format!(" (did you mean {}?)", suggestions.join(", "))
+}
+
+/// Describes why no ladder connects two words, suggesting corrections for a
+/// word that is not in the dictionary.
+fn describe_ladder_error(graph: &WordGraph, error: &LadderError) -> String {
+    match error {
+        LadderError::StartNotInDictionary(word) | LadderError::EndNotInDictionary(word) => {
+            format!("{}{}", error, suggestion_hint(graph, word))
+        }
+        _ => error.to_string(),
+    }
+}
+
+/// Warns about the words of a comma-separated ladder that are not in the dictionary.
+fn warn_unknown_ladder_words(graph: &WordGraph, puzzle: &str, report: &mut CliReport) {
+    let words: Vec<String> = puzzle
+        .split(',')
+        .map(|word| graph.normalization().normalize_query(word))
+        .filter(|word| !word.is_empty())
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    if let Some(missing) = missing_words(graph, &words) {
+        report.warn(missing);
+    }
+}
+
+/// Registers the configured `--exclude-regex` as a word filter on a graph.
+///
+/// # Returns
+///
+/// The graph, or a configuration error if the pattern is not a valid regex.
+fn apply_exclude_regex(graph: WordGraph, config: &Config) -> Result<WordGraph> {
+    let Some(pattern) = &config.exclude_regex else {
+        return Ok(graph);
+    };
+    let regex = Regex::new(pattern).map_err(|e| {
+        anyhow::Error::msg(ConfigError(format!(
+            "Invalid --exclude-regex {}: {}",
+            pattern, e
+        )))
+    })?;
+    Ok(graph.with_word_filter(move |word| !regex.is_match(word)))
+}
+
+/// Loads the word graph with the specified dictionary files.
+///
+/// Applies the configured edge rules and normalization, and loads the
+/// accepted words and landmarks when they are configured.
+///
+/// # Arguments
+///
+/// * `dict` - Path to the dictionary file
+/// * `base_words` - Path to the base words file
+/// * `config` - Configuration supplying the edge rules and optional files
+fn load_graph(dict: &Path, base_words: &Path, config: &Config) -> Result<WordGraph> {
+    let mut graph = apply_exclude_regex(
+        WordGraph::new()
+            .with_edge_rules(config.edge_rules.clone())
+            .with_normalization(config.word_normalization)
+            .with_comparison_unit(config.comparison_unit),
+        config,
+    )?;
+    load_dictionary(&mut graph, dict, config.verbose)?;
+    load_base_words(&mut graph, base_words, config.verbose)?;
+    if let Some(accepted_words) = &config.accepted_words_path {
+        load_accepted_words(&mut graph, accepted_words)?;
+    }
+    if config.landmarks_per_length > 0 {
+        load_landmarks(&mut graph, dict, config.landmarks_per_length)?;
+    }
+    Ok(graph)
+}
+
+/// Loads and initializes a puzzle generator with the specified dictionary files.
+///
+/// This function creates a new `WordGraph`, loads the dictionary and base words,
+/// and returns a configured `PuzzleGenerator` ready for use.
+///
+/// # Arguments
+///
+/// * `dict` - Path to the dictionary file
+/// * `base_words` - Path to the base words file
+/// * `config` - Configuration supplying the edge rules and generation constraints
+///
+/// # Returns
+///
+/// Returns a configured `PuzzleGenerator` or an error if file loading fails.
+fn load_generator(dict: &Path, base_words: &Path, config: &Config) -> Result<PuzzleGenerator> {
+    let graph = load_graph(dict, base_words, config)?;
+    let mut generator = PuzzleGenerator::new(graph)
+        .with_min_branching(config.min_branching)
+        .with_unique_solution(config.unique_solution)
+        .with_require_reversible(config.require_reversible)
+        .with_word_length_policy(config.word_lengths.clone());
+    if config.star_ratings {
+        generator = generator.with_star_ratings(config.star_formula);
+    }
+    if config.puzzle_meta {
+        generator = generator.with_puzzle_meta(sha256_hex(&std::fs::read(dict)?));
+    }
+    Ok(generator)
+}
+
+/// Reads the puzzles already in the output file when `--append` is given.
+///
+/// # Arguments
+///
+/// * `append` - Whether `--append` was given
+/// * `output_path` - The export file that will be appended to
+/// * `format` - Output format of the export
+/// * `report` - Report receiving the status message
+///
+/// # Returns
+///
+/// `None` when not appending, the existing puzzles (empty if the file does not
+/// exist yet) when appending, or an error for text output or an unreadable file.
+fn load_existing_puzzles(
+    append: bool,
+    output_path: &Path,
+    format: &OutputFormat,
+    report: &CliReport,
+) -> Result<Option<ExistingPuzzles>> {
+    if !append {
+        return Ok(None);
+    }
+    if is_stdout(output_path) {
+        return Err(anyhow::Error::msg(ConfigError(
+            "--append cannot be used with --output -".to_string(),
+        )));
+    }
+    if !matches!(
+        format,
+        OutputFormat::Sql
+            | OutputFormat::Json
+            | OutputFormat::Jsonl
+            | OutputFormat::Msgpack
+            | OutputFormat::Cbor
+    ) {
+        return Err(anyhow::Error::msg(ConfigError(
+            "--append supports sql, json, jsonl, msgpack, and cbor output only".to_string(),
+        )));
+    }
+    if !output_path.exists() {
+        return Ok(Some(ExistingPuzzles::new()));
+    }
+
+    if let Some(binary_format) = format.binary_format() {
+        let puzzles = binary::import_puzzles(&std::fs::read(output_path)?, binary_format)
+            .with_context(|| {
+                ConfigError(format!(
+                    "Cannot append to {}: not a {} puzzle array",
+                    output_path.display(),
+                    format.extension()
+                ))
+            })?;
+        let existing = ExistingPuzzles::from_puzzles(&puzzles);
+        report.info(format!(
+            "Found {} existing puzzles in {}",
+            existing.len(),
+            output_path.display()
+        ));
+        return Ok(Some(existing));
+    }
+
+    let content = std::fs::read_to_string(output_path)?;
+    let existing = match format {
+        OutputFormat::Json => ExistingPuzzles::from_json(&content).with_context(|| {
+            ConfigError(format!(
+                "Cannot append to {}: not a puzzle JSON array",
+                output_path.display()
+            ))
+        })?,
+        OutputFormat::Jsonl => ExistingPuzzles::from_json_lines(&content).with_context(|| {
+            ConfigError(format!(
+                "Cannot append to {}: not a puzzle JSON Lines file",
+                output_path.display()
+            ))
+        })?,
+        _ => ExistingPuzzles::from_sql(&content),
+    };
+    report.info(format!(
+        "Found {} existing puzzles in {}",
+        existing.len(),
+        output_path.display()
+    ));
+    Ok(Some(existing))
+}
+
+/// Creates the binary exporter for a `--format msgpack` or `--format cbor` export.
+fn binary_exporter(format: &OutputFormat, clues: bool) -> BinaryExporter {
+    let binary_format = format.binary_format().unwrap_or(BinaryFormat::MessagePack);
+    BinaryExporter::new(binary_format).with_include_clues(clues)
+}
+
+/// Drops puzzles already present in the output file, reporting how many were skipped.
+fn skip_existing_puzzles(
+    existing: Option<&ExistingPuzzles>,
+    puzzles: Vec<Puzzle>,
+    output_path: &Path,
+    report: &mut CliReport,
+) -> Vec<Puzzle> {
+    let Some(existing) = existing else {
+        return puzzles;
+    };
+    let generated = puzzles.len();
+    let puzzles = existing.filter_new(puzzles);
+    if puzzles.len() < generated {
+        report.count("skipped_existing", generated - puzzles.len());
+        report.info(format!(
+            "Skipped {} puzzles already in {}",
+            generated - puzzles.len(),
+            output_path.display()
+        ));
+    }
+    puzzles
+}
+
+/// Creates a SQL exporter whose ID counters continue after the existing puzzles.
+fn sql_exporter_for(
+    sql_config: SqlExportConfig,
+    existing: Option<&ExistingPuzzles>,
+) -> SqlExporter {
+    let exporter = SqlExporter::with_config(sql_config);
+    match existing {
+        Some(existing) => exporter.with_id_counters(existing.id_counters().clone()),
+        None => exporter,
+    }
+}
+
+/// Returns `true` if an output path is `-`, meaning stdout.
+fn is_stdout(path: &Path) -> bool {
+    path == Path::new(STDOUT)
+}
+
+/// Writes an export to the output file, or to stdout for `--output -`.
+fn write_output(output_path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    if is_stdout(output_path) {
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(contents.as_ref())?;
+        stdout.flush()?;
+    } else {
+        std::fs::write(output_path, contents)?;
+    }
+    Ok(())
+}
+
+/// Writes SQL to the output file, appending to it instead of overwriting when requested.
+fn write_sql_output(output_path: &Path, sql: &str, append: bool) -> Result<()> {
+    if append && !is_stdout(output_path) {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(output_path)?;
+        file.write_all(sql.as_bytes())?;
+    } else {
+        write_output(output_path, sql)?;
+    }
+    Ok(())
+}
+
+/// Opens the output file for writing, appending to it instead of truncating
+/// when requested, or opens stdout for `--output -`.
+fn open_output_file(output_path: &Path, append: bool) -> Result<Box<dyn Write>> {
+    if is_stdout(output_path) {
+        return Ok(Box::new(std::io::stdout()));
+    }
+    Ok(Box::new(
+        std::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(output_path)?,
+    ))
+}
+
+/// Opens a streaming exporter on the output file for formats that can be
+/// written a puzzle at a time.
+///
+/// # Arguments
+///
+/// * `format` - Output format of the export
+/// * `output_path` - The export file
+/// * `append` - Whether to append to the file instead of overwriting it
+/// * `clues` - Whether to include masked clue words
+/// * `sql_config` - SQL export settings, used for SQL output; its
+///   `omit_solutions` also applies to JSON Lines
+/// * `existing` - Puzzles already in the file, whose SQL ID counters are continued
+///
+/// # Returns
+///
+/// The exporter for text, JSON Lines, and SQL output, `None` for formats that
+/// need the whole batch, or an error if the file cannot be opened.
+fn open_stream_exporter(
+    format: &OutputFormat,
+    output_path: &Path,
+    append: bool,
+    clues: bool,
+    sql_config: SqlExportConfig,
+    existing: Option<&ExistingPuzzles>,
+) -> Result<Option<StreamExporter<Box<dyn Write>>>> {
+    let open = || open_output_file(output_path, append);
+    Ok(match format {
+        OutputFormat::Text => Some(StreamExporter::lines(open()?, move |puzzle| {
+            puzzle_to_text_line(puzzle, clues)
+        })),
+        OutputFormat::Jsonl => Some(json_lines_exporter(
+            open()?,
+            clues,
+            sql_config.omit_solutions,
+            sql_config.canonical_hash,
+        )),
+        OutputFormat::Sql => Some(StreamExporter::sql(
+            open()?,
+            sql_exporter_for(sql_config, existing),
+        )?),
+        _ => None,
+    })
+}
+
+/// A streaming export that skips puzzles already in the file and counts
+/// what it writes.
+struct StreamedOutput {
+    /// Exporter writing the output file
+    exporter: StreamExporter<Box<dyn Write>>,
+    /// Puzzles already in the file when appending, plus those written since
+    existing: Option<ExistingPuzzles>,
+    /// Per-difficulty counts of the puzzles written
+    counts: PuzzleCounts,
+    /// Number of puzzles skipped as duplicates
+    skipped: usize,
+}
 
-            println!(
-                "Generated {} balanced mobile puzzles and saved to {}",
-                balanced_puzzles.len(),
-                output_path.display()
-            );
-            println!(
-                "Distribution: Easy: {:.1}%, Medium: {:.1}%, Hard: {:.1}%",
-                easy_ratio * 100.0,
-                medium_ratio * 100.0,
-                hard_ratio * 100.0
-            );
+impl StreamedOutput {
+    fn new(exporter: StreamExporter<Box<dyn Write>>, existing: Option<ExistingPuzzles>) -> Self {
+        Self {
+            exporter,
+            existing,
+            counts: PuzzleCounts::default(),
+            skipped: 0,
         }
-        Commands::Verify {
-            dict,
-            base_words,
-            puzzle,
-        } => {
-            let dict_path = if dict == PathBuf::from("data/dictionary.txt") {
-                config.dictionary_path.clone()
-            } else {
-                dict
-            };
-            let base_words_path = if base_words == PathBuf::from("data/base_words.txt") {
-                config.base_words_path.clone()
-            } else {
-                base_words
-            };
-
-            let generator = load_generator(dict_path.as_path(), base_words_path.as_path())?;
+    }
 
-            match generator.verify_puzzle(&puzzle) {
-                Ok(true) => println!("Puzzle is valid"),
-                Ok(false) => println!("Puzzle is invalid"),
-                Err(e) => println!("Error: {}", e),
-            }
+    /// Writes a puzzle unless the file already holds one with the same endpoints.
+    fn write(&mut self, puzzle: Puzzle) -> Result<()> {
+        if self
+            .existing
+            .as_mut()
+            .is_some_and(|existing| !existing.insert(&puzzle))
+        {
+            self.skipped += 1;
+            return Ok(());
         }
-        Commands::ExportDict {
-            dict,
-            output,
-            include_schema,
-            batch_size,
-        } => {
-            let dict_path = if dict == PathBuf::from("data/dictionary.txt") {
-                config.dictionary_path.clone()
-            } else {
-                dict
-            };
-
-            // Load the dictionary
-            let mut graph = WordGraph::new();
-            graph.load_dictionary(dict_path.to_str().unwrap())?;
-            let words = graph.get_words();
-
-            // Export to SQL
-            let output_path =
-                resolve_output_path(output, &config, &OutputFormat::Sql, "dictionary")?;
-            let sql_config = SqlExportConfig {
-                batch_size,
-                include_schema: include_schema.unwrap_or(config.include_schema_by_default),
-                include_comments: true,
-            };
-            let mut exporter = SqlExporter::with_config(sql_config);
-            let sql = exporter.export_dictionary(words)?;
-            std::fs::write(&output_path, sql)?;
+        self.counts.add(&puzzle);
+        self.exporter.write_puzzle(puzzle)
+    }
 
-            println!(
-                "Exported {} dictionary words to {}",
-                words.len(),
+    /// Writes the rest of the export and reports skipped duplicates.
+    ///
+    /// # Returns
+    ///
+    /// The per-difficulty counts of the puzzles written.
+    fn finish(self, output_path: &Path, report: &mut CliReport) -> Result<PuzzleCounts> {
+        self.exporter.finish()?;
+        if self.skipped > 0 {
+            report.count("skipped_existing", self.skipped);
+            report.info(format!(
+                "Skipped {} puzzles already in {}",
+                self.skipped,
                 output_path.display()
-            );
+            ));
         }
+        Ok(self.counts)
     }
-    Ok(())
 }
 
-/// Loads and initializes a puzzle generator with the specified dictionary files.
-///
-/// This function creates a new `WordGraph`, loads the dictionary and base words,
-/// and returns a configured `PuzzleGenerator` ready for use.
+/// Writes the sidecar manifest for an export file that has just been written.
 ///
 /// # Arguments
 ///
-/// * `dict` - Path to the dictionary file
-/// * `base_words` - Path to the base words file
+/// * `output_path` - The export file the manifest describes
+/// * `dict` - Dictionary file the puzzles were built from
+/// * `puzzles` - Puzzles written in this run
+/// * `append` - Whether the puzzles were appended; counts from an existing
+///   manifest are then carried over
+/// * `report` - Report recording the manifest path
 ///
 /// # Returns
 ///
-/// Returns a configured `PuzzleGenerator` or an error if file loading fails.
-fn load_generator(dict: &Path, base_words: &Path) -> Result<PuzzleGenerator> {
-    let mut graph = WordGraph::new();
-    graph.load_dictionary(dict.to_str().unwrap())?;
-    graph.load_base_words(base_words.to_str().unwrap())?;
-    Ok(PuzzleGenerator::new(graph))
+/// Returns `Ok(())` on success, or an error if a file cannot be read or written.
+fn write_manifest(
+    output_path: &Path,
+    dict: &Path,
+    puzzles: &[Puzzle],
+    append: bool,
+    report: &mut CliReport,
+) -> Result<()> {
+    write_manifest_counts(
+        output_path,
+        dict,
+        PuzzleCounts::from_puzzles(puzzles),
+        append,
+        report,
+    )
+}
+
+/// Writes the sidecar manifest like `write_manifest`, from per-difficulty
+/// counts instead of the puzzles, for exports written as they were generated.
+fn write_manifest_counts(
+    output_path: &Path,
+    dict: &Path,
+    counts: PuzzleCounts,
+    append: bool,
+    report: &mut CliReport,
+) -> Result<()> {
+    if is_stdout(output_path) {
+        report.warn("Skipping the manifest; output was written to stdout");
+        return Ok(());
+    }
+    let file_name = output_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut manifest = ExportManifest::new(
+        &std::fs::read(dict)?,
+        &file_name,
+        &std::fs::read(output_path)?,
+    )
+    .with_counts(counts);
+
+    let manifest_path = ExportManifest::sidecar_path(output_path);
+    if append && manifest_path.exists() {
+        let previous = ExportManifest::from_json(&std::fs::read_to_string(&manifest_path)?)?;
+        manifest = manifest.with_previous(&previous);
+    }
+
+    std::fs::write(&manifest_path, manifest.to_json()?)?;
+    report.output(&manifest_path);
+    report.info(format!("Manifest written to {}", manifest_path.display()));
+    Ok(())
+}
+
+/// Writes a puzzle set in any output format, for exports without append or
+/// streaming support.
+///
+/// # Arguments
+///
+/// * `output_path` - The export file, or `-` for stdout
+/// * `format` - Output format of the export
+/// * `puzzles` - The puzzles to write
+/// * `clues` - Whether to include fill-in-the-ladder clues
+/// * `config` - Configuration with the SQL export defaults
+fn write_puzzles(
+    output_path: &Path,
+    format: &OutputFormat,
+    puzzles: &[Puzzle],
+    clues: bool,
+    config: &Config,
+) -> Result<()> {
+    match format {
+        OutputFormat::Sql => {
+            let mut exporter = SqlExporter::new()
+                .with_batch_size(config.sql_batch_size)
+                .with_include_schema(config.include_schema_by_default)
+                .with_include_clues(clues)
+                .with_omit_solutions(config.omit_solutions)
+                .with_canonical_hash(config.canonical_hash);
+            #[cfg(feature = "encrypt")]
+            if let Some(key) = config.solution_key {
+                exporter = exporter.with_solution_key(key);
+            }
+            let sql = exporter.export_puzzles(puzzles)?;
+            write_output(output_path, sql)?;
+        }
+        OutputFormat::Json => {
+            let json_array: Result<Vec<_>, _> = puzzles
+                .iter()
+                .map(|p| puzzle_to_json(p, clues, config.omit_solutions, config.canonical_hash))
+                .collect();
+            write_output(output_path, format!("[\n{}\n]", json_array?.join(",\n")))?;
+        }
+        OutputFormat::Jsonl => {
+            let mut exporter = json_lines_exporter(
+                open_output_file(output_path, false)?,
+                clues,
+                config.omit_solutions,
+                config.canonical_hash,
+            );
+            for puzzle in puzzles {
+                exporter.write_puzzle(puzzle.clone())?;
+            }
+            exporter.finish()?;
+        }
+        OutputFormat::Text => {
+            let text: String = puzzles
+                .iter()
+                .map(|puzzle| puzzle_to_text_line(puzzle, clues))
+                .collect();
+            write_output(output_path, text)?;
+        }
+        OutputFormat::Markdown => {
+            let markdown = MarkdownExporter::new()
+                .with_include_clues(clues)
+                .export_puzzles(puzzles);
+            write_output(output_path, markdown)?;
+        }
+        OutputFormat::Svg => {
+            let svg = SvgExporter::new()
+                .with_include_clues(clues)
+                .export_puzzles(puzzles);
+            write_output(output_path, svg)?;
+        }
+        OutputFormat::Anki => {
+            let deck = AnkiExporter::new()
+                .with_include_clues(clues)
+                .export_puzzles(puzzles);
+            write_output(output_path, deck)?;
+        }
+        OutputFormat::Msgpack | OutputFormat::Cbor => {
+            write_output(
+                output_path,
+                binary_exporter(format, clues).export_puzzles(puzzles)?,
+            )?;
+        }
+        #[cfg(feature = "proto")]
+        OutputFormat::Proto => {
+            write_output(output_path, PuzzlePack::new(puzzles.to_vec()).encode())?;
+        }
+    }
+    Ok(())
+}
+
+/// Serializes a puzzle to JSON, optionally including its clue format, or
+/// as a `MaskedPuzzle` when solutions are omitted, with a `canonical_hash`
+/// field if requested.
+fn puzzle_to_json(
+    puzzle: &Puzzle,
+    clues: bool,
+    omit_solutions: bool,
+    canonical_hash: Option<CanonicalHashOptions>,
+) -> Result<String, serde_json::Error> {
+    let Some(options) = canonical_hash else {
+        return if omit_solutions {
+            MaskedPuzzle::from(puzzle).to_json()
+        } else if clues {
+            puzzle.to_json_with_clues()
+        } else {
+            puzzle.to_json()
+        };
+    };
+    let mut value = if omit_solutions {
+        serde_json::to_value(MaskedPuzzle::from(puzzle))?
+    } else {
+        let mut value = serde_json::to_value(puzzle)?;
+        if clues {
+            value["clues"] = serde_json::to_value(puzzle.to_clue_format())?;
+        }
+        value
+    };
+    value["canonical_hash"] = puzzle.canonical_hash(options).into();
+    serde_json::to_string_pretty(&value)
+}
+
+/// Creates a JSON Lines exporter, writing `MaskedPuzzle` lines when
+/// solutions are omitted, with a `canonical_hash` field if requested.
+fn json_lines_exporter<W: Write>(
+    writer: W,
+    clues: bool,
+    omit_solutions: bool,
+    canonical_hash: Option<CanonicalHashOptions>,
+) -> StreamExporter<W> {
+    let exporter = if omit_solutions {
+        StreamExporter::masked_json_lines(writer)
+    } else {
+        StreamExporter::json_lines(writer, clues)
+    };
+    exporter.with_canonical_hash(canonical_hash)
+}
+
+/// Returns the words of a puzzle chain: the first start word and every end word.
+fn chain_words(chain: &[Puzzle]) -> Vec<&str> {
+    chain
+        .first()
+        .map(|first| first.start.as_str())
+        .into_iter()
+        .chain(chain.iter().map(|puzzle| puzzle.end.as_str()))
+        .collect()
+}
+
+/// Serializes a puzzle chain to JSON.
+///
+/// The object lists the chain `words`, the indexes of the words marked as
+/// `checkpoints` (every `checkpoint_every` words, starting with the first),
+/// and the `puzzles` in chain order.
+fn chain_to_json(chain: &[Puzzle], checkpoint_every: usize) -> Result<String> {
+    let words = chain_words(chain);
+    let checkpoints: Vec<usize> = (0..words.len()).step_by(checkpoint_every).collect();
+    Ok(serde_json::to_string_pretty(&serde_json::json!({
+        "words": words,
+        "checkpoints": checkpoints,
+        "puzzles": chain,
+    }))?)
+}
+
+/// Formats a puzzle chain as one text line per puzzle, with a
+/// `# Checkpoint` line before every `checkpoint_every`th chain word.
+fn chain_to_text(chain: &[Puzzle], checkpoint_every: usize) -> String {
+    let mut text = String::new();
+    for (index, puzzle) in chain.iter().enumerate() {
+        if index % checkpoint_every == 0 {
+            text.push_str(&format!(
+                "# Checkpoint {}: {}\n",
+                index / checkpoint_every + 1,
+                puzzle.start
+            ));
+        }
+        text.push_str(&puzzle_to_text_line(puzzle, false));
+    }
+    text
+}
+
+/// Formats a puzzle as a `start -> end: solution` line for text output.
+fn puzzle_to_text_line(puzzle: &Puzzle, clues: bool) -> String {
+    let mut solution = puzzle.path.join(" -> ");
+    if puzzle.unique_solution == Some(true) {
+        solution.push_str(" (unique)");
+    }
+    if let Some(reverse) = &puzzle.reverse_path {
+        solution.push_str(&format!(" | reverse: {}", reverse.join(" -> ")));
+    }
+    if let Some(stars) = &puzzle.stars {
+        solution.push_str(&format!(
+            " | par {} (3 stars <= {}, 2 stars <= {})",
+            stars.par, stars.three_stars, stars.two_stars
+        ));
+    }
+    if clues {
+        format!(
+            "{} -> {}: {} | clues: {}\n",
+            puzzle.start,
+            puzzle.end,
+            solution,
+            puzzle.to_clue_format().join(" -> ")
+        )
+    } else {
+        format!("{} -> {}: {}\n", puzzle.start, puzzle.end, solution)
+    }
 }
 
 /// Generates bulk puzzles for all difficulty levels and saves them to files.
@@ -619,7 +4928,11 @@ fn load_generator(dict: &Path, base_words: &Path) -> Result<PuzzleGenerator> {
 ///
 /// * `generator` - The puzzle generator to use
 /// * `config` - Configuration containing output settings
-/// * `format` - Output format (Text or Json)
+/// * `format` - Output format (Text, Json, Jsonl, Markdown, Msgpack, Cbor, or Proto)
+/// * `clues` - Whether to include masked clue words
+/// * `manifest_dict` - Dictionary to record in a sidecar manifest per file, if requested
+/// * `cancel` - Cancellation flag; remaining difficulty levels are skipped once set
+/// * `report` - Report receiving counts, output paths, and status messages
 ///
 /// # Returns
 ///
@@ -628,6 +4941,10 @@ fn generate_bulk_puzzles(
     generator: &PuzzleGenerator,
     config: &Config,
     format: &OutputFormat,
+    clues: bool,
+    manifest_dict: Option<&Path>,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
 ) -> Result<()> {
     use std::fs;
 
@@ -641,40 +4958,169 @@ fn generate_bulk_puzzles(
     ];
 
     for (difficulty, filename) in difficulties {
-        let puzzles = generator.generate_batch(config.bulk_puzzle_count, difficulty);
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+        let puzzles = generate_with_progress(
+            generator,
+            config.bulk_puzzle_count,
+            difficulty,
+            cancel,
+            report,
+            None,
+        )?;
         let puzzle_count = puzzles.len();
+        report.count("puzzles", puzzle_count);
 
         match format {
             OutputFormat::Json => {
-                let json_array: Result<Vec<_>, _> = puzzles.iter().map(|p| p.to_json()).collect();
+                let json_array: Result<Vec<_>, _> = puzzles
+                    .iter()
+                    .map(|p| puzzle_to_json(p, clues, config.omit_solutions, config.canonical_hash))
+                    .collect();
                 let json_array = json_array?;
                 let output_content = format!("[\n{}\n]", json_array.join(",\n"));
                 let output_path = config.output_dir.join(format!("{}.json", filename));
                 fs::write(&output_path, output_content)?;
-                println!(
+                report.output(&output_path);
+                report.info(format!(
                     "Generated {} {} puzzles in {}",
                     puzzle_count,
                     filename,
                     output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            OutputFormat::Jsonl => {
+                let output_path = config.output_dir.join(format!("{}.jsonl", filename));
+                let mut exporter = json_lines_exporter(
+                    fs::File::create(&output_path)?,
+                    clues,
+                    config.omit_solutions,
+                    config.canonical_hash,
                 );
+                for puzzle in &puzzles {
+                    exporter.write_puzzle(puzzle.clone())?;
+                }
+                exporter.finish()?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
             }
             OutputFormat::Text => {
                 let mut output_content = String::new();
-                for puzzle in puzzles {
-                    let solution = puzzle.path.join(" -> ");
-                    output_content.push_str(&format!(
-                        "{} -> {}: {}\n",
-                        puzzle.start, puzzle.end, solution
-                    ));
+                for puzzle in &puzzles {
+                    output_content.push_str(&puzzle_to_text_line(puzzle, clues));
                 }
                 let output_path = config.output_dir.join(format!("{}.txt", filename));
                 fs::write(&output_path, output_content)?;
-                println!(
+                report.output(&output_path);
+                report.info(format!(
                     "Generated {} {} puzzles in {}",
                     puzzle_count,
                     filename,
                     output_path.display()
-                );
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            OutputFormat::Markdown => {
+                let output_content = MarkdownExporter::new()
+                    .with_title(Some(format!("{:?} puzzles", difficulty)))
+                    .with_include_clues(clues)
+                    .export_puzzles(&puzzles);
+                let output_path = config.output_dir.join(format!("{}.md", filename));
+                fs::write(&output_path, output_content)?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            OutputFormat::Svg => {
+                let output_content = SvgExporter::new()
+                    .with_include_clues(clues)
+                    .export_puzzles(&puzzles);
+                let output_path = config.output_dir.join(format!("{}.svg", filename));
+                fs::write(&output_path, output_content)?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            OutputFormat::Anki => {
+                let output_content = AnkiExporter::new()
+                    .with_deck(Some(format!("Word Ladders::{:?}", difficulty)))
+                    .with_include_clues(clues)
+                    .export_puzzles(&puzzles);
+                let output_path = config.output_dir.join(format!("{}.tsv", filename));
+                fs::write(&output_path, output_content)?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            OutputFormat::Msgpack | OutputFormat::Cbor => {
+                let output_path =
+                    config
+                        .output_dir
+                        .join(format!("{}.{}", filename, format.extension()));
+                fs::write(
+                    &output_path,
+                    binary_exporter(format, clues).export_puzzles(&puzzles)?,
+                )?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
+            }
+            #[cfg(feature = "proto")]
+            OutputFormat::Proto => {
+                let output_path = config.output_dir.join(format!("{}.pb", filename));
+                fs::write(&output_path, PuzzlePack::new(puzzles.clone()).encode())?;
+                report.output(&output_path);
+                report.info(format!(
+                    "Generated {} {} puzzles in {}",
+                    puzzle_count,
+                    filename,
+                    output_path.display()
+                ));
+                if let Some(dict) = manifest_dict {
+                    write_manifest(&output_path, dict, &puzzles, false, report)?;
+                }
             }
             OutputFormat::Sql => {
                 // This should not happen as SQL format is handled separately
@@ -698,8 +5144,10 @@ fn generate_bulk_puzzles(
 /// * `generator` - The puzzle generator to use
 /// * `config` - Configuration containing output settings
 /// * `output_path` - Path to the output SQL file
-/// * `include_schema` - Whether to include CREATE TABLE statement
-/// * `batch_size` - Batch size for INSERT statements
+/// * `sql_config` - SQL export settings (schema, batch size, optional columns)
+/// * `manifest_dict` - Dictionary to record in a sidecar manifest, if requested
+/// * `cancel` - Cancellation flag; puzzles generated before it was set are still written
+/// * `report` - Report receiving counts, output paths, and status messages
 ///
 /// # Returns
 ///
@@ -708,34 +5156,41 @@ fn generate_bulk_sql(
     generator: &PuzzleGenerator,
     config: &Config,
     output_path: &Path,
-    include_schema: bool,
-    batch_size: usize,
+    sql_config: SqlExportConfig,
+    manifest_dict: Option<&Path>,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
 ) -> Result<()> {
-    use std::fs;
-
     let difficulties = vec![Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
 
     let mut all_puzzles = Vec::new();
 
     for difficulty in difficulties {
-        let puzzles = generator.generate_batch(config.bulk_puzzle_count, difficulty);
+        let puzzles = generate_with_progress(
+            generator,
+            config.bulk_puzzle_count,
+            difficulty,
+            cancel,
+            report,
+            None,
+        )?;
         all_puzzles.extend(puzzles);
     }
 
-    let sql_config = SqlExportConfig {
-        batch_size,
-        include_schema,
-        include_comments: true,
-    };
     let mut exporter = SqlExporter::with_config(sql_config);
     let sql = exporter.export_puzzles(&all_puzzles)?;
 
-    fs::write(output_path, sql)?;
-    println!(
+    write_output(output_path, sql)?;
+    report.output(output_path);
+    report.count("puzzles", all_puzzles.len());
+    report.info(format!(
         "Generated {} puzzles in SQL format to {}",
         all_puzzles.len(),
         output_path.display()
-    );
+    ));
+    if let Some(dict) = manifest_dict {
+        write_manifest(output_path, dict, &all_puzzles, false, report)?;
+    }
 
     Ok(())
 }
@@ -749,22 +5204,389 @@ fn generate_bulk_sql(
 ///
 /// * `generator` - The puzzle generator to use
 /// * `config` - Configuration containing generation settings
+/// * `cancel` - Cancellation flag checked between attempts
+/// * `report` - Report receiving per-difficulty generation statistics
+/// * `checkpoint` - Checkpoint recording progress, if any; difficulties it
+///   already holds puzzles for only generate the remainder
 ///
 /// # Returns
 ///
-/// Returns a vector of all generated puzzles.
+/// Returns a vector of the newly generated puzzles.
 fn generate_all_puzzles_for_mobile(
     generator: &PuzzleGenerator,
     config: &Config,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
+    mut checkpoint: Option<&mut Checkpoint>,
 ) -> Result<Vec<crate::puzzle::Puzzle>> {
     let difficulties = vec![Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
 
     let mut all_puzzles = Vec::new();
 
     for difficulty in difficulties {
-        let puzzles = generator.generate_batch(config.bulk_puzzle_count * 2, difficulty); // Generate more for better selection
+        // Generate more for better selection
+        let puzzles = generate_with_progress(
+            generator,
+            config.bulk_puzzle_count * 2,
+            difficulty,
+            cancel,
+            report,
+            checkpoint.as_deref_mut(),
+        )?;
         all_puzzles.extend(puzzles);
     }
 
     Ok(all_puzzles)
 }
+
+/// Installs a Ctrl-C handler that requests cancellation of puzzle generation.
+///
+/// The first Ctrl-C sets the returned flag so generation stops and whatever was
+/// produced so far is still written out. A second Ctrl-C exits immediately.
+///
+/// # Returns
+///
+/// Returns the shared cancellation flag, or an error if a handler is already installed.
+fn install_cancel_handler() -> Result<Arc<AtomicBool>> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&cancel);
+    ctrlc::set_handler(move || {
+        if flag.swap(true, Ordering::Relaxed) {
+            std::process::exit(130);
+        }
+        eprintln!("\nCancelling; writing puzzles generated so far (press Ctrl-C again to abort)");
+    })?;
+    Ok(cancel)
+}
+
+/// Generates a batch of puzzles while drawing a progress bar on stderr.
+///
+/// Once the batch finishes, a one-line summary of attempts and rejection
+/// reasons is printed to stderr, and the attempts and time are added to the
+/// report. A batch that falls short of `count` also records a warning.
+///
+/// With a checkpoint, puzzles it already holds for `difficulty` count towards
+/// `count`, and the rest are generated in chunks of `CHECKPOINT_INTERVAL`, each
+/// recorded in the checkpoint as soon as it is done. A seeded generator is
+/// reseeded per chunk so chunks do not repeat each other, and the endpoint
+/// reuse quota counts the checkpointed puzzles and every earlier chunk.
+///
+/// # Arguments
+///
+/// * `generator` - The puzzle generator to use
+/// * `count` - Number of puzzles to generate
+/// * `difficulty` - Desired difficulty level
+/// * `cancel` - Cancellation flag checked between attempts
+/// * `report` - Report receiving the batch statistics
+/// * `checkpoint` - Checkpoint recording progress, if any
+///
+/// # Returns
+///
+/// The newly generated puzzles, fewer than `count` minus those already
+/// checkpointed if generation was cancelled, or an error if the checkpoint
+/// cannot be written.
+fn generate_with_progress(
+    generator: &PuzzleGenerator,
+    count: usize,
+    difficulty: Difficulty,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
+    checkpoint: Option<&mut Checkpoint>,
+) -> Result<Vec<crate::puzzle::Puzzle>> {
+    let mut puzzles = Vec::new();
+    stream_with_progress(
+        generator,
+        count,
+        difficulty,
+        cancel,
+        report,
+        checkpoint,
+        |puzzle| {
+            puzzles.push(puzzle);
+            Ok(())
+        },
+    )?;
+    Ok(puzzles)
+}
+
+/// Generates a batch of puzzles like `generate_with_progress`, handing each
+/// puzzle to `on_puzzle` as soon as it is generated instead of collecting them.
+///
+/// With a checkpoint, only the puzzles of the current chunk are kept, until
+/// the chunk is recorded.
+///
+/// # Returns
+///
+/// The number of newly generated puzzles, or the first error returned by
+/// `on_puzzle` or the checkpoint.
+fn stream_with_progress(
+    generator: &PuzzleGenerator,
+    count: usize,
+    difficulty: Difficulty,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
+    mut checkpoint: Option<&mut Checkpoint>,
+    mut on_puzzle: impl FnMut(crate::puzzle::Puzzle) -> Result<()>,
+) -> Result<usize> {
+    let name = format!("{:?}", difficulty).to_lowercase();
+    let done = checkpoint
+        .as_deref()
+        .map_or(0, |checkpoint| checkpoint.completed(&name).min(count));
+    let remaining = count - done;
+    let chunk_size = if checkpoint.is_some() {
+        CHECKPOINT_INTERVAL
+    } else {
+        remaining
+    };
+
+    let bar = ProgressBar::new(count as u64);
+    bar.set_style(
+        ProgressStyle::with_template(
+            "{msg:>8} [{bar:40}] {pos}/{len} puzzles ({elapsed}, {prefix} attempts)",
+        )
+        .expect("progress template is valid")
+        .progress_chars("=> "),
+    );
+    bar.set_message(name.clone());
+    bar.set_position(done as u64);
+
+    let mut endpoint_uses = checkpoint
+        .as_deref()
+        .map(|checkpoint| checkpoint.endpoint_uses(&name))
+        .unwrap_or_default();
+    let mut result = BatchResult::default();
+    while result.generated() < remaining && !cancel.load(Ordering::Relaxed) {
+        let generated = result.generated();
+        let attempts = result.attempts;
+        let target = chunk_size.min(remaining - generated);
+        let chunk_generator = generator
+            .seed()
+            .filter(|_| checkpoint.is_some())
+            .map(|seed| {
+                generator
+                    .clone()
+                    .with_seed(seed.wrapping_add((done + generated) as u64))
+            });
+        let mut chunk_puzzles = Vec::new();
+        let chunk = chunk_generator
+            .as_ref()
+            .unwrap_or(generator)
+            .continue_batch_streaming(
+                target,
+                difficulty,
+                &mut endpoint_uses,
+                cancel,
+                |progress| {
+                    bar.set_position((done + generated + progress.generated) as u64);
+                    bar.set_prefix((attempts + progress.attempts).to_string());
+                },
+                |puzzle| {
+                    if checkpoint.is_some() {
+                        chunk_puzzles.push(puzzle.clone());
+                    }
+                    on_puzzle(puzzle)
+                },
+            )?;
+
+        if let Some(checkpoint) = checkpoint.as_deref_mut() {
+            checkpoint.record(&chunk_puzzles)?;
+        }
+        let short = chunk.generated() < target;
+        result.merge(chunk);
+        if short {
+            break;
+        }
+    }
+
+    if cancel.load(Ordering::Relaxed) {
+        bar.abandon();
+    } else {
+        bar.finish_and_clear();
+    }
+    eprintln!("{:>8}: {}", name, result);
+    report.count(&format!("{}_attempts", name), result.attempts);
+    report.timing(&name, result.elapsed);
+    if done + result.generated() < count {
+        report.warnings.push(format!(
+            "Generated only {} of {} {} puzzles",
+            done + result.generated(),
+            count,
+            name
+        ));
+    }
+    Ok(result.generated())
+}
+
+/// Opens the generation checkpoint for an output file.
+///
+/// Without `resume`, any previous checkpoint is discarded. With `resume` and no
+/// checkpoint to resume, a warning is recorded and the job starts over.
+///
+/// # Arguments
+///
+/// * `output_path` - Path of the job's final output file
+/// * `command` - Name of the command running the job
+/// * `targets` - Number of puzzles wanted per difficulty
+/// * `resume` - Whether to continue a previous run
+/// * `hides_solutions` - Whether the export omits or encrypts the solutions
+/// * `report` - Report receiving progress messages
+///
+/// # Returns
+///
+/// The checkpoint and the puzzles already generated, or a configuration error
+/// if the checkpoint belongs to a different job. Output written to stdout has
+/// no checkpoint, and cannot be resumed. Neither can exports that hide their
+/// solutions, since the checkpoint would keep every solution on disk.
+fn open_checkpoint(
+    output_path: &Path,
+    command: &str,
+    targets: &BTreeMap<String, usize>,
+    resume: bool,
+    hides_solutions: bool,
+    report: &mut CliReport,
+) -> Result<(Option<Checkpoint>, Vec<crate::puzzle::Puzzle>)> {
+    if is_stdout(output_path) {
+        if resume {
+            return Err(anyhow::Error::msg(ConfigError(
+                "--resume cannot be used with --output -".to_string(),
+            )));
+        }
+        return Ok((None, Vec::new()));
+    }
+    if hides_solutions {
+        if resume {
+            return Err(anyhow::Error::msg(ConfigError(
+                "--resume cannot be used with --omit-solutions or --encrypt-solutions".to_string(),
+            )));
+        }
+        return Ok((None, Vec::new()));
+    }
+    if resume {
+        let resumed = Checkpoint::resume(output_path, command, targets).context(ConfigError(
+            "Cannot resume; rerun without --resume to start over".to_string(),
+        ))?;
+        if let Some((checkpoint, puzzles)) = resumed {
+            report.info(format!(
+                "Resuming with {} puzzles from the previous run",
+                puzzles.len()
+            ));
+            return Ok((Some(checkpoint), puzzles));
+        }
+        report.warn(format!(
+            "No checkpoint found for {}; starting from scratch",
+            output_path.display()
+        ));
+    }
+    Ok((
+        Some(Checkpoint::start(output_path, command, targets)?),
+        Vec::new(),
+    ))
+}
+
+/// Returns `true` if exports leave the solutions out or encrypt them.
+fn hides_solutions(config: &Config) -> bool {
+    #[cfg(feature = "encrypt")]
+    return config.omit_solutions || config.solution_key.is_some();
+    #[cfg(not(feature = "encrypt"))]
+    return config.omit_solutions;
+}
+
+/// Removes a finished job's checkpoint, or keeps it if the job was cancelled.
+///
+/// # Arguments
+///
+/// * `checkpoint` - Checkpoint of the job, if any
+/// * `cancel` - Cancellation flag of the job
+/// * `report` - Report receiving progress messages
+fn finish_checkpoint(
+    checkpoint: Option<Checkpoint>,
+    cancel: &AtomicBool,
+    report: &mut CliReport,
+) -> Result<()> {
+    let Some(checkpoint) = checkpoint else {
+        return Ok(());
+    };
+    if cancel.load(Ordering::Relaxed) {
+        report.info("Progress saved; rerun with --resume to continue");
+        Ok(())
+    } else {
+        checkpoint.remove()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts how often each word starts or ends one of the puzzles.
+    fn endpoint_counts(puzzles: &[Puzzle]) -> HashMap<&str, usize> {
+        let mut counts = HashMap::new();
+        for puzzle in puzzles {
+            for word in [&puzzle.start, &puzzle.end] {
+                *counts.entry(word.as_str()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn test_endpoint_quota_spans_checkpoint_chunks_and_resume() {
+        // Every four-letter word over "abcd": most random pairs are 2 or 3
+        // steps apart, and 256 words under a quota of 2 allow 256 puzzles
+        let letters = ['a', 'b', 'c', 'd'];
+        let words: Vec<String> = (0..256)
+            .map(|i: usize| (0..4).map(|p| letters[(i >> (2 * p)) & 3]).collect())
+            .collect();
+        let mut graph = WordGraph::from_words(words.clone());
+        graph.load_base_words_from_str(&words.join("\n"));
+        let generator = PuzzleGenerator::new(graph)
+            .with_seed(7)
+            .with_max_endpoint_reuse(2);
+
+        let dir = std::env::temp_dir().join(format!("wl_cli_quota_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("batch.txt");
+        let targets = BTreeMap::from([("easy".to_string(), 150)]);
+        let cancel = AtomicBool::new(false);
+        let mut report = CliReport::new("batch", false);
+
+        // More than a chunk, then stop as if interrupted
+        let mut checkpoint = Checkpoint::start(&output, "batch", &targets).unwrap();
+        let mut puzzles = generate_with_progress(
+            &generator,
+            120,
+            Difficulty::Easy,
+            &cancel,
+            &mut report,
+            Some(&mut checkpoint),
+        )
+        .unwrap();
+        assert_eq!(puzzles.len(), 120);
+        drop(checkpoint);
+
+        let (mut checkpoint, resumed) = Checkpoint::resume(&output, "batch", &targets)
+            .unwrap()
+            .unwrap();
+        assert_eq!(resumed, puzzles);
+        puzzles.extend(
+            generate_with_progress(
+                &generator,
+                150,
+                Difficulty::Easy,
+                &cancel,
+                &mut report,
+                Some(&mut checkpoint),
+            )
+            .unwrap(),
+        );
+        checkpoint.remove().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(puzzles.len(), 150);
+        let overused: Vec<_> = endpoint_counts(&puzzles)
+            .into_iter()
+            .filter(|&(_, uses)| uses > 2)
+            .collect();
+        assert!(overused.is_empty(), "reused endpoints: {:?}", overused);
+    }
+}
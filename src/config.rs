@@ -8,10 +8,28 @@
 //! The default configuration uses these paths:
 //! - Dictionary: `data/dictionary.txt`
 //! - Base words: `data/base_words.txt`
+//! - Accepted words: none, so player ladders are only checked step by step
 //! - Output directory: `output/`
 //! - Bulk puzzle count: 100 puzzles per difficulty
 //! - SQL batch size: 100 records per INSERT
+//! - SQL puzzle IDs: per-run counters (`start_end_001`)
 //! - Mobile difficulty distribution: 40% easy, 40% medium, 20% hard
+//! - Edge rules: classic one-letter substitution
+//! - Word normalization: lowercase, words with apostrophes or hyphens skipped
+//! - Comparison unit: one `char` per letter
+//! - Excluded words: none beyond what normalization skips
+//! - Minimum branching: disabled for every difficulty
+//! - Endpoint word lengths: any length for every difficulty
+//! - Unique solution: not required
+//! - Puzzle metadata: not attached
+//! - Star ratings: not attached; par plus one-and-a-half times par for two stars
+//! - Solutions: included in exports
+//! - Canonical puzzle hashes: not exported
+//! - Daemon solve cache: 10,000 word pairs
+//! - Landmark (A*) path finding: disabled
+//! - Load summaries: not printed
+//! - HTTP server: `127.0.0.1:8080`, no API keys, 60 requests per minute per client,
+//!   solves capped at 1,000,000 visited words and 5 seconds
 //!
 //! ## Usage
 //!
@@ -29,8 +47,17 @@
 //!     .with_mobile_distribution(0.5, 0.3, 0.2);
 //! ```
 
+#[cfg(feature = "encrypt")]
+use crate::exporters::encryption::SolutionKey;
+use crate::exporters::sql::IdStrategy;
+use crate::graph::{ComparisonUnit, EdgeRule, SolveOptions, WordNormalization};
+pub use crate::puzzle::MinBranching;
+pub use crate::puzzle::WordLengthPolicy;
+pub use crate::puzzle::curation::DifficultyDistribution;
+use crate::puzzle::{CanonicalHashOptions, StarFormula};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Central configuration structure for the word ladder engine.
 ///
@@ -47,6 +74,11 @@ pub struct Config {
     /// These words are used as start and end points for generated puzzles.
     pub base_words_path: PathBuf,
 
+    /// Path to an optional file of extra words players may use in their
+    /// ladders (a broader guess list). Generated paths still only use
+    /// dictionary words.
+    pub accepted_words_path: Option<PathBuf>,
+
     /// Directory where generated puzzle files will be saved.
     /// This directory will be created if it doesn't exist.
     pub output_dir: PathBuf,
@@ -60,44 +92,192 @@ pub struct Config {
     /// Whether to include CREATE TABLE schema by default in SQL exports.
     pub include_schema_by_default: bool,
 
+    /// How puzzle IDs are generated in SQL exports.
+    /// Content hashes keep IDs stable across generation runs.
+    pub sql_id_strategy: IdStrategy,
+
     /// Difficulty distribution for mobile-optimized puzzle generation.
     pub mobile_difficulty_distribution: DifficultyDistribution,
+
+    /// Rules deciding which words are connected in the word graph.
+    /// Changing these selects a different game mode (e.g. word golf).
+    pub edge_rules: Vec<EdgeRule>,
+
+    /// How dictionary words, base words, and solve/verify input are normalized:
+    /// case mapping and whether apostrophes and hyphens are rejected, stripped, or kept.
+    pub word_normalization: WordNormalization,
+
+    /// The unit of a word that one ladder step changes: a `char`, or a
+    /// grapheme cluster for scripts that write one letter with several
+    /// code points.
+    pub comparison_unit: ComparisonUnit,
+
+    /// Regular expression excluding dictionary and base words when they are
+    /// loaded (e.g. `^[A-Z]` for proper nouns), matched against each entry
+    /// before normalization.
+    pub exclude_regex: Option<String>,
+
+    /// Minimum number of neighbors each intermediate word must have, per difficulty.
+    /// Rejects "forced" ladders where every step has only one legal move.
+    pub min_branching: MinBranching,
+
+    /// Word lengths batch generation picks start and end words from, per
+    /// difficulty level (e.g. 3-4 letters for easy, 5-6 for hard).
+    pub word_lengths: WordLengthPolicy,
+
+    /// Whether generated puzzles must have exactly one shortest solution.
+    /// Used for competitive play; the flag is exported with each puzzle.
+    pub unique_solution: bool,
+
+    /// Whether generated puzzles must also be solvable backwards through
+    /// different words ("mirror puzzles"); the reverse path is exported with each puzzle.
+    pub require_reversible: bool,
+
+    /// Whether to attach generation metadata (timestamp, engine version,
+    /// dictionary hash, seed) to each puzzle and include it in JSON and SQL exports.
+    pub puzzle_meta: bool,
+
+    /// Whether to attach a par and two-/three-star thresholds to each puzzle
+    /// and include them in JSON and SQL exports.
+    pub star_ratings: bool,
+
+    /// How the star thresholds are derived from a puzzle's par, its number of
+    /// shortest solutions, and its branching.
+    pub star_formula: StarFormula,
+
+    /// Whether JSON, JSON Lines, and SQL exports leave out the solution,
+    /// keeping only each puzzle's ID, endpoints, step count, and difficulty.
+    /// Used for competitive modes where clients must not receive the ladders.
+    pub omit_solutions: bool,
+
+    /// Options of the `canonical_hash` field (JSON) or column (SQL) added to
+    /// puzzle exports, or `None` to leave it out.
+    pub canonical_hash: Option<CanonicalHashOptions>,
+
+    /// Master key for encrypting the solutions of SQL exports instead of
+    /// leaving them out (see the `encryption` exporter). Never serialized.
+    #[cfg(feature = "encrypt")]
+    #[serde(skip)]
+    pub solution_key: Option<SolutionKey>,
+
+    /// Number of `(start, end)` answers the daemon keeps in its LRU solve cache.
+    /// 0 disables the cache.
+    pub solve_cache_size: usize,
+
+    /// Number of landmarks to pick per word length for A* path finding.
+    /// Landmark distances are saved next to the dictionary and reused while it
+    /// is unchanged. 0 disables landmarks and uses BFS.
+    pub landmarks_per_length: usize,
+
+    /// Whether the CLI prints a `LoadReport` summary (lines read, accepted,
+    /// rejected, duplicates, and words per length) for each dictionary and
+    /// base words file it loads.
+    pub verbose: bool,
+
+    /// Settings for the HTTP server started by the `serve` subcommand.
+    pub server: ServerConfig,
 }
 
-/// Difficulty distribution configuration for mobile puzzle generation.
+/// Settings for the HTTP server (`serve` subcommand).
 ///
-/// This struct defines the ratios of easy, medium, and hard puzzles to generate
-/// for mobile applications, ensuring a balanced gameplay experience.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DifficultyDistribution {
-    /// Ratio of easy puzzles (0.0 to 1.0)
-    pub easy: f64,
-    /// Ratio of medium puzzles (0.0 to 1.0)
-    pub medium: f64,
-    /// Ratio of hard puzzles (0.0 to 1.0)
-    pub hard: f64,
+/// An empty `api_keys` list disables authentication, which is only suitable
+/// for local use; set keys before exposing the server publicly. Reloading is
+/// only available to `admin_keys`, and disabled when there are none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Address to listen on, e.g. `0.0.0.0:8080`
+    pub bind: String,
+    /// API keys accepted as `Authorization: Bearer <key>` or `X-Api-Key`
+    pub api_keys: Vec<String>,
+    /// API keys that may also call `/v1/reload`
+    pub admin_keys: Vec<String>,
+    /// Steady request rate allowed per API key (or per address without keys);
+    /// 0 disables rate limiting
+    pub requests_per_minute: u32,
+    /// Requests a client may make at once after being idle
+    pub burst: u32,
+    /// Largest request body accepted, in bytes
+    pub max_body_bytes: usize,
+    /// Most puzzles one `/v1/generate` request may ask for
+    pub max_count: usize,
+    /// Most word pairs one `/v1/generate` request tries before giving up
+    pub max_attempts: usize,
+    /// Most words one `/v1/solve` request may visit; a client's `max_visited`
+    /// is lowered to this
+    pub solve_max_visited: usize,
+    /// Milliseconds one `/v1/solve` request may search; a client's
+    /// `timeout_ms` is lowered to this
+    pub solve_timeout_ms: u64,
 }
 
-impl Default for DifficultyDistribution {
+impl Default for ServerConfig {
     fn default() -> Self {
         Self {
-            easy: 0.4,   // 40% easy
-            medium: 0.4, // 40% medium
-            hard: 0.2,   // 20% hard
+            bind: "127.0.0.1:8080".to_string(),
+            api_keys: Vec::new(),
+            admin_keys: Vec::new(),
+            requests_per_minute: 60,
+            burst: 10,
+            max_body_bytes: 64 * 1024,
+            max_count: 50,
+            max_attempts: 10_000,
+            solve_max_visited: 1_000_000,
+            solve_timeout_ms: 5_000,
         }
     }
 }
 
+impl ServerConfig {
+    /// Returns the search budget every `/v1/solve` request is held to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use wordladder_engine::config::ServerConfig;
+    ///
+    /// let limits = ServerConfig::default().solve_limits();
+    /// assert_eq!(limits.max_visited, Some(1_000_000));
+    /// assert_eq!(limits.timeout, Some(Duration::from_secs(5)));
+    /// ```
+    pub fn solve_limits(&self) -> SolveOptions {
+        SolveOptions::new()
+            .with_max_visited(self.solve_max_visited)
+            .with_timeout(Duration::from_millis(self.solve_timeout_ms))
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             dictionary_path: PathBuf::from("data/dictionary.txt"),
             base_words_path: PathBuf::from("data/base_words.txt"),
+            accepted_words_path: None,
             output_dir: PathBuf::from("output"),
             bulk_puzzle_count: 100,
             sql_batch_size: 100,
             include_schema_by_default: true,
+            sql_id_strategy: IdStrategy::Counter,
             mobile_difficulty_distribution: DifficultyDistribution::default(),
+            edge_rules: vec![EdgeRule::SubstituteOne],
+            word_normalization: WordNormalization::default(),
+            comparison_unit: ComparisonUnit::Char,
+            exclude_regex: None,
+            min_branching: MinBranching::default(),
+            word_lengths: WordLengthPolicy::default(),
+            unique_solution: false,
+            require_reversible: false,
+            puzzle_meta: false,
+            star_ratings: false,
+            star_formula: StarFormula::default(),
+            omit_solutions: false,
+            canonical_hash: None,
+            #[cfg(feature = "encrypt")]
+            solution_key: None,
+            solve_cache_size: 10_000,
+            landmarks_per_length: 0,
+            verbose: false,
+            server: ServerConfig::default(),
         }
     }
 }
@@ -117,6 +297,25 @@ impl Config {
         Self::default()
     }
 
+    /// Sets the accepted words file used to validate player ladders.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the accepted words file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_accepted_words_path("data/guesses.txt".into());
+    /// ```
+    pub fn with_accepted_words_path(mut self, path: PathBuf) -> Self {
+        self.accepted_words_path = Some(path);
+        self
+    }
+
     /// Sets the dictionary file path.
     ///
     /// # Arguments
@@ -231,6 +430,26 @@ impl Config {
         self
     }
 
+    /// Sets how puzzle IDs are generated in SQL exports.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_strategy` - Per-run counters or stable content hashes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::exporters::sql::IdStrategy;
+    ///
+    /// let config = Config::new()
+    ///     .with_sql_id_strategy(IdStrategy::ContentHash);
+    /// ```
+    pub fn with_sql_id_strategy(mut self, id_strategy: IdStrategy) -> Self {
+        self.sql_id_strategy = id_strategy;
+        self
+    }
+
     /// Sets the mobile difficulty distribution.
     ///
     /// # Arguments
@@ -251,4 +470,368 @@ impl Config {
         self.mobile_difficulty_distribution = DifficultyDistribution { easy, medium, hard };
         self
     }
+
+    /// Sets the rules deciding which words are connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - Edge rules to combine when building the word graph
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::graph::EdgeRule;
+    ///
+    /// let config = Config::new()
+    ///     .with_edge_rules(vec![EdgeRule::SubstituteOne, EdgeRule::InsertOrDeleteOne]);
+    /// ```
+    pub fn with_edge_rules(mut self, rules: Vec<EdgeRule>) -> Self {
+        self.edge_rules = rules;
+        self
+    }
+
+    /// Sets how words are normalized when loaded and when typed by players.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalization` - Case mapping and apostrophe/hyphen policy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::graph::{CaseMapping, PunctuationPolicy, WordNormalization};
+    ///
+    /// let config = Config::new().with_word_normalization(WordNormalization {
+    ///     case: CaseMapping::Turkic,
+    ///     punctuation: PunctuationPolicy::Strip,
+    /// });
+    /// ```
+    pub fn with_word_normalization(mut self, normalization: WordNormalization) -> Self {
+        self.word_normalization = normalization;
+        self
+    }
+
+    /// Sets the unit of a word that one ladder step changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Chars, or grapheme clusters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::graph::ComparisonUnit;
+    ///
+    /// let config = Config::new().with_comparison_unit(ComparisonUnit::Grapheme);
+    /// ```
+    pub fn with_comparison_unit(mut self, unit: ComparisonUnit) -> Self {
+        self.comparison_unit = unit;
+        self
+    }
+
+    /// Sets a regular expression excluding words when they are loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Words matching it are skipped, or `None` to keep every word
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new().with_exclude_regex(Some("^[A-Z]".to_string()));
+    /// ```
+    pub fn with_exclude_regex(mut self, pattern: Option<String>) -> Self {
+        self.exclude_regex = pattern;
+        self
+    }
+
+    /// Sets the minimum branching for each difficulty level.
+    ///
+    /// # Arguments
+    ///
+    /// * `easy` - Minimum neighbors per intermediate word for easy puzzles
+    /// * `medium` - Minimum neighbors per intermediate word for medium puzzles
+    /// * `hard` - Minimum neighbors per intermediate word for hard puzzles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_min_branching(0, 3, 3);
+    /// ```
+    pub fn with_min_branching(mut self, easy: usize, medium: usize, hard: usize) -> Self {
+        self.min_branching = MinBranching { easy, medium, hard };
+        self
+    }
+
+    /// Sets the endpoint word lengths of each difficulty level.
+    ///
+    /// # Arguments
+    ///
+    /// * `word_lengths` - Allowed word lengths per difficulty; `None` allows any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::{Config, WordLengthPolicy};
+    ///
+    /// let config = Config::new().with_word_lengths(WordLengthPolicy {
+    ///     easy: Some(3..=4),
+    ///     medium: None,
+    ///     hard: Some(5..=6),
+    /// });
+    /// ```
+    pub fn with_word_lengths(mut self, word_lengths: WordLengthPolicy) -> Self {
+        self.word_lengths = word_lengths;
+        self
+    }
+
+    /// Sets whether generated puzzles must have exactly one shortest solution.
+    ///
+    /// # Arguments
+    ///
+    /// * `unique_solution` - Whether to require a unique shortest path
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_unique_solution(true);
+    /// ```
+    pub fn with_unique_solution(mut self, unique_solution: bool) -> Self {
+        self.unique_solution = unique_solution;
+        self
+    }
+
+    /// Sets whether generated puzzles must have a reverse path.
+    ///
+    /// # Arguments
+    ///
+    /// * `require_reversible` - Whether to require a same-length ladder from
+    ///   the end word back to the start word through different words
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_require_reversible(true);
+    /// ```
+    pub fn with_require_reversible(mut self, require_reversible: bool) -> Self {
+        self.require_reversible = require_reversible;
+        self
+    }
+
+    /// Sets whether generated puzzles carry generation metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle_meta` - Whether to attach and export a `PuzzleMeta`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_puzzle_meta(true);
+    /// ```
+    pub fn with_puzzle_meta(mut self, puzzle_meta: bool) -> Self {
+        self.puzzle_meta = puzzle_meta;
+        self
+    }
+
+    /// Sets whether generated puzzles carry a par and star thresholds.
+    ///
+    /// # Arguments
+    ///
+    /// * `star_ratings` - Whether to attach and export a `StarRating`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_star_ratings(true);
+    /// ```
+    pub fn with_star_ratings(mut self, star_ratings: bool) -> Self {
+        self.star_ratings = star_ratings;
+        self
+    }
+
+    /// Sets the formula deriving star thresholds from par.
+    ///
+    /// # Arguments
+    ///
+    /// * `formula` - Slack, two-star factor, and bonuses of the thresholds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::puzzle::StarFormula;
+    ///
+    /// let config = Config::new()
+    ///     .with_star_ratings(true)
+    ///     .with_star_formula(StarFormula {
+    ///         two_star_factor: 2.0,
+    ///         ..StarFormula::default()
+    ///     });
+    /// ```
+    pub fn with_star_formula(mut self, formula: StarFormula) -> Self {
+        self.star_formula = formula;
+        self
+    }
+
+    /// Sets whether exports leave out the solutions.
+    ///
+    /// # Arguments
+    ///
+    /// * `omit_solutions` - Whether JSON, JSON Lines, and SQL exports strip
+    ///   paths and every other field that reveals a ladder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_omit_solutions(true);
+    /// ```
+    pub fn with_omit_solutions(mut self, omit_solutions: bool) -> Self {
+        self.omit_solutions = omit_solutions;
+        self
+    }
+
+    /// Sets whether exports include each puzzle's canonical hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Options passed to `Puzzle::canonical_hash`, or `None` to
+    ///   leave the hash out
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::puzzle::CanonicalHashOptions;
+    ///
+    /// let config = Config::new()
+    ///     .with_canonical_hash(Some(CanonicalHashOptions::default()));
+    /// ```
+    pub fn with_canonical_hash(mut self, options: Option<CanonicalHashOptions>) -> Self {
+        self.canonical_hash = options;
+        self
+    }
+
+    /// Sets the master key with which SQL exports encrypt their solutions.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Master key from which each puzzle's key is derived
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    /// use wordladder_engine::exporters::encryption::SolutionKey;
+    ///
+    /// let config = Config::new()
+    ///     .with_solution_key(SolutionKey::from_bytes([7; 32]));
+    /// ```
+    #[cfg(feature = "encrypt")]
+    pub fn with_solution_key(mut self, key: SolutionKey) -> Self {
+        self.solution_key = Some(key);
+        self
+    }
+
+    /// Sets how many solve answers the daemon caches.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Most `(start, end)` pairs to keep (0 disables the cache)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_solve_cache_size(0);
+    /// ```
+    pub fn with_solve_cache_size(mut self, size: usize) -> Self {
+        self.solve_cache_size = size;
+        self
+    }
+
+    /// Sets how many landmarks guide path finding per word length.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Landmarks per word length (0 disables landmarks)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_landmarks_per_length(8);
+    /// ```
+    pub fn with_landmarks_per_length(mut self, count: usize) -> Self {
+        self.landmarks_per_length = count;
+        self
+    }
+
+    /// Sets whether the CLI prints a summary of each word list it loads.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbose` - Whether to print load summaries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_verbose(true);
+    /// ```
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Sets the HTTP server settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Bind address, API keys, and rate limits
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::config::{Config, ServerConfig};
+    ///
+    /// let config = Config::new().with_server(ServerConfig {
+    ///     api_keys: vec!["widget-key".to_string()],
+    ///     requests_per_minute: 120,
+    ///     ..ServerConfig::default()
+    /// });
+    /// ```
+    pub fn with_server(mut self, server: ServerConfig) -> Self {
+        self.server = server;
+        self
+    }
 }
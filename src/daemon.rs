@@ -0,0 +1,696 @@
+//! # Daemon Mode
+//!
+//! This module implements a long-running daemon that keeps a fully built word
+//! graph in memory and answers requests over a newline-delimited JSON protocol.
+//! Graph construction dominates the cost of a single CLI invocation, so scripts
+//! that issue many queries can start the daemon once and amortize the load.
+//!
+//! ## Protocol
+//!
+//! Each request is a single line of JSON with a `method`, optional `params`,
+//! and an optional `id` that is echoed back in the response:
+//!
+//! ```text
+//! {"id": 1, "method": "solve", "params": {"start": "cat", "end": "dog"}}
+//! {"id": 6, "method": "solve", "params": {"start": "cat", "end": "dog", "timeout_ms": 50}}
+//! {"id": 2, "method": "verify", "params": {"puzzle": "cat,cot,cog,dog"}}
+//! {"id": 3, "method": "generate", "params": {"difficulty": "hard", "count": 5}}
+//! {"id": 4, "method": "stats"}
+//! {"id": 7, "method": "reload"}
+//! ```
+//!
+//! Each response is a single line containing either a `result` or an `error`:
+//!
+//! ```text
+//! {"id":1,"result":{"path":["cat","cot","cog","dog"],"steps":3}}
+//! {"id":5,"error":"unknown variant `slove`, expected one of ..."}
+//! ```
+//!
+//! ## Reloading
+//!
+//! A daemon created `with_reloader` answers the `reload` method by rebuilding
+//! its generator on a background thread (for example after the word list was
+//! updated). Requests keep being answered with the old graph until the new one
+//! is ready; it is swapped in before the next request that arrives after the
+//! rebuild finishes. Pass `"params": {"wait": true}` to block until the new
+//! graph is in place; only that request waits, so a daemon shared between
+//! threads (as `serve` mode does) keeps answering the others.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::daemon::Daemon;
+//! use wordladder_engine::puzzle::PuzzleGenerator;
+//! use wordladder_engine::graph::WordGraph;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt").unwrap();
+//! let daemon = Daemon::new(PuzzleGenerator::new(graph));
+//!
+//! let input = "{\"id\": 1, \"method\": \"stats\"}\n";
+//! let mut output = Vec::new();
+//! daemon.serve(input.as_bytes(), &mut output).unwrap();
+//! ```
+
+use crate::graph::cache::CachedSolver;
+use crate::graph::{SolveOptions, SolveOutcome};
+use crate::puzzle::{Difficulty, Puzzle, PuzzleGenerator};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError, RwLock};
+use std::time::Duration;
+
+/// Rebuilds the daemon's generator from its source files for the `reload` method.
+pub type Reloader = Arc<dyn Fn() -> Result<PuzzleGenerator> + Send + Sync>;
+
+/// A single request read from the daemon's input stream.
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    /// Caller-chosen identifier echoed back in the response
+    #[serde(default)]
+    pub id: Value,
+    /// The method to invoke and its parameters
+    #[serde(flatten)]
+    pub call: Call,
+}
+
+/// The methods supported by the daemon, with their parameters.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Call {
+    /// Find the shortest path between two words
+    Solve {
+        /// Starting word
+        start: String,
+        /// Ending word
+        end: String,
+        /// Most words the search may visit before giving up
+        #[serde(default)]
+        max_visited: Option<usize>,
+        /// Milliseconds the search may run before giving up
+        #[serde(default)]
+        timeout_ms: Option<u64>,
+    },
+    /// Verify a comma-separated ladder
+    Verify {
+        /// Puzzle as comma-separated words (e.g., "cat,cot,cog,dog")
+        puzzle: String,
+    },
+    /// Generate random puzzles of a given difficulty
+    Generate {
+        /// Difficulty level (easy, medium, hard)
+        #[serde(default = "default_difficulty")]
+        difficulty: String,
+        /// Number of puzzles to generate
+        #[serde(default = "default_count")]
+        count: usize,
+    },
+    /// Report dictionary and daemon statistics
+    Stats,
+    /// Rebuild the graph from its source files in the background
+    Reload {
+        /// Block until the new graph is swapped in
+        #[serde(default)]
+        wait: bool,
+    },
+}
+
+fn default_difficulty() -> String {
+    "medium".to_string()
+}
+
+fn default_count() -> usize {
+    1
+}
+
+/// A single response written to the daemon's output stream.
+#[derive(Debug, Serialize)]
+pub struct Response {
+    /// Identifier copied from the request (`null` if the line is not a JSON object)
+    pub id: Value,
+    /// Successful result payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error message if the request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Long-running request handler holding a warm puzzle generator.
+///
+/// Requests take `&self`, so one daemon can answer several threads at once.
+pub struct Daemon {
+    /// Generator with the dictionary and base words already loaded; a reload
+    /// swaps in a new one while requests in flight keep their own
+    generator: RwLock<Arc<PuzzleGenerator>>,
+    /// Number of requests handled so far
+    requests_served: AtomicU64,
+    /// Cache of recent `solve` answers (capacity 0 when disabled)
+    solver: Mutex<CachedSolver>,
+    /// Rebuilds the generator for the `reload` method, if configured
+    reloader: Option<Reloader>,
+    /// Running rebuild and the outcome of past ones
+    reload: Mutex<ReloadState>,
+    /// Most puzzles one `generate` request may ask for, or `None` for no limit
+    max_count: Option<usize>,
+    /// Most word pairs one `generate` request tries, or `None` for no limit
+    max_attempts: Option<usize>,
+    /// Ceiling on the search budget of every `solve` request
+    solve_limits: SolveOptions,
+}
+
+/// Background reloads of a `Daemon`.
+#[derive(Default)]
+struct ReloadState {
+    /// Background rebuild that has not been swapped in yet
+    pending: Option<Arc<PendingReload>>,
+    /// Number of completed reloads
+    reloads: u64,
+    /// Error of the most recent failed reload, cleared by a successful one
+    last_error: Option<String>,
+}
+
+/// A rebuild running on a background thread.
+#[derive(Default)]
+struct PendingReload {
+    /// The rebuilt generator or the error, once the rebuild is done
+    result: Mutex<Option<Result<PuzzleGenerator>>>,
+    /// Signaled when `result` is set
+    done: Condvar,
+}
+
+impl PendingReload {
+    /// Starts rebuilding on a new thread.
+    fn spawn(reloader: Reloader) -> Arc<Self> {
+        let pending = Arc::new(Self::default());
+        let worker = Arc::clone(&pending);
+        std::thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| reloader()))
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("Reload panicked")));
+            *lock(&worker.result) = Some(result);
+            worker.done.notify_all();
+        });
+        pending
+    }
+
+    /// Blocks until the rebuild is done.
+    fn wait(&self) {
+        let mut result = lock(&self.result);
+        while result.is_none() {
+            result = self
+                .done
+                .wait(result)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+/// Locks a mutex, recovering the data if a panicking thread poisoned it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the smaller of a requested value and a limit, either of which may
+/// be unset.
+fn at_most<T: Ord>(requested: Option<T>, limit: Option<T>) -> Option<T> {
+    match (requested, limit) {
+        (Some(requested), Some(limit)) => Some(requested.min(limit)),
+        (requested, limit) => requested.or(limit),
+    }
+}
+
+impl Daemon {
+    /// Creates a new daemon around a loaded puzzle generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - A generator whose graph has already been loaded
+    pub fn new(generator: PuzzleGenerator) -> Self {
+        Self {
+            generator: RwLock::new(Arc::new(generator)),
+            requests_served: AtomicU64::new(0),
+            solver: Mutex::new(CachedSolver::new(0)),
+            reloader: None,
+            reload: Mutex::new(ReloadState::default()),
+            max_count: None,
+            max_attempts: None,
+            solve_limits: SolveOptions::default(),
+        }
+    }
+
+    /// Enables the `reload` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `reloader` - Builds a fresh generator, typically by reloading the
+    ///   dictionary and base words files; it runs on a background thread
+    pub fn with_reloader(
+        mut self,
+        reloader: impl Fn() -> Result<PuzzleGenerator> + Send + Sync + 'static,
+    ) -> Self {
+        self.reloader = Some(Arc::new(reloader));
+        self
+    }
+
+    /// Caches the answers of recent `solve` requests.
+    ///
+    /// Hit and miss counts are reported by the `stats` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Most `(start, end)` pairs to keep (0 disables the cache)
+    pub fn with_solve_cache(mut self, capacity: usize) -> Self {
+        self.solver = Mutex::new(CachedSolver::new(capacity));
+        self
+    }
+
+    /// Bounds the work a single `generate` request can cause.
+    ///
+    /// Requests for more than `max_count` puzzles are rejected, and each batch
+    /// gives up after `max_attempts` word pairs, including batches of a
+    /// generator swapped in by a reload.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_count` - Most puzzles per request
+    /// * `max_attempts` - Most word pairs tried per request
+    pub fn with_generate_limits(mut self, max_count: usize, max_attempts: usize) -> Self {
+        self.max_count = Some(max_count);
+        self.max_attempts = Some(max_attempts);
+        let generator = self
+            .generator
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        *generator = Arc::new((**generator).clone().with_max_attempts(max_attempts));
+        self
+    }
+
+    /// Bounds the work a single `solve` request can cause.
+    ///
+    /// A request's `max_visited` and `timeout_ms` are lowered to these limits,
+    /// and a request that omits them gets the limits themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Most words visited and longest search time per request
+    pub fn with_solve_limits(mut self, limits: SolveOptions) -> Self {
+        self.solve_limits = limits;
+        self
+    }
+
+    /// Serves requests until the input stream is closed.
+    ///
+    /// Blank lines are ignored. Each other line produces exactly one response
+    /// line, which is flushed immediately so callers can read it synchronously.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Source of newline-delimited JSON requests
+    /// * `output` - Destination for newline-delimited JSON responses
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on end of input, or an error if reading or writing fails.
+    pub fn serve<R: BufRead, W: Write>(&self, input: R, mut output: W) -> Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = self.handle_line(&line);
+            serde_json::to_writer(&mut output, &response)?;
+            output.write_all(b"\n")?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Parses and dispatches a single request line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A single line of JSON
+    ///
+    /// # Returns
+    ///
+    /// The response to write back, carrying either a result or an error.
+    pub fn handle_line(&self, line: &str) -> Response {
+        self.requests_served.fetch_add(1, Ordering::Relaxed);
+        self.finish_reload();
+
+        let value = match serde_json::from_str::<Value>(line) {
+            Ok(value) => value,
+            Err(e) => {
+                return Response {
+                    id: Value::Null,
+                    result: None,
+                    error: Some(e.to_string()),
+                };
+            }
+        };
+        // An unknown method or bad parameters still answer with the caller's id
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        match serde_json::from_value::<Request>(value)
+            .map_err(anyhow::Error::from)
+            .and_then(|request| self.dispatch(request.call))
+        {
+            Ok(result) => Response {
+                id,
+                result: Some(result),
+                error: None,
+            },
+            Err(e) => Response {
+                id,
+                result: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+
+    /// Swaps in the generator of a background reload once it is ready.
+    fn finish_reload(&self) {
+        let mut state = lock(&self.reload);
+        let Some(result) = state
+            .pending
+            .as_ref()
+            .and_then(|pending| lock(&pending.result).take())
+        else {
+            return;
+        };
+        state.pending = None;
+        match result {
+            Ok(generator) => {
+                let generator = match self.max_attempts {
+                    Some(max_attempts) => generator.with_max_attempts(max_attempts),
+                    None => generator,
+                };
+                *self
+                    .generator
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner) = Arc::new(generator);
+                lock(&self.solver).clear();
+                state.reloads += 1;
+                state.last_error = None;
+            }
+            Err(e) => state.last_error = Some(e.to_string()),
+        }
+    }
+
+    /// Returns the current generator.
+    fn generator(&self) -> Arc<PuzzleGenerator> {
+        Arc::clone(
+            &self
+                .generator
+                .read()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Executes a parsed call against the loaded generator.
+    fn dispatch(&self, call: Call) -> Result<Value> {
+        let generator = self.generator();
+        match call {
+            Call::Solve {
+                start,
+                end,
+                max_visited,
+                timeout_ms,
+            } => {
+                let normalization = generator.graph().normalization();
+                let start = normalization.normalize_query(&start);
+                let end = normalization.normalize_query(&end);
+                let options = SolveOptions {
+                    max_visited: at_most(max_visited, self.solve_limits.max_visited),
+                    timeout: at_most(
+                        timeout_ms.map(Duration::from_millis),
+                        self.solve_limits.timeout,
+                    ),
+                };
+                match lock(&self.solver).find_shortest_path_with(
+                    generator.graph(),
+                    &start,
+                    &end,
+                    &options,
+                ) {
+                    SolveOutcome::Found(path) => {
+                        Ok(json!({ "steps": path.len() - 1, "path": path }))
+                    }
+                    SolveOutcome::NoPath => Err(anyhow::anyhow!(
+                        "No path found between {} and {}",
+                        start,
+                        end
+                    )),
+                    SolveOutcome::BudgetExceeded => Err(anyhow::anyhow!(
+                        "Search budget exceeded between {} and {}",
+                        start,
+                        end
+                    )),
+                }
+            }
+            Call::Verify { puzzle } => {
+                let valid = generator
+                    .verify_puzzle(&puzzle)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                Ok(json!({ "valid": valid }))
+            }
+            Call::Generate { difficulty, count } => {
+                let difficulty: Difficulty = difficulty.parse()?;
+                if let Some(max_count) = self.max_count.filter(|&max| count > max) {
+                    return Err(anyhow::anyhow!(
+                        "count must be at most {}, got {}",
+                        max_count,
+                        count
+                    ));
+                }
+                let puzzles: Vec<Puzzle> = generator.generate_batch(count, difficulty).puzzles;
+                Ok(json!({ "puzzles": puzzles }))
+            }
+            Call::Stats => {
+                let graph = generator.graph();
+                let reload = lock(&self.reload);
+                Ok(json!({
+                    "dictionary_words": graph.get_words().len(),
+                    "base_words": graph.get_base_words().len(),
+                    "memory": graph.memory_footprint(),
+                    "requests_served": self.requests_served.load(Ordering::Relaxed),
+                    "solve_cache": lock(&self.solver).stats(),
+                    "reloads": reload.reloads,
+                    "reload_pending": reload.pending.is_some(),
+                    "last_reload_error": reload.last_error,
+                }))
+            }
+            Call::Reload { wait } => {
+                let Some(reloader) = self.reloader.clone() else {
+                    return Err(anyhow::anyhow!("Reloading is not enabled"));
+                };
+                let pending = Arc::clone(
+                    lock(&self.reload)
+                        .pending
+                        .get_or_insert_with(|| PendingReload::spawn(reloader)),
+                );
+                if !wait {
+                    return Ok(json!({ "reloading": true }));
+                }
+                // Wait without holding any lock, so other requests are still
+                // answered with the old generator meanwhile
+                pending.wait();
+                self.finish_reload();
+                match &lock(&self.reload).last_error {
+                    Some(e) => Err(anyhow::anyhow!("Reload failed: {}", e)),
+                    None => Ok(json!({
+                        "reloaded": true,
+                        "dictionary_words": self.generator().graph().get_words().len(),
+                    })),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::WordGraph;
+
+    fn create_test_daemon() -> Daemon {
+        let graph = WordGraph::from_words(["cat", "dog", "cog", "cot"].map(String::from));
+        Daemon::new(PuzzleGenerator::new(graph))
+    }
+
+    #[test]
+    fn test_serve_answers_each_line() {
+        let daemon = create_test_daemon();
+        let input = "{\"id\": 1, \"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\"}}\n\
+                     \n\
+                     {\"id\": 2, \"method\": \"verify\", \"params\": {\"puzzle\": \"cat,dog\"}}\n\
+                     {\"id\": \"s\", \"method\": \"stats\"}\n";
+        let mut output = Vec::new();
+        daemon.serve(input.as_bytes(), &mut output).unwrap();
+
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["id"], 1);
+        assert_eq!(
+            lines[0]["result"]["path"],
+            json!(["cat", "cot", "cog", "dog"])
+        );
+        assert_eq!(lines[1]["result"]["valid"], false);
+        assert_eq!(lines[2]["id"], "s");
+        assert_eq!(lines[2]["result"]["dictionary_words"], 4);
+        assert_eq!(lines[2]["result"]["requests_served"], 3);
+    }
+
+    #[test]
+    fn test_handle_line_reports_errors() {
+        let daemon = create_test_daemon();
+
+        let malformed = daemon.handle_line("not json");
+        assert!(malformed.result.is_none());
+        assert!(malformed.error.is_some());
+
+        let unknown = daemon.handle_line("{\"id\": 7, \"method\": \"explode\"}");
+        assert_eq!(unknown.id, json!(7));
+        assert!(unknown.error.unwrap().contains("explode"));
+
+        let bad_params =
+            daemon.handle_line("{\"id\": \"a\", \"method\": \"solve\", \"params\": {}}");
+        assert_eq!(bad_params.id, json!("a"));
+        assert!(bad_params.error.is_some());
+
+        let no_path =
+            daemon.handle_line("{\"id\": 8, \"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"zzz\"}}");
+        assert_eq!(no_path.id, json!(8));
+        assert!(no_path.error.unwrap().contains("No path found"));
+
+        let over_budget = daemon.handle_line(
+            "{\"id\": 9, \"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\", \"max_visited\": 2}}",
+        );
+        assert!(over_budget.error.unwrap().contains("budget exceeded"));
+    }
+
+    #[test]
+    fn test_reload_swaps_graph() {
+        let daemon = create_test_daemon().with_reloader(|| {
+            let graph =
+                WordGraph::from_words(["cat", "dog", "cog", "cot", "dot"].map(String::from));
+            Ok(PuzzleGenerator::new(graph))
+        });
+
+        let reloaded = daemon
+            .handle_line("{\"method\": \"reload\", \"params\": {\"wait\": true}}")
+            .result
+            .unwrap();
+        assert_eq!(reloaded["dictionary_words"], 5);
+
+        let stats = daemon
+            .handle_line("{\"method\": \"stats\"}")
+            .result
+            .unwrap();
+        assert_eq!(stats["reloads"], 1);
+        assert_eq!(stats["reload_pending"], false);
+        assert!(
+            create_test_daemon()
+                .handle_line("{\"method\": \"reload\"}")
+                .error
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_waiting_reload_does_not_block_other_requests() {
+        let (release, released) = std::sync::mpsc::channel::<()>();
+        let released = Mutex::new(released);
+        let daemon = create_test_daemon().with_reloader(move || {
+            lock(&released).recv()?;
+            Ok(PuzzleGenerator::new(WordGraph::from_words(
+                ["cat", "cot"].map(String::from),
+            )))
+        });
+
+        std::thread::scope(|scope| {
+            let waiting = scope.spawn(|| {
+                daemon.handle_line("{\"method\": \"reload\", \"params\": {\"wait\": true}}")
+            });
+            while lock(&daemon.reload).pending.is_none() {
+                std::thread::yield_now();
+            }
+
+            // The old graph keeps answering while the reload is waited on
+            let solved = daemon.handle_line(
+                "{\"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\"}}",
+            );
+            assert_eq!(solved.result.unwrap()["steps"], 3);
+            let stats = daemon
+                .handle_line("{\"method\": \"stats\"}")
+                .result
+                .unwrap();
+            assert_eq!(stats["reload_pending"], true);
+
+            release.send(()).unwrap();
+            let reloaded = waiting.join().unwrap().result.unwrap();
+            assert_eq!(reloaded["dictionary_words"], 2);
+        });
+    }
+
+    #[test]
+    fn test_generate_limits() {
+        let daemon = create_test_daemon().with_generate_limits(2, 10);
+
+        let too_many = daemon
+            .handle_line("{\"method\": \"generate\", \"params\": {\"count\": 3}}")
+            .error
+            .unwrap();
+        assert!(too_many.contains("at most 2"));
+        let batch = daemon
+            .handle_line("{\"method\": \"generate\", \"params\": {\"count\": 2}}")
+            .result
+            .unwrap();
+        assert!(batch["puzzles"].as_array().unwrap().len() <= 2);
+    }
+
+    #[test]
+    fn test_solve_limits_cap_client_budget() {
+        let daemon =
+            create_test_daemon().with_solve_limits(SolveOptions::new().with_max_visited(2));
+        let solve = "{\"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\"}}";
+        let generous = "{\"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\", \"max_visited\": 1000}}";
+
+        for line in [solve, generous] {
+            let error = daemon.handle_line(line).error.unwrap();
+            assert!(error.contains("budget exceeded"), "{}", error);
+        }
+        assert_eq!(at_most(Some(5), None), Some(5));
+        assert_eq!(at_most(None, Some(3)), Some(3));
+        assert_eq!(at_most(Some(5), Some(3)), Some(3));
+        assert_eq!(at_most(Some(1), Some(3)), Some(1));
+    }
+
+    #[test]
+    fn test_stats_report_solve_cache() {
+        let daemon = create_test_daemon().with_solve_cache(16);
+        let solve = "{\"method\": \"solve\", \"params\": {\"start\": \"cat\", \"end\": \"dog\"}}";
+        daemon.handle_line(solve);
+        let cached = daemon.handle_line(solve);
+        assert_eq!(cached.result.unwrap()["steps"], 3);
+
+        let stats = daemon
+            .handle_line("{\"method\": \"stats\"}")
+            .result
+            .unwrap();
+        assert_eq!(stats["solve_cache"]["hits"], 1);
+        assert_eq!(stats["solve_cache"]["misses"], 1);
+        assert_eq!(stats["solve_cache"]["capacity"], 16);
+        assert!(stats["memory"]["words"].as_u64().unwrap() > 0);
+        assert!(stats["memory"]["adjacency"].as_u64().unwrap() > 0);
+    }
+}
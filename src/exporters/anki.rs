@@ -0,0 +1,206 @@
+//! # Anki Export Module
+//!
+//! This module exports puzzles as flashcards for Anki, e.g. for vocabulary
+//! practice in a language class. Each puzzle becomes one Basic note: the front
+//! asks for the ladder from the start word to the end word, the back gives the
+//! solution path (and the reverse ladder of mirror puzzles).
+//!
+//! The output is Anki's tab-separated text import format. Header lines select
+//! the separator, HTML fields, the note type, the deck and the tag column, so
+//! File → Import in Anki needs no further settings. Every note is tagged with
+//! `wordladder` and its difficulty.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::anki::AnkiExporter;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let exporter = AnkiExporter::new().with_deck(Some("Word Ladders".to_string()));
+//! let deck = exporter.export_puzzles(&[puzzle]);
+//! assert!(deck.starts_with("#separator:tab\n"));
+//! assert!(deck.contains("cat → dog\tcat → cot → cog → dog\twordladder easy\n"));
+//! ```
+
+use super::graph::escape_xml;
+use super::markdown::difficulty_name;
+use crate::puzzle::Puzzle;
+
+/// Tag added to every exported note.
+const NOTE_TAG: &str = "wordladder";
+
+/// Configuration for Anki export functionality.
+#[derive(Debug, Clone, Default)]
+pub struct AnkiExportConfig {
+    /// Deck the notes are imported into (chosen in Anki's import dialog if
+    /// `None`); `::` separates subdecks
+    pub deck: Option<String>,
+    /// Whether to show the masked ladder (see `Puzzle::to_clue_format`) on
+    /// the front of each card
+    pub include_clues: bool,
+}
+
+/// Exporter writing puzzles as an Anki text import file.
+#[derive(Debug, Default)]
+pub struct AnkiExporter {
+    config: AnkiExportConfig,
+}
+
+impl AnkiExporter {
+    /// Creates a new Anki exporter with default configuration (no deck, no clues).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::anki::AnkiExporter;
+    ///
+    /// let exporter = AnkiExporter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Anki exporter with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration for the exporter
+    pub fn with_config(config: AnkiExportConfig) -> Self {
+        Self { config }
+    }
+
+    /// Sets the deck the notes are imported into.
+    ///
+    /// # Arguments
+    ///
+    /// * `deck` - Deck name, or `None` to pick the deck when importing
+    pub fn with_deck(mut self, deck: Option<String>) -> Self {
+        self.config.deck = deck;
+        self
+    }
+
+    /// Sets whether to show the masked ladder on the front of each card.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_clues` - Whether to export each puzzle's clues
+    pub fn with_include_clues(mut self, include_clues: bool) -> Self {
+        self.config.include_clues = include_clues;
+        self
+    }
+
+    /// Exports puzzles as an Anki text import file, one note per line.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to export
+    ///
+    /// # Returns
+    ///
+    /// The import file, ending with a newline.
+    pub fn export_puzzles(&self, puzzles: &[Puzzle]) -> String {
+        let mut deck = String::from("#separator:tab\n#html:true\n#notetype:Basic\n");
+        if let Some(name) = &self.config.deck {
+            deck.push_str(&format!("#deck:{}\n", single_line(name)));
+        }
+        deck.push_str("#columns:Front\tBack\tTags\n#tags column:3\n");
+
+        for puzzle in puzzles {
+            let mut front = format!(
+                "{} → {}",
+                escape_xml(&puzzle.start),
+                escape_xml(&puzzle.end)
+            );
+            if self.config.include_clues {
+                front.push_str(&format!("<br>{}", ladder_to_html(&puzzle.to_clue_format())));
+            }
+            let mut back = ladder_to_html(&puzzle.path);
+            if let Some(reverse) = &puzzle.reverse_path {
+                back.push_str(&format!("<br>Reverse: {}", ladder_to_html(reverse)));
+            }
+            deck.push_str(&format!(
+                "{}\t{}\t{} {}\n",
+                front,
+                back,
+                NOTE_TAG,
+                difficulty_name(puzzle.difficulty)
+            ));
+        }
+
+        deck
+    }
+}
+
+/// Formats a ladder as an HTML field, e.g. `cat → cot → cog → dog`.
+fn ladder_to_html(words: &[String]) -> String {
+    words
+        .iter()
+        .map(|word| escape_xml(word))
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+/// Replaces the tabs and line breaks that would end a header line.
+fn single_line(text: &str) -> String {
+    text.replace(['\t', '\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_export_puzzles() {
+        let mut mirrored = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        mirrored.reverse_path = Some(
+            ["warm", "ward", "card", "cord", "cold"]
+                .map(String::from)
+                .to_vec(),
+        );
+        let deck = AnkiExporter::new()
+            .with_deck(Some("Ladders\n::Week 1".to_string()))
+            .export_puzzles(&[
+                create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]),
+                mirrored,
+            ]);
+
+        let lines: Vec<&str> = deck.lines().collect();
+        assert_eq!(
+            lines[..6],
+            [
+                "#separator:tab",
+                "#html:true",
+                "#notetype:Basic",
+                "#deck:Ladders ::Week 1",
+                "#columns:Front\tBack\tTags",
+                "#tags column:3",
+            ]
+        );
+        assert_eq!(
+            lines[6],
+            "cold → warm\tcold → cord → card → ward → warm\twordladder medium"
+        );
+        assert_eq!(
+            lines[7],
+            "cold → warm\tcold → cord → card → ward → warm<br>Reverse: \
+             warm → ward → card → cord → cold\twordladder medium"
+        );
+        assert_eq!(lines.len(), 8);
+    }
+
+    #[test]
+    fn test_export_puzzles_with_clues() {
+        let deck = AnkiExporter::new()
+            .with_include_clues(true)
+            .export_puzzles(&[create_test_puzzle(&[
+                "cold", "cord", "card", "ward", "warm",
+            ])]);
+
+        assert!(!deck.contains("#deck:"));
+        assert!(deck.contains("cold → warm<br>cold → co_d → c_rd → _ard → warm\t"));
+    }
+}
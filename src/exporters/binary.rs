@@ -0,0 +1,466 @@
+//! # Compact Binary Export Module
+//!
+//! This module exports puzzles as MessagePack or CBOR arrays, which are much
+//! smaller than JSON for over-the-air puzzle updates. Each puzzle is written
+//! with the same fields as its JSON export (including `clues` if requested),
+//! so clients can decode packs with any MessagePack or CBOR library.
+//!
+//! Exports can be read back with [`import_puzzles`], which is also how
+//! `--append` merges new puzzles into an existing binary file.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::binary::{BinaryExporter, BinaryFormat, import_puzzles};
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let bytes = BinaryExporter::new(BinaryFormat::MessagePack)
+//!     .export_puzzles(std::slice::from_ref(&puzzle))
+//!     .unwrap();
+//! assert!(bytes.len() < puzzle.to_json().unwrap().len());
+//! assert_eq!(import_puzzles(&bytes, BinaryFormat::MessagePack).unwrap(), vec![puzzle]);
+//! ```
+
+use crate::puzzle::Puzzle;
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Value};
+
+/// Deepest nesting of arrays and maps accepted when decoding MessagePack.
+const MAX_DEPTH: usize = 32;
+
+/// Compact binary serialization formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryFormat {
+    /// MessagePack (<https://msgpack.org>)
+    MessagePack,
+    /// CBOR (RFC 8949)
+    Cbor,
+}
+
+/// Exporter writing puzzles as a MessagePack or CBOR array.
+#[derive(Debug)]
+pub struct BinaryExporter {
+    format: BinaryFormat,
+    include_clues: bool,
+}
+
+impl BinaryExporter {
+    /// Creates a new binary exporter without clues.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The binary format to write
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::binary::{BinaryExporter, BinaryFormat};
+    ///
+    /// let exporter = BinaryExporter::new(BinaryFormat::Cbor);
+    /// ```
+    pub fn new(format: BinaryFormat) -> Self {
+        Self {
+            format,
+            include_clues: false,
+        }
+    }
+
+    /// Sets whether to add each puzzle's `clues` array (see `Puzzle::to_clue_format`).
+    ///
+    /// # Arguments
+    ///
+    /// * `include_clues` - Whether to export each puzzle's clues
+    pub fn with_include_clues(mut self, include_clues: bool) -> Self {
+        self.include_clues = include_clues;
+        self
+    }
+
+    /// Exports puzzles as a single binary array.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to export
+    ///
+    /// # Returns
+    ///
+    /// The encoded array, or an error if a puzzle cannot be serialized.
+    pub fn export_puzzles(&self, puzzles: &[Puzzle]) -> Result<Vec<u8>> {
+        self.encode(self.entries(puzzles)?)
+    }
+
+    /// Appends puzzles to an existing binary export.
+    ///
+    /// Existing entries are kept as decoded, so fields such as `clues` survive
+    /// even if this exporter does not include them.
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - Contents of the existing export, in this exporter's format
+    /// * `puzzles` - Puzzles to append
+    ///
+    /// # Returns
+    ///
+    /// The combined array, or an error if `existing` is not an array in this
+    /// exporter's format.
+    pub fn append_puzzles(&self, existing: &[u8], puzzles: &[Puzzle]) -> Result<Vec<u8>> {
+        let mut entries = decode_entries(existing, self.format)?;
+        entries.extend(self.entries(puzzles)?);
+        self.encode(entries)
+    }
+
+    /// Serializes puzzles into the JSON values written for each array entry.
+    fn entries(&self, puzzles: &[Puzzle]) -> Result<Vec<Value>> {
+        puzzles
+            .iter()
+            .map(|puzzle| {
+                let mut value = serde_json::to_value(puzzle)?;
+                if self.include_clues {
+                    value["clues"] = serde_json::to_value(puzzle.to_clue_format())?;
+                }
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Encodes array entries in this exporter's format.
+    fn encode(&self, entries: Vec<Value>) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        match self.format {
+            BinaryFormat::MessagePack => write_msgpack(&mut buf, &Value::Array(entries)),
+            BinaryFormat::Cbor => ciborium::into_writer(&entries, &mut buf)?,
+        }
+        Ok(buf)
+    }
+}
+
+/// Reads the puzzles of a binary export produced by `BinaryExporter`.
+///
+/// Extra fields such as `clues` are ignored.
+///
+/// # Arguments
+///
+/// * `bytes` - Contents of the binary export
+/// * `format` - The format the export was written in
+///
+/// # Returns
+///
+/// The puzzles in export order, or an error if the bytes are not an array of
+/// puzzles in the given format.
+pub fn import_puzzles(bytes: &[u8], format: BinaryFormat) -> Result<Vec<Puzzle>> {
+    decode_entries(bytes, format)?
+        .into_iter()
+        .map(|entry| Ok(serde_json::from_value(entry)?))
+        .collect()
+}
+
+/// Decodes a binary export into its array entries.
+fn decode_entries(bytes: &[u8], format: BinaryFormat) -> Result<Vec<Value>> {
+    let value = match format {
+        BinaryFormat::MessagePack => {
+            let mut reader = MsgpackReader { bytes, pos: 0 };
+            let value = reader.read_value(0)?;
+            if reader.pos != bytes.len() {
+                bail!("Trailing bytes after MessagePack array");
+            }
+            value
+        }
+        BinaryFormat::Cbor => ciborium::from_reader(bytes)?,
+    };
+    match value {
+        Value::Array(entries) => Ok(entries),
+        _ => bail!("Binary export is not an array"),
+    }
+}
+
+/// Writes a JSON value as MessagePack, using the smallest encoding for each item.
+fn write_msgpack(buf: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => buf.push(0xc0),
+        Value::Bool(b) => buf.push(if *b { 0xc3 } else { 0xc2 }),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                match n {
+                    0..=0x7f => buf.push(n as u8),
+                    0x80..=0xff => buf.extend([0xcc, n as u8]),
+                    0x100..=0xffff => {
+                        buf.push(0xcd);
+                        buf.extend((n as u16).to_be_bytes());
+                    }
+                    0x1_0000..=0xffff_ffff => {
+                        buf.push(0xce);
+                        buf.extend((n as u32).to_be_bytes());
+                    }
+                    _ => {
+                        buf.push(0xcf);
+                        buf.extend(n.to_be_bytes());
+                    }
+                }
+            } else if let Some(n) = n.as_i64() {
+                if n >= -32 {
+                    buf.push(n as u8);
+                } else {
+                    buf.push(0xd3);
+                    buf.extend(n.to_be_bytes());
+                }
+            } else if let Some(n) = n.as_f64() {
+                buf.push(0xcb);
+                buf.extend(n.to_be_bytes());
+            }
+        }
+        Value::String(s) => {
+            write_msgpack_len(buf, s.len(), 0xa0, 32, [0xd9, 0xda, 0xdb]);
+            buf.extend(s.as_bytes());
+        }
+        Value::Array(items) => {
+            write_msgpack_len(buf, items.len(), 0x90, 16, [0, 0xdc, 0xdd]);
+            for item in items {
+                write_msgpack(buf, item);
+            }
+        }
+        Value::Object(map) => {
+            write_msgpack_len(buf, map.len(), 0x80, 16, [0, 0xde, 0xdf]);
+            for (key, item) in map {
+                write_msgpack(buf, &Value::String(key.clone()));
+                write_msgpack(buf, item);
+            }
+        }
+    }
+}
+
+/// Writes a MessagePack length header: the `fix` marker for lengths below
+/// `fix_limit`, else the 8-, 16-, or 32-bit marker (0 if there is no 8-bit form).
+fn write_msgpack_len(buf: &mut Vec<u8>, len: usize, fix: u8, fix_limit: usize, markers: [u8; 3]) {
+    if len < fix_limit {
+        buf.push(fix | len as u8);
+    } else if len <= 0xff && markers[0] != 0 {
+        buf.extend([markers[0], len as u8]);
+    } else if len <= 0xffff {
+        buf.push(markers[1]);
+        buf.extend((len as u16).to_be_bytes());
+    } else {
+        buf.push(markers[2]);
+        buf.extend((len as u32).to_be_bytes());
+    }
+}
+
+/// Cursor decoding MessagePack into JSON values.
+struct MsgpackReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MsgpackReader<'a> {
+    /// Reads the next `len` bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| anyhow!("Truncated MessagePack data"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Reads a big-endian unsigned integer of `width` bytes.
+    fn read_uint(&mut self, width: usize) -> Result<u64> {
+        Ok(self
+            .take(width)?
+            .iter()
+            .fold(0, |n, &byte| (n << 8) | u64::from(byte)))
+    }
+
+    /// Reads one value, with `depth` enclosing arrays and maps.
+    fn read_value(&mut self, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            bail!("MessagePack data is nested too deeply");
+        }
+        let marker = self.take(1)?[0];
+        Ok(match marker {
+            0x00..=0x7f => Value::from(marker),
+            0x80..=0x8f => self.read_map(usize::from(marker & 0x0f), depth)?,
+            0x90..=0x9f => self.read_array(usize::from(marker & 0x0f), depth)?,
+            0xa0..=0xbf => self.read_string(usize::from(marker & 0x1f))?,
+            0xc0 => Value::Null,
+            0xc2 => Value::Bool(false),
+            0xc3 => Value::Bool(true),
+            0xca => Value::from(f32::from_bits(self.read_uint(4)? as u32)),
+            0xcb => Value::from(f64::from_bits(self.read_uint(8)?)),
+            0xcc => Value::from(self.read_uint(1)?),
+            0xcd => Value::from(self.read_uint(2)?),
+            0xce => Value::from(self.read_uint(4)?),
+            0xcf => Value::from(self.read_uint(8)?),
+            0xd0 => Value::from(self.read_uint(1)? as u8 as i8),
+            0xd1 => Value::from(self.read_uint(2)? as u16 as i16),
+            0xd2 => Value::from(self.read_uint(4)? as u32 as i32),
+            0xd3 => Value::from(self.read_uint(8)? as i64),
+            0xd9 => {
+                let len = self.read_uint(1)? as usize;
+                self.read_string(len)?
+            }
+            0xda => {
+                let len = self.read_uint(2)? as usize;
+                self.read_string(len)?
+            }
+            0xdb => {
+                let len = self.read_uint(4)? as usize;
+                self.read_string(len)?
+            }
+            0xdc => {
+                let len = self.read_uint(2)? as usize;
+                self.read_array(len, depth)?
+            }
+            0xdd => {
+                let len = self.read_uint(4)? as usize;
+                self.read_array(len, depth)?
+            }
+            0xde => {
+                let len = self.read_uint(2)? as usize;
+                self.read_map(len, depth)?
+            }
+            0xdf => {
+                let len = self.read_uint(4)? as usize;
+                self.read_map(len, depth)?
+            }
+            0xe0..=0xff => Value::from(marker as i8),
+            other => bail!("Unsupported MessagePack type 0x{:02x}", other),
+        })
+    }
+
+    /// Reads a UTF-8 string of `len` bytes.
+    fn read_string(&mut self, len: usize) -> Result<Value> {
+        Ok(Value::String(String::from_utf8(self.take(len)?.to_vec())?))
+    }
+
+    /// Reads an array of `len` values.
+    fn read_array(&mut self, len: usize, depth: usize) -> Result<Value> {
+        let mut items = Vec::with_capacity(len.min(self.bytes.len() - self.pos));
+        for _ in 0..len {
+            items.push(self.read_value(depth + 1)?);
+        }
+        Ok(Value::Array(items))
+    }
+
+    /// Reads a map of `len` string keys and values.
+    fn read_map(&mut self, len: usize, depth: usize) -> Result<Value> {
+        let mut map = Map::new();
+        for _ in 0..len {
+            let Value::String(key) = self.read_value(depth + 1)? else {
+                bail!("MessagePack map key is not a string");
+            };
+            map.insert(key, self.read_value(depth + 1)?);
+        }
+        Ok(Value::Object(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::PuzzleMeta;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_round_trip() {
+        let mut annotated = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        annotated.unique_solution = Some(false);
+        annotated.reverse_path = Some(
+            ["warm", "worm", "word", "cord", "cold"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        );
+        annotated.meta = Some(PuzzleMeta {
+            created_at: 1_700_000_000,
+            engine_version: "0.1.0".to_string(),
+            dictionary_sha256: "ab".repeat(32),
+            seed: Some(u64::MAX),
+        });
+        let puzzles = vec![create_test_puzzle(&["cat", "cot", "cog", "dog"]), annotated];
+
+        for (format, empty_array) in [
+            (BinaryFormat::MessagePack, 0x90),
+            (BinaryFormat::Cbor, 0x80),
+        ] {
+            let bytes = BinaryExporter::new(format)
+                .with_include_clues(true)
+                .export_puzzles(&puzzles)
+                .unwrap();
+            assert_eq!(import_puzzles(&bytes, format).unwrap(), puzzles);
+
+            let empty = BinaryExporter::new(format).export_puzzles(&[]).unwrap();
+            assert_eq!(empty, vec![empty_array]);
+            assert!(import_puzzles(&empty, format).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_append_keeps_existing_entries() {
+        let first = create_test_puzzle(&["cat", "cot", "cog", "dog"]);
+        let second = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        for format in [BinaryFormat::MessagePack, BinaryFormat::Cbor] {
+            let existing = BinaryExporter::new(format)
+                .with_include_clues(true)
+                .export_puzzles(std::slice::from_ref(&first))
+                .unwrap();
+            let combined = BinaryExporter::new(format)
+                .append_puzzles(&existing, std::slice::from_ref(&second))
+                .unwrap();
+            assert_eq!(
+                import_puzzles(&combined, format).unwrap(),
+                vec![first.clone(), second.clone()]
+            );
+
+            let entries = decode_entries(&combined, format).unwrap();
+            assert!(entries[0].get("clues").is_some());
+            assert!(entries[1].get("clues").is_none());
+        }
+    }
+
+    #[test]
+    fn test_msgpack_encoding() {
+        let mut buf = Vec::new();
+        write_msgpack(
+            &mut buf,
+            &serde_json::json!([0, 127, 128, 65536, -1, -33, true, null, "cat"]),
+        );
+        assert_eq!(
+            buf,
+            vec![
+                0x99, 0x00, 0x7f, 0xcc, 0x80, 0xce, 0x00, 0x01, 0x00, 0x00, 0xff, 0xd3, 0xff, 0xff,
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xdf, 0xc3, 0xc0, 0xa3, b'c', b'a', b't'
+            ]
+        );
+
+        let long = "x".repeat(40);
+        buf.clear();
+        write_msgpack(&mut buf, &Value::String(long.clone()));
+        assert_eq!(&buf[..2], &[0xd9, 40]);
+        let mut reader = MsgpackReader {
+            bytes: &buf,
+            pos: 0,
+        };
+        assert_eq!(reader.read_value(0).unwrap(), Value::String(long));
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let bytes = BinaryExporter::new(BinaryFormat::MessagePack)
+            .export_puzzles(&[create_test_puzzle(&["cat", "cot", "cog", "dog"])])
+            .unwrap();
+        assert!(import_puzzles(&bytes[..bytes.len() - 1], BinaryFormat::MessagePack).is_err());
+        assert!(
+            import_puzzles(
+                &[bytes.as_slice(), &[0xc0]].concat(),
+                BinaryFormat::MessagePack
+            )
+            .is_err()
+        );
+        assert!(import_puzzles(&[0x91, 0xc4], BinaryFormat::MessagePack).is_err());
+        assert!(import_puzzles(&[0x91; 64], BinaryFormat::MessagePack).is_err());
+        assert!(import_puzzles(&bytes, BinaryFormat::Cbor).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! # Solution Encryption Module
+//!
+//! An alternative to omitting solutions: the SQL exporter can write each
+//! ladder XChaCha20-encrypted to an `encrypted_solution` column, so a client
+//! holding the key can check a player's final answer offline while the pack
+//! itself does not reveal any ladder to someone reading the database.
+//!
+//! Every puzzle is encrypted under its own key and nonce, derived from a
+//! 32-byte master key and the puzzle ID with keyed BLAKE3:
+//!
+//! 1. Hash the puzzle ID with `blake3::Hasher::new_keyed(master_key)`
+//! 2. Read 56 bytes of extendable output: the first 32 are the XChaCha20
+//!    key, the next 24 the nonce
+//! 3. XOR the comma-separated ladder (as in the `solution` column) with the
+//!    keystream and write the result as lowercase hex
+//!
+//! The cipher is unauthenticated: decrypting with the wrong key or puzzle ID
+//! yields garbage rather than an error, so clients should compare the
+//! decrypted ladder's endpoints with the puzzle's before trusting it. Since
+//! the keystream depends only on the ID, use content-hash IDs (or a fresh
+//! master key) when a pack may reuse a counter ID for a different ladder.
+//!
+//! Master keys are stored as 64 hex characters, e.g. from `openssl rand -hex 32`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::encryption::{SolutionKey, decrypt_solution, encrypt_solution};
+//!
+//! let key = SolutionKey::from_hex(&"2a".repeat(32)).unwrap();
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//!
+//! let ciphertext = encrypt_solution(&path, "cat_dog_001", &key);
+//! assert!(!ciphertext.contains("cot"));
+//! assert_eq!(decrypt_solution(&ciphertext, "cat_dog_001", &key).unwrap(), path);
+//! ```
+
+use super::hex;
+use anyhow::{Result, anyhow};
+use chacha20::XChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use std::fmt;
+
+/// A 32-byte master key from which per-puzzle keys are derived.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SolutionKey([u8; 32]);
+
+impl SolutionKey {
+    /// Creates a key from its raw bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Parses a key from hex.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 64 hex characters; surrounding whitespace is ignored
+    pub fn from_hex(key: &str) -> Result<Self> {
+        Ok(Self(hex::decode_array(key.trim())?))
+    }
+
+    /// Returns the XChaCha20 cipher for one puzzle.
+    fn cipher_for(&self, puzzle_id: &str) -> XChaCha20 {
+        let mut output = [0u8; 56];
+        blake3::Hasher::new_keyed(&self.0)
+            .update(puzzle_id.as_bytes())
+            .finalize_xof()
+            .fill(&mut output);
+        let (key, nonce) = output.split_at(32);
+        XChaCha20::new(key.into(), nonce.into())
+    }
+}
+
+impl fmt::Debug for SolutionKey {
+    // Keeps the key out of logs
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SolutionKey(..)")
+    }
+}
+
+/// Encrypts a puzzle's ladder under the key derived for its ID.
+///
+/// # Arguments
+///
+/// * `path` - The ladder, start word to end word
+/// * `puzzle_id` - ID of the puzzle, as written to the export
+/// * `key` - The master key
+///
+/// # Returns
+///
+/// The ciphertext as lowercase hex, twice as long as the comma-separated ladder.
+pub fn encrypt_solution(path: &[String], puzzle_id: &str, key: &SolutionKey) -> String {
+    let mut bytes = path.join(",").into_bytes();
+    key.cipher_for(puzzle_id).apply_keystream(&mut bytes);
+    hex::encode(&bytes)
+}
+
+/// Decrypts a ladder written by `encrypt_solution`.
+///
+/// # Arguments
+///
+/// * `ciphertext` - The hex ciphertext
+/// * `puzzle_id` - ID of the puzzle the ciphertext belongs to
+/// * `key` - The master key
+///
+/// # Returns
+///
+/// The ladder, or an error if the hex is malformed or the result is not
+/// UTF-8 (usually a sign of the wrong key or ID).
+pub fn decrypt_solution(
+    ciphertext: &str,
+    puzzle_id: &str,
+    key: &SolutionKey,
+) -> Result<Vec<String>> {
+    let mut bytes = hex::decode(ciphertext.trim())?;
+    key.cipher_for(puzzle_id).apply_keystream(&mut bytes);
+    let plaintext = String::from_utf8(bytes)
+        .map_err(|_| anyhow!("Decrypted solution is not UTF-8; wrong key or puzzle ID?"))?;
+    Ok(plaintext.split(',').map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let key = SolutionKey::from_hex(&format!("{}\n", "01".repeat(32))).unwrap();
+        let path: Vec<String> = ["cold", "cord", "card", "ward", "warm"]
+            .map(String::from)
+            .to_vec();
+
+        let ciphertext = encrypt_solution(&path, "cold_warm_001", &key);
+        assert_eq!(ciphertext.len(), 2 * "cold,cord,card,ward,warm".len());
+        assert_eq!(ciphertext, encrypt_solution(&path, "cold_warm_001", &key));
+        assert_ne!(ciphertext, encrypt_solution(&path, "cold_warm_002", &key));
+        assert_eq!(
+            decrypt_solution(&ciphertext, "cold_warm_001", &key).unwrap(),
+            path
+        );
+
+        let other = SolutionKey::from_bytes([2; 32]);
+        assert_ne!(
+            decrypt_solution(&ciphertext, "cold_warm_001", &other).ok(),
+            Some(path)
+        );
+        assert!(decrypt_solution("abc", "cold_warm_001", &key).is_err());
+    }
+
+    #[test]
+    fn test_key_hex_errors() {
+        assert!(SolutionKey::from_hex("abc").is_err());
+        assert!(SolutionKey::from_hex(&"zz".repeat(32)).is_err());
+        assert!(SolutionKey::from_hex(&"01".repeat(31)).is_err());
+        assert_eq!(
+            format!("{:?}", SolutionKey::from_bytes([7; 32])),
+            "SolutionKey(..)"
+        );
+    }
+}
@@ -0,0 +1,599 @@
+//! # Graph Export Module
+//!
+//! This module exports the word graph itself (its adjacency structure) for
+//! external analysis and visualization tools such as Gephi or Graphviz.
+//!
+//! ## Supported Formats
+//!
+//! - **DOT**: Graphviz undirected graph description
+//! - **GraphML**: XML format understood by Gephi, yEd, and NetworkX
+//! - **CSV**: Plain `source,target` edge list
+//! - **SVG**: A drawing of a puzzle's neighborhood (see below)
+//!
+//! Each undirected edge is emitted exactly once and output is sorted so
+//! repeated exports of the same dictionary produce identical files.
+//!
+//! ## Puzzle Neighborhoods
+//!
+//! Instead of the whole graph, an export can cover the words around a
+//! ladder: `with_neighborhood` runs a layered BFS from every word on the path
+//! and keeps the words within `radius` steps of it. DOT exports then
+//! highlight the path's words and steps, and SVG exports draw the path as the
+//! top row with each further BFS layer in a row below it, every word placed
+//! under the path word it was reached from.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::graph::{GraphExporter, GraphFormat};
+//! use wordladder_engine::graph::WordGraph;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt").unwrap();
+//!
+//! let exporter = GraphExporter::new()
+//!     .with_format(GraphFormat::Dot)
+//!     .with_word_length(Some(3));
+//! let dot = exporter.export(&graph).unwrap();
+//! ```
+
+use crate::graph::WordGraph;
+use anyhow::{Result, anyhow};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+/// Horizontal distance between the centers of neighboring SVG nodes.
+const SVG_NODE_SPACING: usize = 100;
+/// Vertical distance between SVG rows (BFS layers).
+const SVG_LAYER_SPACING: usize = 90;
+/// Empty border around an SVG drawing.
+const SVG_MARGIN: usize = 40;
+/// Fill color of the path's words in DOT and SVG exports.
+const PATH_NODE_COLOR: &str = "#ffd54f";
+/// Color of the path's steps in DOT and SVG exports.
+const PATH_EDGE_COLOR: &str = "#e65100";
+
+/// Output format for graph exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT format
+    Dot,
+    /// GraphML XML format
+    GraphMl,
+    /// Edge-list CSV with a `source,target` header
+    Csv,
+    /// SVG drawing of a neighborhood; requires `with_neighborhood`
+    Svg,
+}
+
+impl GraphFormat {
+    /// Returns the conventional file extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::GraphMl => "graphml",
+            GraphFormat::Csv => "csv",
+            GraphFormat::Svg => "svg",
+        }
+    }
+}
+
+/// Configuration for graph export functionality.
+#[derive(Debug, Clone)]
+pub struct GraphExportConfig {
+    /// Output format to produce
+    pub format: GraphFormat,
+    /// Restrict the export to words of this length (all lengths if `None`)
+    pub word_length: Option<usize>,
+    /// Export only the words around a ladder instead of the whole graph;
+    /// `word_length` is ignored when set
+    pub neighborhood: Option<Neighborhood>,
+}
+
+impl Default for GraphExportConfig {
+    fn default() -> Self {
+        Self {
+            format: GraphFormat::Dot,
+            word_length: None,
+            neighborhood: None,
+        }
+    }
+}
+
+/// The ladder at the center of a neighborhood export.
+#[derive(Debug, Clone)]
+pub struct Neighborhood {
+    /// The ladder, start word to end word
+    pub path: Vec<String>,
+    /// Most steps a word may be from the nearest word of the ladder
+    pub radius: usize,
+}
+
+/// Exporter for the adjacency structure of a `WordGraph`.
+#[derive(Debug, Default)]
+pub struct GraphExporter {
+    config: GraphExportConfig,
+}
+
+impl GraphExporter {
+    /// Creates a new graph exporter with default configuration (DOT, all lengths).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::graph::GraphExporter;
+    ///
+    /// let exporter = GraphExporter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new graph exporter with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration for the exporter
+    pub fn with_config(config: GraphExportConfig) -> Self {
+        Self { config }
+    }
+
+    /// Sets the output format.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format to export
+    pub fn with_format(mut self, format: GraphFormat) -> Self {
+        self.config.format = format;
+        self
+    }
+
+    /// Restricts the export to words of a single length.
+    ///
+    /// # Arguments
+    ///
+    /// * `word_length` - Word length to keep, or `None` for all lengths
+    pub fn with_word_length(mut self, word_length: Option<usize>) -> Self {
+        self.config.word_length = word_length;
+        self
+    }
+
+    /// Restricts the export to the words around a ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The ladder, start word to end word
+    /// * `radius` - Most steps a word may be from the ladder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::graph::{GraphExporter, GraphFormat};
+    /// use wordladder_engine::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::from_words(["cat", "cot", "cog", "dog", "bat", "bag"].map(String::from));
+    /// let path = graph.find_shortest_path("cot", "dog").unwrap();
+    ///
+    /// let csv = GraphExporter::new()
+    ///     .with_format(GraphFormat::Csv)
+    ///     .with_neighborhood(path, 1)
+    ///     .export(&graph)
+    ///     .unwrap();
+    /// assert_eq!(csv, "source,target\ncat,cot\ncog,cot\ncog,dog\n");
+    /// ```
+    pub fn with_neighborhood(mut self, path: Vec<String>, radius: usize) -> Self {
+        self.config.neighborhood = Some(Neighborhood { path, radius });
+        self
+    }
+
+    /// Exports the graph in the configured format.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The word graph to export
+    ///
+    /// # Returns
+    ///
+    /// A string containing the complete graph document, or an error for an
+    /// SVG export without a neighborhood.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::graph::{GraphExporter, GraphFormat};
+    /// use wordladder_engine::graph::WordGraph;
+    ///
+    /// let graph = WordGraph::new();
+    /// let csv = GraphExporter::new()
+    ///     .with_format(GraphFormat::Csv)
+    ///     .export(&graph)
+    ///     .unwrap();
+    /// assert_eq!(csv, "source,target\n");
+    /// ```
+    pub fn export(&self, graph: &WordGraph) -> Result<String> {
+        let layers = self
+            .config
+            .neighborhood
+            .as_ref()
+            .map(|neighborhood| neighborhood_layers(graph, neighborhood));
+        let nodes = match &layers {
+            Some(layers) => {
+                let mut nodes: Vec<&str> = layers.iter().flatten().copied().collect();
+                nodes.sort();
+                nodes
+            }
+            None => self.collect_nodes(graph),
+        };
+        let edges = self.collect_edges(graph, &nodes);
+        let path = self
+            .config
+            .neighborhood
+            .as_ref()
+            .map_or(&[][..], |neighborhood| &neighborhood.path[..]);
+
+        Ok(match self.config.format {
+            GraphFormat::Dot => self.to_dot(&nodes, &edges, path),
+            GraphFormat::GraphMl => self.to_graphml(&nodes, &edges),
+            GraphFormat::Csv => self.to_csv(&edges),
+            GraphFormat::Svg => {
+                let layers = layers
+                    .ok_or_else(|| anyhow!("SVG graph exports need a neighborhood to draw"))?;
+                self.to_svg(&layers, &edges, path)
+            }
+        })
+    }
+
+    /// Collects the sorted list of words included in the export.
+    fn collect_nodes<'a>(&self, graph: &'a WordGraph) -> Vec<&'a str> {
+        let mut nodes: Vec<&str> = graph
+            .get_words()
+            .iter()
+            .filter(|word| {
+                self.config
+                    .word_length
+                    .is_none_or(|len| word.chars().count() == len)
+            })
+            .map(String::as_str)
+            .collect();
+        nodes.sort();
+        nodes
+    }
+
+    /// Collects each undirected edge between exported words once, as a sorted
+    /// `(smaller, larger)` pair.
+    fn collect_edges<'a>(
+        &self,
+        graph: &'a WordGraph,
+        nodes: &[&'a str],
+    ) -> Vec<(&'a str, &'a str)> {
+        let included: HashSet<&str> = nodes.iter().copied().collect();
+        let mut edges = Vec::new();
+        for &word in nodes {
+            if let Some(neighbors) = graph.get_neighbors(word) {
+                for neighbor in neighbors {
+                    if word < neighbor && included.contains(neighbor) {
+                        edges.push((word, neighbor));
+                    }
+                }
+            }
+        }
+        edges.sort();
+        edges
+    }
+
+    /// Renders nodes and edges as a Graphviz DOT document, highlighting the
+    /// words and steps of `path`.
+    fn to_dot(&self, nodes: &[&str], edges: &[(&str, &str)], path: &[String]) -> String {
+        let path_nodes: HashSet<&str> = path.iter().map(String::as_str).collect();
+        let path_edges = path_edges(path);
+
+        let mut dot = String::from("graph wordladder {\n");
+        for node in nodes {
+            if path_nodes.contains(node) {
+                dot.push_str(&format!(
+                    "\t\"{}\" [style=filled, fillcolor=\"{}\"];\n",
+                    escape_dot(node),
+                    PATH_NODE_COLOR
+                ));
+            } else {
+                dot.push_str(&format!("\t\"{}\";\n", escape_dot(node)));
+            }
+        }
+        for edge in edges {
+            let (source, target) = edge;
+            if path_edges.contains(edge) {
+                dot.push_str(&format!(
+                    "\t\"{}\" -- \"{}\" [color=\"{}\", penwidth=3];\n",
+                    escape_dot(source),
+                    escape_dot(target),
+                    PATH_EDGE_COLOR
+                ));
+            } else {
+                dot.push_str(&format!(
+                    "\t\"{}\" -- \"{}\";\n",
+                    escape_dot(source),
+                    escape_dot(target)
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders nodes and edges as a GraphML document.
+    fn to_graphml(&self, nodes: &[&str], edges: &[(&str, &str)]) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+             \t<key id=\"length\" for=\"node\" attr.name=\"length\" attr.type=\"int\"/>\n\
+             \t<graph id=\"wordladder\" edgedefault=\"undirected\">\n",
+        );
+        for node in nodes {
+            xml.push_str(&format!(
+                "\t\t<node id=\"{}\"><data key=\"length\">{}</data></node>\n",
+                escape_xml(node),
+                node.chars().count()
+            ));
+        }
+        for (source, target) in edges {
+            xml.push_str(&format!(
+                "\t\t<edge source=\"{}\" target=\"{}\"/>\n",
+                escape_xml(source),
+                escape_xml(target)
+            ));
+        }
+        xml.push_str("\t</graph>\n</graphml>\n");
+        xml
+    }
+
+    /// Renders edges as a `source,target` CSV edge list.
+    fn to_csv(&self, edges: &[(&str, &str)]) -> String {
+        let mut csv = String::from("source,target\n");
+        for (source, target) in edges {
+            csv.push_str(&format!("{},{}\n", source, target));
+        }
+        csv
+    }
+
+    /// Draws a neighborhood as an SVG image, one row per BFS layer with the
+    /// path on top.
+    fn to_svg(&self, layers: &[Vec<&str>], edges: &[(&str, &str)], path: &[String]) -> String {
+        let columns = layers.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        let width = 2 * SVG_MARGIN + columns * SVG_NODE_SPACING;
+        let height = 2 * SVG_MARGIN + layers.len().saturating_sub(1) * SVG_LAYER_SPACING;
+
+        // Center each row horizontally
+        let mut positions: HashMap<&str, (usize, usize)> = HashMap::new();
+        for (layer, words) in layers.iter().enumerate() {
+            let offset = SVG_MARGIN + (columns - words.len()) * SVG_NODE_SPACING / 2;
+            for (i, word) in words.iter().enumerate() {
+                let x = offset + i * SVG_NODE_SPACING + SVG_NODE_SPACING / 2;
+                let y = SVG_MARGIN + layer * SVG_LAYER_SPACING;
+                positions.insert(word, (x, y));
+            }
+        }
+
+        let path_nodes: HashSet<&str> = path.iter().map(String::as_str).collect();
+        let path_edges = path_edges(path);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+             viewBox=\"0 0 {0} {1}\" font-family=\"monospace\" font-size=\"14\">\n\
+             \t<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+            width, height
+        );
+        // Path steps are drawn last so no other edge covers them
+        let (highlighted, others): (Vec<_>, Vec<_>) =
+            edges.iter().partition(|edge| path_edges.contains(*edge));
+        for (source, target) in others {
+            let ((x1, y1), (x2, y2)) = (positions[source], positions[target]);
+            svg.push_str(&format!(
+                "\t<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#9e9e9e\"/>\n",
+                x1, y1, x2, y2
+            ));
+        }
+        for (source, target) in highlighted {
+            let ((x1, y1), (x2, y2)) = (positions[source], positions[target]);
+            svg.push_str(&format!(
+                "\t<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"4\"/>\n",
+                x1, y1, x2, y2, PATH_EDGE_COLOR
+            ));
+        }
+        for word in layers.iter().flatten() {
+            let (x, y) = positions[word];
+            let box_width = 10 * word.chars().count() + 16;
+            let fill = if path_nodes.contains(word) {
+                PATH_NODE_COLOR
+            } else {
+                "white"
+            };
+            svg.push_str(&format!(
+                "\t<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"24\" rx=\"6\" fill=\"{}\" stroke=\"#424242\"/>\n\
+                 \t<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+                x - box_width / 2,
+                y - 12,
+                box_width,
+                fill,
+                x,
+                y + 5,
+                escape_xml(word)
+            ));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Groups the words around a ladder by their distance from it, with a
+/// layered BFS started from every word of the path at once.
+///
+/// Layer 0 holds the path's words in ladder order. Every later layer is
+/// sorted by the position on the path of the word it was reached from, then
+/// alphabetically. Path words missing from the dictionary are skipped.
+fn neighborhood_layers<'a>(graph: &'a WordGraph, neighborhood: &Neighborhood) -> Vec<Vec<&'a str>> {
+    let mut anchors: HashMap<&str, usize> = HashMap::new();
+    let mut layer: Vec<&str> = Vec::new();
+    for (i, word) in neighborhood.path.iter().enumerate() {
+        if let Some(word) = graph.get_words().get(word)
+            && let Entry::Vacant(entry) = anchors.entry(word)
+        {
+            entry.insert(i);
+            layer.push(word);
+        }
+    }
+
+    let mut layers = Vec::new();
+    while !layer.is_empty() && layers.len() <= neighborhood.radius {
+        let mut next: Vec<&str> = Vec::new();
+        for &word in &layer {
+            let anchor = anchors[word];
+            let mut neighbors: Vec<&str> = graph
+                .get_neighbors(word)
+                .map(Iterator::collect)
+                .unwrap_or_default();
+            neighbors.sort_unstable();
+            for neighbor in neighbors {
+                if let Entry::Vacant(entry) = anchors.entry(neighbor) {
+                    entry.insert(anchor);
+                    next.push(neighbor);
+                }
+            }
+        }
+        next.sort_by_key(|word| (anchors[word], *word));
+        layers.push(layer);
+        layer = next;
+    }
+    layers
+}
+
+/// Returns the steps of a ladder as sorted `(smaller, larger)` pairs, matching
+/// the edges of an export.
+fn path_edges(path: &[String]) -> HashSet<(&str, &str)> {
+    path.windows(2)
+        .map(|step| {
+            let (a, b) = (step[0].as_str(), step[1].as_str());
+            if a < b { (a, b) } else { (b, a) }
+        })
+        .collect()
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes the XML special characters in a string.
+pub(super) fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_graph() -> WordGraph {
+        WordGraph::from_words(["cat", "cot", "cog", "dog", "cart", "card"].map(String::from))
+    }
+
+    #[test]
+    fn test_export_csv_emits_each_edge_once() {
+        let graph = create_test_graph();
+        let csv = GraphExporter::new()
+            .with_format(GraphFormat::Csv)
+            .export(&graph)
+            .unwrap();
+
+        assert_eq!(csv, "source,target\ncard,cart\ncat,cot\ncog,cot\ncog,dog\n");
+    }
+
+    #[test]
+    fn test_export_dot_restricted_to_length() {
+        let graph = create_test_graph();
+        let dot = GraphExporter::new()
+            .with_format(GraphFormat::Dot)
+            .with_word_length(Some(4))
+            .export(&graph)
+            .unwrap();
+
+        assert!(dot.starts_with("graph wordladder {"));
+        assert!(dot.contains("\"card\" -- \"cart\";"));
+        assert!(!dot.contains("\"cat\""));
+    }
+
+    #[test]
+    fn test_export_graphml() {
+        let graph = create_test_graph();
+        let xml = GraphExporter::new()
+            .with_format(GraphFormat::GraphMl)
+            .export(&graph)
+            .unwrap();
+
+        assert!(xml.contains("<node id=\"cat\"><data key=\"length\">3</data></node>"));
+        assert!(xml.contains("<edge source=\"cat\" target=\"cot\"/>"));
+        assert!(xml.trim_end().ends_with("</graphml>"));
+
+        // Lengths count characters, not bytes
+        let graph = WordGraph::from_words(["café", "cafés"].map(String::from));
+        let xml = GraphExporter::new()
+            .with_format(GraphFormat::GraphMl)
+            .with_word_length(Some(4))
+            .export(&graph)
+            .unwrap();
+        assert!(xml.contains("<node id=\"café\"><data key=\"length\">4</data></node>"));
+        assert!(!xml.contains("cafés"));
+    }
+
+    #[test]
+    fn test_escape_dot() {
+        assert_eq!(escape_dot("o'clock"), "o'clock");
+        assert_eq!(escape_dot("say \"hi\""), "say \\\"hi\\\"");
+        assert_eq!(escape_dot("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn test_export_neighborhood() {
+        let graph = WordGraph::from_words(
+            ["cat", "cot", "cog", "dog", "bat", "bag", "big", "dig"].map(String::from),
+        );
+        let path: Vec<String> = ["cat", "cot", "cog"].map(String::from).to_vec();
+        let exporter = GraphExporter::new().with_neighborhood(path.clone(), 1);
+
+        let dot = exporter.export(&graph).unwrap();
+        assert!(dot.contains("\t\"cat\" [style=filled, fillcolor=\"#ffd54f\"];"));
+        assert!(dot.contains("\t\"bat\";"));
+        assert!(dot.contains("\t\"cat\" -- \"cot\" [color=\"#e65100\", penwidth=3];"));
+        assert!(dot.contains("\t\"bat\" -- \"cat\";"));
+        // "bag" and "dig" are two steps from the path
+        assert!(!dot.contains("\"bag\""));
+
+        let layers = neighborhood_layers(&graph, &Neighborhood { path, radius: 2 });
+        assert_eq!(
+            layers,
+            vec![
+                vec!["cat", "cot", "cog"],
+                vec!["bat", "dog"],
+                vec!["bag", "dig"]
+            ]
+        );
+
+        let svg = exporter
+            .with_format(GraphFormat::Svg)
+            .export(&graph)
+            .unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert_eq!(svg.matches("<text").count(), 5);
+        assert_eq!(svg.matches("stroke-width=\"4\"").count(), 2);
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_export_svg_requires_neighborhood() {
+        let graph = create_test_graph();
+        let result = GraphExporter::new()
+            .with_format(GraphFormat::Svg)
+            .export(&graph);
+        assert!(result.is_err());
+    }
+}
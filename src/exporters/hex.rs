@@ -0,0 +1,62 @@
+//! # Hex Encoding
+//!
+//! Lowercase hex encoding of keys, signatures, and ciphertexts, shared by the
+//! `encryption` and `signing` modules.
+
+use anyhow::{Result, anyhow};
+
+/// Returns the lowercase hex encoding of some bytes.
+pub fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes an even number of hex characters into bytes.
+///
+/// # Arguments
+///
+/// * `hex` - Hex characters in either case, without surrounding whitespace
+pub fn decode(hex: &str) -> Result<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return Err(anyhow!("Expected an even number of hex characters"));
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair)?;
+            u8::from_str_radix(pair, 16).map_err(|_| anyhow!("Invalid hex digit in {}", pair))
+        })
+        .collect()
+}
+
+/// Decodes exactly `2 * N` hex characters into `N` bytes.
+///
+/// # Arguments
+///
+/// * `hex` - Hex characters in either case, without surrounding whitespace
+pub fn decode_array<const N: usize>(hex: &str) -> Result<[u8; N]> {
+    if hex.len() != 2 * N {
+        return Err(anyhow!("Expected {} hex characters", 2 * N));
+    }
+    let bytes = decode(hex)?;
+    Ok(bytes
+        .try_into()
+        .expect("decoded length matches the checked hex length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_errors() {
+        assert_eq!(encode(&[0, 0x2a, 0xff]), "002aff");
+        assert_eq!(decode("002AfF").unwrap(), [0, 0x2a, 0xff]);
+        assert_eq!(decode_array::<2>("beef").unwrap(), [0xbe, 0xef]);
+
+        assert!(decode("abc").is_err());
+        assert!(decode("zz").is_err());
+        assert!(decode("é0").is_err());
+        assert!(decode_array::<2>("be").is_err());
+        assert!(decode_array::<2>("beefed").is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! # Incremental Export Module
+//!
+//! This module supports appending newly generated puzzles to an existing
+//! export instead of overwriting it. It reads the puzzles already present in a
+//! SQL file, JSON file, binary export, or ID manifest, filters out duplicates, and seeds the
+//! SQL exporter's ID counters so counter-based IDs continue where the existing
+//! file left off.
+//!
+//! A puzzle counts as a duplicate when an existing puzzle has the same start
+//! and end words, regardless of the path or ID strategy used.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::incremental::ExistingPuzzles;
+//! use wordladder_engine::exporters::sql::SqlExporter;
+//!
+//! let existing_sql = "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES\n\
+//!                     \t('cat_dog_001', 'cat', 'dog', 3, 'easy');\n";
+//! let existing = ExistingPuzzles::from_sql(existing_sql);
+//!
+//! let new_puzzles = vec![/* freshly generated puzzles */];
+//! let new_puzzles = existing.filter_new(new_puzzles);
+//!
+//! let mut exporter = SqlExporter::new()
+//!     .with_include_schema(false)
+//!     .with_id_counters(existing.id_counters().clone());
+//! let sql = exporter.export_puzzles(&new_puzzles).unwrap();
+//! ```
+
+use crate::puzzle::Puzzle;
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+
+/// Longest suffix treated as a counter; content-hash suffixes are longer.
+const MAX_COUNTER_DIGITS: usize = 6;
+
+/// Puzzles already present in an export, used to append without duplicates.
+#[derive(Debug, Clone, Default)]
+pub struct ExistingPuzzles {
+    /// `start_end` keys of the existing puzzles
+    pairs: HashSet<String>,
+    /// Highest counter used for each `start_end` key by counter-based IDs
+    id_counters: HashMap<String, usize>,
+    /// Number of existing puzzles read
+    count: usize,
+}
+
+impl ExistingPuzzles {
+    /// Creates an empty set, for appending to a file that does not exist yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the puzzle IDs from a set of existing puzzle IDs.
+    ///
+    /// Works with both counter IDs (`cat_dog_001`) and content-hash IDs
+    /// (`cat_dog_93288671e9fe`).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Existing puzzle IDs
+    pub fn from_ids<'a>(ids: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut existing = Self::new();
+        for id in ids {
+            existing.add_id(id);
+        }
+        existing
+    }
+
+    /// Reads an ID manifest with one puzzle ID per line.
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Contents of the manifest file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::incremental::ExistingPuzzles;
+    ///
+    /// let existing = ExistingPuzzles::from_id_manifest("cat_dog_001\ncat_dog_002\n");
+    /// assert_eq!(existing.len(), 2);
+    /// assert_eq!(existing.id_counters()["cat_dog"], 2);
+    /// ```
+    pub fn from_id_manifest(manifest: &str) -> Self {
+        Self::from_ids(
+            manifest
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#')),
+        )
+    }
+
+    /// Reads the puzzle IDs from a SQL export produced by `SqlExporter`.
+    ///
+    /// See `puzzle_ids_from_sql` for which lines are read.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql` - Contents of the existing SQL file
+    pub fn from_sql(sql: &str) -> Self {
+        Self::from_ids(puzzle_ids_from_sql(sql))
+    }
+
+    /// Reads the puzzles from a JSON export (an array of puzzle objects).
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - Contents of the existing JSON file
+    ///
+    /// # Returns
+    ///
+    /// The existing puzzles, or an error if the JSON is not an array of
+    /// objects with `start` and `end` strings.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let entries: Vec<serde_json::Value> = serde_json::from_str(json)?;
+        let mut existing = Self::new();
+        for entry in entries {
+            let (Some(start), Some(end)) = (entry["start"].as_str(), entry["end"].as_str()) else {
+                return Err(anyhow!("Puzzle entry is missing start or end word"));
+            };
+            existing.pairs.insert(pair_key(start, end));
+            existing.count += 1;
+        }
+        Ok(existing)
+    }
+
+    /// Reads the puzzles from a JSON Lines export (one puzzle object per line).
+    ///
+    /// Blank lines are ignored, so a file cut off after a complete line still
+    /// reads. A partial last line, as left by an interrupted streamed export,
+    /// is an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `jsonl` - Contents of the existing JSON Lines file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::incremental::ExistingPuzzles;
+    ///
+    /// let jsonl = "{\"start\":\"cat\",\"end\":\"dog\"}\n{\"start\":\"cold\",\"end\":\"warm\"}\n";
+    /// assert_eq!(ExistingPuzzles::from_json_lines(jsonl).unwrap().len(), 2);
+    /// ```
+    pub fn from_json_lines(jsonl: &str) -> Result<Self> {
+        let mut existing = Self::new();
+        for line in jsonl.lines().filter(|line| !line.trim().is_empty()) {
+            let entry: serde_json::Value = serde_json::from_str(line)?;
+            let (Some(start), Some(end)) = (entry["start"].as_str(), entry["end"].as_str()) else {
+                return Err(anyhow!("Puzzle entry is missing start or end word"));
+            };
+            existing.pairs.insert(pair_key(start, end));
+            existing.count += 1;
+        }
+        Ok(existing)
+    }
+
+    /// Reads the start and end words of already decoded puzzles, such as the
+    /// contents of a binary export.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Existing puzzles
+    pub fn from_puzzles<'a>(puzzles: impl IntoIterator<Item = &'a Puzzle>) -> Self {
+        let mut existing = Self::new();
+        for puzzle in puzzles {
+            existing.pairs.insert(pair_key(&puzzle.start, &puzzle.end));
+            existing.count += 1;
+        }
+        existing
+    }
+
+    /// Records one existing puzzle ID.
+    fn add_id(&mut self, id: &str) {
+        self.count += 1;
+        let Some((base, suffix)) = id.rsplit_once('_') else {
+            return;
+        };
+        self.pairs.insert(base.to_string());
+        if suffix.len() <= MAX_COUNTER_DIGITS
+            && let Ok(counter) = suffix.parse::<usize>()
+        {
+            let highest = self.id_counters.entry(base.to_string()).or_insert(0);
+            *highest = (*highest).max(counter);
+        }
+    }
+
+    /// Returns the number of existing puzzles read.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no existing puzzles were read.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if a puzzle with the same start and end words already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to look up
+    pub fn contains(&self, puzzle: &Puzzle) -> bool {
+        self.pairs.contains(&pair_key(&puzzle.start, &puzzle.end))
+    }
+
+    /// Records a newly written puzzle so later duplicates of it are detected.
+    ///
+    /// Used when puzzles are appended one at a time as they are generated,
+    /// instead of filtered as a whole with `filter_new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle about to be written
+    ///
+    /// # Returns
+    ///
+    /// `true` if the puzzle is new, or `false` if it is a duplicate and
+    /// should be skipped.
+    pub fn insert(&mut self, puzzle: &Puzzle) -> bool {
+        let new = self.pairs.insert(pair_key(&puzzle.start, &puzzle.end));
+        if new {
+            self.count += 1;
+        }
+        new
+    }
+
+    /// Returns the highest counter used for each `start_end` key.
+    ///
+    /// Pass this to `SqlExporter::with_id_counters` so new counter IDs do not
+    /// collide with existing ones.
+    pub fn id_counters(&self) -> &HashMap<String, usize> {
+        &self.id_counters
+    }
+
+    /// Drops puzzles that already exist, or that repeat an earlier new puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Newly generated puzzles
+    ///
+    /// # Returns
+    ///
+    /// The puzzles to append, in their original order.
+    pub fn filter_new(&self, puzzles: Vec<Puzzle>) -> Vec<Puzzle> {
+        let mut seen = self.pairs.clone();
+        puzzles
+            .into_iter()
+            .filter(|puzzle| seen.insert(pair_key(&puzzle.start, &puzzle.end)))
+            .collect()
+    }
+}
+
+/// Returns the puzzle IDs of a SQL export produced by `SqlExporter`, in order.
+///
+/// Each value row of an `INSERT INTO puzzles` statement starts with the
+/// quoted puzzle ID; every other line, including the rows of a
+/// `puzzle_steps` table, is ignored.
+///
+/// # Arguments
+///
+/// * `sql` - Contents of the SQL export
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_engine::exporters::incremental::puzzle_ids_from_sql;
+///
+/// let sql = "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES\n\
+///            \t('cat_dog_001', 'cat', 'dog', 3, 'easy');\n";
+/// assert_eq!(puzzle_ids_from_sql(sql), vec!["cat_dog_001"]);
+/// ```
+pub fn puzzle_ids_from_sql(sql: &str) -> Vec<&str> {
+    let mut in_puzzles = false;
+    sql.lines()
+        .filter_map(|line| {
+            if line.starts_with("INSERT INTO ") {
+                in_puzzles = line.starts_with("INSERT INTO puzzles ");
+            }
+            let row = line
+                .trim_start()
+                .strip_prefix("('")
+                .filter(|_| in_puzzles)?;
+            row.split_once('\'').map(|(id, _)| id)
+        })
+        .collect()
+}
+
+/// Appends JSON puzzle objects to an existing JSON array.
+///
+/// The existing text is kept as-is so earlier entries are not reformatted.
+///
+/// # Arguments
+///
+/// * `existing` - Contents of the existing JSON file
+/// * `entries` - Serialized puzzle objects to append
+///
+/// # Returns
+///
+/// The combined JSON array, or an error if `existing` is not a JSON array.
+pub fn append_json(existing: &str, entries: &[String]) -> Result<String> {
+    let trimmed = existing.trim_end();
+    let body = trimmed
+        .strip_suffix(']')
+        .ok_or_else(|| anyhow!("Existing JSON file is not an array"))?
+        .trim_end();
+    if entries.is_empty() {
+        return Ok(existing.to_string());
+    }
+    let separator = if body.ends_with('[') { "\n" } else { ",\n" };
+    Ok(format!("{}{}{}\n]", body, separator, entries.join(",\n")))
+}
+
+/// Builds the key identifying a puzzle by its endpoints, matching puzzle ID prefixes.
+fn pair_key(start: &str, end: &str) -> String {
+    format!("{}_{}", start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporters::sql::{IdStrategy, SqlExporter};
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_from_sql_continues_counters() {
+        let puzzles = vec![
+            create_test_puzzle(&["cat", "cot", "cog", "dog"]),
+            create_test_puzzle(&["cat", "cot", "dot", "dog"]),
+            create_test_puzzle(&["cold", "cord", "word", "worm", "warm"]),
+        ];
+        let sql = SqlExporter::new()
+            .with_include_steps_table(true)
+            .export_puzzles(&puzzles)
+            .unwrap();
+
+        let existing = ExistingPuzzles::from_sql(&sql);
+        assert_eq!(existing.len(), 3);
+        assert_eq!(existing.id_counters()["cat_dog"], 2);
+        assert_eq!(existing.id_counters()["cold_warm"], 1);
+
+        let new_puzzles = vec![
+            create_test_puzzle(&["cat", "cot", "cog", "dog"]),
+            create_test_puzzle(&["cat", "cot"]),
+            create_test_puzzle(&["cat", "cot"]),
+        ];
+        let new_puzzles = existing.filter_new(new_puzzles);
+        assert_eq!(new_puzzles.len(), 1);
+        assert_eq!(new_puzzles[0].end, "cot");
+
+        let mut exporter = SqlExporter::new().with_id_counters(existing.id_counters().clone());
+        let sql = exporter
+            .export_puzzles(&[create_test_puzzle(&["cat", "dog"])])
+            .unwrap();
+        assert!(sql.contains("'cat_dog_003'"));
+    }
+
+    #[test]
+    fn test_from_sql_content_hash_ids() {
+        let puzzles = vec![create_test_puzzle(&["cat", "cot", "cog", "dog"])];
+        let sql = SqlExporter::new()
+            .with_id_strategy(IdStrategy::ContentHash)
+            .export_puzzles(&puzzles)
+            .unwrap();
+
+        let existing = ExistingPuzzles::from_sql(&sql);
+        assert_eq!(existing.len(), 1);
+        assert!(existing.contains(&puzzles[0]));
+        assert!(existing.id_counters().is_empty());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let first = create_test_puzzle(&["cat", "cot", "cog", "dog"]);
+        let second = create_test_puzzle(&["cat", "cot"]);
+
+        let json = append_json("[\n\n]", &[first.to_json().unwrap()]).unwrap();
+        let existing = ExistingPuzzles::from_json(&json).unwrap();
+        assert_eq!(existing.len(), 1);
+        assert!(existing.contains(&first));
+        assert!(!existing.contains(&second));
+
+        let json = append_json(&json, &[second.to_json().unwrap()]).unwrap();
+        let existing = ExistingPuzzles::from_json(&json).unwrap();
+        assert_eq!(existing.len(), 2);
+        assert!(existing.contains(&second));
+
+        assert!(append_json("{}", &[]).is_err());
+        assert!(ExistingPuzzles::from_json("[{\"start\": \"cat\"}]").is_err());
+    }
+}
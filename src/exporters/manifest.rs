@@ -0,0 +1,266 @@
+//! # Export Manifest Module
+//!
+//! This module builds the sidecar `manifest.json` written next to an export so
+//! mobile clients can tell which puzzle bundle they have. A manifest records
+//! the engine version, a SHA-256 of the dictionary the puzzles were built from,
+//! the generation seed (if any), puzzle counts per difficulty, and a SHA-256 of
+//! the exported file itself.
+//!
+//! The manifest for `output/batch_medium.sql` is written to
+//! `output/batch_medium.manifest.json`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::manifest::ExportManifest;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let puzzles: Vec<Puzzle> = vec![/* exported puzzles */];
+//! let sql = "-- Generated 0 puzzles\n";
+//!
+//! let manifest = ExportManifest::new(b"cat\ncot\ncog\ndog\n", "puzzles.sql", sql.as_bytes())
+//!     .with_puzzles(&puzzles);
+//! let json = manifest.to_json().unwrap();
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// File extension of manifest sidecars, replacing the export's own extension.
+pub const MANIFEST_EXTENSION: &str = "manifest.json";
+
+/// Number of exported puzzles per difficulty level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PuzzleCounts {
+    /// Number of easy puzzles
+    pub easy: usize,
+    /// Number of medium puzzles
+    pub medium: usize,
+    /// Number of hard puzzles
+    pub hard: usize,
+}
+
+impl PuzzleCounts {
+    /// Counts puzzles by difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - The puzzles to count
+    pub fn from_puzzles(puzzles: &[Puzzle]) -> Self {
+        let mut counts = Self::default();
+        for puzzle in puzzles {
+            counts.add(puzzle);
+        }
+        counts
+    }
+
+    /// Counts one more puzzle, for exports written a puzzle at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to count
+    pub fn add(&mut self, puzzle: &Puzzle) {
+        match puzzle.difficulty {
+            Difficulty::Easy => self.easy += 1,
+            Difficulty::Medium => self.medium += 1,
+            Difficulty::Hard => self.hard += 1,
+        }
+    }
+
+    /// Returns the number of puzzles across all difficulty levels.
+    pub fn total(&self) -> usize {
+        self.easy + self.medium + self.hard
+    }
+}
+
+/// Metadata describing one exported file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    /// Version of the engine that produced the export
+    pub engine_version: String,
+    /// Hex SHA-256 of the dictionary file the puzzles were built from
+    pub dictionary_sha256: String,
+    /// RNG seed used for generation, or `None` if generation was not seeded
+    pub seed: Option<u64>,
+    /// Number of puzzles per difficulty level in the exported file
+    pub puzzle_counts: PuzzleCounts,
+    /// File name of the export this manifest describes
+    pub output_file: String,
+    /// Hex SHA-256 of the exported file
+    pub output_sha256: String,
+}
+
+impl ExportManifest {
+    /// Creates a manifest for an export with no puzzles counted yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - Contents of the dictionary file
+    /// * `output_file` - File name of the export
+    /// * `output` - Contents of the exported file
+    pub fn new(dictionary: &[u8], output_file: &str, output: &[u8]) -> Self {
+        Self {
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            dictionary_sha256: sha256_hex(dictionary),
+            seed: None,
+            puzzle_counts: PuzzleCounts::default(),
+            output_file: output_file.to_string(),
+            output_sha256: sha256_hex(output),
+        }
+    }
+
+    /// Adds puzzles to the per-difficulty counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles contained in the export
+    pub fn with_puzzles(mut self, puzzles: &[Puzzle]) -> Self {
+        self.add_counts(PuzzleCounts::from_puzzles(puzzles));
+        self
+    }
+
+    /// Adds already tallied per-difficulty counts, for exports whose puzzles
+    /// were not kept in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - Counts of the puzzles contained in the export
+    pub fn with_counts(mut self, counts: PuzzleCounts) -> Self {
+        self.add_counts(counts);
+        self
+    }
+
+    /// Carries over the puzzle counts of the previous manifest for the same file.
+    ///
+    /// Used when appending to an export, so the counts cover the whole file and
+    /// not only the puzzles added in this run.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - Manifest written for the file before this run
+    pub fn with_previous(mut self, previous: &ExportManifest) -> Self {
+        self.add_counts(previous.puzzle_counts);
+        self
+    }
+
+    /// Sets the RNG seed used for generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed, or `None` if generation was not seeded
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Serializes the manifest to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parses a manifest from JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - Contents of a manifest file
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Checks an exported file against the manifest's checksum.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Contents of the exported file
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file's SHA-256 matches `output_sha256`.
+    pub fn matches_output(&self, output: &[u8]) -> bool {
+        self.output_sha256.eq_ignore_ascii_case(&sha256_hex(output))
+    }
+
+    /// Returns the sidecar path of the manifest for an export file.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_path` - Path of the exported file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use wordladder_engine::exporters::manifest::ExportManifest;
+    ///
+    /// let sidecar = ExportManifest::sidecar_path(Path::new("output/puzzles.sql"));
+    /// assert_eq!(sidecar, Path::new("output/puzzles.manifest.json"));
+    /// ```
+    pub fn sidecar_path(output_path: &Path) -> PathBuf {
+        output_path.with_extension(MANIFEST_EXTENSION)
+    }
+
+    /// Adds puzzle counts to the manifest's totals.
+    fn add_counts(&mut self, counts: PuzzleCounts) {
+        self.puzzle_counts.easy += counts.easy;
+        self.puzzle_counts.medium += counts.medium;
+        self.puzzle_counts.hard += counts.hard;
+    }
+}
+
+/// Returns the lowercase hex SHA-256 digest of the given bytes.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_manifest_counts_and_round_trip() {
+        let puzzles = vec![
+            create_test_puzzle(&["cat", "cot", "cog", "dog"]),
+            create_test_puzzle(&["cat", "cot", "dot"]),
+            create_test_puzzle(&["cold", "cord", "word", "worm", "warm"]),
+        ];
+        let manifest = ExportManifest::new(b"cat\ndog\n", "puzzles.sql", b"INSERT")
+            .with_puzzles(&puzzles)
+            .with_seed(Some(42));
+
+        assert_eq!(manifest.engine_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(manifest.dictionary_sha256, sha256_hex(b"cat\ndog\n"));
+        assert_eq!(manifest.output_sha256, sha256_hex(b"INSERT"));
+        assert!(manifest.matches_output(b"INSERT"));
+        assert!(!manifest.matches_output(b"INSERT INSERT"));
+        assert_eq!(
+            manifest.puzzle_counts,
+            PuzzleCounts {
+                easy: 2,
+                medium: 1,
+                hard: 0
+            }
+        );
+        assert_eq!(manifest.puzzle_counts.total(), 3);
+
+        let parsed = ExportManifest::from_json(&manifest.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, manifest);
+
+        let appended = ExportManifest::new(b"cat\ndog\n", "puzzles.sql", b"INSERT INSERT")
+            .with_puzzles(&puzzles[..1])
+            .with_previous(&parsed);
+        assert_eq!(appended.puzzle_counts.easy, 3);
+        assert_eq!(appended.puzzle_counts.total(), 4);
+    }
+}
@@ -0,0 +1,237 @@
+//! # Markdown Export Module
+//!
+//! This module exports puzzles as Markdown for publishing, e.g. in a blog post
+//! or newsletter. The puzzles are listed in a table (start, end, steps,
+//! difficulty), followed by one collapsible `<details>` block per puzzle so
+//! readers only see a solution when they open it.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::markdown::MarkdownExporter;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let exporter = MarkdownExporter::new().with_title(Some("This Week's Ladders".to_string()));
+//! let markdown = exporter.export_puzzles(&[puzzle]);
+//! assert!(markdown.contains("| 1 | cat | dog | 3 | easy |"));
+//! assert!(markdown.contains("<summary>Solution 1: cat to dog</summary>"));
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle};
+
+/// Configuration for Markdown export functionality.
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownExportConfig {
+    /// Heading written above the table (no heading if `None`)
+    pub title: Option<String>,
+    /// Whether to add a `Clues` column with the masked ladder (see
+    /// `Puzzle::to_clue_format`)
+    pub include_clues: bool,
+}
+
+/// Exporter writing puzzles as a Markdown table with collapsible solutions.
+#[derive(Debug, Default)]
+pub struct MarkdownExporter {
+    config: MarkdownExportConfig,
+}
+
+impl MarkdownExporter {
+    /// Creates a new Markdown exporter with default configuration (no title, no clues).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::markdown::MarkdownExporter;
+    ///
+    /// let exporter = MarkdownExporter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Markdown exporter with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration for the exporter
+    pub fn with_config(config: MarkdownExportConfig) -> Self {
+        Self { config }
+    }
+
+    /// Sets the heading written above the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Heading text, or `None` for no heading
+    pub fn with_title(mut self, title: Option<String>) -> Self {
+        self.config.title = title;
+        self
+    }
+
+    /// Sets whether to add a `Clues` column with the masked ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_clues` - Whether to export each puzzle's clues
+    pub fn with_include_clues(mut self, include_clues: bool) -> Self {
+        self.config.include_clues = include_clues;
+        self
+    }
+
+    /// Exports puzzles as a Markdown document.
+    ///
+    /// Puzzles are numbered from 1 in the given order; each solution block
+    /// refers to its puzzle by that number. A puzzle with a `reverse_path`
+    /// also shows the reverse ladder in its solution block.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to export
+    ///
+    /// # Returns
+    ///
+    /// The Markdown document, ending with a newline.
+    pub fn export_puzzles(&self, puzzles: &[Puzzle]) -> String {
+        let mut markdown = String::new();
+        if let Some(title) = &self.config.title {
+            markdown.push_str(&format!("# {}\n\n", escape_markdown(title)));
+        }
+
+        if self.config.include_clues {
+            markdown.push_str("| # | Start | End | Steps | Difficulty | Clues |\n");
+            markdown.push_str("|---|-------|-----|-------|------------|-------|\n");
+        } else {
+            markdown.push_str("| # | Start | End | Steps | Difficulty |\n");
+            markdown.push_str("|---|-------|-----|-------|------------|\n");
+        }
+        for (i, puzzle) in puzzles.iter().enumerate() {
+            markdown.push_str(&format!(
+                "| {} | {} | {} | {} | {} |",
+                i + 1,
+                escape_markdown(&puzzle.start),
+                escape_markdown(&puzzle.end),
+                puzzle.path.len() - 1,
+                difficulty_name(puzzle.difficulty)
+            ));
+            if self.config.include_clues {
+                markdown.push_str(&format!(
+                    " `{}` |",
+                    puzzle.to_clue_format().join(" → ").replace('|', "\\|")
+                ));
+            }
+            markdown.push('\n');
+        }
+
+        for (i, puzzle) in puzzles.iter().enumerate() {
+            markdown.push_str(&format!(
+                "\n<details>\n<summary>Solution {}: {} to {}</summary>\n\n{}\n",
+                i + 1,
+                escape_markdown(&puzzle.start),
+                escape_markdown(&puzzle.end),
+                ladder_to_markdown(&puzzle.path)
+            ));
+            if let Some(reverse) = &puzzle.reverse_path {
+                markdown.push_str(&format!("\nReverse: {}\n", ladder_to_markdown(reverse)));
+            }
+            markdown.push_str("\n</details>\n");
+        }
+
+        markdown
+    }
+}
+
+/// Returns the lowercase name of a difficulty level, as used in SQL exports.
+pub(super) fn difficulty_name(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "easy",
+        Difficulty::Medium => "medium",
+        Difficulty::Hard => "hard",
+    }
+}
+
+/// Formats a ladder as `word → word → word`.
+fn ladder_to_markdown(path: &[String]) -> String {
+    path.iter()
+        .map(|word| escape_markdown(word))
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+/// Escapes characters that Markdown or inline HTML would interpret.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '\\' | '`' | '*' | '_' | '[' | ']' | '|' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_export_puzzles() {
+        let exporter = MarkdownExporter::new().with_title(Some("Week 12".to_string()));
+        let mut mirror = create_test_puzzle(&["cat", "cot", "cog", "dog"]);
+        mirror.reverse_path = Some(
+            ["dog", "dag", "cag", "cat"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        );
+        let puzzles = [
+            mirror,
+            create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]),
+        ];
+
+        let markdown = exporter.export_puzzles(&puzzles);
+        assert!(markdown.starts_with(
+            "# Week 12\n\n| # | Start | End | Steps | Difficulty |\n|---|-------|-----|-------|------------|\n"
+        ));
+        assert!(
+            markdown.contains("| 1 | cat | dog | 3 | easy |\n| 2 | cold | warm | 4 | medium |\n")
+        );
+        assert!(markdown.contains(
+            "<details>\n<summary>Solution 1: cat to dog</summary>\n\ncat → cot → cog → dog\n\nReverse: dog → dag → cag → cat\n\n</details>\n"
+        ));
+        assert!(markdown.contains(
+            "<summary>Solution 2: cold to warm</summary>\n\ncold → cord → card → ward → warm\n\n</details>\n"
+        ));
+        assert_eq!(markdown.matches("<details>").count(), 2);
+    }
+
+    #[test]
+    fn test_export_puzzles_with_clues() {
+        let exporter = MarkdownExporter::new().with_include_clues(true);
+        let markdown =
+            exporter.export_puzzles(&[create_test_puzzle(&["cat", "cot", "cog", "dog"])]);
+
+        assert!(markdown.starts_with("| # | Start | End | Steps | Difficulty | Clues |\n"));
+        assert!(markdown.contains("| 1 | cat | dog | 3 | easy | `cat → c_t → co_ → dog` |\n"));
+        assert_eq!(
+            MarkdownExporter::new().export_puzzles(&[]),
+            "| # | Start | End | Steps | Difficulty |\n|---|-------|-----|-------|------------|\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown("cat"), "cat");
+        assert_eq!(escape_markdown("a|b_c*"), "a\\|b\\_c\\*");
+        assert_eq!(escape_markdown("<b>&"), "&lt;b&gt;&amp;");
+    }
+}
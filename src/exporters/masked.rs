@@ -0,0 +1,88 @@
+//! # Solution Masking Module
+//!
+//! Competitive modes must not ship solutions to game clients. When solutions
+//! are omitted, the JSON and JSON Lines exports write `MaskedPuzzle` records
+//! instead of puzzles, and the SQL exporter writes only its base columns (see
+//! `SqlExporter::with_omit_solutions`).
+//!
+//! A `MaskedPuzzle` keeps the start and end words, the number of steps of the
+//! shortest ladder, and the difficulty. It has no field that could hold a
+//! path, so clues, reverse ladders, and any field added to `Puzzle` later
+//! cannot end up in a masked export by accident.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::masked::MaskedPuzzle;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let masked = MaskedPuzzle::from(&puzzle);
+//! assert_eq!(masked.min_steps, 3);
+//! assert!(!masked.to_json().unwrap().contains("cot"));
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle};
+use serde::{Deserialize, Serialize};
+
+/// A puzzle without its solution, as exported with solutions omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaskedPuzzle {
+    /// The starting word of the puzzle
+    pub start: String,
+    /// The ending word of the puzzle
+    pub end: String,
+    /// Number of steps of the shortest ladder
+    pub min_steps: usize,
+    /// The difficulty level of the puzzle
+    pub difficulty: Difficulty,
+}
+
+impl From<&Puzzle> for MaskedPuzzle {
+    fn from(puzzle: &Puzzle) -> Self {
+        Self {
+            start: puzzle.start.clone(),
+            end: puzzle.end.clone(),
+            min_steps: puzzle.path.len() - 1,
+            difficulty: puzzle.difficulty,
+        }
+    }
+}
+
+impl MaskedPuzzle {
+    /// Serializes the masked puzzle to a pretty-printed JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masked_puzzle() {
+        let path: Vec<String> = ["cold", "cord", "card", "ward", "warm"]
+            .map(String::from)
+            .to_vec();
+        let mut puzzle = Puzzle::new("cold".to_string(), "warm".to_string(), path).unwrap();
+        puzzle.reverse_path = Some(
+            ["warm", "worm", "word", "cord", "cold"]
+                .map(String::from)
+                .to_vec(),
+        );
+
+        let masked = MaskedPuzzle::from(&puzzle);
+        assert_eq!(masked.min_steps, 4);
+        assert_eq!(masked.difficulty, puzzle.difficulty);
+
+        let value = serde_json::to_value(&masked).unwrap();
+        let mut keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["difficulty", "end", "min_steps", "start"]);
+        let json = masked.to_json().unwrap();
+        assert!(!json.contains("cord") && !json.contains("worm"));
+    }
+}
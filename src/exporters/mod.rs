@@ -1,10 +1,43 @@
 //! # Export Modules
 //!
 //! This module provides various export formats for word ladder puzzles.
-//! Currently supports SQL export for mobile application integration.
+//! Currently supports SQL export for mobile application integration, compact
+//! binary export for over-the-air updates, Markdown export for publishing, SVG
+//! ladder graphics for social sharing, Anki flashcards for vocabulary
+//! practice, and graph export for external analysis tools.
 //!
 //! ## Available Exporters
 //!
+//! - `anki`: Anki flashcard import files, one note per puzzle
+//! - `binary`: Compact MessagePack and CBOR puzzle arrays for over-the-air updates
+//! - `encryption`: XChaCha20-encrypted solutions for offline answer checking (feature `encrypt`)
+//! - `graph`: DOT, GraphML, and CSV edge-list export of the word graph, and SVG
+//!   drawings of a puzzle's neighborhood
+//! - `incremental`: Appending new puzzles to an existing SQL, JSON, or binary export
+//! - `manifest`: Sidecar manifests with checksums and metadata for exported files
+//! - `markdown`: Markdown tables with collapsible solutions for publishing
+//! - `masked`: Puzzles without their solutions, for competitive game clients
+//! - `proto`: Binary protobuf puzzle packs for game clients (feature `proto`)
+//! - `signing`: Detached ed25519 signatures for exported packs (feature `sign`)
 //! - `sql`: SQLite-compatible SQL export with batching and schema generation
+//! - `stream`: JSON Lines, SQL, and line exports written as puzzles are generated
+//! - `svg`: Ladder graphics of puzzles for social sharing
 
+pub mod anki;
+pub mod binary;
+#[cfg(feature = "encrypt")]
+pub mod encryption;
+pub mod graph;
+#[cfg(any(feature = "encrypt", feature = "sign"))]
+pub(crate) mod hex;
+pub mod incremental;
+pub mod manifest;
+pub mod markdown;
+pub mod masked;
+#[cfg(feature = "proto")]
+pub mod proto;
+#[cfg(feature = "sign")]
+pub mod signing;
 pub mod sql;
+pub mod stream;
+pub mod svg;
@@ -0,0 +1,395 @@
+//! # Protocol Buffers Export Module
+//!
+//! This module serializes puzzles into binary puzzle packs for game clients
+//! (e.g. Unity) that prefer binary assets over SQL. Packs follow the versioned
+//! schema in `proto/wordladder.proto`, which is shipped with the crate and
+//! available as [`SCHEMA`], so clients can generate their own bindings with
+//! `protoc`.
+//!
+//! The wire format is written and read directly, without a protobuf runtime:
+//! the schema only needs varints and length-delimited fields. Unknown fields
+//! are skipped when decoding, so packs from newer schema versions still load.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::proto::{PuzzlePack, SCHEMA_VERSION};
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let bytes = PuzzlePack::new(vec![puzzle.clone()]).encode();
+//! let pack = PuzzlePack::decode(&bytes).unwrap();
+//! assert_eq!(pack.schema_version, SCHEMA_VERSION);
+//! assert_eq!(pack.puzzles, vec![puzzle]);
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle, PuzzleMeta};
+use anyhow::{Result, anyhow, bail};
+
+/// The protobuf schema of puzzle packs (`proto/wordladder.proto`).
+pub const SCHEMA: &str = include_str!("../../proto/wordladder.proto");
+
+/// Version of the schema packs are written with.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Wire type of varint fields.
+const VARINT: u8 = 0;
+/// Wire type of fixed 64-bit fields.
+const FIXED64: u8 = 1;
+/// Wire type of length-delimited fields (strings and messages).
+const LENGTH_DELIMITED: u8 = 2;
+/// Wire type of fixed 32-bit fields.
+const FIXED32: u8 = 5;
+
+/// A set of puzzles shipped to clients as one binary asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzlePack {
+    /// Version of the schema the pack was written with
+    pub schema_version: u32,
+    /// Version of the engine that wrote the pack
+    pub engine_version: String,
+    /// The puzzles in the pack
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl PuzzlePack {
+    /// Creates a pack of the current schema version and engine version.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - The puzzles to ship
+    pub fn new(puzzles: Vec<Puzzle>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            puzzles,
+        }
+    }
+
+    /// Serializes the pack as a `PuzzlePack` protobuf message.
+    ///
+    /// # Returns
+    ///
+    /// The encoded message bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        put_uint(&mut buf, 1, self.schema_version.into());
+        put_string(&mut buf, 2, &self.engine_version);
+        for puzzle in &self.puzzles {
+            put_message(&mut buf, 3, &encode_puzzle(puzzle));
+        }
+        buf
+    }
+
+    /// Parses a `PuzzlePack` protobuf message.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded message
+    ///
+    /// # Returns
+    ///
+    /// The pack, or an error if the bytes are truncated or malformed, a
+    /// puzzle has no path, or a difficulty is unknown.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut pack = Self {
+            schema_version: 0,
+            engine_version: String::new(),
+            puzzles: Vec::new(),
+        };
+        let mut reader = Reader::new(bytes);
+        while let Some((field, wire_type)) = reader.read_key()? {
+            match (field, wire_type) {
+                (1, VARINT) => pack.schema_version = reader.read_varint()? as u32,
+                (2, LENGTH_DELIMITED) => pack.engine_version = reader.read_string()?,
+                (3, LENGTH_DELIMITED) => pack.puzzles.push(decode_puzzle(reader.read_bytes()?)?),
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(pack)
+    }
+}
+
+/// Encodes a puzzle as a `Puzzle` message.
+fn encode_puzzle(puzzle: &Puzzle) -> Vec<u8> {
+    let mut buf = Vec::new();
+    put_string(&mut buf, 1, &puzzle.start);
+    put_string(&mut buf, 2, &puzzle.end);
+    for word in &puzzle.path {
+        put_tagged_string(&mut buf, 3, word);
+    }
+    let difficulty = match puzzle.difficulty {
+        Difficulty::Easy => 1,
+        Difficulty::Medium => 2,
+        Difficulty::Hard => 3,
+    };
+    put_uint(&mut buf, 4, difficulty);
+    put_uint(&mut buf, 5, puzzle.path.len().saturating_sub(1) as u64);
+    if let Some(unique) = puzzle.unique_solution {
+        put_key(&mut buf, 6, VARINT);
+        put_varint(&mut buf, unique.into());
+    }
+    for word in puzzle.reverse_path.iter().flatten() {
+        put_tagged_string(&mut buf, 7, word);
+    }
+    if let Some(meta) = &puzzle.meta {
+        let mut meta_buf = Vec::new();
+        put_uint(&mut meta_buf, 1, meta.created_at);
+        put_string(&mut meta_buf, 2, &meta.engine_version);
+        put_string(&mut meta_buf, 3, &meta.dictionary_sha256);
+        if let Some(seed) = meta.seed {
+            put_key(&mut meta_buf, 4, VARINT);
+            put_varint(&mut meta_buf, seed);
+        }
+        put_message(&mut buf, 8, &meta_buf);
+    }
+    buf
+}
+
+/// Decodes a `Puzzle` message.
+fn decode_puzzle(bytes: &[u8]) -> Result<Puzzle> {
+    let mut puzzle = Puzzle {
+        start: String::new(),
+        end: String::new(),
+        path: Vec::new(),
+        difficulty: Difficulty::Easy,
+        unique_solution: None,
+        reverse_path: None,
+        stars: None,
+        meta: None,
+    };
+    let mut difficulty = 0;
+    let mut reverse_path = Vec::new();
+    let mut reader = Reader::new(bytes);
+    while let Some((field, wire_type)) = reader.read_key()? {
+        match (field, wire_type) {
+            (1, LENGTH_DELIMITED) => puzzle.start = reader.read_string()?,
+            (2, LENGTH_DELIMITED) => puzzle.end = reader.read_string()?,
+            (3, LENGTH_DELIMITED) => puzzle.path.push(reader.read_string()?),
+            (4, VARINT) => difficulty = reader.read_varint()?,
+            (6, VARINT) => puzzle.unique_solution = Some(reader.read_varint()? != 0),
+            (7, LENGTH_DELIMITED) => reverse_path.push(reader.read_string()?),
+            (8, LENGTH_DELIMITED) => puzzle.meta = Some(decode_meta(reader.read_bytes()?)?),
+            _ => reader.skip(wire_type)?,
+        }
+    }
+
+    puzzle.difficulty = match difficulty {
+        1 => Difficulty::Easy,
+        2 => Difficulty::Medium,
+        3 => Difficulty::Hard,
+        other => bail!("Unknown difficulty {} in puzzle {}", other, puzzle.start),
+    };
+    if puzzle.path.is_empty() {
+        bail!("Puzzle {} -> {} has no path", puzzle.start, puzzle.end);
+    }
+    puzzle.reverse_path = (!reverse_path.is_empty()).then_some(reverse_path);
+    Ok(puzzle)
+}
+
+/// Decodes a `PuzzleMeta` message.
+fn decode_meta(bytes: &[u8]) -> Result<PuzzleMeta> {
+    let mut meta = PuzzleMeta {
+        created_at: 0,
+        engine_version: String::new(),
+        dictionary_sha256: String::new(),
+        seed: None,
+    };
+    let mut reader = Reader::new(bytes);
+    while let Some((field, wire_type)) = reader.read_key()? {
+        match (field, wire_type) {
+            (1, VARINT) => meta.created_at = reader.read_varint()?,
+            (2, LENGTH_DELIMITED) => meta.engine_version = reader.read_string()?,
+            (3, LENGTH_DELIMITED) => meta.dictionary_sha256 = reader.read_string()?,
+            (4, VARINT) => meta.seed = Some(reader.read_varint()?),
+            _ => reader.skip(wire_type)?,
+        }
+    }
+    Ok(meta)
+}
+
+/// Appends a base-128 varint.
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Appends a field key (field number and wire type).
+fn put_key(buf: &mut Vec<u8>, field: u32, wire_type: u8) {
+    put_varint(buf, (u64::from(field) << 3) | u64::from(wire_type));
+}
+
+/// Appends a varint field, leaving it out if zero (the proto3 default).
+fn put_uint(buf: &mut Vec<u8>, field: u32, value: u64) {
+    if value != 0 {
+        put_key(buf, field, VARINT);
+        put_varint(buf, value);
+    }
+}
+
+/// Appends a string field, leaving it out if empty (the proto3 default).
+fn put_string(buf: &mut Vec<u8>, field: u32, value: &str) {
+    if !value.is_empty() {
+        put_tagged_string(buf, field, value);
+    }
+}
+
+/// Appends a string field even if empty, as needed for repeated fields.
+fn put_tagged_string(buf: &mut Vec<u8>, field: u32, value: &str) {
+    put_message(buf, field, value.as_bytes());
+}
+
+/// Appends a length-delimited field.
+fn put_message(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    put_key(buf, field, LENGTH_DELIMITED);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// Cursor over the fields of an encoded message.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Reads the next field key, or `None` at the end of the message.
+    fn read_key(&mut self) -> Result<Option<(u64, u8)>> {
+        if self.pos == self.bytes.len() {
+            return Ok(None);
+        }
+        let key = self.read_varint()?;
+        Ok(Some((key >> 3, (key & 0x7) as u8)))
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .bytes
+                .get(self.pos)
+                .ok_or_else(|| anyhow!("Truncated varint at byte {}", self.pos))?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("Varint longer than 10 bytes at byte {}", self.pos)
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let len = usize::try_from(self.read_varint()?)?;
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| anyhow!("Truncated field of {} bytes at byte {}", len, self.pos))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        Ok(String::from_utf8(self.read_bytes()?.to_vec())?)
+    }
+
+    /// Skips the value of a field this decoder does not know.
+    fn skip(&mut self, wire_type: u8) -> Result<()> {
+        let width = match wire_type {
+            VARINT => return self.read_varint().map(|_| ()),
+            LENGTH_DELIMITED => return self.read_bytes().map(|_| ()),
+            FIXED64 => 8,
+            FIXED32 => 4,
+            other => bail!("Unsupported wire type {} at byte {}", other, self.pos),
+        };
+        if self.bytes.len() - self.pos < width {
+            bail!("Truncated fixed-width field at byte {}", self.pos);
+        }
+        self.pos += width;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_encode_matches_wire_format() {
+        let pack = PuzzlePack {
+            schema_version: 1,
+            engine_version: String::new(),
+            puzzles: vec![create_test_puzzle(&["ab", "cb", "cd"])],
+        };
+
+        // Hand-encoded: schema_version = 1, then one puzzle with start "ab",
+        // end "cd", path ["ab", "cb", "cd"], difficulty EASY, and 2 steps
+        let puzzle = [
+            0x0a, 2, b'a', b'b', 0x12, 2, b'c', b'd', 0x1a, 2, b'a', b'b', 0x1a, 2, b'c', b'b',
+            0x1a, 2, b'c', b'd', 0x20, 1, 0x28, 2,
+        ];
+        let mut expected = vec![0x08, 1, 0x1a, puzzle.len() as u8];
+        expected.extend_from_slice(&puzzle);
+        assert_eq!(pack.encode(), expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut checked = create_test_puzzle(&["cat", "cot", "cog", "dog"]);
+        checked.unique_solution = Some(false);
+        checked.reverse_path = Some(
+            ["dog", "dag", "cag", "cat"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        );
+        checked.meta = Some(PuzzleMeta {
+            created_at: 1_700_000_000,
+            engine_version: "0.1.0".to_string(),
+            dictionary_sha256: "ab12".to_string(),
+            seed: Some(0),
+        });
+        let mut unique = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        unique.unique_solution = Some(true);
+        let pack = PuzzlePack::new(vec![checked, unique]);
+
+        assert_eq!(PuzzlePack::decode(&pack.encode()).unwrap(), pack);
+        assert_eq!(
+            PuzzlePack::decode(&PuzzlePack::new(Vec::new()).encode()).unwrap(),
+            PuzzlePack::new(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_decode_errors_and_unknown_fields() {
+        let bytes = PuzzlePack::new(vec![create_test_puzzle(&["cat", "cot", "cog"])]).encode();
+        for len in 0..bytes.len() {
+            // Every proper prefix either ends between fields or is truncated
+            if let Ok(pack) = PuzzlePack::decode(&bytes[..len]) {
+                assert!(pack.puzzles.is_empty());
+            }
+        }
+
+        // Fields from a newer schema are skipped
+        let mut newer = bytes.clone();
+        newer.extend_from_slice(&[
+            0x20, 0x96, 0x01, 0x2a, 1, b'x', 0x31, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]);
+        assert_eq!(PuzzlePack::decode(&newer).unwrap().puzzles.len(), 1);
+
+        assert!(PuzzlePack::decode(&[0x1a, 0]).is_err()); // puzzle without a path
+        assert!(PuzzlePack::decode(&[0x0b]).is_err()); // unsupported wire type
+        assert!(SCHEMA.contains("message PuzzlePack"));
+    }
+}
@@ -0,0 +1,157 @@
+//! # Pack Signing Module
+//!
+//! This module signs exported files with ed25519 so clients can detect
+//! tampered or corrupted puzzle packs after download. Signatures are detached:
+//! the signature of `output/batch_medium.sql` is written as lowercase hex to
+//! `output/batch_medium.sql.sig`, leaving the export itself untouched.
+//!
+//! Keys are stored as 64 hex characters: the 32-byte secret seed for signing
+//! keys, and the 32-byte public key for verifying keys.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::signing::{generate_key, sign, verify};
+//!
+//! let key = generate_key();
+//! let pack = b"INSERT INTO puzzles VALUES ('cat_dog_001', 'cat', 'dog', 3, 'easy');";
+//!
+//! let signature = sign(pack, &key);
+//! assert!(verify(pack, &signature, &key.verifying_key()).is_ok());
+//! assert!(verify(b"tampered", &signature, &key.verifying_key()).is_err());
+//! ```
+
+use super::hex;
+use anyhow::{Result, anyhow};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use std::path::{Path, PathBuf};
+
+/// Extension appended to an export's file name for its detached signature.
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+/// Generates a new random signing key.
+pub fn generate_key() -> SigningKey {
+    SigningKey::from_bytes(&rand::random::<[u8; 32]>())
+}
+
+/// Parses a signing key from the hex encoding of its secret seed.
+///
+/// # Arguments
+///
+/// * `key` - 64 hex characters; surrounding whitespace is ignored
+pub fn signing_key_from_hex(key: &str) -> Result<SigningKey> {
+    Ok(SigningKey::from_bytes(&hex::decode_array(key.trim())?))
+}
+
+/// Parses a verifying (public) key from hex.
+///
+/// # Arguments
+///
+/// * `key` - 64 hex characters; surrounding whitespace is ignored
+///
+/// # Returns
+///
+/// The key, or an error if the hex is malformed or not a valid curve point.
+pub fn verifying_key_from_hex(key: &str) -> Result<VerifyingKey> {
+    VerifyingKey::from_bytes(&hex::decode_array(key.trim())?)
+        .map_err(|e| anyhow!("Invalid public key: {}", e))
+}
+
+/// Signs the contents of an export file.
+///
+/// # Arguments
+///
+/// * `bytes` - Contents of the exported file
+/// * `key` - The signing key
+///
+/// # Returns
+///
+/// The detached signature as lowercase hex.
+pub fn sign(bytes: &[u8], key: &SigningKey) -> String {
+    hex::encode(&key.sign(bytes).to_bytes())
+}
+
+/// Checks a detached signature against the contents of an export file.
+///
+/// # Arguments
+///
+/// * `bytes` - Contents of the exported file
+/// * `signature` - The hex signature written by `sign`; surrounding whitespace is ignored
+/// * `key` - The verifying key of the signer
+///
+/// # Returns
+///
+/// `Ok(())` if the signature is valid, or an error describing why it is not.
+pub fn verify(bytes: &[u8], signature: &str, key: &VerifyingKey) -> Result<()> {
+    let signature = signature.trim();
+    if signature.len() != 128 || !signature.is_ascii() {
+        return Err(anyhow!("Signature must be 128 hex characters"));
+    }
+    let raw = hex::decode_array(signature)?;
+    key.verify(bytes, &Signature::from_bytes(&raw))
+        .map_err(|_| anyhow!("Signature does not match the file contents"))
+}
+
+/// Returns the sidecar path of the detached signature for an export file.
+///
+/// # Arguments
+///
+/// * `output_path` - Path of the exported file
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::Path;
+/// use wordladder_engine::exporters::signing::signature_path;
+///
+/// let sidecar = signature_path(Path::new("output/puzzles.sql"));
+/// assert_eq!(sidecar, Path::new("output/puzzles.sql.sig"));
+/// ```
+pub fn signature_path(output_path: &Path) -> PathBuf {
+    let mut file_name = output_path.as_os_str().to_os_string();
+    file_name.push(".");
+    file_name.push(SIGNATURE_EXTENSION);
+    PathBuf::from(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = signing_key_from_hex(&"01".repeat(32)).unwrap();
+        let public = verifying_key_from_hex(&hex::encode(key.verifying_key().as_bytes())).unwrap();
+        let signature = sign(b"cat cot cog dog", &key);
+
+        assert_eq!(signature.len(), 128);
+        assert!(verify(b"cat cot cog dog", &format!("{}\n", signature), &public).is_ok());
+        assert!(verify(b"cat cot dot dog", &signature, &public).is_err());
+        assert!(
+            verify(
+                b"cat cot cog dog",
+                &signature,
+                &generate_key().verifying_key()
+            )
+            .is_err()
+        );
+        assert!(verify(b"cat cot cog dog", &signature[..64], &public).is_err());
+        // 128 bytes with a multi-byte character straddling the midpoint
+        let tampered = format!("{}é{}", &signature[..63], &signature[65..]);
+        assert_eq!(tampered.len(), 128);
+        assert!(verify(b"cat cot cog dog", &tampered, &public).is_err());
+    }
+
+    #[test]
+    fn test_key_hex_errors() {
+        assert!(signing_key_from_hex("abc").is_err());
+        assert!(signing_key_from_hex(&"zz".repeat(32)).is_err());
+        let key = generate_key();
+        assert_eq!(
+            signing_key_from_hex(&hex::encode(&key.to_bytes()))
+                .unwrap()
+                .to_bytes(),
+            key.to_bytes()
+        );
+    }
+}
@@ -7,8 +7,23 @@
 //!
 //! - **SQL Generation**: Creates valid SQLite-compatible INSERT statements
 //! - **Batch Processing**: Groups INSERTs for optimal performance
-//! - **ID Generation**: Creates unique puzzle IDs in word1_word2_counter format
-//! - **Schema Creation**: Optional CREATE TABLE statements
+//! - **ID Generation**: Creates unique puzzle IDs in word1_word2_counter format, or
+//!   stable word1_word2_hash IDs that stay the same across runs
+//! - **Schema Creation**: Optional CREATE TABLE statements, or versioned
+//!   ALTER TABLE migrations for tables created by an older release
+//! - **Clues**: Optional solution and masked clue columns for print editions
+//! - **Uniqueness**: Optional column flagging puzzles with a single shortest solution
+//! - **Star Ratings**: Optional par and two-/three-star threshold columns
+//! - **Canonical Hashes**: Optional `canonical_hash` column for deduplicating
+//!   puzzles server-side (see `Puzzle::canonical_hash`)
+//! - **Steps Table**: Optional normalized `puzzle_steps` table with one row per ladder word
+//! - **Solution Masking**: Optional `omit_solutions` mode writing only the
+//!   ID, endpoints, step count, and difficulty, for competitive clients
+//! - **Solution Encryption**: Optional `encrypted_solution` column with each
+//!   ladder XChaCha20-encrypted for offline answer checking (feature `encrypt`)
+//! - **Reading Exports**: `parse_puzzle_rows` reads the puzzles back from a
+//!   SQL export (or a SQLite database with the `sqlite` feature) for checks
+//! - **Word Metadata**: Optional frequency rank and base word columns in dictionary exports
 //! - **SQL Injection Prevention**: Proper escaping of string values
 //!
 //! ## Usage
@@ -27,10 +42,203 @@
 //! std::fs::write("puzzles.sql", sql).unwrap();
 //! ```
 
-use crate::puzzle::{Difficulty, Puzzle};
-use anyhow::Result;
+#[cfg(feature = "encrypt")]
+use crate::exporters::encryption::{self, SolutionKey};
+use crate::graph::ComparisonUnit;
+use crate::puzzle::{CanonicalHashOptions, Difficulty, Puzzle};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::collections::HashSet;
+#[cfg(feature = "sqlite")]
+use std::path::Path;
+
+/// How puzzle IDs are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// `start_end_001`, numbered per exporter instance. IDs from separate runs
+    /// can collide while referring to different paths.
+    #[default]
+    Counter,
+    /// `start_end_<hash>`, where the hash is the first 12 hex digits of the
+    /// puzzle's default `Puzzle::canonical_hash` (of the start word, end word,
+    /// and path). The same puzzle gets the same ID in every run; a puzzle
+    /// repeated within one export gets `-2`, `-3`, ... appended to the hash of
+    /// its later copies so the IDs stay unique.
+    ContentHash,
+}
+
+/// Version of the puzzles table layout.
+///
+/// Tables shipped by an older release can be upgraded in place with
+/// `SchemaMode::MigrateFrom` instead of being recreated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaVersion {
+    /// The original table: `id`, `start_word`, `target_word`, `min_steps`, `difficulty`
+    V1,
+    /// Adds the clue, uniqueness, and puzzle metadata columns
+    V2,
+    /// Adds the `reverse_path` column for mirror puzzles
+    V3,
+    /// Adds the `par`, `two_stars`, and `three_stars` columns for star ratings
+    V4,
+    /// Adds the `canonical_hash` column for deduplicating puzzles
+    V5,
+    /// Adds the `encrypted_solution` column for encrypted ladders
+    V6,
+}
+
+impl SchemaVersion {
+    /// The newest schema version.
+    pub const CURRENT: SchemaVersion = SchemaVersion::V6;
+
+    /// Every schema version, oldest first.
+    const ALL: [SchemaVersion; 6] = [
+        SchemaVersion::V1,
+        SchemaVersion::V2,
+        SchemaVersion::V3,
+        SchemaVersion::V4,
+        SchemaVersion::V5,
+        SchemaVersion::V6,
+    ];
+
+    /// Returns the version number, e.g. 2 for `V2`.
+    pub fn number(self) -> u32 {
+        match self {
+            SchemaVersion::V1 => 1,
+            SchemaVersion::V2 => 2,
+            SchemaVersion::V3 => 3,
+            SchemaVersion::V4 => 4,
+            SchemaVersion::V5 => 5,
+            SchemaVersion::V6 => 6,
+        }
+    }
+
+    /// Returns the columns (name and SQL type) this version added to the puzzles table.
+    ///
+    /// Added columns are nullable, since SQLite cannot add a `NOT NULL` column
+    /// without a default to a table that already has rows.
+    fn added_columns(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            SchemaVersion::V1 => &[],
+            SchemaVersion::V2 => &[
+                ("solution", "TEXT"),
+                ("clues", "TEXT"),
+                ("unique_solution", "INTEGER"),
+                ("created_at", "INTEGER"),
+                ("engine_version", "TEXT"),
+                ("dictionary_sha256", "TEXT"),
+                ("seed", "INTEGER"),
+            ],
+            SchemaVersion::V3 => &[("reverse_path", "TEXT")],
+            SchemaVersion::V4 => &[
+                ("par", "INTEGER"),
+                ("two_stars", "INTEGER"),
+                ("three_stars", "INTEGER"),
+            ],
+            SchemaVersion::V5 => &[("canonical_hash", "TEXT")],
+            SchemaVersion::V6 => &[("encrypted_solution", "TEXT")],
+        }
+    }
+}
+
+/// How the schema part of a puzzle export is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaMode {
+    /// A CREATE TABLE statement with the columns selected in `SqlExportConfig`
+    #[default]
+    Full,
+    /// ALTER TABLE statements upgrading a table created with the given version
+    /// to `SchemaVersion::CURRENT`, adding every column introduced since
+    MigrateFrom(SchemaVersion),
+}
+
+/// Optional per-word metadata for dictionary exports.
+///
+/// Each field that is set adds a column (and an index) to the dictionary table,
+/// letting apps tell common words from obscure ones, e.g. for UI hints.
+#[derive(Debug, Clone, Default)]
+pub struct WordMetadata {
+    /// Frequency rank of each word (1 = most common). Adds a `frequency_rank`
+    /// column; words without a rank get NULL.
+    pub frequency_ranks: Option<HashMap<String, usize>>,
+    /// Base words (puzzle endpoints). Adds an `is_base_word` column (1 or 0).
+    pub base_words: Option<HashSet<String>>,
+    /// Unit the `length` column counts, matching the graph the words come from
+    pub comparison_unit: ComparisonUnit,
+}
+
+impl WordMetadata {
+    /// Creates metadata with no extra columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the frequency rank of each word.
+    ///
+    /// # Arguments
+    ///
+    /// * `frequency_ranks` - Rank per word, 1 being the most common
+    pub fn with_frequency_ranks(mut self, frequency_ranks: HashMap<String, usize>) -> Self {
+        self.frequency_ranks = Some(frequency_ranks);
+        self
+    }
+
+    /// Sets the base words to flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_words` - Words used as puzzle endpoints
+    pub fn with_base_words(mut self, base_words: HashSet<String>) -> Self {
+        self.base_words = Some(base_words);
+        self
+    }
+
+    /// Sets the unit the `length` column counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `comparison_unit` - The word graph's `comparison_unit()`
+    pub fn with_comparison_unit(mut self, comparison_unit: ComparisonUnit) -> Self {
+        self.comparison_unit = comparison_unit;
+        self
+    }
+
+    /// Parses a frequency list into ranks.
+    ///
+    /// The list has one word per line, most common first; anything after the
+    /// first whitespace-separated token (such as a count) is ignored, so both
+    /// plain ranked lists and `word count` files work. A word listed twice
+    /// keeps its first (best) rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Frequency list text
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::WordMetadata;
+    ///
+    /// let ranks = WordMetadata::parse_frequency_list("the 5000\nCat 120\n\ndog 95\n");
+    /// assert_eq!(ranks["the"], 1);
+    /// assert_eq!(ranks["cat"], 2);
+    /// assert_eq!(ranks["dog"], 3);
+    /// ```
+    pub fn parse_frequency_list(content: &str) -> HashMap<String, usize> {
+        let mut ranks = HashMap::new();
+        let words = content
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_lowercase);
+        for (i, word) in words.enumerate() {
+            ranks.entry(word).or_insert(i + 1);
+        }
+        ranks
+    }
+}
 
 /// Configuration for SQL export functionality.
 ///
@@ -42,8 +250,40 @@ pub struct SqlExportConfig {
     pub batch_size: usize,
     /// Whether to include CREATE TABLE statement at the beginning
     pub include_schema: bool,
+    /// Whether the schema is created from scratch or migrated from an older version
+    pub schema_mode: SchemaMode,
     /// Whether to include comments in the SQL output
     pub include_comments: bool,
+    /// Whether to include `solution` and `clues` columns (see `Puzzle::to_clue_format`)
+    pub include_clues: bool,
+    /// Whether to include a `unique_solution` column (1, 0, or NULL if unchecked)
+    pub include_unique_solution: bool,
+    /// Whether to include `created_at`, `engine_version`, `dictionary_sha256`,
+    /// and `seed` columns from each puzzle's `PuzzleMeta` (NULL if absent)
+    pub include_meta: bool,
+    /// Whether to include a `reverse_path` column with each puzzle's
+    /// comma-separated reverse ladder (NULL if it has none)
+    pub include_reverse_path: bool,
+    /// Whether to include `par`, `two_stars`, and `three_stars` columns from
+    /// each puzzle's `StarRating` (NULL if absent)
+    pub include_stars: bool,
+    /// Whether to also emit a `puzzle_steps` table with one
+    /// `(puzzle_id, step_index, word)` row per word of each ladder
+    pub include_steps_table: bool,
+    /// Options of a `canonical_hash` column holding each puzzle's
+    /// `Puzzle::canonical_hash`, or `None` for no such column
+    pub canonical_hash: Option<CanonicalHashOptions>,
+    /// How puzzle IDs are generated
+    pub id_strategy: IdStrategy,
+    /// Whether to write only the `id`, `start_word`, `target_word`,
+    /// `min_steps`, and `difficulty` columns, overriding every optional
+    /// column and the `puzzle_steps` table so no ladder reaches the client
+    pub omit_solutions: bool,
+    /// Master key for an `encrypted_solution` column holding each ladder
+    /// encrypted under a key derived from the puzzle ID (see the `encryption`
+    /// module). Like `omit_solutions`, it turns off every other optional column.
+    #[cfg(feature = "encrypt")]
+    pub solution_key: Option<SolutionKey>,
 }
 
 impl Default for SqlExportConfig {
@@ -51,7 +291,19 @@ impl Default for SqlExportConfig {
         Self {
             batch_size: 100,
             include_schema: true,
+            schema_mode: SchemaMode::Full,
             include_comments: true,
+            include_clues: false,
+            include_unique_solution: false,
+            include_meta: false,
+            include_reverse_path: false,
+            include_stars: false,
+            include_steps_table: false,
+            canonical_hash: None,
+            id_strategy: IdStrategy::Counter,
+            omit_solutions: false,
+            #[cfg(feature = "encrypt")]
+            solution_key: None,
         }
     }
 }
@@ -92,12 +344,24 @@ impl SqlExporter {
     /// # Examples
     ///
     /// ```rust
-    /// use wordladder_engine::exporters::sql::{SqlExporter, SqlExportConfig};
+    /// use wordladder_engine::exporters::sql::{IdStrategy, SchemaMode, SqlExporter, SqlExportConfig};
     ///
     /// let config = SqlExportConfig {
     ///     batch_size: 50,
     ///     include_schema: false,
+    ///     schema_mode: SchemaMode::Full,
     ///     include_comments: true,
+    ///     include_clues: false,
+    ///     include_unique_solution: false,
+    ///     include_meta: false,
+    ///     include_reverse_path: false,
+    ///     include_stars: false,
+    ///     include_steps_table: false,
+    ///     canonical_hash: None,
+    ///     id_strategy: IdStrategy::Counter,
+    ///     omit_solutions: false,
+    ///     # #[cfg(feature = "encrypt")]
+    ///     # solution_key: None,
     /// };
     /// let exporter = SqlExporter::with_config(config);
     /// ```
@@ -144,10 +408,254 @@ impl SqlExporter {
         self
     }
 
+    /// Sets whether to include the solution path and masked clues.
+    ///
+    /// When enabled, the puzzles table gains `solution` and `clues` columns,
+    /// each holding comma-separated words.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_clues` - Whether to export solution and clue columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_clues(true);
+    /// ```
+    pub fn with_include_clues(mut self, include_clues: bool) -> Self {
+        self.config.include_clues = include_clues;
+        self
+    }
+
+    /// Sets whether to include the `unique_solution` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_unique_solution` - Whether to export each puzzle's uniqueness flag
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_unique_solution(true);
+    /// ```
+    pub fn with_include_unique_solution(mut self, include_unique_solution: bool) -> Self {
+        self.config.include_unique_solution = include_unique_solution;
+        self
+    }
+
+    /// Sets whether to include the `reverse_path` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_reverse_path` - Whether to export each puzzle's reverse ladder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_reverse_path(true);
+    /// ```
+    pub fn with_include_reverse_path(mut self, include_reverse_path: bool) -> Self {
+        self.config.include_reverse_path = include_reverse_path;
+        self
+    }
+
+    /// Sets whether to include the `par`, `two_stars`, and `three_stars` columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_stars` - Whether to export each puzzle's `StarRating`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_stars(true);
+    /// ```
+    pub fn with_include_stars(mut self, include_stars: bool) -> Self {
+        self.config.include_stars = include_stars;
+        self
+    }
+
+    /// Sets whether the schema is created from scratch or migrated.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_mode` - `SchemaMode::Full` for CREATE TABLE, or
+    ///   `SchemaMode::MigrateFrom` for ALTER TABLE statements
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::{SchemaMode, SchemaVersion, SqlExporter};
+    ///
+    /// let mut exporter =
+    ///     SqlExporter::new().with_schema_mode(SchemaMode::MigrateFrom(SchemaVersion::V1));
+    /// let sql = exporter.export_puzzles(&[]).unwrap();
+    /// assert!(sql.contains("ALTER TABLE puzzles ADD COLUMN solution TEXT;"));
+    /// ```
+    pub fn with_schema_mode(mut self, schema_mode: SchemaMode) -> Self {
+        self.config.schema_mode = schema_mode;
+        self
+    }
+
+    /// Sets whether to include the puzzle metadata columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_meta` - Whether to export each puzzle's `PuzzleMeta`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_meta(true);
+    /// ```
+    pub fn with_include_meta(mut self, include_meta: bool) -> Self {
+        self.config.include_meta = include_meta;
+        self
+    }
+
+    /// Sets whether to emit the normalized `puzzle_steps` table.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_steps_table` - Whether to export one row per ladder word
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new().with_include_steps_table(true);
+    /// ```
+    pub fn with_include_steps_table(mut self, include_steps_table: bool) -> Self {
+        self.config.include_steps_table = include_steps_table;
+        self
+    }
+
+    /// Sets whether to export a `canonical_hash` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Options passed to `Puzzle::canonical_hash`, or `None` for no column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    /// use wordladder_engine::puzzle::CanonicalHashOptions;
+    ///
+    /// let exporter = SqlExporter::new().with_canonical_hash(Some(CanonicalHashOptions {
+    ///     unordered: true,
+    ///     path_independent: true,
+    /// }));
+    /// ```
+    pub fn with_canonical_hash(mut self, options: Option<CanonicalHashOptions>) -> Self {
+        self.config.canonical_hash = options;
+        self
+    }
+
+    /// Sets whether solutions are kept out of the export.
+    ///
+    /// When enabled, only the `id`, `start_word`, `target_word`, `min_steps`,
+    /// and `difficulty` columns are written, whatever other columns or the
+    /// `puzzle_steps` table were requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `omit_solutions` - Whether to strip paths and every other optional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let exporter = SqlExporter::new()
+    ///     .with_include_clues(true)
+    ///     .with_omit_solutions(true);
+    /// ```
+    pub fn with_omit_solutions(mut self, omit_solutions: bool) -> Self {
+        self.config.omit_solutions = omit_solutions;
+        self
+    }
+
+    /// Sets the master key for encrypting solutions.
+    ///
+    /// Only the base columns and an `encrypted_solution` column are written,
+    /// whatever other columns or the `puzzle_steps` table were requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Master key from which each puzzle's key is derived
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::encryption::SolutionKey;
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// let key = SolutionKey::from_hex(&"2a".repeat(32)).unwrap();
+    /// let exporter = SqlExporter::new().with_solution_key(key);
+    /// ```
+    #[cfg(feature = "encrypt")]
+    pub fn with_solution_key(mut self, key: SolutionKey) -> Self {
+        self.config.solution_key = Some(key);
+        self
+    }
+
+    /// Sets how puzzle IDs are generated.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_strategy` - Per-run counters or stable content hashes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::{IdStrategy, SqlExporter};
+    ///
+    /// let exporter = SqlExporter::new().with_id_strategy(IdStrategy::ContentHash);
+    /// ```
+    pub fn with_id_strategy(mut self, id_strategy: IdStrategy) -> Self {
+        self.config.id_strategy = id_strategy;
+        self
+    }
+
+    /// Seeds the per-pair ID counters, so counter IDs continue after existing ones.
+    ///
+    /// Used when appending to an existing export (see `ExistingPuzzles::id_counters`).
+    ///
+    /// # Arguments
+    ///
+    /// * `counters` - Highest counter already used for each `start_end` key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    ///
+    /// // The next cat -> dog puzzle gets the ID cat_dog_003
+    /// let counters = HashMap::from([("cat_dog".to_string(), 2)]);
+    /// let exporter = SqlExporter::new().with_id_counters(counters);
+    /// ```
+    pub fn with_id_counters(mut self, counters: HashMap<String, usize>) -> Self {
+        self.id_counter = counters;
+        self
+    }
+
     /// Exports a collection of puzzles to SQL format.
     ///
     /// This method generates a complete SQL script containing:
-    /// 1. Optional CREATE TABLE statement
+    /// 1. Optional CREATE TABLE statement (or migration, see `SchemaMode`)
     /// 2. Batched INSERT statements for all puzzles
     ///
     /// # Arguments
@@ -169,13 +677,7 @@ impl SqlExporter {
     /// let sql = exporter.export_puzzles(&puzzles).unwrap();
     /// ```
     pub fn export_puzzles(&mut self, puzzles: &[Puzzle]) -> Result<String> {
-        let mut sql = String::new();
-
-        // Add schema if requested
-        if self.config.include_schema {
-            sql.push_str(&self.generate_schema());
-            sql.push('\n');
-        }
+        let mut sql = self.export_header();
 
         // Add comments if requested
         if self.config.include_comments {
@@ -185,19 +687,103 @@ impl SqlExporter {
 
         // Generate INSERT statements in batches
         for chunk in puzzles.chunks(self.config.batch_size) {
-            sql.push_str(&self.generate_batch_insert(chunk));
-            sql.push('\n');
+            sql.push_str(&self.export_batch(chunk));
         }
 
         Ok(sql)
     }
 
+    /// Returns the schema (or migration) that precedes the INSERT statements.
+    ///
+    /// Empty unless `include_schema` is set. Together with `export_batch`, this
+    /// lets a caller write an export piece by piece as puzzles are generated.
+    pub fn export_header(&self) -> String {
+        let config = self.effective_config();
+        let mut sql = String::new();
+        if config.include_schema {
+            match config.schema_mode {
+                SchemaMode::Full => sql.push_str(&self.generate_schema()),
+                SchemaMode::MigrateFrom(from) => sql.push_str(&self.generate_migration(from)),
+            }
+            sql.push('\n');
+            if config.include_steps_table {
+                sql.push_str(&self.generate_steps_schema());
+                sql.push('\n');
+            }
+        }
+        sql
+    }
+
+    /// Returns one batched INSERT statement (and its `puzzle_steps` rows, if
+    /// enabled) for a chunk of puzzles.
+    ///
+    /// IDs continue from earlier calls on the same exporter. The chunk is not
+    /// split, so callers should pass at most `batch_size()` puzzles.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to insert
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::sql::SqlExporter;
+    /// use wordladder_engine::puzzle::Puzzle;
+    ///
+    /// let mut exporter = SqlExporter::new();
+    /// let puzzle = Puzzle::new("cat".into(), "dog".into(), vec![
+    ///     "cat".into(), "cot".into(), "cog".into(), "dog".into(),
+    /// ]).unwrap();
+    ///
+    /// let first = exporter.export_batch(std::slice::from_ref(&puzzle));
+    /// let second = exporter.export_batch(std::slice::from_ref(&puzzle));
+    /// assert!(first.contains("'cat_dog_001'"));
+    /// assert!(second.contains("'cat_dog_002'"));
+    /// ```
+    pub fn export_batch(&mut self, puzzles: &[Puzzle]) -> String {
+        if puzzles.is_empty() {
+            return String::new();
+        }
+        let mut sql = self.generate_batch_insert(puzzles);
+        sql.push('\n');
+        sql
+    }
+
+    /// Returns the number of puzzles per INSERT statement.
+    pub fn batch_size(&self) -> usize {
+        self.config.batch_size
+    }
+
+    /// Returns the configuration in effect: with `omit_solutions` or a
+    /// solution key, every optional column and the `puzzle_steps` table are
+    /// turned off, except a path-independent `canonical_hash`.
+    fn effective_config(&self) -> SqlExportConfig {
+        let mut config = self.config.clone();
+        #[cfg(feature = "encrypt")]
+        let strip = config.omit_solutions || config.solution_key.is_some();
+        #[cfg(not(feature = "encrypt"))]
+        let strip = config.omit_solutions;
+        if strip {
+            config.include_clues = false;
+            config.include_unique_solution = false;
+            config.include_meta = false;
+            config.include_reverse_path = false;
+            config.include_stars = false;
+            config.include_steps_table = false;
+            config.canonical_hash = config
+                .canonical_hash
+                .filter(|options| options.path_independent);
+        }
+        config
+    }
+
     /// Generates the CREATE TABLE statement for the puzzles table.
     ///
     /// # Returns
     ///
     /// A string containing the CREATE TABLE SQL statement.
     fn generate_schema(&self) -> String {
+        let config = self.effective_config();
         let mut schema = String::from(
             "-- Create puzzles table\n\
              CREATE TABLE IF NOT EXISTS puzzles (\n\
@@ -205,22 +791,112 @@ impl SqlExporter {
              \tstart_word TEXT NOT NULL,\n\
              \ttarget_word TEXT NOT NULL,\n\
              \tmin_steps INTEGER NOT NULL,\n\
-             \tdifficulty TEXT NOT NULL\n\
-             );",
+             \tdifficulty TEXT NOT NULL",
         );
+        if config.include_clues {
+            schema.push_str(",\n\tsolution TEXT NOT NULL,\n\tclues TEXT NOT NULL");
+        }
+        if config.include_unique_solution {
+            schema.push_str(",\n\tunique_solution INTEGER");
+        }
+        if config.include_meta {
+            schema.push_str(
+                ",\n\tcreated_at INTEGER,\n\tengine_version TEXT,\n\tdictionary_sha256 TEXT,\n\tseed INTEGER",
+            );
+        }
+        if config.include_reverse_path {
+            schema.push_str(",\n\treverse_path TEXT");
+        }
+        if config.include_stars {
+            schema.push_str(",\n\tpar INTEGER,\n\ttwo_stars INTEGER,\n\tthree_stars INTEGER");
+        }
+        if config.canonical_hash.is_some() {
+            schema.push_str(",\n\tcanonical_hash TEXT NOT NULL");
+        }
+        #[cfg(feature = "encrypt")]
+        if config.solution_key.is_some() {
+            schema.push_str(",\n\tencrypted_solution TEXT NOT NULL");
+        }
+        schema.push_str("\n);");
 
-        if self.config.include_comments {
+        if config.include_comments {
             schema.push_str("\n\n-- Indexes for better query performance\n");
             schema.push_str(
                 "CREATE INDEX IF NOT EXISTS idx_puzzles_difficulty ON puzzles(difficulty);\n",
             );
             schema
                 .push_str("CREATE INDEX IF NOT EXISTS idx_puzzles_steps ON puzzles(min_steps);\n");
+            if config.canonical_hash.is_some() {
+                schema.push_str(
+                    "CREATE INDEX IF NOT EXISTS idx_puzzles_canonical_hash ON puzzles(canonical_hash);\n",
+                );
+            }
         }
 
         schema
     }
 
+    /// Generates the CREATE TABLE statement for the `puzzle_steps` table.
+    ///
+    /// Each row references its puzzle by `puzzle_id`; rows are removed along
+    /// with their puzzle, and an index on `word` supports per-word analytics.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CREATE TABLE and CREATE INDEX statements.
+    fn generate_steps_schema(&self) -> String {
+        let mut schema = String::new();
+        if self.config.include_comments {
+            schema.push_str("-- Create puzzle steps table\n");
+        }
+        schema.push_str(
+            "CREATE TABLE IF NOT EXISTS puzzle_steps (\n\
+             \tpuzzle_id TEXT NOT NULL REFERENCES puzzles(id) ON DELETE CASCADE,\n\
+             \tstep_index INTEGER NOT NULL,\n\
+             \tword TEXT NOT NULL,\n\
+             \tPRIMARY KEY (puzzle_id, step_index)\n\
+             );\n",
+        );
+        schema
+            .push_str("CREATE INDEX IF NOT EXISTS idx_puzzle_steps_word ON puzzle_steps(word);\n");
+        schema
+    }
+
+    /// Generates ALTER TABLE statements upgrading the puzzles table to the current version.
+    ///
+    /// Each version after `from` contributes one ADD COLUMN statement per
+    /// column it introduced, oldest version first.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Schema version the existing table was created with
+    ///
+    /// # Returns
+    ///
+    /// A string containing the migration script.
+    fn generate_migration(&self, from: SchemaVersion) -> String {
+        let mut migration = String::new();
+        if self.config.include_comments {
+            migration.push_str(&format!(
+                "-- Migrate puzzles table from schema v{} to v{}\n",
+                from.number(),
+                SchemaVersion::CURRENT.number()
+            ));
+        }
+        for version in SchemaVersion::ALL.into_iter().filter(|v| *v > from) {
+            if self.config.include_comments {
+                migration.push_str(&format!("-- v{}\n", version.number()));
+            }
+            for (column, sql_type) in version.added_columns() {
+                migration.push_str(&format!(
+                    "ALTER TABLE puzzles ADD COLUMN {} {};\n",
+                    column, sql_type
+                ));
+            }
+        }
+        migration
+    }
+
     /// Generates a batched INSERT statement for a chunk of puzzles.
     ///
     /// # Arguments
@@ -235,21 +911,100 @@ impl SqlExporter {
             return String::new();
         }
 
-        let mut sql = String::from(
-            "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES\n",
-        );
+        let config = self.effective_config();
+        let mut columns = String::from("id, start_word, target_word, min_steps, difficulty");
+        if config.include_clues {
+            columns.push_str(", solution, clues");
+        }
+        if config.include_unique_solution {
+            columns.push_str(", unique_solution");
+        }
+        if config.include_meta {
+            columns.push_str(", created_at, engine_version, dictionary_sha256, seed");
+        }
+        if config.include_reverse_path {
+            columns.push_str(", reverse_path");
+        }
+        if config.include_stars {
+            columns.push_str(", par, two_stars, three_stars");
+        }
+        if config.canonical_hash.is_some() {
+            columns.push_str(", canonical_hash");
+        }
+        #[cfg(feature = "encrypt")]
+        if config.solution_key.is_some() {
+            columns.push_str(", encrypted_solution");
+        }
+        let mut sql = format!("INSERT INTO puzzles ({}) VALUES\n", columns);
+        let mut ids = Vec::with_capacity(puzzles.len());
 
         for (i, puzzle) in puzzles.iter().enumerate() {
             let id = self.generate_puzzle_id(puzzle);
+            ids.push(id.clone());
             let start_word = self.escape_sql_string(&puzzle.start);
             let target_word = self.escape_sql_string(&puzzle.end);
             let min_steps = puzzle.path.len() - 1; // number of steps
             let difficulty = self.difficulty_to_string(puzzle.difficulty);
 
             sql.push_str(&format!(
-                "\t('{}', '{}', '{}', {}, '{}')",
+                "\t('{}', '{}', '{}', {}, '{}'",
                 id, start_word, target_word, min_steps, difficulty
             ));
+            if config.include_clues {
+                let solution = self.escape_sql_string(&puzzle.path.join(","));
+                let clues = self.escape_sql_string(&puzzle.to_clue_format().join(","));
+                sql.push_str(&format!(", '{}', '{}'", solution, clues));
+            }
+            if config.include_unique_solution {
+                let unique = match puzzle.unique_solution {
+                    Some(true) => "1",
+                    Some(false) => "0",
+                    None => "NULL",
+                };
+                sql.push_str(&format!(", {}", unique));
+            }
+            if config.include_meta {
+                match &puzzle.meta {
+                    Some(meta) => sql.push_str(&format!(
+                        ", {}, '{}', '{}', {}",
+                        meta.created_at,
+                        self.escape_sql_string(&meta.engine_version),
+                        self.escape_sql_string(&meta.dictionary_sha256),
+                        meta.seed
+                            .map_or("NULL".to_string(), |seed| seed.to_string())
+                    )),
+                    None => sql.push_str(", NULL, NULL, NULL, NULL"),
+                }
+            }
+            if config.include_reverse_path {
+                match &puzzle.reverse_path {
+                    Some(reverse) => sql.push_str(&format!(
+                        ", '{}'",
+                        self.escape_sql_string(&reverse.join(","))
+                    )),
+                    None => sql.push_str(", NULL"),
+                }
+            }
+            if config.include_stars {
+                match &puzzle.stars {
+                    Some(stars) => sql.push_str(&format!(
+                        ", {}, {}, {}",
+                        stars.par, stars.two_stars, stars.three_stars
+                    )),
+                    None => sql.push_str(", NULL, NULL, NULL"),
+                }
+            }
+            if let Some(options) = config.canonical_hash {
+                sql.push_str(&format!(", '{}'", puzzle.canonical_hash(options)));
+            }
+            #[cfg(feature = "encrypt")]
+            if let Some(key) = &config.solution_key {
+                sql.push_str(&format!(
+                    ", '{}'",
+                    encryption::encrypt_solution(&puzzle.path, &id, key)
+                ));
+            }
+            sql.push(')');
 
             if i < puzzles.len() - 1 {
                 sql.push_str(",\n");
@@ -258,10 +1013,44 @@ impl SqlExporter {
             }
         }
 
+        if config.include_steps_table {
+            sql.push('\n');
+            sql.push_str(&self.generate_steps_insert(puzzles, &ids));
+        }
+
         sql
     }
 
-    /// Generates a unique ID for a puzzle in the format word1_word2_counter.
+    /// Generates a batched INSERT statement for the `puzzle_steps` rows of a chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Slice of puzzles to insert
+    /// * `ids` - The ID of each puzzle, as used in the puzzles INSERT
+    ///
+    /// # Returns
+    ///
+    /// A string containing the INSERT SQL statement.
+    fn generate_steps_insert(&self, puzzles: &[Puzzle], ids: &[String]) -> String {
+        let rows: Vec<String> = puzzles
+            .iter()
+            .zip(ids)
+            .flat_map(|(puzzle, id)| {
+                puzzle.path.iter().enumerate().map(move |(step, word)| {
+                    format!("\t('{}', {}, '{}')", id, step, self.escape_sql_string(word))
+                })
+            })
+            .collect();
+        format!(
+            "INSERT INTO puzzle_steps (puzzle_id, step_index, word) VALUES\n{};",
+            rows.join(",\n")
+        )
+    }
+
+    /// Generates a unique ID for a puzzle using the configured `IdStrategy`.
+    ///
+    /// Counter IDs have the format word1_word2_counter; content-hash IDs have
+    /// the format word1_word2_hash, plus a copy number for repeated puzzles.
     ///
     /// # Arguments
     ///
@@ -272,9 +1061,24 @@ impl SqlExporter {
     /// A unique string ID for the puzzle.
     fn generate_puzzle_id(&mut self, puzzle: &Puzzle) -> String {
         let base_id = format!("{}_{}", puzzle.start, puzzle.end);
-        let counter = self.id_counter.entry(base_id.clone()).or_insert(0);
-        *counter += 1;
-        format!("{}_{:03}", base_id, counter)
+        match self.config.id_strategy {
+            IdStrategy::Counter => {
+                let counter = self.id_counter.entry(base_id.clone()).or_insert(0);
+                *counter += 1;
+                format!("{}_{:03}", base_id, counter)
+            }
+            IdStrategy::ContentHash => {
+                let hash = puzzle.canonical_hash(CanonicalHashOptions::default());
+                let id = format!("{}_{}", base_id, &hash[..12]);
+                let copies = self.id_counter.entry(id.clone()).or_insert(0);
+                *copies += 1;
+                match *copies {
+                    1 => id,
+                    // Keeps the last `_` separating the pair from the hash
+                    copy => format!("{}-{}", id, copy),
+                }
+            }
+        }
     }
 
     /// Converts a Difficulty enum to its string representation.
@@ -310,136 +1114,43 @@ impl SqlExporter {
         s.replace('\'', "''") // Escape single quotes by doubling them
     }
 
-    /// Exports puzzles with balanced difficulty distribution for mobile apps.
+    /// Exports dictionary words to SQL format for mobile database integration.
     ///
-    /// This method creates a balanced set of puzzles with the specified distribution
-    /// across difficulty levels, optimized for mobile game consumption.
+    /// This method generates SQL statements to create and populate a dictionary table
+    /// with all valid words from the word graph. The table includes an index for
+    /// efficient word lookups (O(log n) vs O(n) for text file scanning).
     ///
     /// # Arguments
     ///
-    /// * `puzzles` - All available puzzles to select from
-    /// * `total_count` - Total number of puzzles to export
-    /// * `easy_ratio` - Ratio of easy puzzles (0.0 to 1.0)
-    /// * `medium_ratio` - Ratio of medium puzzles (0.0 to 1.0)
-    /// * `hard_ratio` - Ratio of hard puzzles (0.0 to 1.0)
+    /// * `words` - The set of dictionary words to export
     ///
     /// # Returns
     ///
-    /// A vector of selected puzzles with balanced difficulty distribution.
+    /// A string containing the complete SQL script for the dictionary table.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use wordladder_engine::exporters::sql::SqlExporter;
-    /// use wordladder_engine::puzzle::{Puzzle, Difficulty};
-    ///
-    /// let exporter = SqlExporter::new();
-    /// let all_puzzles = vec![/* all available puzzles */];
+    /// use std::collections::HashSet;
     ///
-    /// // Create balanced set: 40% easy, 40% medium, 20% hard
-    /// let balanced = exporter.create_balanced_set(&all_puzzles, 1000, 0.4, 0.4, 0.2);
+    /// let mut exporter = SqlExporter::new();
+    /// let words: HashSet<String> = ["cat", "dog", "bat"].iter().map(|s| s.to_string()).collect();
+    /// let sql = exporter.export_dictionary(&words).unwrap();
     /// ```
-    pub fn create_balanced_set(
-        &self,
-        puzzles: &[Puzzle],
-        total_count: usize,
-        easy_ratio: f64,
-        medium_ratio: f64,
-        hard_ratio: f64,
-    ) -> Vec<Puzzle> {
-        // Group puzzles by difficulty
-        let mut easy: Vec<&Puzzle> = puzzles
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Easy))
-            .collect();
-        let mut medium: Vec<&Puzzle> = puzzles
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Medium))
-            .collect();
-        let mut hard: Vec<&Puzzle> = puzzles
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Hard))
-            .collect();
-
-        // Shuffle each group for randomness
-        use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        easy.shuffle(&mut rng);
-        medium.shuffle(&mut rng);
-        hard.shuffle(&mut rng);
-
-        // Calculate counts for each difficulty
-        let easy_count = (total_count as f64 * easy_ratio).round() as usize;
-        let medium_count = (total_count as f64 * medium_ratio).round() as usize;
-        let hard_count = (total_count as f64 * hard_ratio).round() as usize;
-
-        // Adjust for rounding errors
-        let actual_total = easy_count + medium_count + hard_count;
-        let adjustment = total_count as isize - actual_total as isize;
-
-        let (easy_count, medium_count, hard_count) = if adjustment > 0 {
-            // Add extra to medium
-            (easy_count, medium_count + adjustment as usize, hard_count)
-        } else if adjustment < 0 {
-            // Remove from hard if possible
-            if hard_count > 0 {
-                (
-                    easy_count,
-                    medium_count,
-                    hard_count.saturating_sub((-adjustment) as usize),
-                )
-            } else if medium_count > 0 {
-                (
-                    easy_count,
-                    medium_count.saturating_sub((-adjustment) as usize),
-                    hard_count,
-                )
-            } else {
-                (
-                    easy_count.saturating_sub((-adjustment) as usize),
-                    medium_count,
-                    hard_count,
-                )
-            }
-        } else {
-            (easy_count, medium_count, hard_count)
-        };
-
-        // Select puzzles from each group, allowing duplicates if needed
-        let mut selected = Vec::new();
-
-        // Helper function to add puzzles of a specific difficulty
-        let mut add_puzzles = |puzzles_of_type: &Vec<&Puzzle>, count: usize| {
-            for i in 0..count {
-                if !puzzles_of_type.is_empty() {
-                    let index = i % puzzles_of_type.len();
-                    selected.push((*puzzles_of_type[index]).clone());
-                }
-            }
-        };
-
-        add_puzzles(&easy, easy_count);
-        add_puzzles(&medium, medium_count);
-        add_puzzles(&hard, hard_count);
-
-        // If we still don't have enough, fill with any available puzzles
-        while selected.len() < total_count && !puzzles.is_empty() {
-            let index = selected.len() % puzzles.len();
-            selected.push(puzzles[index].clone());
-        }
-
-        selected
+    pub fn export_dictionary(&mut self, words: &HashSet<String>) -> Result<String> {
+        self.export_dictionary_with_metadata(words, &WordMetadata::new())
     }
 
-    /// Exports dictionary words to SQL format for mobile database integration.
+    /// Exports dictionary words with optional per-word metadata columns.
     ///
-    /// This method generates SQL statements to create and populate a dictionary table
-    /// with all valid words from the word graph. The table includes an index for
-    /// efficient word lookups (O(log n) vs O(n) for text file scanning).
+    /// Behaves like `export_dictionary`, adding a `frequency_rank` and/or
+    /// `is_base_word` column (with indexes) for each metadata field that is set.
     ///
     /// # Arguments
     ///
     /// * `words` - The set of dictionary words to export
+    /// * `metadata` - Frequency ranks and base words to include
     ///
     /// # Returns
     ///
@@ -448,19 +1159,27 @@ impl SqlExporter {
     /// # Examples
     ///
     /// ```rust
-    /// use wordladder_engine::exporters::sql::SqlExporter;
+    /// use wordladder_engine::exporters::sql::{SqlExporter, WordMetadata};
     /// use std::collections::HashSet;
     ///
     /// let mut exporter = SqlExporter::new();
-    /// let words: HashSet<String> = ["cat", "dog", "bat"].iter().map(|s| s.to_string()).collect();
-    /// let sql = exporter.export_dictionary(&words).unwrap();
+    /// let words: HashSet<String> = ["cat", "dog", "cwm"].iter().map(|s| s.to_string()).collect();
+    /// let metadata = WordMetadata::new()
+    ///     .with_frequency_ranks(WordMetadata::parse_frequency_list("cat\ndog\n"))
+    ///     .with_base_words(["cat".to_string()].into());
+    /// let sql = exporter.export_dictionary_with_metadata(&words, &metadata).unwrap();
+    /// assert!(sql.contains("('cwm', 3, NULL, 0)"));
     /// ```
-    pub fn export_dictionary(&mut self, words: &HashSet<String>) -> Result<String> {
+    pub fn export_dictionary_with_metadata(
+        &mut self,
+        words: &HashSet<String>,
+        metadata: &WordMetadata,
+    ) -> Result<String> {
         let mut sql = String::new();
 
         // Add schema if requested
         if self.config.include_schema {
-            sql.push_str(&self.generate_dictionary_schema());
+            sql.push_str(&self.generate_dictionary_schema(metadata));
             sql.push('\n');
         }
 
@@ -473,7 +1192,7 @@ impl SqlExporter {
         // Generate INSERT statements in batches
         let word_list: Vec<&String> = words.iter().collect();
         for chunk in word_list.chunks(self.config.batch_size) {
-            sql.push_str(&self.generate_dictionary_batch_insert(chunk));
+            sql.push_str(&self.generate_dictionary_batch_insert(chunk, metadata));
             sql.push('\n');
         }
 
@@ -482,23 +1201,43 @@ impl SqlExporter {
 
     /// Generates the CREATE TABLE statement for the dictionary table.
     ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Metadata deciding which optional columns are present
+    ///
     /// # Returns
     ///
     /// A string containing the CREATE TABLE SQL statement for the dictionary.
-    fn generate_dictionary_schema(&self) -> String {
+    fn generate_dictionary_schema(&self, metadata: &WordMetadata) -> String {
         let mut schema = String::from(
             "-- Create dictionary table\n\
              CREATE TABLE IF NOT EXISTS dictionary (\n\
              \tword TEXT PRIMARY KEY,\n\
-             \tlength INTEGER NOT NULL\n\
-             );",
+             \tlength INTEGER NOT NULL",
         );
+        if metadata.frequency_ranks.is_some() {
+            schema.push_str(",\n\tfrequency_rank INTEGER");
+        }
+        if metadata.base_words.is_some() {
+            schema.push_str(",\n\tis_base_word INTEGER NOT NULL DEFAULT 0");
+        }
+        schema.push_str("\n);");
 
         if self.config.include_comments {
             schema.push_str("\n\n-- Indexes for efficient word lookups\n");
             schema.push_str(
                 "CREATE INDEX IF NOT EXISTS idx_dictionary_length ON dictionary(length);\n",
             );
+            if metadata.frequency_ranks.is_some() {
+                schema.push_str(
+                    "CREATE INDEX IF NOT EXISTS idx_dictionary_frequency ON dictionary(frequency_rank);\n",
+                );
+            }
+            if metadata.base_words.is_some() {
+                schema.push_str(
+                    "CREATE INDEX IF NOT EXISTS idx_dictionary_base_word ON dictionary(is_base_word);\n",
+                );
+            }
         }
 
         schema
@@ -509,22 +1248,45 @@ impl SqlExporter {
     /// # Arguments
     ///
     /// * `words` - Slice of words to insert
+    /// * `metadata` - Frequency ranks and base words for the optional columns
     ///
     /// # Returns
     ///
     /// A string containing the INSERT SQL statement for the dictionary words.
-    fn generate_dictionary_batch_insert(&self, words: &[&String]) -> String {
+    fn generate_dictionary_batch_insert(
+        &self,
+        words: &[&String],
+        metadata: &WordMetadata,
+    ) -> String {
         if words.is_empty() {
             return String::new();
         }
 
-        let mut sql = String::from("INSERT OR IGNORE INTO dictionary (word, length) VALUES\n");
+        let mut columns = String::from("word, length");
+        if metadata.frequency_ranks.is_some() {
+            columns.push_str(", frequency_rank");
+        }
+        if metadata.base_words.is_some() {
+            columns.push_str(", is_base_word");
+        }
+        let mut sql = format!("INSERT OR IGNORE INTO dictionary ({}) VALUES\n", columns);
 
         for (i, word) in words.iter().enumerate() {
             let escaped_word = self.escape_sql_string(word);
-            let length = word.len();
+            let length = metadata.comparison_unit.length(word);
 
-            sql.push_str(&format!("\t('{}', {})", escaped_word, length));
+            sql.push_str(&format!("\t('{}', {}", escaped_word, length));
+            if let Some(ranks) = &metadata.frequency_ranks {
+                match ranks.get(word.as_str()) {
+                    Some(rank) => sql.push_str(&format!(", {}", rank)),
+                    None => sql.push_str(", NULL"),
+                }
+            }
+            if let Some(base_words) = &metadata.base_words {
+                let is_base_word = u8::from(base_words.contains(word.as_str()));
+                sql.push_str(&format!(", {}", is_base_word));
+            }
+            sql.push(')');
 
             if i < words.len() - 1 {
                 sql.push_str(",\n");
@@ -543,10 +1305,201 @@ impl Default for SqlExporter {
     }
 }
 
+/// One row of an exported puzzles table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleRow {
+    /// The puzzle ID
+    pub id: String,
+    /// The `start_word` column
+    pub start: String,
+    /// The `target_word` column
+    pub end: String,
+    /// The `min_steps` column
+    pub min_steps: usize,
+    /// The `difficulty` column
+    pub difficulty: Difficulty,
+    /// The ladder from the `solution` column, if the export has one
+    pub solution: Option<Vec<String>>,
+}
+
+/// Reads the puzzles table rows back from a SQL export.
+///
+/// Rows are taken from every `INSERT INTO puzzles` statement, with values
+/// matched to the statement's column list, so exports with any combination
+/// of optional columns can be read. Other statements, including the
+/// `puzzle_steps` rows, are ignored.
+///
+/// # Arguments
+///
+/// * `sql` - Contents of the SQL export
+///
+/// # Returns
+///
+/// The rows in file order, or an error naming the line of the first row that
+/// lacks a base column or cannot be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_engine::exporters::sql::parse_puzzle_rows;
+/// use wordladder_engine::puzzle::Difficulty;
+///
+/// let sql = "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES\n\
+///            \t('cat_dog_001', 'cat', 'dog', 3, 'easy');\n";
+/// let rows = parse_puzzle_rows(sql).unwrap();
+/// assert_eq!((rows[0].end.as_str(), rows[0].min_steps), ("dog", 3));
+/// assert_eq!(rows[0].difficulty, Difficulty::Easy);
+/// ```
+pub fn parse_puzzle_rows(sql: &str) -> Result<Vec<PuzzleRow>> {
+    // Column names of the puzzles INSERT statement being read, if any
+    let mut columns: Option<Vec<&str>> = None;
+    let mut rows = Vec::new();
+    for (index, line) in sql.lines().enumerate() {
+        if let Some(statement) = line.strip_prefix("INSERT INTO ") {
+            columns = statement
+                .strip_prefix("puzzles (")
+                .and_then(|rest| rest.split_once(')'))
+                .map(|(names, _)| names.split(',').map(str::trim).collect());
+            continue;
+        }
+        let Some(names) = &columns else {
+            continue;
+        };
+        let Some(body) = line.trim_start().strip_prefix('(') else {
+            continue;
+        };
+        let row = parse_sql_values(body)
+            .and_then(|values| puzzle_row(names, values))
+            .with_context(|| format!("Invalid puzzle row on line {}", index + 1))?;
+        rows.push(row);
+        if line.trim_end().ends_with(';') {
+            columns = None;
+        }
+    }
+    Ok(rows)
+}
+
+/// Reads the puzzles table rows from a SQLite database.
+///
+/// # Arguments
+///
+/// * `path` - The database file, as created by applying a SQL export
+///
+/// # Returns
+///
+/// The rows ordered by ID, or an error if the database has no puzzles table
+/// or a row cannot be read.
+#[cfg(feature = "sqlite")]
+pub fn read_puzzle_rows_from_db(path: &Path) -> Result<Vec<PuzzleRow>> {
+    use rusqlite::{Connection, OpenFlags};
+
+    let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let has_solution = connection
+        .prepare("SELECT solution FROM puzzles LIMIT 0")
+        .is_ok();
+    let query = if has_solution {
+        "SELECT id, start_word, target_word, min_steps, difficulty, solution FROM puzzles ORDER BY id"
+    } else {
+        "SELECT id, start_word, target_word, min_steps, difficulty, NULL FROM puzzles ORDER BY id"
+    };
+    let mut statement = connection.prepare(query)?;
+    let mut rows = statement.query([])?;
+    let mut puzzles = Vec::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let difficulty: String = row.get(4)?;
+        let solution: Option<String> = row.get(5)?;
+        puzzles.push(PuzzleRow {
+            start: row.get(1)?,
+            end: row.get(2)?,
+            min_steps: row.get::<_, i64>(3)?.try_into()?,
+            difficulty: difficulty
+                .parse()
+                .with_context(|| format!("Invalid puzzle row {}", id))?,
+            solution: solution.map(|path| path.split(',').map(String::from).collect()),
+            id,
+        });
+    }
+    Ok(puzzles)
+}
+
+/// Parses the values of one row, from after its opening parenthesis up to
+/// the closing one. `NULL` becomes `None`; quoted strings are unescaped.
+fn parse_sql_values(body: &str) -> Result<Vec<Option<String>>> {
+    let mut values = Vec::new();
+    let mut chars = body.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let value = if chars.next_if_eq(&'\'').is_some() {
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.next_if_eq(&'\'').is_some() => value.push('\''),
+                    Some('\'') => break,
+                    Some(c) => value.push(c),
+                    None => return Err(anyhow!("Unterminated string")),
+                }
+            }
+            Some(value)
+        } else {
+            let mut token = String::new();
+            while let Some(c) = chars.next_if(|c| *c != ',' && *c != ')') {
+                token.push(c);
+            }
+            let token = token.trim();
+            (token != "NULL").then(|| token.to_string())
+        };
+        values.push(value);
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        match chars.next() {
+            Some(',') => continue,
+            Some(')') => return Ok(values),
+            _ => return Err(anyhow!("Expected ',' or ')' after value {}", values.len())),
+        }
+    }
+}
+
+/// Builds a `PuzzleRow` from the values of a row and the INSERT column names.
+fn puzzle_row(columns: &[&str], values: Vec<Option<String>>) -> Result<PuzzleRow> {
+    if columns.len() != values.len() {
+        return Err(anyhow!(
+            "Expected {} values, found {}",
+            columns.len(),
+            values.len()
+        ));
+    }
+    let mut by_name: HashMap<&str, Option<String>> = columns.iter().copied().zip(values).collect();
+    let mut required = |name: &str| {
+        by_name
+            .remove(name)
+            .flatten()
+            .ok_or_else(|| anyhow!("Missing {}", name))
+    };
+    let id = required("id")?;
+    let start = required("start_word")?;
+    let end = required("target_word")?;
+    let min_steps = required("min_steps")?
+        .parse()
+        .map_err(|_| anyhow!("min_steps is not a number"))?;
+    let difficulty = required("difficulty")?.parse()?;
+    let solution = by_name
+        .remove("solution")
+        .flatten()
+        .map(|path| path.split(',').map(String::from).collect());
+    Ok(PuzzleRow {
+        id,
+        start,
+        end,
+        min_steps,
+        difficulty,
+        solution,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::puzzle::{Difficulty, Puzzle};
+    use crate::puzzle::{Difficulty, Puzzle, PuzzleMeta, StarRating};
 
     fn create_test_puzzle(
         start: &str,
@@ -559,6 +1512,10 @@ mod tests {
             end: end.to_string(),
             path,
             difficulty,
+            unique_solution: None,
+            reverse_path: None,
+            stars: None,
+            meta: None,
         }
     }
 
@@ -579,6 +1536,65 @@ mod tests {
         assert_eq!(id2, "cat_dog_002");
     }
 
+    #[test]
+    fn test_generate_puzzle_id_content_hash() {
+        let puzzle = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        let other_path = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "dot".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+
+        let mut first = SqlExporter::new().with_id_strategy(IdStrategy::ContentHash);
+        let mut second = SqlExporter::new().with_id_strategy(IdStrategy::ContentHash);
+        let id = first.generate_puzzle_id(&puzzle);
+
+        assert!(id.starts_with("cat_dog_"));
+        assert_eq!(id.len(), "cat_dog_".len() + 12);
+        assert_eq!(second.generate_puzzle_id(&puzzle), id);
+        assert_ne!(second.generate_puzzle_id(&other_path), id);
+    }
+
+    #[test]
+    fn test_content_hash_ids_of_repeated_puzzle_are_unique() {
+        let puzzle = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        let mut exporter = SqlExporter::new().with_id_strategy(IdStrategy::ContentHash);
+        let id = SqlExporter::new()
+            .with_id_strategy(IdStrategy::ContentHash)
+            .generate_puzzle_id(&puzzle);
+
+        let sql = exporter.generate_batch_insert(&[puzzle.clone(), puzzle.clone(), puzzle]);
+        assert!(sql.contains(&format!("'{}'", id)));
+        assert!(sql.contains(&format!("'{}-2'", id)));
+        assert!(sql.contains(&format!("'{}-3'", id)));
+    }
+
     #[test]
     fn test_escape_sql_string() {
         let exporter = SqlExporter::new();
@@ -617,78 +1633,430 @@ mod tests {
     }
 
     #[test]
-    fn test_create_balanced_set() {
-        let exporter = SqlExporter::new();
+    fn test_export_puzzles_with_clues() {
+        let mut exporter = SqlExporter::new().with_include_clues(true);
+        let puzzles = vec![create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        )];
+
+        let sql = exporter.export_puzzles(&puzzles).unwrap();
+        assert!(sql.contains("\tsolution TEXT NOT NULL,\n\tclues TEXT NOT NULL\n);"));
+        assert!(sql.contains("difficulty, solution, clues) VALUES"));
+        assert!(sql.contains(
+            "('cat_dog_001', 'cat', 'dog', 3, 'easy', 'cat,cot,cog,dog', 'cat,c_t,co_,dog');"
+        ));
+    }
+
+    #[test]
+    fn test_export_puzzles_omitting_solutions() {
+        let mut exporter = SqlExporter::new()
+            .with_include_clues(true)
+            .with_include_reverse_path(true)
+            .with_include_stars(true)
+            .with_include_steps_table(true)
+            .with_omit_solutions(true);
+        let mut puzzle = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        puzzle.reverse_path = Some(vec![
+            "dog".to_string(),
+            "dot".to_string(),
+            "cot".to_string(),
+            "cat".to_string(),
+        ]);
+
+        let sql = exporter.export_puzzles(&[puzzle]).unwrap();
+        assert!(sql.contains("\tdifficulty TEXT NOT NULL\n);"));
+        assert!(sql.contains(
+            "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES"
+        ));
+        assert!(sql.contains("('cat_dog_001', 'cat', 'dog', 3, 'easy');"));
+        assert!(!sql.contains("puzzle_steps"));
+        assert!(!sql.contains("cot"));
+    }
+
+    #[cfg(feature = "encrypt")]
+    #[test]
+    fn test_export_puzzles_encrypting_solutions() {
+        let key = SolutionKey::from_bytes([7; 32]);
+        let mut exporter = SqlExporter::new()
+            .with_include_clues(true)
+            .with_include_steps_table(true)
+            .with_solution_key(key);
+        let path = vec![
+            "cat".to_string(),
+            "cot".to_string(),
+            "cog".to_string(),
+            "dog".to_string(),
+        ];
+        let puzzle = create_test_puzzle("cat", "dog", path.clone(), Difficulty::Easy);
+
+        let sql = exporter.export_puzzles(&[puzzle]).unwrap();
+        let ciphertext = encryption::encrypt_solution(&path, "cat_dog_001", &key);
+        assert!(sql.contains("\tencrypted_solution TEXT NOT NULL\n);"));
+        assert!(sql.contains(
+            "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty, encrypted_solution) VALUES"
+        ));
+        assert!(sql.contains(&format!(
+            "('cat_dog_001', 'cat', 'dog', 3, 'easy', '{}');",
+            ciphertext
+        )));
+        assert!(!sql.contains("puzzle_steps"));
+        assert!(!sql.contains("cot"));
+        assert_eq!(
+            encryption::decrypt_solution(&ciphertext, "cat_dog_001", &key).unwrap(),
+            path
+        );
+    }
+
+    #[test]
+    fn test_export_puzzles_with_canonical_hash() {
+        let puzzle = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        let pair = CanonicalHashOptions {
+            unordered: true,
+            path_independent: true,
+        };
+
+        let mut exporter = SqlExporter::new().with_canonical_hash(Some(Default::default()));
+        let sql = exporter
+            .export_puzzles(std::slice::from_ref(&puzzle))
+            .unwrap();
+        assert!(sql.contains("\tcanonical_hash TEXT NOT NULL\n);"));
+        assert!(sql.contains("idx_puzzles_canonical_hash ON puzzles(canonical_hash)"));
+        assert!(sql.contains(&format!(
+            "('cat_dog_001', 'cat', 'dog', 3, 'easy', '{}');",
+            puzzle.canonical_hash(Default::default())
+        )));
+
+        // Without solutions, only a path-independent hash is kept
+        let mut masked = SqlExporter::new()
+            .with_omit_solutions(true)
+            .with_canonical_hash(Some(Default::default()));
+        let sql = masked
+            .export_puzzles(std::slice::from_ref(&puzzle))
+            .unwrap();
+        assert!(!sql.contains("canonical_hash"));
+        let mut masked = SqlExporter::new()
+            .with_omit_solutions(true)
+            .with_canonical_hash(Some(pair));
+        let sql = masked
+            .export_puzzles(std::slice::from_ref(&puzzle))
+            .unwrap();
+        assert!(sql.contains(&format!(
+            "('cat_dog_001', 'cat', 'dog', 3, 'easy', '{}');",
+            puzzle.canonical_hash(pair)
+        )));
+    }
+
+    #[test]
+    fn test_parse_puzzle_rows() {
         let puzzles = vec![
             create_test_puzzle(
-                "a",
-                "b",
-                vec!["a".to_string(), "b".to_string()],
+                "cat",
+                "cog",
+                vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
                 Difficulty::Easy,
             ),
             create_test_puzzle(
-                "c",
-                "d",
-                vec!["c".to_string(), "d".to_string(), "e".to_string()],
-                Difficulty::Easy,
+                "cold",
+                "warm",
+                ["cold", "cord", "card", "ward", "warm"]
+                    .map(String::from)
+                    .to_vec(),
+                Difficulty::Medium,
             ),
+        ];
+        let mut exporter = SqlExporter::new()
+            .with_batch_size(1)
+            .with_include_clues(true)
+            .with_include_reverse_path(true)
+            .with_include_steps_table(true);
+        let sql = exporter.export_puzzles(&puzzles).unwrap();
+
+        let rows = parse_puzzle_rows(&sql).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id, "cat_cog_001");
+        assert_eq!(
+            (rows[1].start.as_str(), rows[1].end.as_str()),
+            ("cold", "warm")
+        );
+        assert_eq!(rows[1].min_steps, 4);
+        assert_eq!(rows[1].difficulty, Difficulty::Medium);
+        assert_eq!(rows[1].solution.as_ref(), Some(&puzzles[1].path));
+
+        let masked = SqlExporter::new()
+            .with_omit_solutions(true)
+            .export_puzzles(&puzzles)
+            .unwrap();
+        assert_eq!(parse_puzzle_rows(&masked).unwrap()[0].solution, None);
+
+        let broken = "INSERT INTO puzzles (id, start_word, target_word, min_steps, difficulty) VALUES\n\
+                      \t('cat_dog_001', 'cat', 'dog', 3);\n";
+        let error = parse_puzzle_rows(broken).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid puzzle row on line 2");
+        assert_eq!(
+            parse_sql_values("'it''s', NULL, 3)").unwrap(),
+            vec![Some("it's".to_string()), None, Some("3".to_string())]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_parse_puzzle_rows_matches_database() {
+        let puzzles = vec![
             create_test_puzzle(
-                "f",
-                "g",
-                vec![
-                    "f".to_string(),
-                    "g".to_string(),
-                    "h".to_string(),
-                    "i".to_string(),
-                    "j".to_string(),
-                    "k".to_string(),
-                ],
-                Difficulty::Medium,
+                "cat",
+                "cog",
+                vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+                Difficulty::Easy,
             ),
             create_test_puzzle(
-                "l",
-                "m",
-                vec![
-                    "l".to_string(),
-                    "m".to_string(),
-                    "n".to_string(),
-                    "o".to_string(),
-                    "p".to_string(),
-                    "q".to_string(),
-                    "r".to_string(),
-                    "s".to_string(),
-                    "t".to_string(),
-                ],
-                Difficulty::Hard,
+                "cold",
+                "warm",
+                ["cold", "cord", "card", "ward", "warm"]
+                    .map(String::from)
+                    .to_vec(),
+                Difficulty::Medium,
             ),
         ];
+        let sql = SqlExporter::new()
+            .with_include_clues(true)
+            .with_include_steps_table(true)
+            .export_puzzles(&puzzles)
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("wl_sql_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        rusqlite::Connection::open(&path)
+            .unwrap()
+            .execute_batch(&sql)
+            .unwrap();
+        let from_db = read_puzzle_rows_from_db(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(parse_puzzle_rows(&sql).unwrap(), from_db);
+        assert_eq!(from_db[1].solution.as_ref(), Some(&puzzles[1].path));
+    }
+
+    #[test]
+    fn test_export_puzzles_with_unique_solution() {
+        let mut exporter = SqlExporter::new().with_include_unique_solution(true);
+        let mut unique = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+        unique.unique_solution = Some(true);
+        let unchecked = create_test_puzzle(
+            "cat",
+            "dog",
+            vec!["cat".to_string(), "cot".to_string(), "dog".to_string()],
+            Difficulty::Easy,
+        );
 
-        let balanced = exporter.create_balanced_set(&puzzles, 10, 0.5, 0.3, 0.2);
+        let sql = exporter.export_puzzles(&[unique, unchecked]).unwrap();
+        assert!(sql.contains("\tunique_solution INTEGER\n);"));
+        assert!(sql.contains("difficulty, unique_solution) VALUES"));
+        assert!(sql.contains("('cat_cog_001', 'cat', 'cog', 2, 'easy', 1)"));
+        assert!(sql.contains("('cat_dog_001', 'cat', 'dog', 2, 'easy', NULL)"));
+    }
 
-        let easy_count = balanced
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Easy))
-            .count();
-        let medium_count = balanced
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Medium))
-            .count();
-        let hard_count = balanced
-            .iter()
-            .filter(|p| matches!(p.difficulty, Difficulty::Hard))
-            .count();
-
-        // Should have roughly the right distribution, but limited by available puzzles
-        // We have 2 easy, 1 medium, 1 hard available
-        // For 10 requested with 50%/30%/20% distribution, we expect:
-        // - Easy: min(5, 2) = 2, but algorithm may duplicate to fill
-        // - Medium: min(3, 1) = 1, but may duplicate
-        // - Hard: min(2, 1) = 1, but may duplicate
-        // Total should be 10, with remaining filled from available puzzles
-        assert_eq!(balanced.len(), 10); // Should return exactly the requested count
-        assert!(easy_count >= 1); // Should have at least some easy puzzles
-        assert!(medium_count >= 1); // Should have at least some medium puzzles
-        assert!(hard_count >= 1); // Should have at least some hard puzzles
+    #[test]
+    fn test_export_puzzles_with_reverse_path() {
+        let mut exporter = SqlExporter::new().with_include_reverse_path(true);
+        let mut mirror = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        mirror.reverse_path = Some(
+            ["dog", "dot", "cot", "cat"]
+                .iter()
+                .map(|w| w.to_string())
+                .collect(),
+        );
+        let unchecked = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+
+        let sql = exporter.export_puzzles(&[mirror, unchecked]).unwrap();
+        assert!(sql.contains("\treverse_path TEXT\n);"));
+        assert!(sql.contains("difficulty, reverse_path) VALUES"));
+        assert!(sql.contains("('cat_dog_001', 'cat', 'dog', 3, 'easy', 'dog,dot,cot,cat')"));
+        assert!(sql.contains("('cat_cog_001', 'cat', 'cog', 2, 'easy', NULL)"));
+    }
+
+    #[test]
+    fn test_export_puzzles_with_stars() {
+        let mut exporter = SqlExporter::new().with_include_stars(true);
+        let mut rated = create_test_puzzle(
+            "cat",
+            "dog",
+            vec![
+                "cat".to_string(),
+                "cot".to_string(),
+                "cog".to_string(),
+                "dog".to_string(),
+            ],
+            Difficulty::Easy,
+        );
+        rated.stars = Some(StarRating {
+            par: 3,
+            two_stars: 5,
+            three_stars: 3,
+        });
+        let unrated = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+
+        let sql = exporter.export_puzzles(&[rated, unrated]).unwrap();
+        assert!(sql.contains("\tpar INTEGER,\n\ttwo_stars INTEGER,\n\tthree_stars INTEGER\n);"));
+        assert!(sql.contains("difficulty, par, two_stars, three_stars) VALUES"));
+        assert!(sql.contains("('cat_dog_001', 'cat', 'dog', 3, 'easy', 3, 5, 3)"));
+        assert!(sql.contains("('cat_cog_001', 'cat', 'cog', 2, 'easy', NULL, NULL, NULL)"));
+    }
+
+    #[test]
+    fn test_export_puzzles_with_meta() {
+        let mut exporter = SqlExporter::new().with_include_meta(true);
+        let mut stamped = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+        stamped.meta = Some(PuzzleMeta {
+            created_at: 1_700_000_000,
+            engine_version: "0.1.0".to_string(),
+            dictionary_sha256: "ab12".to_string(),
+            seed: Some(42),
+        });
+        let plain = create_test_puzzle(
+            "cat",
+            "dog",
+            vec!["cat".to_string(), "cot".to_string(), "dog".to_string()],
+            Difficulty::Easy,
+        );
+
+        let sql = exporter.export_puzzles(&[stamped, plain]).unwrap();
+        assert!(sql.contains("\tdictionary_sha256 TEXT,\n\tseed INTEGER\n);"));
+        assert!(
+            sql.contains("difficulty, created_at, engine_version, dictionary_sha256, seed) VALUES")
+        );
+        assert!(
+            sql.contains(
+                "('cat_cog_001', 'cat', 'cog', 2, 'easy', 1700000000, '0.1.0', 'ab12', 42)"
+            )
+        );
+        assert!(sql.contains("('cat_dog_001', 'cat', 'dog', 2, 'easy', NULL, NULL, NULL, NULL)"));
+    }
+
+    #[test]
+    fn test_export_migration() {
+        let mut exporter = SqlExporter::new()
+            .with_schema_mode(SchemaMode::MigrateFrom(SchemaVersion::V1))
+            .with_include_unique_solution(true);
+        let puzzle = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+
+        let sql = exporter.export_puzzles(&[puzzle]).unwrap();
+        assert!(!sql.contains("CREATE TABLE"));
+        assert!(sql.starts_with("-- Migrate puzzles table from schema v1 to v6\n-- v2\n"));
+        assert!(sql.contains("ALTER TABLE puzzles ADD COLUMN clues TEXT;\n"));
+        assert!(sql.contains(
+            "ALTER TABLE puzzles ADD COLUMN seed INTEGER;\n-- v3\nALTER TABLE puzzles ADD COLUMN reverse_path TEXT;\n-- v4\n"
+        ));
+        assert!(sql.contains(
+            "ALTER TABLE puzzles ADD COLUMN three_stars INTEGER;\n-- v5\nALTER TABLE puzzles ADD COLUMN canonical_hash TEXT;\n\
+             -- v6\nALTER TABLE puzzles ADD COLUMN encrypted_solution TEXT;\n"
+        ));
+        assert_eq!(sql.matches("ALTER TABLE").count(), 13);
+        assert!(sql.contains("('cat_cog_001', 'cat', 'cog', 2, 'easy', NULL)"));
+
+        let current = SqlExporter::new()
+            .with_schema_mode(SchemaMode::MigrateFrom(SchemaVersion::CURRENT))
+            .generate_migration(SchemaVersion::CURRENT);
+        assert!(!current.contains("ALTER TABLE"));
+
+        let uncommented = SqlExporter::with_config(SqlExportConfig {
+            include_comments: false,
+            ..SqlExportConfig::default()
+        })
+        .generate_migration(SchemaVersion::V4);
+        assert_eq!(
+            uncommented,
+            "ALTER TABLE puzzles ADD COLUMN canonical_hash TEXT;\n\
+             ALTER TABLE puzzles ADD COLUMN encrypted_solution TEXT;\n"
+        );
+    }
+
+    #[test]
+    fn test_export_puzzles_with_steps_table() {
+        let mut exporter = SqlExporter::new().with_include_steps_table(true);
+        let puzzle = create_test_puzzle(
+            "cat",
+            "cog",
+            vec!["cat".to_string(), "cot".to_string(), "cog".to_string()],
+            Difficulty::Easy,
+        );
+
+        let sql = exporter.export_puzzles(&[puzzle]).unwrap();
+        assert!(sql.contains("CREATE TABLE IF NOT EXISTS puzzle_steps ("));
+        assert!(sql.contains("puzzle_id TEXT NOT NULL REFERENCES puzzles(id) ON DELETE CASCADE"));
+        assert!(sql.contains("PRIMARY KEY (puzzle_id, step_index)"));
+        assert!(sql.contains("idx_puzzle_steps_word ON puzzle_steps(word)"));
+        assert!(sql.contains(
+            "INSERT INTO puzzle_steps (puzzle_id, step_index, word) VALUES\n\
+             \t('cat_cog_001', 0, 'cat'),\n\
+             \t('cat_cog_001', 1, 'cot'),\n\
+             \t('cat_cog_001', 2, 'cog');"
+        ));
+
+        let sql = SqlExporter::new().export_puzzles(&[]).unwrap();
+        assert!(!sql.contains("puzzle_steps"));
     }
 
     #[test]
@@ -712,4 +2080,49 @@ mod tests {
         // Check that the SQL ends with a semicolon
         assert!(sql.trim().ends_with(';'));
     }
+
+    #[test]
+    fn test_export_dictionary_with_metadata() {
+        let mut exporter = SqlExporter::new();
+        let words: HashSet<String> = ["cat", "dog", "cwm"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let metadata = WordMetadata::new()
+            .with_frequency_ranks(WordMetadata::parse_frequency_list("dog 90\ncat 80\n"))
+            .with_base_words(["cat".to_string()].into());
+
+        let sql = exporter
+            .export_dictionary_with_metadata(&words, &metadata)
+            .unwrap();
+
+        assert!(sql.contains("frequency_rank INTEGER"));
+        assert!(sql.contains("is_base_word INTEGER NOT NULL DEFAULT 0"));
+        assert!(sql.contains("idx_dictionary_frequency"));
+        assert!(sql.contains("idx_dictionary_base_word"));
+        assert!(sql.contains("(word, length, frequency_rank, is_base_word)"));
+        assert!(sql.contains("('cat', 3, 2, 1)"));
+        assert!(sql.contains("('dog', 3, 1, 0)"));
+        assert!(sql.contains("('cwm', 3, NULL, 0)"));
+
+        // Only the base word column
+        let metadata = WordMetadata::new().with_base_words(["dog".to_string()].into());
+        let sql = exporter
+            .export_dictionary_with_metadata(&words, &metadata)
+            .unwrap();
+        assert!(!sql.contains("frequency_rank"));
+        assert!(sql.contains("('dog', 3, 1)"));
+
+        // Lengths count letters, not bytes
+        let accented: HashSet<String> = ["café".to_string(), "cafe\u{301}".to_string()].into();
+        let sql = exporter.export_dictionary(&accented).unwrap();
+        assert!(sql.contains("('café', 4)"));
+        assert!(sql.contains("('cafe\u{301}', 5)"));
+        let metadata = WordMetadata::new().with_comparison_unit(ComparisonUnit::Grapheme);
+        let sql = exporter
+            .export_dictionary_with_metadata(&accented, &metadata)
+            .unwrap();
+        assert!(sql.contains("('café', 4)"));
+        assert!(sql.contains("('cafe\u{301}', 4)"));
+    }
 }
@@ -0,0 +1,319 @@
+//! # Streaming Export Module
+//!
+//! The other exporters turn a finished list of puzzles into one string or
+//! byte buffer, so a batch is held in memory until generation ends and is
+//! lost if the run is interrupted. `StreamExporter` instead writes puzzles as
+//! they are generated and flushes the writer as it goes, so the output always
+//! holds every puzzle produced so far.
+//!
+//! Only formats that stay valid when cut off between records can be streamed:
+//!
+//! - JSON Lines: one compact puzzle object per line, flushed per puzzle (or
+//!   one `MaskedPuzzle` per line when solutions are omitted)
+//! - SQL: the schema up front, then one INSERT statement per `batch_size`
+//!   puzzles, flushed per statement
+//! - Lines: one line per puzzle in a caller-defined format, flushed per puzzle
+//!
+//! JSON arrays, Markdown tables, and binary packs need the whole batch and
+//! are written with their regular exporters.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::stream::StreamExporter;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let puzzle = Puzzle::new("cat".into(), "dog".into(), vec![
+//!     "cat".into(), "cot".into(), "cog".into(), "dog".into(),
+//! ]).unwrap();
+//!
+//! let mut exporter = StreamExporter::json_lines(Vec::new(), false);
+//! exporter.write_puzzle(puzzle).unwrap();
+//! let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+//! assert_eq!(output.lines().count(), 1);
+//! ```
+
+use crate::exporters::masked::MaskedPuzzle;
+use crate::exporters::sql::SqlExporter;
+use crate::puzzle::{CanonicalHashOptions, Puzzle};
+use anyhow::Result;
+use std::io::Write;
+
+/// How each puzzle is encoded.
+enum Encoding {
+    /// One compact JSON object per line, optionally with a `clues` array
+    JsonLines { include_clues: bool },
+    /// One compact `MaskedPuzzle` object per line, without the solution
+    MaskedJsonLines,
+    /// One line per puzzle, formatted by the caller
+    Lines(Box<dyn Fn(&Puzzle) -> String>),
+    /// Batched INSERT statements
+    Sql(SqlExporter),
+}
+
+/// Writes puzzles to a writer one at a time as they are generated.
+///
+/// Call `finish` once generation ends to write any SQL batch still pending;
+/// puzzles of a JSON Lines or line export are on disk as soon as
+/// `write_puzzle` returns.
+pub struct StreamExporter<W: Write> {
+    /// Destination of the export
+    writer: W,
+    /// How puzzles are encoded
+    encoding: Encoding,
+    /// SQL puzzles waiting for a full INSERT batch
+    pending: Vec<Puzzle>,
+    /// Number of puzzles passed to `write_puzzle`
+    count: usize,
+    /// Options of the `canonical_hash` field added to JSON Lines objects
+    canonical_hash: Option<CanonicalHashOptions>,
+}
+
+impl<W: Write> StreamExporter<W> {
+    /// Creates an exporter writing JSON Lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the export
+    /// * `include_clues` - Whether each object gets a `clues` array (see
+    ///   `Puzzle::to_clue_format`)
+    pub fn json_lines(writer: W, include_clues: bool) -> Self {
+        Self::with_encoding(writer, Encoding::JsonLines { include_clues })
+    }
+
+    /// Creates an exporter writing JSON Lines without solutions.
+    ///
+    /// Each line holds a `MaskedPuzzle`: the start and end words, the number
+    /// of steps, and the difficulty.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the export
+    pub fn masked_json_lines(writer: W) -> Self {
+        Self::with_encoding(writer, Encoding::MaskedJsonLines)
+    }
+
+    /// Creates an exporter writing one line per puzzle in a custom format.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the export
+    /// * `format` - Formats a puzzle as a line; a missing trailing newline is added
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::stream::StreamExporter;
+    ///
+    /// let exporter = StreamExporter::lines(Vec::new(), |p| p.path.join(" -> "));
+    /// ```
+    pub fn lines(writer: W, format: impl Fn(&Puzzle) -> String + 'static) -> Self {
+        Self::with_encoding(writer, Encoding::Lines(Box::new(format)))
+    }
+
+    /// Creates an exporter writing SQL and writes the schema right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the export
+    /// * `exporter` - SQL exporter providing the schema, columns, batch size,
+    ///   and ID counters
+    ///
+    /// # Returns
+    ///
+    /// The exporter, or an error if the schema cannot be written.
+    pub fn sql(mut writer: W, exporter: SqlExporter) -> Result<Self> {
+        writer.write_all(exporter.export_header().as_bytes())?;
+        writer.flush()?;
+        Ok(Self::with_encoding(writer, Encoding::Sql(exporter)))
+    }
+
+    fn with_encoding(writer: W, encoding: Encoding) -> Self {
+        Self {
+            writer,
+            encoding,
+            pending: Vec::new(),
+            count: 0,
+            canonical_hash: None,
+        }
+    }
+
+    /// Sets whether JSON Lines objects get a `canonical_hash` field.
+    ///
+    /// Masked lines only get a path-independent hash, since a hash covering
+    /// the path could be matched against candidate ladders. SQL exports take
+    /// the setting from their `SqlExporter`, and line exports ignore it.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Options passed to `Puzzle::canonical_hash`, or `None` for no field
+    pub fn with_canonical_hash(mut self, options: Option<CanonicalHashOptions>) -> Self {
+        self.canonical_hash = options;
+        self
+    }
+
+    /// Writes one puzzle.
+    ///
+    /// JSON Lines and line exports write and flush the puzzle immediately.
+    /// SQL exports buffer it until a full INSERT batch is ready.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to write
+    pub fn write_puzzle(&mut self, puzzle: Puzzle) -> Result<()> {
+        self.count += 1;
+        let mut line = match &mut self.encoding {
+            Encoding::JsonLines { include_clues } => {
+                let mut value = serde_json::to_value(&puzzle)?;
+                if *include_clues {
+                    value["clues"] = serde_json::to_value(puzzle.to_clue_format())?;
+                }
+                if let Some(options) = self.canonical_hash {
+                    value["canonical_hash"] = puzzle.canonical_hash(options).into();
+                }
+                serde_json::to_string(&value)?
+            }
+            Encoding::MaskedJsonLines => {
+                let masked = MaskedPuzzle::from(&puzzle);
+                match self
+                    .canonical_hash
+                    .filter(|options| options.path_independent)
+                {
+                    Some(options) => {
+                        let mut value = serde_json::to_value(masked)?;
+                        value["canonical_hash"] = puzzle.canonical_hash(options).into();
+                        serde_json::to_string(&value)?
+                    }
+                    None => serde_json::to_string(&masked)?,
+                }
+            }
+            Encoding::Lines(format) => format(&puzzle),
+            Encoding::Sql(exporter) => {
+                self.pending.push(puzzle);
+                if self.pending.len() < exporter.batch_size().max(1) {
+                    return Ok(());
+                }
+                exporter.export_batch(&std::mem::take(&mut self.pending))
+            }
+        };
+        if !line.ends_with('\n') {
+            line.push('\n');
+        }
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the number of puzzles passed to `write_puzzle` so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Writes any pending SQL batch and flushes the writer.
+    ///
+    /// # Returns
+    ///
+    /// The writer, or an error if the last batch cannot be written.
+    pub fn finish(mut self) -> Result<W> {
+        if let Encoding::Sql(exporter) = &mut self.encoding
+            && !self.pending.is_empty()
+        {
+            let sql = exporter.export_batch(&std::mem::take(&mut self.pending));
+            self.writer.write_all(sql.as_bytes())?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    fn create_test_puzzles() -> Vec<Puzzle> {
+        vec![
+            create_test_puzzle(&["cat", "cot", "cog", "dog"]),
+            create_test_puzzle(&["cold", "cord", "word", "ward", "warm"]),
+        ]
+    }
+
+    #[test]
+    fn test_json_lines_round_trip() {
+        let puzzles = create_test_puzzles();
+        let mut exporter = StreamExporter::json_lines(Vec::new(), true);
+        for puzzle in puzzles.clone() {
+            exporter.write_puzzle(puzzle).unwrap();
+        }
+        assert_eq!(exporter.count(), 2);
+
+        let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: Puzzle = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first, puzzles[0]);
+        assert!(lines[1].contains("\"clues\""));
+    }
+
+    #[test]
+    fn test_masked_json_lines() {
+        let mut exporter = StreamExporter::masked_json_lines(Vec::new());
+        for puzzle in create_test_puzzles() {
+            exporter.write_puzzle(puzzle).unwrap();
+        }
+
+        let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let first: MaskedPuzzle = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!((first.start.as_str(), first.min_steps), ("cat", 3));
+        assert!(!output.contains("path") && !output.contains("cord"));
+    }
+
+    #[test]
+    fn test_json_lines_with_canonical_hash() {
+        let puzzle = create_test_puzzles().remove(0);
+        let pair = CanonicalHashOptions {
+            unordered: false,
+            path_independent: true,
+        };
+
+        let mut exporter =
+            StreamExporter::json_lines(Vec::new(), false).with_canonical_hash(Some(pair));
+        exporter.write_puzzle(puzzle.clone()).unwrap();
+        let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(value["canonical_hash"], puzzle.canonical_hash(pair));
+
+        let mut masked = StreamExporter::masked_json_lines(Vec::new())
+            .with_canonical_hash(Some(CanonicalHashOptions::default()));
+        masked.write_puzzle(puzzle).unwrap();
+        let output = String::from_utf8(masked.finish().unwrap()).unwrap();
+        assert!(!output.contains("canonical_hash"));
+    }
+
+    #[test]
+    fn test_sql_writes_full_batches_then_remainder() {
+        let mut puzzles = create_test_puzzles();
+        puzzles.push(puzzles[0].clone());
+        let exporter = SqlExporter::new().with_batch_size(2);
+        let header = exporter.export_header();
+        let mut stream = StreamExporter::sql(Vec::new(), exporter).unwrap();
+
+        let mut puzzles = puzzles.into_iter();
+        stream.write_puzzle(puzzles.next().unwrap()).unwrap();
+        assert_eq!(stream.writer, header.as_bytes());
+        for puzzle in puzzles {
+            stream.write_puzzle(puzzle).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8_lossy(&stream.writer)
+                .matches("INSERT")
+                .count(),
+            1
+        );
+
+        let output = String::from_utf8(stream.finish().unwrap()).unwrap();
+        assert!(output.starts_with(&header));
+        assert_eq!(output.matches("INSERT INTO puzzles").count(), 2);
+        assert!(output.contains("'cat_dog_002'"));
+    }
+}
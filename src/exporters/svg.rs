@@ -0,0 +1,277 @@
+//! # SVG Ladder Module
+//!
+//! This module draws puzzles as ladder graphics for social sharing. Each word
+//! of a puzzle is a rung between two rails, written as letter tiles; the tile
+//! of every letter that changed from the word above is highlighted, so the
+//! image shows how the ladder was climbed. A header names the start and end
+//! words and a footer the number of steps and the difficulty.
+//!
+//! With clues enabled, the intermediate rungs show the masked clue format
+//! (`Puzzle::to_clue_format`) instead of the solution, which gives a
+//! shareable image of the puzzle itself. Several puzzles are drawn side by
+//! side in one image.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::exporters::svg::SvgExporter;
+//! use wordladder_engine::puzzle::Puzzle;
+//!
+//! let path = vec!["cat".to_string(), "cot".to_string(), "cog".to_string(), "dog".to_string()];
+//! let puzzle = Puzzle::new("cat".to_string(), "dog".to_string(), path).unwrap();
+//!
+//! let svg = SvgExporter::new().export_puzzle(&puzzle);
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains(">cat → dog</text>"));
+//! ```
+
+use super::graph::escape_xml;
+use super::markdown::difficulty_name;
+use crate::puzzle::Puzzle;
+
+/// Width and height of a letter tile.
+const TILE_SIZE: usize = 36;
+/// Horizontal gap between the tiles of a word.
+const TILE_GAP: usize = 6;
+/// Space between the outermost tiles and the rails.
+const RAIL_PADDING: usize = 18;
+/// Vertical distance between the centers of neighboring rungs.
+const RUNG_SPACING: usize = 56;
+/// Height of the header above the first rung, and of the footer below the last.
+const CAPTION_HEIGHT: usize = 60;
+/// Empty border around each ladder.
+const MARGIN: usize = 24;
+/// Fill color of tiles whose letter changed from the word above.
+const CHANGED_TILE_COLOR: &str = "#ffd54f";
+/// Color of the rails and rungs.
+const LADDER_COLOR: &str = "#8d6e63";
+
+/// Configuration for SVG export functionality.
+#[derive(Debug, Clone, Default)]
+pub struct SvgExportConfig {
+    /// Whether intermediate rungs show the masked clue format instead of
+    /// the solution
+    pub include_clues: bool,
+}
+
+/// Exporter drawing puzzles as SVG ladder graphics.
+#[derive(Debug, Default)]
+pub struct SvgExporter {
+    config: SvgExportConfig,
+}
+
+impl SvgExporter {
+    /// Creates a new SVG exporter with default configuration (solutions shown).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordladder_engine::exporters::svg::SvgExporter;
+    ///
+    /// let exporter = SvgExporter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new SVG exporter with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration for the exporter
+    pub fn with_config(config: SvgExportConfig) -> Self {
+        Self { config }
+    }
+
+    /// Sets whether intermediate rungs show the clue format instead of the solution.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_clues` - Whether to mask the changed letters of intermediate words
+    pub fn with_include_clues(mut self, include_clues: bool) -> Self {
+        self.config.include_clues = include_clues;
+        self
+    }
+
+    /// Draws a single puzzle as an SVG image.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - Puzzle to draw
+    ///
+    /// # Returns
+    ///
+    /// The SVG document, ending with a newline.
+    pub fn export_puzzle(&self, puzzle: &Puzzle) -> String {
+        self.export_puzzles(std::slice::from_ref(puzzle))
+    }
+
+    /// Draws puzzles side by side, in the given order, as one SVG image.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to draw
+    ///
+    /// # Returns
+    ///
+    /// The SVG document, ending with a newline.
+    pub fn export_puzzles(&self, puzzles: &[Puzzle]) -> String {
+        let widths: Vec<usize> = puzzles.iter().map(ladder_width).collect();
+        let width = widths.iter().sum::<usize>().max(2 * MARGIN);
+        let height = puzzles
+            .iter()
+            .map(|puzzle| ladder_height(puzzle.path.len()))
+            .max()
+            .unwrap_or(2 * MARGIN);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+             viewBox=\"0 0 {0} {1}\" font-family=\"monospace\">\n\
+             \t<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+            width, height
+        );
+        let mut left = 0;
+        for (puzzle, width) in puzzles.iter().zip(widths) {
+            svg.push_str(&self.draw_ladder(puzzle, left, width));
+            left += width;
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Draws one puzzle in the column starting at `left`.
+    fn draw_ladder(&self, puzzle: &Puzzle, left: usize, width: usize) -> String {
+        let center = left + width / 2;
+        let rail_left = left + MARGIN;
+        let rail_right = left + width - MARGIN;
+        let first_rung = MARGIN + CAPTION_HEIGHT;
+        let last_rung = first_rung + (puzzle.path.len() - 1) * RUNG_SPACING;
+        let words = if self.config.include_clues {
+            puzzle.to_clue_format()
+        } else {
+            puzzle.path.clone()
+        };
+
+        let mut svg = format!(
+            "\t<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"22\" \
+             font-weight=\"bold\">{} → {}</text>\n",
+            center,
+            MARGIN + 28,
+            escape_xml(&puzzle.start),
+            escape_xml(&puzzle.end)
+        );
+        for x in [rail_left, rail_right] {
+            svg.push_str(&format!(
+                "\t<line x1=\"{0}\" y1=\"{1}\" x2=\"{0}\" y2=\"{2}\" stroke=\"{3}\" stroke-width=\"6\"/>\n",
+                x,
+                first_rung - RUNG_SPACING / 2,
+                last_rung + RUNG_SPACING / 2,
+                LADDER_COLOR
+            ));
+        }
+
+        for (i, word) in words.iter().enumerate() {
+            let y = first_rung + i * RUNG_SPACING;
+            svg.push_str(&format!(
+                "\t<line x1=\"{0}\" y1=\"{2}\" x2=\"{1}\" y2=\"{2}\" stroke=\"{3}\" stroke-width=\"4\"/>\n",
+                rail_left, rail_right, y, LADDER_COLOR
+            ));
+
+            let letters: Vec<char> = word.chars().collect();
+            let previous: Vec<char> = match i {
+                0 => letters.clone(),
+                _ => puzzle.path[i - 1].chars().collect(),
+            };
+            let solved: Vec<char> = puzzle.path[i].chars().collect();
+            let tiles_width = letters.len() * (TILE_SIZE + TILE_GAP) - TILE_GAP;
+            let first_tile = center - tiles_width / 2;
+            for (j, &letter) in letters.iter().enumerate() {
+                let x = first_tile + j * (TILE_SIZE + TILE_GAP);
+                let fill = if previous.get(j) == solved.get(j) {
+                    "white"
+                } else {
+                    CHANGED_TILE_COLOR
+                };
+                svg.push_str(&format!(
+                    "\t<rect x=\"{0}\" y=\"{1}\" width=\"{2}\" height=\"{2}\" rx=\"4\" fill=\"{3}\" stroke=\"#424242\"/>\n",
+                    x,
+                    y - TILE_SIZE / 2,
+                    TILE_SIZE,
+                    fill
+                ));
+                if letter != '_' {
+                    svg.push_str(&format!(
+                        "\t<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"20\" \
+                         font-weight=\"bold\">{}</text>\n",
+                        x + TILE_SIZE / 2,
+                        y + 7,
+                        escape_xml(&letter.to_string())
+                    ));
+                }
+            }
+        }
+
+        svg.push_str(&format!(
+            "\t<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"16\">{} steps · {}</text>\n",
+            center,
+            last_rung + RUNG_SPACING / 2 + 36,
+            puzzle.path.len() - 1,
+            difficulty_name(puzzle.difficulty)
+        ));
+        svg
+    }
+}
+
+/// Returns the width of the column holding one puzzle's ladder.
+fn ladder_width(puzzle: &Puzzle) -> usize {
+    let letters = puzzle
+        .path
+        .iter()
+        .chain([&puzzle.start, &puzzle.end])
+        .map(|word| word.chars().count())
+        .max()
+        .unwrap_or(0);
+    let tiles_width = letters * (TILE_SIZE + TILE_GAP);
+    // Leave room for the header on short words
+    (tiles_width + 2 * RAIL_PADDING).max(200) + 2 * MARGIN
+}
+
+/// Returns the height of a ladder with this many rungs.
+fn ladder_height(rungs: usize) -> usize {
+    2 * MARGIN + 2 * CAPTION_HEIGHT + rungs.saturating_sub(1) * RUNG_SPACING
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordladder_core::test_support::create_test_puzzle;
+
+    #[test]
+    fn test_export_puzzle_highlights_changed_letters() {
+        let svg = SvgExporter::new().export_puzzle(&create_test_puzzle(&[
+            "cold", "cord", "card", "ward", "warm",
+        ]));
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(">cold → warm</text>"));
+        assert!(svg.contains(">4 steps · medium</text>"));
+        // Five rungs of four tiles, one changed tile per step
+        assert_eq!(svg.matches("<rect x=").count(), 20);
+        assert_eq!(svg.matches(CHANGED_TILE_COLOR).count(), 4);
+        assert_eq!(svg.matches("font-size=\"20\"").count(), 20);
+    }
+
+    #[test]
+    fn test_export_puzzles_with_clues() {
+        let puzzle = create_test_puzzle(&["cold", "cord", "card", "ward", "warm"]);
+        let svg = SvgExporter::new()
+            .with_include_clues(true)
+            .export_puzzles(&[puzzle.clone(), puzzle]);
+
+        assert_eq!(svg.matches(">cold → warm</text>").count(), 2);
+        // The three intermediate words each hide their changed letter
+        assert_eq!(svg.matches("<rect x=").count(), 40);
+        assert_eq!(svg.matches("font-size=\"20\"").count(), 34);
+    }
+}
@@ -0,0 +1,232 @@
+//! # Dictionary Fetching
+//!
+//! This module downloads word lists for the `fetch-dict` subcommand, so a
+//! dictionary does not have to be assembled by hand. Downloads can come from
+//! a well-known list (see [`WordList`]) or any URL. The text is normalized the
+//! same way the word graph reads dictionaries (trimmed, lowercased, alphabetic
+//! words only), deduplicated, and sorted before it is written.
+//!
+//! Next to the written dictionary, a `.source.json` sidecar records where the
+//! words came from and SHA-256 hashes of the download and of the written file,
+//! so a dictionary can be traced back to its source later.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//! use wordladder_engine::fetch::{WordList, fetch_word_list};
+//!
+//! let fetched = fetch_word_list(WordList::Enable.url()).unwrap();
+//! fetched.write(Path::new("data/dictionary.txt")).unwrap();
+//! println!("{} words", fetched.words.len());
+//! ```
+
+use crate::exporters::manifest::sha256_hex;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File extension of source records, replacing the dictionary's own extension.
+pub const SOURCE_EXTENSION: &str = "source.json";
+
+/// Well-known word lists that can be fetched by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordList {
+    /// ENABLE (Enhanced North American Benchmark Lexicon), about 173,000 words
+    Enable,
+    /// Popular subset of ENABLE, about 25,000 common words
+    Popular,
+}
+
+impl WordList {
+    /// Returns the URL the word list is downloaded from.
+    pub fn url(self) -> &'static str {
+        match self {
+            WordList::Enable => {
+                "https://raw.githubusercontent.com/dolph/dictionary/master/enable1.txt"
+            }
+            WordList::Popular => {
+                "https://raw.githubusercontent.com/dolph/dictionary/master/popular.txt"
+            }
+        }
+    }
+}
+
+/// Where a fetched dictionary came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionarySource {
+    /// URL the word list was downloaded from
+    pub url: String,
+    /// Unix timestamp (seconds) of the download
+    pub fetched_at: u64,
+    /// Hex SHA-256 of the downloaded text before normalization
+    pub download_sha256: String,
+    /// Hex SHA-256 of the written dictionary file
+    pub dictionary_sha256: String,
+    /// Number of words in the written dictionary
+    pub word_count: usize,
+}
+
+/// A downloaded and normalized word list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedDictionary {
+    /// Normalized words in sorted order
+    pub words: Vec<String>,
+    /// Record of the download, written next to the dictionary
+    pub source: DictionarySource,
+}
+
+impl FetchedDictionary {
+    /// Normalizes downloaded word list text and records its source.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL the text was downloaded from
+    /// * `text` - The downloaded word list, one word per line
+    pub fn from_download(url: &str, text: &str) -> Self {
+        let words = normalize_word_list(text);
+        let source = DictionarySource {
+            url: url.to_string(),
+            fetched_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            download_sha256: sha256_hex(text.as_bytes()),
+            dictionary_sha256: sha256_hex(dictionary_text(&words).as_bytes()),
+            word_count: words.len(),
+        };
+        Self { words, source }
+    }
+
+    /// Writes the dictionary and its source record.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the dictionary file; missing parent directories are created
+    ///
+    /// # Returns
+    ///
+    /// The path of the source record, or an error if a file cannot be written.
+    pub fn write(&self, path: &Path) -> Result<PathBuf> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, dictionary_text(&self.words))?;
+        let source_path = source_path(path);
+        std::fs::write(&source_path, serde_json::to_string_pretty(&self.source)?)?;
+        Ok(source_path)
+    }
+}
+
+/// Downloads a word list and normalizes it.
+///
+/// # Arguments
+///
+/// * `url` - URL of a plain-text word list with one word per line
+///
+/// # Returns
+///
+/// The normalized dictionary, or an error if the download fails or contains
+/// no usable words.
+pub fn fetch_word_list(url: &str) -> Result<FetchedDictionary> {
+    let text = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .with_context(|| format!("Failed to download {}", url))?;
+    let fetched = FetchedDictionary::from_download(url, &text);
+    if fetched.words.is_empty() {
+        bail!("{} contains no alphabetic words", url);
+    }
+    Ok(fetched)
+}
+
+/// Normalizes word list text: trims and lowercases each line, keeps alphabetic
+/// words only, and removes duplicates.
+///
+/// # Arguments
+///
+/// * `text` - Word list text, one word per line
+///
+/// # Returns
+///
+/// The words in sorted order.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordladder_engine::fetch::normalize_word_list;
+///
+/// let words = normalize_word_list("Dog\r\ncat\n\ncan't\ncat\n");
+/// assert_eq!(words, vec!["cat", "dog"]);
+/// ```
+pub fn normalize_word_list(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|word| !word.is_empty() && word.chars().all(|c| c.is_alphabetic()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Returns the sidecar path of the source record for a dictionary file.
+///
+/// # Arguments
+///
+/// * `dictionary_path` - Path of the dictionary file
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::Path;
+/// use wordladder_engine::fetch::source_path;
+///
+/// let sidecar = source_path(Path::new("data/dictionary.txt"));
+/// assert_eq!(sidecar, Path::new("data/dictionary.source.json"));
+/// ```
+pub fn source_path(dictionary_path: &Path) -> PathBuf {
+    dictionary_path.with_extension(SOURCE_EXTENSION)
+}
+
+/// Formats words as a dictionary file with one word per line.
+fn dictionary_text(words: &[String]) -> String {
+    words.iter().map(|word| format!("{}\n", word)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_download_records_hashes() {
+        let text = "Cat\ncot\nCOG\nhalf-life\ndog\ncat\n";
+        let fetched = FetchedDictionary::from_download("https://example.com/words.txt", text);
+
+        assert_eq!(fetched.words, vec!["cat", "cog", "cot", "dog"]);
+        assert_eq!(fetched.source.word_count, 4);
+        assert_eq!(fetched.source.download_sha256, sha256_hex(text.as_bytes()));
+        assert_eq!(
+            fetched.source.dictionary_sha256,
+            sha256_hex(b"cat\ncog\ncot\ndog\n")
+        );
+    }
+
+    #[test]
+    fn test_write_dictionary_and_source() {
+        let dir = std::env::temp_dir().join(format!("wl_fetch_{}", std::process::id()));
+        let path = dir.join("dictionary.txt");
+        let fetched = FetchedDictionary::from_download("https://example.com/words.txt", "cat\n");
+
+        let written = fetched.write(&path).unwrap();
+        assert_eq!(written, dir.join("dictionary.source.json"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "cat\n");
+        let source: DictionarySource =
+            serde_json::from_str(&std::fs::read_to_string(&written).unwrap()).unwrap();
+        assert_eq!(source, fetched.source);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
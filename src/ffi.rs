@@ -0,0 +1,254 @@
+//! # C FFI Bindings
+//!
+//! This module exposes the engine through a small `extern "C"` API so native
+//! mobile apps (Swift via a bridging header, Kotlin via JNI/JNA) can call the
+//! solver directly instead of shipping SQL dumps. It is only compiled with the
+//! `ffi` feature; the matching C declarations live in `include/wordladder.h`.
+//!
+//! ## Ownership Rules
+//!
+//! - `wl_engine_new` returns an engine owned by the caller, which must be
+//!   released exactly once with `wl_engine_free`.
+//! - Every `char *` returned by this API is owned by the caller and must be
+//!   released with `wl_string_free`. Never pass it to the C `free`.
+//! - Input strings are borrowed for the duration of the call only.
+//!
+//! ## Example (C)
+//!
+//! ```c
+//! WlEngine *engine = wl_engine_new(dict, dict_len, base, base_len);
+//! char *path = wl_solve(engine, "cat", "dog");   // "cat,cot,cog,dog"
+//! wl_string_free(path);
+//! int valid = wl_verify(engine, "cat,cot,cog,dog"); // 1
+//! wl_engine_free(engine);
+//! ```
+
+use crate::graph::WordGraph;
+use crate::puzzle::{Difficulty, PuzzleGenerator};
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+/// Opaque engine handle passed across the FFI boundary.
+pub struct WlEngine {
+    generator: PuzzleGenerator,
+}
+
+/// Borrows a UTF-8 string from a C pointer, returning `None` for null or invalid input.
+///
+/// # Safety
+///
+/// `s` must be null or point to a valid NUL-terminated string.
+unsafe fn borrow_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+/// Borrows a UTF-8 buffer from a pointer and length, treating null as empty.
+///
+/// # Safety
+///
+/// `data` must be null or point to at least `len` readable bytes.
+unsafe fn borrow_buffer<'a>(data: *const u8, len: usize) -> Option<&'a str> {
+    if data.is_null() {
+        return Some("");
+    }
+    std::str::from_utf8(unsafe { std::slice::from_raw_parts(data, len) }).ok()
+}
+
+/// Transfers ownership of a Rust string to the caller.
+fn into_c_string(s: String) -> *mut c_char {
+    CString::new(s).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Builds an engine from in-memory dictionary and base word buffers.
+///
+/// Both buffers contain UTF-8 text with one word per line. `base_words` may be
+/// null, in which case random puzzle generation is unavailable.
+///
+/// Returns null if either buffer is not valid UTF-8.
+///
+/// # Safety
+///
+/// `dictionary` must point to `dictionary_len` readable bytes, and `base_words`
+/// must be null or point to `base_words_len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_engine_new(
+    dictionary: *const u8,
+    dictionary_len: usize,
+    base_words: *const u8,
+    base_words_len: usize,
+) -> *mut WlEngine {
+    let (Some(dictionary), Some(base_words)) = (unsafe {
+        (
+            borrow_buffer(dictionary, dictionary_len),
+            borrow_buffer(base_words, base_words_len),
+        )
+    }) else {
+        return ptr::null_mut();
+    };
+
+    let mut graph = WordGraph::new();
+    graph.load_dictionary_from_str(dictionary);
+    graph.load_base_words_from_str(base_words);
+    Box::into_raw(Box::new(WlEngine {
+        generator: PuzzleGenerator::new(graph),
+    }))
+}
+
+/// Releases an engine created by `wl_engine_new`. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `engine` must be null or a pointer returned by `wl_engine_new` that has not
+/// already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_engine_free(engine: *mut WlEngine) {
+    if !engine.is_null() {
+        drop(unsafe { Box::from_raw(engine) });
+    }
+}
+
+/// Finds the shortest ladder between two words.
+///
+/// Returns the path as a comma-separated string (e.g. `"cat,cot,cog,dog"`),
+/// or null if no path exists or the input is invalid. The result must be
+/// released with `wl_string_free`.
+///
+/// # Safety
+///
+/// `engine` must be a live engine handle; `start` and `end` must be null or
+/// valid NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_solve(
+    engine: *const WlEngine,
+    start: *const c_char,
+    end: *const c_char,
+) -> *mut c_char {
+    let (Some(engine), Some(start), Some(end)) = (
+        unsafe { engine.as_ref() },
+        unsafe { borrow_str(start) },
+        unsafe { borrow_str(end) },
+    ) else {
+        return ptr::null_mut();
+    };
+
+    let graph = engine.generator.graph();
+    let normalization = graph.normalization();
+    graph
+        .find_shortest_path(
+            &normalization.normalize_query(start),
+            &normalization.normalize_query(end),
+        )
+        .map_or(ptr::null_mut(), |path| into_c_string(path.join(",")))
+}
+
+/// Verifies a comma-separated ladder.
+///
+/// Returns `1` if valid, `0` if invalid, and `-1` for malformed input
+/// (null pointers, invalid UTF-8, or fewer than two words).
+///
+/// # Safety
+///
+/// `engine` must be a live engine handle; `puzzle` must be null or a valid
+/// NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_verify(engine: *const WlEngine, puzzle: *const c_char) -> i32 {
+    let (Some(engine), Some(puzzle)) = (unsafe { engine.as_ref() }, unsafe { borrow_str(puzzle) })
+    else {
+        return -1;
+    };
+
+    match engine.generator.verify_puzzle(puzzle) {
+        Ok(true) => 1,
+        Ok(false) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Generates a random puzzle of the given difficulty from the base words.
+///
+/// `difficulty` is `0` for easy, `1` for medium, and `2` for hard. Returns the
+/// puzzle serialized as JSON, or null if the difficulty is unknown or no
+/// puzzle could be generated. The result must be released with `wl_string_free`.
+///
+/// # Safety
+///
+/// `engine` must be a live engine handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_generate(engine: *const WlEngine, difficulty: i32) -> *mut c_char {
+    let Some(engine) = (unsafe { engine.as_ref() }) else {
+        return ptr::null_mut();
+    };
+    let difficulty = match difficulty {
+        0 => Difficulty::Easy,
+        1 => Difficulty::Medium,
+        2 => Difficulty::Hard,
+        _ => return ptr::null_mut(),
+    };
+
+    engine
+        .generator
+        .generate_batch(1, difficulty)
+        .puzzles
+        .first()
+        .and_then(|puzzle| serde_json::to_string(puzzle).ok())
+        .map_or(ptr::null_mut(), into_c_string)
+}
+
+/// Releases a string returned by this API. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by this API that has not already
+/// been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wl_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_engine() -> *mut WlEngine {
+        let dictionary = b"cat\ncot\ncog\ndog\n";
+        unsafe { wl_engine_new(dictionary.as_ptr(), dictionary.len(), ptr::null(), 0) }
+    }
+
+    #[test]
+    fn test_solve_and_free() {
+        let engine = create_test_engine();
+        assert!(!engine.is_null());
+
+        let path = unsafe { wl_solve(engine, c"cat".as_ptr(), c"dog".as_ptr()) };
+        assert!(!path.is_null());
+        assert_eq!(
+            unsafe { CStr::from_ptr(path) }.to_str().unwrap(),
+            "cat,cot,cog,dog"
+        );
+
+        let missing = unsafe { wl_solve(engine, c"cat".as_ptr(), c"zzz".as_ptr()) };
+        assert!(missing.is_null());
+
+        unsafe {
+            wl_string_free(path);
+            wl_engine_free(engine);
+        }
+    }
+
+    #[test]
+    fn test_verify_return_codes() {
+        let engine = create_test_engine();
+
+        assert_eq!(unsafe { wl_verify(engine, c"cat,cot,cog,dog".as_ptr()) }, 1);
+        assert_eq!(unsafe { wl_verify(engine, c"cat,dog".as_ptr()) }, 0);
+        assert_eq!(unsafe { wl_verify(engine, c"cat".as_ptr()) }, -1);
+        assert_eq!(unsafe { wl_verify(engine, ptr::null()) }, -1);
+
+        unsafe { wl_engine_free(engine) };
+    }
+}
@@ -0,0 +1,310 @@
+//! # gRPC Service
+//!
+//! This module serves the engine over gRPC for backend services, using the
+//! service definition in `proto/wordladder_service.proto` (compiled by
+//! `build.rs` with `tonic-build`). It exposes the same operations as the
+//! daemon and HTTP server:
+//!
+//! - `Solve`: shortest ladder between two words, with optional search budgets
+//! - `Verify`: check a ladder
+//! - `GeneratePuzzle`: a puzzle between two words, or random base words
+//! - `GenerateBatch`: puzzles of one difficulty, streamed one message per
+//!   puzzle so large batches never have to fit in a single response
+//!
+//! Puzzles are sent as the `Puzzle` message of `proto/wordladder.proto`, the
+//! same schema binary puzzle packs use. Generation is CPU-bound, so it runs on
+//! tokio's blocking thread pool; batch puzzles are sent as they are generated,
+//! and a batch stops early when the client hangs up.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use wordladder_engine::graph::WordGraph;
+//! use wordladder_engine::grpc::serve;
+//! use wordladder_engine::puzzle::PuzzleGenerator;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt").unwrap();
+//! let generator = PuzzleGenerator::new(graph);
+//!
+//! let runtime = tokio::runtime::Runtime::new().unwrap();
+//! runtime
+//!     .block_on(serve(generator, "127.0.0.1:50051".parse().unwrap()))
+//!     .unwrap();
+//! ```
+
+use crate::graph::{LadderError, SolveOptions, SolveOutcome};
+use crate::puzzle::{Difficulty, Puzzle, PuzzleError, PuzzleGenerator};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+/// Types generated from `proto/wordladder_service.proto`.
+pub mod pb {
+    tonic::include_proto!("wordladder.v1");
+}
+
+use pb::word_ladder_server::{WordLadder, WordLadderServer};
+
+/// Puzzles buffered per `GenerateBatch` stream before generation waits for the client.
+const STREAM_BUFFER: usize = 64;
+
+impl From<Difficulty> for pb::Difficulty {
+    fn from(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Easy => pb::Difficulty::Easy,
+            Difficulty::Medium => pb::Difficulty::Medium,
+            Difficulty::Hard => pb::Difficulty::Hard,
+        }
+    }
+}
+
+impl From<Puzzle> for pb::Puzzle {
+    fn from(puzzle: Puzzle) -> Self {
+        Self {
+            steps: puzzle.path.len().saturating_sub(1) as u32,
+            difficulty: pb::Difficulty::from(puzzle.difficulty).into(),
+            start: puzzle.start,
+            end: puzzle.end,
+            path: puzzle.path,
+            unique_solution: puzzle.unique_solution,
+            reverse_path: puzzle.reverse_path.unwrap_or_default(),
+            meta: puzzle.meta.map(|meta| pb::PuzzleMeta {
+                created_at: meta.created_at,
+                engine_version: meta.engine_version,
+                dictionary_sha256: meta.dictionary_sha256,
+                seed: meta.seed,
+            }),
+        }
+    }
+}
+
+/// gRPC handler holding a loaded puzzle generator.
+///
+/// Cloning the generator shares its graph, so handlers on different tokio
+/// tasks work on one copy of the dictionary.
+#[derive(Clone)]
+pub struct WordLadderService {
+    /// Generator with the dictionary and base words already loaded
+    generator: PuzzleGenerator,
+}
+
+impl WordLadderService {
+    /// Creates a service around a loaded generator.
+    pub fn new(generator: PuzzleGenerator) -> Self {
+        Self { generator }
+    }
+
+    /// Wraps the service for registration with a tonic server.
+    pub fn into_server(self) -> WordLadderServer<Self> {
+        WordLadderServer::new(self)
+    }
+}
+
+#[tonic::async_trait]
+impl WordLadder for WordLadderService {
+    async fn solve(
+        &self,
+        request: Request<pb::SolveRequest>,
+    ) -> Result<Response<pb::SolveResponse>, Status> {
+        let request = request.into_inner();
+        let normalization = self.generator.graph().normalization();
+        let start = normalization.normalize_query(&request.start);
+        let end = normalization.normalize_query(&request.end);
+        let options = SolveOptions {
+            max_visited: request.max_visited.map(|max| max as usize),
+            timeout: request.timeout_ms.map(Duration::from_millis),
+        };
+        let generator = self.generator.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            generator
+                .graph()
+                .find_shortest_path_with(&start, &end, &options)
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+
+        match outcome {
+            SolveOutcome::Found(path) => Ok(Response::new(pb::SolveResponse {
+                steps: path.len() as u32 - 1,
+                path,
+            })),
+            SolveOutcome::NoPath => Err(Status::not_found(format!(
+                "No path found between {} and {}",
+                request.start, request.end
+            ))),
+            SolveOutcome::BudgetExceeded => Err(Status::resource_exhausted(format!(
+                "Search budget exceeded between {} and {}",
+                request.start, request.end
+            ))),
+        }
+    }
+
+    async fn verify(
+        &self,
+        request: Request<pb::VerifyRequest>,
+    ) -> Result<Response<pb::VerifyResponse>, Status> {
+        let puzzle = request.into_inner().words.join(",");
+        let valid = self
+            .generator
+            .verify_puzzle(&puzzle)
+            .map_err(Status::invalid_argument)?;
+        Ok(Response::new(pb::VerifyResponse { valid }))
+    }
+
+    async fn generate_puzzle(
+        &self,
+        request: Request<pb::GeneratePuzzleRequest>,
+    ) -> Result<Response<pb::Puzzle>, Status> {
+        let request = request.into_inner();
+        let generator = self.generator.clone();
+        let puzzle = tokio::task::spawn_blocking(move || {
+            let (start, end) = if request.start.is_empty() && request.end.is_empty() {
+                generator
+                    .pick_random_words()
+                    .map_err(|e| Status::failed_precondition(e.to_string()))?
+            } else {
+                let normalization = generator.graph().normalization();
+                (
+                    normalization.normalize_query(&request.start),
+                    normalization.normalize_query(&request.end),
+                )
+            };
+            generator
+                .generate_puzzle(&start, &end)
+                .map_err(|e| match e {
+                    PuzzleError::Ladder(LadderError::NoPath { .. }) => {
+                        Status::not_found(e.to_string())
+                    }
+                    _ => Status::invalid_argument(e.to_string()),
+                })
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))??;
+        Ok(Response::new(puzzle.into()))
+    }
+
+    type GenerateBatchStream = ReceiverStream<Result<pb::Puzzle, Status>>;
+
+    async fn generate_batch(
+        &self,
+        request: Request<pb::GenerateBatchRequest>,
+    ) -> Result<Response<Self::GenerateBatchStream>, Status> {
+        let request = request.into_inner();
+        let difficulty = match pb::Difficulty::try_from(request.difficulty) {
+            Ok(pb::Difficulty::Easy) => Difficulty::Easy,
+            Ok(pb::Difficulty::Unspecified | pb::Difficulty::Medium) => Difficulty::Medium,
+            Ok(pb::Difficulty::Hard) => Difficulty::Hard,
+            Err(_) => return Err(Status::invalid_argument("Unknown difficulty")),
+        };
+        let count = request.count as usize;
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+        let generator = self.generator.clone();
+        tokio::task::spawn_blocking(move || {
+            let cancel = AtomicBool::new(false);
+            // Each puzzle is sent as soon as it is generated; a closed stream
+            // ends generation through the send error or the cancel flag
+            let _ = generator.generate_batch_streaming(
+                count,
+                difficulty,
+                &cancel,
+                |_| {
+                    if sender.is_closed() {
+                        cancel.store(true, Ordering::Relaxed);
+                    }
+                },
+                |puzzle| {
+                    sender
+                        .blocking_send(Ok(puzzle.into()))
+                        .map_err(|_| anyhow::anyhow!("client disconnected"))
+                },
+            );
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+}
+
+/// Serves the gRPC service until the server fails.
+///
+/// # Arguments
+///
+/// * `generator` - A generator whose graph has already been loaded
+/// * `addr` - Address to listen on
+pub async fn serve(generator: PuzzleGenerator, addr: SocketAddr) -> anyhow::Result<()> {
+    tonic::transport::Server::builder()
+        .add_service(WordLadderService::new(generator).into_server())
+        .serve(addr)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::WordGraph;
+    use tokio_stream::StreamExt;
+
+    fn create_test_service() -> WordLadderService {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\nbat\n");
+        graph.load_base_words_from_str("cat\ndog\n");
+        WordLadderService::new(PuzzleGenerator::new(graph))
+    }
+
+    #[test]
+    fn test_solve_and_verify() {
+        let service = create_test_service();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let solved = runtime
+            .block_on(service.solve(Request::new(pb::SolveRequest {
+                start: "cat".to_string(),
+                end: "dog".to_string(),
+                ..Default::default()
+            })))
+            .unwrap()
+            .into_inner();
+        assert_eq!(solved.steps, 3);
+
+        let missing = runtime
+            .block_on(service.solve(Request::new(pb::SolveRequest {
+                start: "cat".to_string(),
+                end: "zzz".to_string(),
+                ..Default::default()
+            })))
+            .unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::NotFound);
+
+        let verified = runtime
+            .block_on(service.verify(Request::new(pb::VerifyRequest { words: solved.path })))
+            .unwrap()
+            .into_inner();
+        assert!(verified.valid);
+    }
+
+    #[test]
+    fn test_generate_batch_streams_puzzles() {
+        let service = create_test_service();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let puzzles: Vec<pb::Puzzle> = runtime.block_on(async {
+            let stream = service
+                .generate_batch(Request::new(pb::GenerateBatchRequest {
+                    difficulty: pb::Difficulty::Easy.into(),
+                    count: 2,
+                }))
+                .await
+                .unwrap()
+                .into_inner();
+            stream.map(Result::unwrap).collect().await
+        });
+        assert!(puzzles.len() <= 2);
+        for puzzle in puzzles {
+            assert_eq!(puzzle.difficulty, i32::from(pb::Difficulty::Easy));
+            assert_eq!(puzzle.steps as usize + 1, puzzle.path.len());
+        }
+    }
+}
@@ -8,10 +8,21 @@
 //!
 //! The library is organized into several key modules:
 //! - `config`: Configuration management and defaults
-//! - `graph`: Word graph construction and BFS path finding
-//! - `puzzle`: Puzzle generation, validation, and difficulty assessment
+//! - `graph`: Word graph construction and BFS path finding (from `wordladder-core`)
+//! - `puzzle`: Puzzle generation, validation, and difficulty assessment (from `wordladder-core`)
 //! - `cli`: Command-line interface for the application
+//! - `daemon`: Long-running JSON request server with a warm graph
 //! - `exporters`: Export functionality for different formats (SQL, etc.)
+//! - `fetch`: Word list downloads for the `fetch-dict` subcommand (`fetch` feature)
+//! - `ffi`: C ABI for native mobile integration (`ffi` feature)
+//! - `grpc`: gRPC service for backend integration (`grpc` feature)
+//! - `review`: Terminal dashboard for curating generated puzzles (`tui` feature)
+//! - `server`: HTTP front end of the daemon with API keys and rate limiting
+//! - `wasm`: JavaScript bindings for WebAssembly builds (`wasm-bindgen` feature)
+//!
+//! The `graph` and `puzzle` modules live in the `wordladder-core` workspace
+//! crate (`crates/wordladder-core`) and are re-exported here, so apps that
+//! only need the algorithms can depend on the core crate alone.
 //!
 //! ## Key Features
 //!
@@ -38,13 +49,31 @@
 //! let generator = PuzzleGenerator::new(graph);
 //!
 //! // Generate a puzzle
-//! if let Some(puzzle) = generator.generate_puzzle("cat", "dog") {
+//! if let Ok(puzzle) = generator.generate_puzzle("cat", "dog") {
 //!     println!("Found path: {:?}", puzzle.path);
 //! }
 //! ```
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod checkpoint;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod cli;
 pub mod config;
+pub mod daemon;
 pub mod exporters;
-pub mod graph;
-pub mod puzzle;
+#[cfg(all(feature = "fetch", not(target_arch = "wasm32")))]
+pub mod fetch;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(feature = "grpc", not(target_arch = "wasm32")))]
+pub mod grpc;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod report;
+#[cfg(all(feature = "tui", not(target_arch = "wasm32")))]
+pub mod review;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod server;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+pub use wordladder_core::{graph, puzzle};
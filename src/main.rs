@@ -30,10 +30,13 @@
 //!
 //! The application uses `anyhow` for comprehensive error handling and provides
 //! user-friendly error messages for common issues like missing files or invalid input.
+//! The process exit code follows `cli::CliExit`; argument errors exit with the
+//! configuration error code (4) rather than clap's default of 2, which is
+//! reserved for "no path found".
 
-use anyhow::Result;
 use clap::Parser;
-use wordladder_engine::cli::{Cli, run};
+use std::process::ExitCode;
+use wordladder_engine::cli::{Cli, CliExit, run};
 
 /// Main entry point for the word ladder engine.
 ///
@@ -44,8 +47,27 @@ use wordladder_engine::cli::{Cli, run};
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` on successful execution, or an error if something goes wrong.
-fn main() -> Result<()> {
-    let cli = Cli::parse();
-    run(cli)
+/// Returns the documented exit code of the command (see `CliExit`).
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(error) => {
+            // Printing help or version is not an error
+            let exit = if error.use_stderr() {
+                CliExit::ConfigError
+            } else {
+                CliExit::Success
+            };
+            let _ = error.print();
+            return exit.into();
+        }
+    };
+
+    match run(cli) {
+        Ok(exit) => exit.into(),
+        Err(error) => {
+            eprintln!("Error: {:?}", error);
+            CliExit::from_error(&error).into()
+        }
+    }
 }
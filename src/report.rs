@@ -0,0 +1,217 @@
+//! # CLI Reports
+//!
+//! This module provides `CliReport`, the common result type every CLI
+//! subcommand fills in while it runs. In the default text mode, messages are
+//! printed as they happen, just like plain `println!` output. With the global
+//! `--json` flag, the messages are suppressed and a single structured object is
+//! printed to stdout when the command finishes, so scripts don't have to parse
+//! human-readable sentences.
+//!
+//! ## JSON Shape
+//!
+//! ```json
+//! {
+//!   "command": "batch",
+//!   "counts": { "puzzles": 50, "medium_attempts": 71 },
+//!   "outputs": ["output/batch_medium.txt"],
+//!   "timings": { "medium": 0.41, "total": 0.63 },
+//!   "warnings": [],
+//!   "result": null
+//! }
+//! ```
+//!
+//! `counts` and `timings` (in seconds) are keyed by name; `result` holds
+//! command-specific data such as a solved path, and is omitted when unused.
+//!
+//! When an export is written to stdout (`--output -`), `use_stderr` moves the
+//! messages and the JSON report to stderr so they don't mix with the export.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::report::CliReport;
+//!
+//! let mut report = CliReport::new("export-dict", true);
+//! report.count("words", 3);
+//! report.output("output/dictionary.sql");
+//! report.info("Exported 3 dictionary words to output/dictionary.sql");
+//!
+//! let json = report.to_json().unwrap();
+//! assert!(json.contains("\"words\": 3"));
+//! ```
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Structured result of one CLI command.
+#[derive(Debug, Serialize)]
+pub struct CliReport {
+    /// Name of the subcommand that produced the report
+    pub command: String,
+    /// Named counts, e.g. puzzles generated or words exported
+    pub counts: BTreeMap<String, usize>,
+    /// Files written by the command, in the order they were written
+    pub outputs: Vec<PathBuf>,
+    /// Named durations in seconds; `total` is added when the report is finished
+    pub timings: BTreeMap<String, f64>,
+    /// Problems that did not stop the command
+    pub warnings: Vec<String>,
+    /// Command-specific data, such as a solved path or a verification result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// Whether to print JSON at the end instead of text as the command runs
+    #[serde(skip)]
+    json: bool,
+    /// Whether to print to stderr instead of stdout
+    #[serde(skip)]
+    stderr: bool,
+    /// When the command started, for the `total` timing
+    #[serde(skip)]
+    started: Instant,
+}
+
+impl CliReport {
+    /// Creates an empty report for a command.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Name of the subcommand
+    /// * `json` - Whether the report is printed as JSON when finished
+    pub fn new(command: &str, json: bool) -> Self {
+        Self {
+            command: command.to_string(),
+            counts: BTreeMap::new(),
+            outputs: Vec::new(),
+            timings: BTreeMap::new(),
+            warnings: Vec::new(),
+            result: None,
+            json,
+            stderr: false,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns `true` if the report is printed as JSON.
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
+    /// Prints messages and the JSON report to stderr instead of stdout, for
+    /// commands writing their export to stdout.
+    pub fn use_stderr(&mut self) {
+        self.stderr = true;
+    }
+
+    /// Prints a line to stdout, or to stderr if `use_stderr` was called.
+    fn print(&self, line: &str) {
+        if self.stderr {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    /// Prints a human-readable message, unless in JSON mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The line to print
+    pub fn info(&self, message: impl AsRef<str>) {
+        if !self.json {
+            self.print(message.as_ref());
+        }
+    }
+
+    /// Records a warning, also printing it unless in JSON mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Description of the problem
+    pub fn warn(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.info(&message);
+        self.warnings.push(message);
+    }
+
+    /// Adds to a named count.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the count
+    /// * `value` - Amount to add
+    pub fn count(&mut self, name: &str, value: usize) {
+        *self.counts.entry(name.to_string()).or_insert(0) += value;
+    }
+
+    /// Records a file written by the command.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the written file
+    pub fn output(&mut self, path: impl AsRef<Path>) {
+        self.outputs.push(path.as_ref().to_path_buf());
+    }
+
+    /// Adds to a named timing.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the timed step
+    /// * `elapsed` - Time the step took
+    pub fn timing(&mut self, name: &str, elapsed: Duration) {
+        *self.timings.entry(name.to_string()).or_insert(0.0) += elapsed.as_secs_f64();
+    }
+
+    /// Sets the command-specific result data.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - Any serializable value
+    pub fn set_result(&mut self, result: impl Serialize) -> Result<(), serde_json::Error> {
+        self.result = Some(serde_json::to_value(result)?);
+        Ok(())
+    }
+
+    /// Serializes the report to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Records the total run time and, in JSON mode, prints the report.
+    pub fn finish(mut self) -> Result<(), serde_json::Error> {
+        self.timing("total", self.started.elapsed());
+        if self.json {
+            self.print(&self.to_json()?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_json() {
+        let mut report = CliReport::new("solve", true);
+        report.count("steps", 2);
+        report.count("steps", 1);
+        report.output("output/puzzles.sql");
+        report.timing("easy", Duration::from_millis(500));
+        report.warn("No path found between cat and xyz");
+        report.set_result(vec!["cat", "cot", "dot"]).unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(value["command"], "solve");
+        assert_eq!(value["counts"]["steps"], 3);
+        assert_eq!(value["outputs"][0], "output/puzzles.sql");
+        assert_eq!(value["timings"]["easy"], 0.5);
+        assert_eq!(value["warnings"][0], "No path found between cat and xyz");
+        assert_eq!(value["result"][2], "dot");
+
+        let empty = CliReport::new("verify", false);
+        assert!(!empty.to_json().unwrap().contains("\"result\""));
+    }
+}
@@ -0,0 +1,363 @@
+//! # Puzzle Review
+//!
+//! This module implements an interactive terminal dashboard for curating
+//! generated puzzles. It pages through a batch one puzzle at a time, showing the
+//! solution path, quality metrics, and alternative shortest paths, and records
+//! an accept/reject decision for each puzzle. It is only compiled with the `tui`
+//! feature.
+//!
+//! ## Key Bindings
+//!
+//! - `a` / `y`: Accept the puzzle and move to the next one
+//! - `r` / `n`: Reject the puzzle and move to the next one
+//! - `←` / `→` (or `h` / `l`): Move between puzzles without deciding
+//! - `q` / `Esc`: Finish the review
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use wordladder_engine::graph::WordGraph;
+//! use wordladder_engine::puzzle::{Difficulty, PuzzleGenerator};
+//! use wordladder_engine::review;
+//!
+//! let mut graph = WordGraph::new();
+//! graph.load_dictionary("data/dictionary.txt").unwrap();
+//! graph.load_base_words("data/base_words.txt").unwrap();
+//! let generator = PuzzleGenerator::new(graph);
+//!
+//! let batch = generator.generate_batch(20, Difficulty::Medium).puzzles;
+//! let approved = review::run(&generator, batch).unwrap();
+//! ```
+
+use crate::puzzle::{Difficulty, Puzzle, PuzzleGenerator};
+use anyhow::Result;
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+
+/// Maximum number of alternative shortest paths shown per puzzle.
+const MAX_ALTERNATIVES: usize = 5;
+
+/// Curator decision for a single puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// Keep the puzzle in the approved set
+    Accept,
+    /// Drop the puzzle from the approved set
+    Reject,
+}
+
+/// Quality metrics shown alongside a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleMetrics {
+    /// Number of steps in the solution
+    pub steps: usize,
+    /// Difficulty level of the puzzle
+    pub difficulty: Difficulty,
+    /// Smallest neighbor count among intermediate words (see `PuzzleGenerator::branching`)
+    pub branching: Option<usize>,
+    /// Number of distinct shortest paths between the endpoints
+    pub shortest_paths: u64,
+    /// Shortest paths other than the puzzle's own solution
+    pub alternatives: Vec<Vec<String>>,
+}
+
+impl PuzzleMetrics {
+    /// Computes the metrics of a puzzle against the generator's graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - Generator whose graph the puzzle was built from
+    /// * `puzzle` - The puzzle to measure
+    pub fn compute(generator: &PuzzleGenerator, puzzle: &Puzzle) -> Self {
+        let graph = generator.graph();
+        let alternatives = graph
+            .shortest_paths(&puzzle.start, &puzzle.end, MAX_ALTERNATIVES + 1)
+            .into_iter()
+            .filter(|path| *path != puzzle.path)
+            .take(MAX_ALTERNATIVES)
+            .collect();
+
+        Self {
+            steps: puzzle.path.len() - 1,
+            difficulty: puzzle.difficulty,
+            branching: generator.branching(puzzle),
+            shortest_paths: graph.count_shortest_paths(&puzzle.start, &puzzle.end),
+            alternatives,
+        }
+    }
+}
+
+/// State of a review session: the puzzles, the decisions so far, and the cursor.
+#[derive(Debug, Clone)]
+pub struct ReviewSession {
+    /// Puzzles under review
+    puzzles: Vec<Puzzle>,
+    /// Decision for each puzzle (`None` if not yet reviewed)
+    decisions: Vec<Option<Decision>>,
+    /// Index of the puzzle currently shown
+    current: usize,
+}
+
+impl ReviewSession {
+    /// Creates a session positioned at the first puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzles` - Puzzles to review
+    pub fn new(puzzles: Vec<Puzzle>) -> Self {
+        let decisions = vec![None; puzzles.len()];
+        Self {
+            puzzles,
+            decisions,
+            current: 0,
+        }
+    }
+
+    /// Returns the puzzle currently shown, or `None` if the batch is empty.
+    pub fn current(&self) -> Option<&Puzzle> {
+        self.puzzles.get(self.current)
+    }
+
+    /// Returns the index of the puzzle currently shown.
+    pub fn position(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the number of puzzles in the session.
+    pub fn len(&self) -> usize {
+        self.puzzles.len()
+    }
+
+    /// Returns `true` if the session has no puzzles.
+    pub fn is_empty(&self) -> bool {
+        self.puzzles.is_empty()
+    }
+
+    /// Returns the decision recorded for the current puzzle.
+    pub fn current_decision(&self) -> Option<Decision> {
+        self.decisions.get(self.current).copied().flatten()
+    }
+
+    /// Records a decision for the current puzzle and moves to the next one.
+    ///
+    /// # Arguments
+    ///
+    /// * `decision` - Whether to accept or reject the current puzzle
+    pub fn decide(&mut self, decision: Decision) {
+        if let Some(slot) = self.decisions.get_mut(self.current) {
+            *slot = Some(decision);
+        }
+        self.next();
+    }
+
+    /// Moves to the next puzzle, staying on the last one at the end.
+    pub fn next(&mut self) {
+        if self.current + 1 < self.puzzles.len() {
+            self.current += 1;
+        }
+    }
+
+    /// Moves to the previous puzzle, staying on the first one at the start.
+    pub fn previous(&mut self) {
+        self.current = self.current.saturating_sub(1);
+    }
+
+    /// Returns the number of puzzles with a decision.
+    pub fn reviewed(&self) -> usize {
+        self.decisions.iter().flatten().count()
+    }
+
+    /// Consumes the session, returning the accepted puzzles in their original order.
+    pub fn into_approved(self) -> Vec<Puzzle> {
+        self.puzzles
+            .into_iter()
+            .zip(self.decisions)
+            .filter(|(_, decision)| *decision == Some(Decision::Accept))
+            .map(|(puzzle, _)| puzzle)
+            .collect()
+    }
+}
+
+/// Runs the interactive review dashboard until the curator quits.
+///
+/// Takes over the terminal for the duration of the review and restores it
+/// before returning, including when an error occurs.
+///
+/// # Arguments
+///
+/// * `generator` - Generator whose graph is used to compute metrics
+/// * `puzzles` - Puzzles to review
+///
+/// # Returns
+///
+/// The accepted puzzles, or an error if the terminal cannot be used.
+pub fn run(generator: &PuzzleGenerator, puzzles: Vec<Puzzle>) -> Result<Vec<Puzzle>> {
+    let mut session = ReviewSession::new(puzzles);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, generator, &mut session);
+    ratatui::restore();
+    result?;
+    Ok(session.into_approved())
+}
+
+/// Draws the dashboard and applies key presses until the curator quits.
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    generator: &PuzzleGenerator,
+    session: &mut ReviewSession,
+) -> Result<()> {
+    // Metrics are recomputed only when the cursor moves to another puzzle
+    let mut metrics: Option<(usize, PuzzleMetrics)> = None;
+
+    loop {
+        if let Some(puzzle) = session.current()
+            && metrics
+                .as_ref()
+                .is_none_or(|(i, _)| *i != session.position())
+        {
+            metrics = Some((
+                session.position(),
+                PuzzleMetrics::compute(generator, puzzle),
+            ));
+        }
+        terminal.draw(|frame| draw(frame, session, metrics.as_ref().map(|(_, m)| m)))?;
+
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            match key.code {
+                KeyCode::Char('a') | KeyCode::Char('y') => session.decide(Decision::Accept),
+                KeyCode::Char('r') | KeyCode::Char('n') => session.decide(Decision::Reject),
+                KeyCode::Right | KeyCode::Char('l') => session.next(),
+                KeyCode::Left | KeyCode::Char('h') => session.previous(),
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Renders the header, the current puzzle with its metrics, and the key help.
+fn draw(frame: &mut Frame, session: &ReviewSession, metrics: Option<&PuzzleMetrics>) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    frame.render_widget(
+        Line::from(format!(
+            " Puzzle {}/{} | reviewed {}",
+            (session.position() + 1).min(session.len()),
+            session.len(),
+            session.reviewed()
+        ))
+        .bold(),
+        header,
+    );
+    frame.render_widget(
+        Line::from(" a/y accept  r/n reject  ←/→ navigate  q quit").dim(),
+        footer,
+    );
+
+    let (Some(puzzle), Some(metrics)) = (session.current(), metrics) else {
+        frame.render_widget(Paragraph::new("No puzzles to review."), body);
+        return;
+    };
+
+    let status = match session.current_decision() {
+        Some(Decision::Accept) => Span::styled("ACCEPTED", Style::new().fg(Color::Green)),
+        Some(Decision::Reject) => Span::styled("REJECTED", Style::new().fg(Color::Red)),
+        None => Span::raw("undecided"),
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw(format!("{} -> {}  ", puzzle.start, puzzle.end)).bold(),
+            status,
+        ]),
+        Line::from(""),
+        Line::from(format!("Path: {}", puzzle.path.join(" -> "))),
+        Line::from(""),
+        Line::from(format!("Steps: {}", metrics.steps)),
+        Line::from(format!("Difficulty: {:?}", metrics.difficulty)),
+        Line::from(format!(
+            "Branching: {}",
+            metrics
+                .branching
+                .map_or("n/a".to_string(), |b| b.to_string())
+        )),
+        Line::from(format!("Shortest paths: {}", metrics.shortest_paths)),
+        Line::from(""),
+        Line::from("Alternative paths:").bold(),
+    ];
+    if metrics.alternatives.is_empty() {
+        lines.push(Line::from("  (none)"));
+    }
+    for alternative in &metrics.alternatives {
+        lines.push(Line::from(format!("  {}", alternative.join(" -> "))));
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::bordered().title(" Review "))
+            .wrap(Wrap { trim: false }),
+        body,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::WordGraph;
+
+    fn create_test_generator() -> PuzzleGenerator {
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str("cat\ncot\ncog\ndog\ndot\n");
+        PuzzleGenerator::new(graph)
+    }
+
+    #[test]
+    fn test_session_decisions() {
+        let generator = create_test_generator();
+        let puzzles = vec![
+            generator.generate_puzzle("cat", "dog").unwrap(),
+            generator.generate_puzzle("cat", "cog").unwrap(),
+            generator.generate_puzzle("cot", "dog").unwrap(),
+        ];
+        let mut session = ReviewSession::new(puzzles);
+
+        session.decide(Decision::Reject);
+        session.decide(Decision::Accept);
+        assert_eq!(session.position(), 2);
+        session.previous();
+        assert_eq!(session.current_decision(), Some(Decision::Accept));
+        session.next();
+        session.decide(Decision::Accept);
+        assert_eq!(session.position(), 2);
+        assert_eq!(session.reviewed(), 3);
+
+        let approved = session.into_approved();
+        assert_eq!(approved.len(), 2);
+        assert_eq!(approved[0].end, "cog");
+        assert_eq!(approved[1].start, "cot");
+    }
+
+    #[test]
+    fn test_metrics() {
+        let generator = create_test_generator();
+        let puzzle = generator.generate_puzzle("cat", "dog").unwrap();
+        let metrics = PuzzleMetrics::compute(&generator, &puzzle);
+
+        assert_eq!(metrics.steps, 3);
+        assert_eq!(metrics.shortest_paths, 2);
+        assert_eq!(metrics.alternatives.len(), 1);
+        assert_ne!(metrics.alternatives[0], puzzle.path);
+    }
+}
@@ -0,0 +1,1019 @@
+//! # HTTP Server
+//!
+//! This module exposes the daemon's methods over HTTP for the `serve`
+//! subcommand, so a public client such as a puzzle-of-the-day widget can query
+//! a warm graph directly. Each method is a route under `/v1`; the JSON body of
+//! a `POST` is the method's `params`, and the response body is the daemon's
+//! `result` (or `{"error": ...}`):
+//!
+//! ```text
+//! POST /v1/solve     {"start": "cat", "end": "dog"}
+//! POST /v1/verify    {"puzzle": "cat,cot,cog,dog"}
+//! POST /v1/generate  {"difficulty": "hard", "count": 5}
+//! POST /v1/reload    {"wait": true}   (admin keys only)
+//! GET  /v1/stats
+//! GET  /health       (no authentication, not rate limited)
+//! GET  /openapi.json (no authentication, not rate limited)
+//! ```
+//!
+//! `/openapi.json` returns an OpenAPI 3.0 description of these routes (see
+//! `openapi`) for generating client SDKs.
+//!
+//! ## Authentication and Rate Limiting
+//!
+//! Requests pass through a tower middleware stack before reaching the daemon:
+//!
+//! - `ApiKeyLayer`: when `ServerConfig::api_keys` is non-empty, every `/v1`
+//!   request must carry one of the keys (or an admin key) as
+//!   `Authorization: Bearer <key>` or `X-Api-Key: <key>`; other requests get
+//!   `401`. `/v1/reload` additionally requires one of
+//!   `ServerConfig::admin_keys` and gets `403` otherwise, so reloading is
+//!   disabled when no admin keys are configured. A client address that keeps
+//!   failing these checks gets `429` before its keys are checked, so keys
+//!   cannot be guessed at full speed.
+//! - `RateLimitLayer`: each key (or each client address when authentication
+//!   is disabled) has a token bucket that refills at `requests_per_minute`
+//!   and holds up to `burst` requests. Requests over the limit get `429` with
+//!   a `Retry-After` header. Buckets of idle clients are dropped.
+//! - `DaemonService`: answers the request with the daemon. `/v1/generate` is
+//!   limited to `max_count` puzzles and `max_attempts` word pairs per request,
+//!   and `/v1/solve` to `solve_max_visited` words and `solve_timeout_ms`.
+//!
+//! ## Connections
+//!
+//! `Server::run` serves HTTP/1.1 with hyper on a tokio runtime, one task per
+//! connection (up to `MAX_CONNECTIONS` at once), so a slow client does not hold
+//! up others. Clients get `READ_TIMEOUT` to send their headers and body, and
+//! bodies over `max_body_bytes` get `413`. The daemon answers requests on
+//! tokio's blocking thread pool; a `reload` waiting for its rebuild does not
+//! hold up the others. Run the server behind a reverse proxy for TLS.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::config::ServerConfig;
+//! use wordladder_engine::daemon::Daemon;
+//! use wordladder_engine::graph::WordGraph;
+//! use wordladder_engine::puzzle::PuzzleGenerator;
+//! use wordladder_engine::server::{HttpRequest, Server};
+//!
+//! let graph = WordGraph::from_words(["cat", "cot", "cog", "dog"].map(String::from));
+//! let config = ServerConfig {
+//!     api_keys: vec!["secret".to_string()],
+//!     ..ServerConfig::default()
+//! };
+//! let server = Server::new(Daemon::new(PuzzleGenerator::new(graph)), config);
+//!
+//! let request = HttpRequest::post("/v1/solve", r#"{"start": "cat", "end": "dog"}"#)
+//!     .with_header("authorization", "Bearer secret")
+//!     .with_peer("127.0.0.1");
+//! let runtime = tokio::runtime::Runtime::new().unwrap();
+//! let response = runtime.block_on(server.handle(request));
+//! assert_eq!(response.status, 200);
+//! ```
+
+pub mod openapi;
+
+use crate::config::ServerConfig;
+use crate::daemon::Daemon;
+use anyhow::Result;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::{TokioIo, TokioTimer};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::TcpListener;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tower::{Layer, Service, ServiceBuilder, ServiceExt};
+
+/// How long a client may take to send its request headers, and again its body.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Most connections served at once; further clients wait to be accepted.
+const MAX_CONNECTIONS: usize = 1024;
+
+/// How often the rate limiter drops the buckets of idle clients.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Failed authentication attempts a client address may make per minute, so
+/// API and admin keys cannot be guessed at full speed.
+const AUTH_FAILURES_PER_MINUTE: u32 = 10;
+
+/// Failed authentication attempts a client address may make in a row before
+/// `AUTH_FAILURES_PER_MINUTE` applies.
+const AUTH_FAILURE_BURST: u32 = 5;
+
+/// How long to pause after failing to accept a connection, e.g. when out of
+/// file descriptors, before accepting again.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Future returned by the middleware services.
+type ResponseFuture = Pin<Box<dyn Future<Output = Result<HttpResponse, Infallible>> + Send>>;
+
+/// A parsed HTTP request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// Request method, e.g. `GET`
+    pub method: String,
+    /// Request path without the query string
+    pub path: String,
+    /// Headers with lowercased names
+    pub headers: HashMap<String, String>,
+    /// Request body
+    pub body: Vec<u8>,
+    /// Client address, used as the rate limit key when authentication is
+    /// disabled
+    pub peer: String,
+}
+
+impl HttpRequest {
+    /// Creates a `GET` request without headers.
+    pub fn get(path: &str) -> Self {
+        Self {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            headers: HashMap::new(),
+            body: Vec::new(),
+            peer: String::new(),
+        }
+    }
+
+    /// Creates a `POST` request with a body and no headers.
+    pub fn post(path: &str, body: &str) -> Self {
+        Self {
+            method: "POST".to_string(),
+            body: body.as_bytes().to_vec(),
+            ..Self::get(path)
+        }
+    }
+
+    /// Adds a header; the name is lowercased.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers
+            .insert(name.to_ascii_lowercase(), value.to_string());
+        self
+    }
+
+    /// Sets the client address.
+    pub fn with_peer(mut self, peer: &str) -> Self {
+        self.peer = peer.to_string();
+        self
+    }
+
+    /// Returns a header value by lowercase name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// Returns the API key sent as a bearer token or `X-Api-Key` header.
+    pub fn api_key(&self) -> Option<&str> {
+        self.header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .or_else(|| self.header("x-api-key"))
+            .map(str::trim)
+    }
+
+    /// Reads a request and its body from hyper.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request as received by hyper
+    /// * `peer` - Client address
+    /// * `max_body_bytes` - Largest body accepted
+    ///
+    /// # Returns
+    ///
+    /// The request, or the error response to send if the body is too large,
+    /// too slow, or cannot be read.
+    async fn from_hyper(
+        request: hyper::Request<Incoming>,
+        peer: String,
+        max_body_bytes: usize,
+    ) -> Result<Self, HttpResponse> {
+        let (parts, body) = request.into_parts();
+        let body = Limited::new(body, max_body_bytes).collect();
+        let body = match tokio::time::timeout(READ_TIMEOUT, body).await {
+            Ok(Ok(body)) => body.to_bytes().to_vec(),
+            Ok(Err(e)) if e.is::<LengthLimitError>() => {
+                return Err(HttpResponse::error(
+                    413,
+                    &format!("Request body is larger than {} bytes", max_body_bytes),
+                ));
+            }
+            Ok(Err(e)) => return Err(HttpResponse::error(400, &e.to_string())),
+            Err(_) => return Err(HttpResponse::error(408, "Request body timed out")),
+        };
+        let headers = parts
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        Ok(Self {
+            method: parts.method.to_string(),
+            path: parts.uri.path().to_string(),
+            headers,
+            body,
+            peer,
+        })
+    }
+}
+
+/// An HTTP response with a JSON body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    /// Status code
+    pub status: u16,
+    /// Extra headers besides `Content-Type` and `Content-Length`
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: Value,
+}
+
+impl HttpResponse {
+    /// Creates a response with a JSON body.
+    pub fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Creates an error response with an `{"error": ...}` body.
+    pub fn error(status: u16, message: &str) -> Self {
+        Self::json(status, json!({ "error": message }))
+    }
+
+    /// Creates a `429` response telling the client when to retry.
+    pub fn too_many_requests(message: &str, retry_after: Duration) -> Self {
+        let mut response = Self::error(429, message);
+        response.headers.push((
+            "Retry-After".to_string(),
+            retry_after.as_secs().max(1).to_string(),
+        ));
+        response
+    }
+
+    /// Converts the response for hyper to send.
+    fn into_hyper(self) -> hyper::Response<Full<Bytes>> {
+        let body = serde_json::to_vec(&self.body).unwrap_or_default();
+        let mut response = hyper::Response::builder()
+            .status(self.status)
+            .header("content-type", "application/json");
+        for (name, value) in &self.headers {
+            response = response.header(name, value);
+        }
+        response
+            .body(Full::new(Bytes::from(body)))
+            .unwrap_or_else(|_| {
+                let mut response = hyper::Response::new(Full::default());
+                *response.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+                response
+            })
+    }
+}
+
+/// What a request path refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route<'a> {
+    /// `GET /health`
+    Health,
+    /// `GET /openapi.json`
+    OpenApi,
+    /// A daemon method under `/v1`, with the HTTP method it expects
+    Method {
+        name: &'a str,
+        expected: &'static str,
+    },
+    /// Any other path
+    NotFound,
+}
+
+impl<'a> Route<'a> {
+    /// Classifies a request path.
+    fn of(path: &'a str) -> Self {
+        match path {
+            "/health" => return Self::Health,
+            "/openapi.json" => return Self::OpenApi,
+            _ => {}
+        }
+        let Some(name) = path.strip_prefix("/v1/") else {
+            return Self::NotFound;
+        };
+        let expected = match name {
+            "solve" | "verify" | "generate" | "reload" => "POST",
+            "stats" => "GET",
+            _ => return Self::NotFound,
+        };
+        Self::Method { name, expected }
+    }
+}
+
+/// Tower layer checking API keys on `/v1` routes.
+///
+/// Regular keys reach every method but `reload`, which needs an admin key.
+/// Without regular keys, authentication is disabled for everything but
+/// `reload`. Each client address may fail authentication
+/// `AUTH_FAILURES_PER_MINUTE` times a minute; after that its requests get
+/// `429` before their keys are checked.
+#[derive(Debug, Clone)]
+pub struct ApiKeyLayer {
+    /// Keys accepted on every route but `/v1/reload`
+    api_keys: Arc<[String]>,
+    /// Keys accepted on every route
+    admin_keys: Arc<[String]>,
+    /// Failed attempts per client address, shared by every connection
+    failures: Arc<Mutex<RateLimiter>>,
+}
+
+impl ApiKeyLayer {
+    /// Creates the layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_keys` - Accepted keys (empty disables authentication)
+    /// * `admin_keys` - Keys that may also reload (empty disables reloading)
+    pub fn new(api_keys: Vec<String>, admin_keys: Vec<String>) -> Self {
+        Self {
+            api_keys: api_keys.into(),
+            admin_keys: admin_keys.into(),
+            failures: Arc::new(Mutex::new(RateLimiter::new(
+                AUTH_FAILURES_PER_MINUTE,
+                AUTH_FAILURE_BURST,
+            ))),
+        }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKey<S>;
+
+    fn layer(&self, inner: S) -> ApiKey<S> {
+        ApiKey {
+            inner,
+            keys: self.clone(),
+        }
+    }
+}
+
+/// Service produced by `ApiKeyLayer`.
+#[derive(Debug, Clone)]
+pub struct ApiKey<S> {
+    /// Service answering authenticated requests
+    inner: S,
+    /// Accepted keys
+    keys: ApiKeyLayer,
+}
+
+impl<S> ApiKey<S> {
+    /// Returns the response rejecting a request, or `None` if it may proceed.
+    fn reject(&self, request: &HttpRequest) -> Option<HttpResponse> {
+        let Route::Method { name, .. } = Route::of(&request.path) else {
+            return None;
+        };
+        let now = Instant::now();
+        let mut failures = self
+            .keys
+            .failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Err(retry_after) = failures.peek(&request.peer, now) {
+            return Some(HttpResponse::too_many_requests(
+                "Too many failed authentication attempts",
+                retry_after,
+            ));
+        }
+
+        let key = request.api_key();
+        let is_admin = key.is_some_and(|key| contains_key(&self.keys.admin_keys, key));
+        let is_valid = is_admin
+            || self.keys.api_keys.is_empty()
+            || key.is_some_and(|key| contains_key(&self.keys.api_keys, key));
+        let rejection = if !is_valid {
+            HttpResponse::error(401, "Missing or invalid API key")
+        } else if name == "reload" && !is_admin {
+            HttpResponse::error(403, "Reloading requires an admin key")
+        } else {
+            return None;
+        };
+        // Only the count matters here; the next attempt is refused by `peek`
+        let _ = failures.check(&request.peer, now);
+        Some(rejection)
+    }
+}
+
+impl<S> Service<HttpRequest> for ApiKey<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse, Error = Infallible>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = Infallible;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> ResponseFuture {
+        match self.reject(&request) {
+            Some(response) => Box::pin(async move { Ok(response) }),
+            None => Box::pin(self.inner.call(request)),
+        }
+    }
+}
+
+/// Compares a key against every accepted key in constant time per key.
+fn contains_key(keys: &[String], key: &str) -> bool {
+    keys.iter()
+        .fold(false, |found, valid| found | constant_time_eq(key, valid))
+}
+
+/// Compares two strings without returning early on the first difference.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+/// Token bucket state of one client.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    /// Requests the client may still make right now
+    tokens: f64,
+    /// When `tokens` was last refilled
+    refilled_at: Instant,
+}
+
+/// Per-client token bucket rate limiter.
+///
+/// A bucket that has refilled to capacity behaves exactly like a new one, so
+/// such buckets are dropped every `SWEEP_INTERVAL` to keep memory bounded by
+/// the recently active clients.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// Tokens added per second
+    rate: f64,
+    /// Most tokens a bucket holds
+    capacity: f64,
+    /// Buckets keyed by client (API key or address)
+    buckets: HashMap<String, Bucket>,
+    /// When idle buckets were last dropped
+    swept_at: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_minute` - Steady request rate per client (0 disables limiting)
+    /// * `burst` - Most requests a client may make at once after being idle
+    pub fn new(requests_per_minute: u32, burst: u32) -> Self {
+        Self {
+            rate: f64::from(requests_per_minute) / 60.0,
+            capacity: f64::from(burst.max(1)),
+            buckets: HashMap::new(),
+            swept_at: Instant::now(),
+        }
+    }
+
+    /// Takes one token from a client's bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - API key or address identifying the client
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the request is allowed, or the time until the next token
+    /// is available.
+    pub fn check(&mut self, client: &str, now: Instant) -> Result<(), Duration> {
+        self.take(client, now, 1.0)
+    }
+
+    /// Reports whether a client could make a request, without taking a
+    /// token or tracking a client that has none taken yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - API key or address identifying the client
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if a token is available, or the time until the next one is.
+    pub fn peek(&mut self, client: &str, now: Instant) -> Result<(), Duration> {
+        if !self.buckets.contains_key(client) {
+            return Ok(());
+        }
+        self.take(client, now, 0.0)
+    }
+
+    /// Refills a client's bucket and takes `cost` tokens if one is available.
+    fn take(&mut self, client: &str, now: Instant, cost: f64) -> Result<(), Duration> {
+        if self.rate == 0.0 {
+            return Ok(());
+        }
+        if now.saturating_duration_since(self.swept_at) >= SWEEP_INTERVAL {
+            self.sweep(now);
+        }
+        let bucket = self.buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            refilled_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= cost;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    /// Drops the buckets that have refilled to capacity by `now`.
+    fn sweep(&mut self, now: Instant) {
+        let (rate, capacity) = (self.rate, self.capacity);
+        self.buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.refilled_at);
+            bucket.tokens + elapsed.as_secs_f64() * rate < capacity
+        });
+        self.swept_at = now;
+    }
+}
+
+/// Tower layer applying a `RateLimiter` to `/v1` routes.
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    /// Limiter shared by every connection
+    limiter: Arc<Mutex<RateLimiter>>,
+    /// Identify clients by API key rather than by address
+    by_key: bool,
+}
+
+impl RateLimitLayer {
+    /// Creates the layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `limiter` - Per-client request budget
+    /// * `by_key` - Identify clients by their API key; only set this when an
+    ///   `ApiKeyLayer` checks the keys first, or clients could pick a fresh key
+    ///   per request
+    pub fn new(limiter: RateLimiter, by_key: bool) -> Self {
+        Self {
+            limiter: Arc::new(Mutex::new(limiter)),
+            by_key,
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> RateLimit<S> {
+        RateLimit {
+            inner,
+            limit: self.clone(),
+        }
+    }
+}
+
+/// Service produced by `RateLimitLayer`.
+#[derive(Debug, Clone)]
+pub struct RateLimit<S> {
+    /// Service answering requests within the limit
+    inner: S,
+    /// Shared limiter
+    limit: RateLimitLayer,
+}
+
+impl<S> Service<HttpRequest> for RateLimit<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse, Error = Infallible>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = Infallible;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> ResponseFuture {
+        if let Route::Method { .. } = Route::of(&request.path) {
+            let client = match request.api_key() {
+                Some(key) if self.limit.by_key => key,
+                _ => &request.peer,
+            };
+            let checked = self
+                .limit
+                .limiter
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .check(client, Instant::now());
+            if let Err(retry_after) = checked {
+                let response = HttpResponse::too_many_requests("Rate limit exceeded", retry_after);
+                return Box::pin(async move { Ok(response) });
+            }
+        }
+        Box::pin(self.inner.call(request))
+    }
+}
+
+/// Tower service answering requests with a `Daemon`.
+///
+/// The daemon runs on tokio's blocking thread pool, answering requests
+/// concurrently; clones share it.
+#[derive(Clone)]
+pub struct DaemonService {
+    /// Daemon answering the methods
+    daemon: Arc<Daemon>,
+}
+
+impl DaemonService {
+    /// Creates the service around a daemon.
+    pub fn new(daemon: Daemon) -> Self {
+        Self {
+            daemon: Arc::new(daemon),
+        }
+    }
+}
+
+impl Service<HttpRequest> for DaemonService {
+    type Response = HttpResponse;
+    type Error = Infallible;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> ResponseFuture {
+        let (method, expected) = match Route::of(&request.path) {
+            Route::Health => {
+                return Box::pin(async { Ok(HttpResponse::json(200, json!({ "status": "ok" }))) });
+            }
+            Route::OpenApi => {
+                return Box::pin(async {
+                    Ok(HttpResponse::json(200, openapi::openapi_document()))
+                });
+            }
+            Route::NotFound => {
+                return Box::pin(async { Ok(HttpResponse::error(404, "Not found")) });
+            }
+            Route::Method { name, expected } => (name, expected),
+        };
+        if request.method != expected {
+            return Box::pin(async { Ok(HttpResponse::error(405, "Method not allowed")) });
+        }
+
+        let params: Value = if request.body.is_empty() {
+            json!({})
+        } else {
+            match serde_json::from_slice(&request.body) {
+                Ok(params) => params,
+                Err(e) => {
+                    let response = HttpResponse::error(400, &e.to_string());
+                    return Box::pin(async move { Ok(response) });
+                }
+            }
+        };
+        let line = if method == "stats" {
+            json!({ "method": method })
+        } else {
+            json!({ "method": method, "params": params })
+        };
+        let daemon = Arc::clone(&self.daemon);
+        Box::pin(async move {
+            let answered =
+                tokio::task::spawn_blocking(move || daemon.handle_line(&line.to_string())).await;
+            Ok(match answered {
+                Ok(response) => match (response.result, response.error) {
+                    (Some(result), _) => HttpResponse::json(200, result),
+                    (None, error) => HttpResponse::error(400, &error.unwrap_or_default()),
+                },
+                Err(e) => HttpResponse::error(500, &e.to_string()),
+            })
+        })
+    }
+}
+
+/// HTTP front end of a `Daemon` with API key checks and rate limiting.
+pub struct Server {
+    /// Bind address and limits
+    config: ServerConfig,
+    /// Middleware stack ending in the daemon
+    service: ApiKey<RateLimit<DaemonService>>,
+}
+
+impl Server {
+    /// Creates a server around a daemon.
+    ///
+    /// The daemon's `generate` method is limited to the configured
+    /// `max_count` and `max_attempts`, and its `solve` method to
+    /// `solve_max_visited` and `solve_timeout_ms`.
+    ///
+    /// # Arguments
+    ///
+    /// * `daemon` - Daemon with a loaded generator
+    /// * `config` - Server settings
+    pub fn new(daemon: Daemon, config: ServerConfig) -> Self {
+        let daemon = daemon
+            .with_generate_limits(config.max_count, config.max_attempts)
+            .with_solve_limits(config.solve_limits());
+        let limiter = RateLimiter::new(config.requests_per_minute, config.burst);
+        let service = ServiceBuilder::new()
+            .layer(ApiKeyLayer::new(
+                config.api_keys.clone(),
+                config.admin_keys.clone(),
+            ))
+            .layer(RateLimitLayer::new(limiter, !config.api_keys.is_empty()))
+            .service(DaemonService::new(daemon));
+        Self { config, service }
+    }
+
+    /// Accepts and answers connections until the process exits.
+    ///
+    /// Starts a tokio runtime and serves each connection on its own task.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Bound listener, e.g. `TcpListener::bind(&config.bind)`
+    pub fn run(self, listener: TcpListener) -> Result<()> {
+        tokio::runtime::Runtime::new()?.block_on(self.serve(listener))
+    }
+
+    /// Accepts connections and spawns a task for each one.
+    async fn serve(self, listener: TcpListener) -> Result<()> {
+        listener.set_nonblocking(true)?;
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        let server = Arc::new(self);
+        let connections = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+        loop {
+            let permit = Arc::clone(&connections).acquire_owned().await?;
+            // Failing to accept one connection (it was reset, or file
+            // descriptors ran out) must not stop the server
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    eprintln!("Failed to accept a connection: {}", e);
+                    tokio::time::sleep(ACCEPT_BACKOFF).await;
+                    continue;
+                }
+            };
+            let server = Arc::clone(&server);
+            tokio::spawn(async move {
+                let peer = peer.ip().to_string();
+                let service = service_fn(|request| {
+                    let server = Arc::clone(&server);
+                    let peer = peer.clone();
+                    async move { Ok::<_, Infallible>(server.answer(request, peer).await) }
+                });
+                // A misbehaving client must not stop the server
+                let _ = http1::Builder::new()
+                    .timer(TokioTimer::new())
+                    .header_read_timeout(READ_TIMEOUT)
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+                drop(permit);
+            });
+        }
+    }
+
+    /// Reads a request's body and answers it.
+    async fn answer(
+        &self,
+        request: hyper::Request<Incoming>,
+        peer: String,
+    ) -> hyper::Response<Full<Bytes>> {
+        let response =
+            match HttpRequest::from_hyper(request, peer, self.config.max_body_bytes).await {
+                Ok(request) => self.handle(request).await,
+                Err(response) => response,
+            };
+        response.into_hyper()
+    }
+
+    /// Answers a parsed request through the middleware stack.
+    ///
+    /// Must be awaited on a tokio runtime, which runs the daemon.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request, with `peer` set to the client address
+    pub async fn handle(&self, request: HttpRequest) -> HttpResponse {
+        match self.service.clone().oneshot(request).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::WordGraph;
+    use crate::puzzle::PuzzleGenerator;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    fn create_test_server(api_keys: &[&str], requests_per_minute: u32) -> Server {
+        let graph = Arc::new(WordGraph::from_words(
+            ["cat", "cot", "cog", "dog"].map(String::from),
+        ));
+        let config = ServerConfig {
+            api_keys: api_keys.iter().map(|key| key.to_string()).collect(),
+            admin_keys: vec!["admin".to_string()],
+            requests_per_minute,
+            burst: 2,
+            max_count: 3,
+            ..ServerConfig::default()
+        };
+        let daemon = Daemon::new(PuzzleGenerator::new(Arc::clone(&graph)))
+            .with_reloader(move || Ok(PuzzleGenerator::new(Arc::clone(&graph))));
+        Server::new(daemon, config)
+    }
+
+    fn respond(server: &Server, request: HttpRequest) -> HttpResponse {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(server.handle(request.with_peer("1.2.3.4")))
+    }
+
+    #[test]
+    fn test_routes_and_auth() {
+        let server = create_test_server(&["k1", "k2"], 0);
+        let solve = HttpRequest::post("/v1/solve", r#"{"start": "cat", "end": "dog"}"#);
+
+        assert_eq!(respond(&server, solve.clone()).status, 401);
+        let wrong = solve.clone().with_header("X-Api-Key", "nope");
+        assert_eq!(respond(&server, wrong).status, 401);
+
+        let ok = solve.with_header("Authorization", "Bearer k2");
+        let response = respond(&server, ok);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["steps"], 3);
+
+        let stats = HttpRequest::get("/v1/stats").with_header("x-api-key", "k1");
+        assert_eq!(respond(&server, stats).status, 200);
+        let wrong_method = HttpRequest::get("/v1/solve").with_header("x-api-key", "k1");
+        assert_eq!(respond(&server, wrong_method).status, 405);
+        assert_eq!(
+            respond(&server, HttpRequest::get("/v1/explode")).status,
+            404
+        );
+        assert_eq!(respond(&server, HttpRequest::get("/health")).status, 200);
+        let openapi = respond(&server, HttpRequest::get("/openapi.json"));
+        assert_eq!(openapi.status, 200);
+        assert!(openapi.body["paths"]["/v1/verify"].is_object());
+    }
+
+    #[test]
+    fn test_reload_requires_admin_key() {
+        let server = create_test_server(&["k1"], 0);
+        let reload = HttpRequest::post("/v1/reload", r#"{"wait": true}"#);
+
+        assert_eq!(respond(&server, reload.clone()).status, 401);
+        let user = reload.clone().with_header("x-api-key", "k1");
+        assert_eq!(respond(&server, user).status, 403);
+        let admin = reload.with_header("x-api-key", "admin");
+        let response = respond(&server, admin);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["reloaded"], true);
+
+        let open = create_test_server(&[], 0);
+        let anonymous = HttpRequest::post("/v1/reload", "{}");
+        assert_eq!(respond(&open, anonymous).status, 403);
+    }
+
+    #[test]
+    fn test_generate_count_is_limited() {
+        let server = create_test_server(&[], 0);
+        let too_many = HttpRequest::post("/v1/generate", r#"{"count": 1000000}"#);
+
+        let response = respond(&server, too_many);
+        assert_eq!(response.status, 400);
+        assert!(
+            response.body["error"]
+                .as_str()
+                .unwrap()
+                .contains("at most 3")
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_per_client() {
+        let server = create_test_server(&[], 60);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let stats = |peer: &str| {
+            runtime.block_on(server.handle(HttpRequest::get("/v1/stats").with_peer(peer)))
+        };
+
+        assert_eq!(stats("1.1.1.1").status, 200);
+        assert_eq!(stats("1.1.1.1").status, 200);
+        let limited = stats("1.1.1.1");
+        assert_eq!(limited.status, 429);
+        assert_eq!(
+            limited.headers,
+            vec![("Retry-After".to_string(), "1".to_string())]
+        );
+        assert_eq!(stats("2.2.2.2").status, 200);
+    }
+
+    #[test]
+    fn test_failed_auth_is_limited_per_client() {
+        let server = create_test_server(&["k1"], 0);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let stats = |peer: &str, key: &str| {
+            let request = HttpRequest::get("/v1/stats")
+                .with_header("x-api-key", key)
+                .with_peer(peer);
+            runtime.block_on(server.handle(request))
+        };
+
+        for _ in 0..AUTH_FAILURE_BURST {
+            assert_eq!(stats("1.1.1.1", "guess").status, 401);
+        }
+        // Further guesses are refused before the key is checked, even a right one
+        let limited = stats("1.1.1.1", "k1");
+        assert_eq!(limited.status, 429);
+        assert!(!limited.headers.is_empty());
+        assert_eq!(stats("2.2.2.2", "k1").status, 200);
+    }
+
+    #[test]
+    fn test_rate_limiter_peek_takes_nothing() {
+        let mut limiter = RateLimiter::new(60, 1);
+        let start = Instant::now();
+
+        assert!(limiter.peek("a", start).is_ok());
+        assert!(limiter.buckets.is_empty());
+        assert!(limiter.check("a", start).is_ok());
+        assert!(limiter.peek("a", start).is_err());
+        assert!(limiter.peek("a", start + Duration::from_secs(1)).is_ok());
+        assert!(limiter.check("a", start + Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_refills() {
+        let mut limiter = RateLimiter::new(60, 1);
+        let start = Instant::now();
+
+        assert!(limiter.check("a", start).is_ok());
+        assert!(limiter.check("a", start).is_err());
+        assert!(limiter.check("a", start + Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_drops_idle_buckets() {
+        let mut limiter = RateLimiter::new(60, 2);
+        let start = Instant::now();
+
+        for client in 0..100 {
+            assert!(limiter.check(&client.to_string(), start).is_ok());
+        }
+        assert_eq!(limiter.buckets.len(), 100);
+        let later = start + SWEEP_INTERVAL * 2;
+        assert!(limiter.check("new", later).is_ok());
+        assert_eq!(limiter.buckets.len(), 1);
+    }
+
+    #[test]
+    fn test_slow_client_does_not_block_others() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = create_test_server(&[], 0);
+        std::thread::spawn(move || server.run(listener));
+
+        // Sends half a request and stalls
+        let mut slow = TcpStream::connect(address).unwrap();
+        slow.write_all(b"GET /health HTTP/1.1\r\nHost: loc")
+            .unwrap();
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        client
+            .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with(r#"{"status":"ok"}"#));
+    }
+}
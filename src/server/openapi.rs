@@ -0,0 +1,315 @@
+//! # OpenAPI Document
+//!
+//! This module describes the HTTP API of the `serve` subcommand as an
+//! OpenAPI 3.0 document, so client teams can generate SDKs from it. The server
+//! returns it at `GET /openapi.json` (without authentication), and
+//! `serve --dump-openapi` prints it without loading a dictionary.
+//!
+//! The document is written out by hand next to the routes in `server::Route`;
+//! update both together when a route or its parameters change.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use wordladder_engine::server::openapi::openapi_document;
+//!
+//! let document = openapi_document();
+//! assert_eq!(document["openapi"], "3.0.3");
+//! assert!(document["paths"]["/v1/solve"]["post"].is_object());
+//! ```
+
+use serde_json::{Value, json};
+
+/// Returns the OpenAPI 3.0 document of the HTTP API.
+pub fn openapi_document() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Word Ladder Engine API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Solve, verify, and generate word ladder puzzles.",
+        },
+        "security": [{ "bearerAuth": [] }, { "apiKeyHeader": [] }],
+        "paths": {
+            "/v1/solve": post_operation(
+                "solve",
+                "Find the shortest ladder between two words",
+                "SolveRequest",
+                "SolveResult",
+            ),
+            "/v1/verify": post_operation(
+                "verify",
+                "Check that a ladder is valid",
+                "VerifyRequest",
+                "VerifyResult",
+            ),
+            "/v1/generate": post_operation(
+                "generate",
+                "Generate random puzzles of one difficulty",
+                "GenerateRequest",
+                "GenerateResult",
+            ),
+            "/v1/reload": admin_only(post_operation(
+                "reload",
+                "Reload the dictionary and base words (admin keys only)",
+                "ReloadRequest",
+                "ReloadResult",
+            )),
+            "/v1/stats": {
+                "get": {
+                    "operationId": "stats",
+                    "summary": "Report dictionary, cache, and server statistics",
+                    "responses": responses("Stats"),
+                },
+            },
+            "/health": {
+                "get": {
+                    "operationId": "health",
+                    "summary": "Liveness check",
+                    "security": [],
+                    "responses": {
+                        "200": json_response("Health"),
+                    },
+                },
+            },
+        },
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+                "apiKeyHeader": { "type": "apiKey", "in": "header", "name": "X-Api-Key" },
+            },
+            "schemas": schemas(),
+        },
+    })
+}
+
+/// Describes a `POST` route taking a JSON request body.
+fn post_operation(operation_id: &str, summary: &str, request: &str, result: &str) -> Value {
+    json!({
+        "post": {
+            "operationId": operation_id,
+            "summary": summary,
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": schema_ref(request) } },
+            },
+            "responses": responses(result),
+        },
+    })
+}
+
+/// Adds the response of a `POST` route that needs an admin key.
+fn admin_only(mut operation: Value) -> Value {
+    operation["post"]["responses"]["403"] = error_response("The API key is not an admin key");
+    operation
+}
+
+/// Describes the responses shared by every authenticated route.
+fn responses(result: &str) -> Value {
+    json!({
+        "200": json_response(result),
+        "400": error_response("Invalid parameters, or the request failed (e.g. no path)"),
+        "401": error_response("Missing or invalid API key"),
+        "429": {
+            "description": "Rate limit exceeded",
+            "headers": {
+                "Retry-After": {
+                    "description": "Seconds until the next request is allowed",
+                    "schema": { "type": "integer" },
+                },
+            },
+            "content": { "application/json": { "schema": schema_ref("Error") } },
+        },
+    })
+}
+
+/// Describes a successful JSON response.
+fn json_response(schema: &str) -> Value {
+    json!({
+        "description": "Success",
+        "content": { "application/json": { "schema": schema_ref(schema) } },
+    })
+}
+
+/// Describes an error response.
+fn error_response(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema_ref("Error") } },
+    })
+}
+
+/// Returns a reference to a component schema.
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+/// Returns the component schemas of request and response bodies.
+fn schemas() -> Value {
+    let words = json!({ "type": "array", "items": { "type": "string" } });
+    json!({
+        "SolveRequest": {
+            "type": "object",
+            "required": ["start", "end"],
+            "properties": {
+                "start": { "type": "string", "example": "cat" },
+                "end": { "type": "string", "example": "dog" },
+                "max_visited": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Most words the search may visit before giving up; at most the server's solve_max_visited (1000000 by default)",
+                },
+                "timeout_ms": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Milliseconds the search may run before giving up; at most the server's solve_timeout_ms (5000 by default)",
+                },
+            },
+        },
+        "SolveResult": {
+            "type": "object",
+            "required": ["path", "steps"],
+            "properties": {
+                "path": words,
+                "steps": { "type": "integer" },
+            },
+        },
+        "VerifyRequest": {
+            "type": "object",
+            "required": ["puzzle"],
+            "properties": {
+                "puzzle": {
+                    "type": "string",
+                    "description": "Comma-separated ladder",
+                    "example": "cat,cot,cog,dog",
+                },
+            },
+        },
+        "VerifyResult": {
+            "type": "object",
+            "required": ["valid"],
+            "properties": { "valid": { "type": "boolean" } },
+        },
+        "GenerateRequest": {
+            "type": "object",
+            "properties": {
+                "difficulty": {
+                    "type": "string",
+                    "enum": ["easy", "medium", "hard"],
+                    "default": "medium",
+                },
+                "count": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "default": 1,
+                    "description": "At most the server's max_count (50 by default)",
+                },
+            },
+        },
+        "GenerateResult": {
+            "type": "object",
+            "required": ["puzzles"],
+            "properties": {
+                "puzzles": { "type": "array", "items": schema_ref("Puzzle") },
+            },
+        },
+        "Puzzle": {
+            "type": "object",
+            "required": ["start", "end", "path", "difficulty"],
+            "properties": {
+                "start": { "type": "string" },
+                "end": { "type": "string" },
+                "path": words,
+                "difficulty": { "type": "string", "enum": ["Easy", "Medium", "Hard"] },
+                "unique_solution": { "type": "boolean" },
+                "reverse_path": words,
+                "stars": {
+                    "type": "object",
+                    "properties": {
+                        "par": { "type": "integer" },
+                        "two_stars": { "type": "integer" },
+                        "three_stars": { "type": "integer" },
+                    },
+                },
+                "meta": { "type": "object" },
+            },
+        },
+        "ReloadRequest": {
+            "type": "object",
+            "properties": {
+                "wait": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Block until the new graph is in place",
+                },
+            },
+        },
+        "ReloadResult": {
+            "type": "object",
+            "properties": {
+                "reloading": { "type": "boolean" },
+                "reloaded": { "type": "boolean" },
+                "dictionary_words": { "type": "integer" },
+            },
+        },
+        "Stats": {
+            "type": "object",
+            "properties": {
+                "dictionary_words": { "type": "integer" },
+                "base_words": { "type": "integer" },
+                "requests_served": { "type": "integer" },
+                "solve_cache": { "type": "object" },
+                "reloads": { "type": "integer" },
+                "reload_pending": { "type": "boolean" },
+                "last_reload_error": { "type": "string", "nullable": true },
+            },
+        },
+        "Health": {
+            "type": "object",
+            "properties": { "status": { "type": "string", "example": "ok" } },
+        },
+        "Error": {
+            "type": "object",
+            "required": ["error"],
+            "properties": { "error": { "type": "string" } },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Collects every `$ref` target in a JSON value.
+    fn refs(value: &Value, found: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value.as_str()) {
+                        ("$ref", Some(target)) => found.push(target.to_string()),
+                        _ => refs(value, found),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|value| refs(value, found)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_every_reference_resolves() {
+        let document = openapi_document();
+        let mut found = Vec::new();
+        refs(&document, &mut found);
+
+        assert!(!found.is_empty());
+        for target in found {
+            let name = target.trim_start_matches("#/components/schemas/");
+            assert!(
+                document["components"]["schemas"][name].is_object(),
+                "{} is not defined",
+                target
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! # WebAssembly Bindings
+//!
+//! This module exposes a JavaScript-friendly API for solving and verifying
+//! word ladders entirely client-side. It is only compiled with the
+//! `wasm-bindgen` feature and never touches the file system: the dictionary
+//! is supplied as a byte buffer (e.g. the body of a `fetch` response).
+//!
+//! ## Building
+//!
+//! ```bash
+//! cargo build --target wasm32-unknown-unknown --features wasm-bindgen --release
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/wordladder_engine.wasm
+//! ```
+//!
+//! ## JavaScript Usage
+//!
+//! ```javascript
+//! import init, { WordLadder } from './pkg/wordladder_engine.js';
+//!
+//! await init();
+//! const bytes = new Uint8Array(await (await fetch('dictionary.txt')).arrayBuffer());
+//! const ladder = new WordLadder(bytes);
+//!
+//! ladder.solve('cat', 'dog');                  // ["cat", "cot", "cog", "dog"]
+//! ladder.verify(['cat', 'cot', 'cog', 'dog']); // true
+//! ```
+
+use crate::graph::WordGraph;
+use crate::puzzle::PuzzleGenerator;
+use wasm_bindgen::prelude::*;
+
+/// A loaded word ladder engine exported to JavaScript as `WordLadder`.
+#[wasm_bindgen(js_name = WordLadder)]
+pub struct WasmWordLadder {
+    generator: PuzzleGenerator,
+}
+
+#[wasm_bindgen(js_class = WordLadder)]
+impl WasmWordLadder {
+    /// Builds the word graph from a UTF-8 dictionary buffer (one word per line).
+    #[wasm_bindgen(constructor)]
+    pub fn new(dictionary: &[u8]) -> Result<WasmWordLadder, JsError> {
+        let content = std::str::from_utf8(dictionary)?;
+        let mut graph = WordGraph::new();
+        graph.load_dictionary_from_str(content);
+        Ok(Self {
+            generator: PuzzleGenerator::new(graph),
+        })
+    }
+
+    /// Returns the shortest ladder from `start` to `end`, or `undefined` if none exists.
+    pub fn solve(&self, start: &str, end: &str) -> Option<Vec<String>> {
+        let graph = self.generator.graph();
+        let normalization = graph.normalization();
+        graph.find_shortest_path(
+            &normalization.normalize_query(start),
+            &normalization.normalize_query(end),
+        )
+    }
+
+    /// Returns whether each consecutive pair of words differs by exactly one letter.
+    pub fn verify(&self, words: Vec<String>) -> Result<bool, JsError> {
+        self.generator
+            .verify_puzzle(&words.join(","))
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Returns the number of words in the loaded dictionary.
+    #[wasm_bindgen(js_name = wordCount)]
+    pub fn word_count(&self) -> usize {
+        self.generator.graph().get_words().len()
+    }
+}